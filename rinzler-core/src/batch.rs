@@ -0,0 +1,26 @@
+// Batching settings for the crawl's webhook sink
+
+use std::time::Duration;
+
+/// Findings collected before a flush unless `--batch-size` says otherwise
+pub const DEFAULT_BATCH_SIZE: usize = 100;
+
+/// Longest a finding waits in a partial batch unless `--flush-interval` says otherwise
+pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// When queued findings go out: once `size` have collected, or once the oldest
+/// has waited `flush_interval`, whichever comes first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchSettings {
+    pub size: usize,
+    pub flush_interval: Duration,
+}
+
+impl Default for BatchSettings {
+    fn default() -> Self {
+        Self {
+            size: DEFAULT_BATCH_SIZE,
+            flush_interval: DEFAULT_FLUSH_INTERVAL,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+// rinzler.toml: defaults for crawl and fuzz that command-line flags override
+
+use crate::crawl::RequestSettings;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE_NAME: &str = "rinzler.toml";
+
+/// Defaults loaded from `rinzler.toml`; every field is optional
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RinzlerConfig {
+    /// Worker count for crawl and fuzz
+    pub threads: Option<usize>,
+    /// Maximum crawl depth
+    pub depth: Option<usize>,
+    /// Milliseconds each worker waits before every request
+    pub delay_ms: Option<u64>,
+    pub user_agent: Option<String>,
+    /// Headers sent with every request
+    pub headers: BTreeMap<String, String>,
+    /// Proxy URL every request is routed through
+    pub proxy: Option<String>,
+}
+
+/// Template written by `rinzler config init`
+pub const DEFAULT_CONFIG: &str = r#"# Rinzler configuration
+#
+# Defaults for `rinzler crawl` and `rinzler fuzz`. Command-line flags always win.
+# Rinzler reads ./rinzler.toml first, then ~/.config/rinzler/rinzler.toml.
+
+# Worker count for crawl and fuzz (flag: --threads)
+# threads = 10
+
+# Maximum crawl depth (flag: --depth)
+# depth = 3
+
+# Milliseconds each worker waits before every request (flag: --delay)
+# delay_ms = 0
+
+# User-Agent sent with every request (flag: --user-agent)
+# user_agent = "Rinzler/0.1 (https://github.com/trapdoorsec/rinzler)"
+
+# Proxy every request is routed through (flag: --proxy)
+# proxy = "http://127.0.0.1:8080"
+
+# Headers sent with every request (flag: --header "Name: value")
+# [headers]
+# X-Engagement-Id = "acme-2024-q3"
+"#;
+
+/// Where `rinzler.toml` is looked for, in order: the working directory, then the config directory
+pub fn config_search_paths(cwd: &Path, config_dir: &Path) -> Vec<PathBuf> {
+    vec![
+        cwd.join(CONFIG_FILE_NAME),
+        config_dir.join(CONFIG_FILE_NAME),
+    ]
+}
+
+pub fn parse_config(text: &str) -> Result<RinzlerConfig, String> {
+    toml::from_str(text).map_err(|e| e.to_string())
+}
+
+/// Load the first config file that exists among `paths`, with the path it came from
+pub fn load_config(paths: &[PathBuf]) -> Result<Option<(PathBuf, RinzlerConfig)>, String> {
+    let Some(path) = paths.iter().find(|path| path.is_file()) else {
+        return Ok(None);
+    };
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let config = parse_config(&text).map_err(|e| format!("Invalid {}: {}", path.display(), e))?;
+    Ok(Some((path.clone(), config)))
+}
+
+/// A command-line value if given, else the config value, else the built-in default
+pub fn resolve<T>(flag: Option<T>, config: Option<T>, default: T) -> T {
+    flag.or(config).unwrap_or(default)
+}
+
+/// Parse a `Name: value` header
+pub fn parse_header(header: &str) -> Result<(String, String), String> {
+    let (name, value) = header
+        .split_once(':')
+        .ok_or_else(|| format!("expected 'Name: value', got '{}'", header))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("missing header name in '{}'", header));
+    }
+    Ok((name.to_string(), value.trim().to_string()))
+}
+
+impl RinzlerConfig {
+    /// Request settings with command-line values layered over the config: a flag
+    /// proxy replaces the config one, and flag headers replace config headers of
+    /// the same (case-insensitive) name
+    pub fn request_settings(
+        &self,
+        headers: &[(String, String)],
+        proxy: Option<String>,
+    ) -> RequestSettings {
+        let mut merged: Vec<(String, String)> = self
+            .headers
+            .iter()
+            .filter(|(name, _)| {
+                !headers
+                    .iter()
+                    .any(|(flag_name, _)| flag_name.eq_ignore_ascii_case(name))
+            })
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        merged.extend(headers.iter().cloned());
+
+        RequestSettings {
+            headers: merged,
+            proxy: proxy.or_else(|| self.proxy.clone()),
+            auth: None,
+        }
+    }
+}
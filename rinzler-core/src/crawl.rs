@@ -1,11 +1,17 @@
 use indicatif::{ProgressBar, ProgressStyle};
-use rinzler_scanner::Crawler;
-use rinzler_scanner::result::CrawlResult;
-use std::collections::{HashMap, HashSet};
+use rinzler_scanner::{ClientConfig, Crawler};
+pub use rinzler_scanner::crawler::{DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_PER_HOST};
+use rinzler_scanner::result::{CrawlResult, CrawlStats};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
 use url::Url;
 
+pub use rinzler_scanner::client::{
+    Auth, DEFAULT_POOL_IDLE_TIMEOUT_SECS, DEFAULT_POOL_SIZE, LoginSettings, RequestSettings,
+};
+
 /// Options for configuring a crawl operation
 pub struct CrawlOptions {
     pub urls: Vec<String>,
@@ -13,6 +19,72 @@ pub struct CrawlOptions {
     pub max_depth: usize,
     pub follow_mode: FollowMode,
     pub show_progress_bars: bool,
+    /// Extra paths resolved against each URL and crawled as additional roots
+    pub entry_paths: Vec<String>,
+    /// Stop the crawl as stalled after this many seconds without a completed page
+    pub idle_timeout_secs: Option<u64>,
+    /// Stop fetching new pages once this many have been started
+    pub max_pages: Option<usize>,
+    /// Drop discovered links once this many URLs are waiting in the worker queues
+    pub max_queue_size: Option<usize>,
+    /// Skip discovered links whose path has more than this many segments
+    pub max_path_depth: Option<usize>,
+    /// Domain treated as same-domain scope instead of the seed's registrable domain
+    pub scope_domain: Option<String>,
+    /// Sent instead of the default Rinzler User-Agent
+    pub user_agent: Option<String>,
+    /// Extra headers and proxy applied to every request
+    pub request: RequestSettings,
+    /// Milliseconds each worker waits before every request
+    pub request_delay_ms: Option<u64>,
+    /// Form login performed before the crawl; its session cookies are sent with every request
+    pub login: Option<LoginSettings>,
+    /// Seed the first URL's crawl from this page (at depth 0) instead of its base URL
+    pub resume_from_url: Option<String>,
+    /// Simultaneous requests allowed to any one host
+    pub max_per_host: usize,
+    /// Response body bytes read per page; larger pages are recorded as truncated
+    pub max_body_bytes: usize,
+    /// Record binary content types (images, PDFs, archives) without downloading the body
+    pub skip_binary_bodies: bool,
+    /// Idle connections kept open per host
+    pub pool_size: usize,
+    /// Seconds an idle pooled connection is kept alive
+    pub pool_idle_timeout_secs: u64,
+    /// While set, crawl workers hold off taking new URLs (e.g. toggled from the TUI)
+    pub paused: Option<Arc<AtomicBool>>,
+    /// Once set, workers finish their current page and the crawl returns what it has
+    pub stop: Option<Arc<AtomicBool>>,
+    /// Fetch with this client, e.g. from `build_crawl_client` and shared with the CORS
+    /// probes, instead of building one; it should already hold any login session
+    pub client: Option<reqwest::Client>,
+}
+
+/// Settings of the client a crawl with these options fetches with
+pub fn crawl_client_config(options: &CrawlOptions) -> ClientConfig {
+    let mut config = ClientConfig {
+        pool_size: options.pool_size,
+        pool_idle_timeout_secs: options.pool_idle_timeout_secs,
+        request: options.request.clone(),
+        cookies: true,
+        ..ClientConfig::default()
+    };
+    if let Some(ref user_agent) = options.user_agent {
+        config.user_agent = user_agent.clone();
+    }
+    config
+}
+
+/// Build the client a crawl with these options fetches with, logging in first when
+/// `login` is set, so other requests made for the crawl carry the same settings
+pub async fn build_crawl_client(options: &CrawlOptions) -> Result<reqwest::Client, String> {
+    let config = crawl_client_config(options);
+    match options.login {
+        Some(ref login) => rinzler_scanner::establish_session(&config, login)
+            .await
+            .map_err(|e| format!("Login failed: {}", e)),
+        None => rinzler_scanner::build_client(&config).map_err(|e| e.to_string()),
+    }
 }
 
 /// Cross-domain following behavior
@@ -23,8 +95,13 @@ pub enum FollowMode {
     Prompt,
     /// Automatically follow all cross-domain links
     Auto,
+    /// Ask through the callback for each new cross-domain, e.g. a TUI modal
+    PromptWith(FollowPrompt),
 }
 
+/// Asked once per new cross-domain in `FollowMode::PromptWith`; returns whether to follow it
+pub type FollowPrompt = Arc<dyn Fn(&str) -> bool + Send + Sync>;
+
 /// Callback for reporting crawl progress
 pub type CrawlProgressCallback = Arc<dyn Fn(String) + Send + Sync>;
 
@@ -46,6 +123,84 @@ pub fn extract_url_path(url: &str) -> String {
         .unwrap_or_else(|| url.to_string())
 }
 
+/// Build the list of crawl roots: the base URL followed by each entry path resolved against it
+pub fn resolve_entry_paths(base_url: &str, entry_paths: &[String]) -> Result<Vec<String>, String> {
+    let base = Url::parse(base_url).map_err(|e| format!("Invalid URL {}: {}", base_url, e))?;
+
+    let mut roots = vec![base_url.to_string()];
+    for entry_path in entry_paths {
+        let resolved = base
+            .join(entry_path)
+            .map_err(|e| format!("Invalid entry path {}: {}", entry_path, e))?
+            .to_string();
+        if !roots.contains(&resolved) {
+            roots.push(resolved);
+        }
+    }
+
+    Ok(roots)
+}
+
+/// Replace the base URL (the first root) with `resume_url`, keeping the other roots
+pub fn resume_roots(roots: &[String], resume_url: &str) -> Vec<String> {
+    let mut resumed = vec![resume_url.to_string()];
+    for root in roots.iter().skip(1) {
+        if !resumed.contains(root) {
+            resumed.push(root.clone());
+        }
+    }
+    resumed
+}
+
+/// URLs processed per crawler worker, used to spot starved or overloaded workers
+#[derive(Debug, Clone, Default)]
+pub struct WorkerStats {
+    counts: BTreeMap<usize, usize>,
+}
+
+impl WorkerStats {
+    /// Track `workers` workers so idle ones are reported with a count of zero
+    pub fn new(workers: usize) -> Self {
+        Self {
+            counts: (0..workers).map(|id| (id, 0)).collect(),
+        }
+    }
+
+    pub fn record(&mut self, worker_id: usize) {
+        *self.counts.entry(worker_id).or_insert(0) += 1;
+    }
+
+    /// Per-worker counts keyed by worker id
+    pub fn counts(&self) -> &BTreeMap<usize, usize> {
+        &self.counts
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// One-line summary, e.g. `Worker stats: 5 URLs across 2 workers (min 2, max 3) [#0: 3, #1: 2]`
+    pub fn summary(&self) -> String {
+        let min = self.counts.values().min().copied().unwrap_or(0);
+        let max = self.counts.values().max().copied().unwrap_or(0);
+        let per_worker = self
+            .counts
+            .iter()
+            .map(|(id, count)| format!("#{}: {}", id, count))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            "Worker stats: {} URLs across {} workers (min {}, max {}) [{}]",
+            self.total(),
+            self.counts.len(),
+            min,
+            max,
+            per_worker
+        )
+    }
+}
+
 /// Execute a crawl with the given options
 /// Returns the crawl results
 pub async fn execute_crawl(
@@ -53,12 +208,43 @@ pub async fn execute_crawl(
     progress_callback: Option<CrawlProgressCallback>,
     result_callback: Option<CrawlResultCallback>,
 ) -> Result<Vec<CrawlResult>, String> {
+    execute_crawl_with_stats(options, progress_callback, result_callback)
+        .await
+        .map(|(results, _)| results)
+}
+
+/// Execute a crawl with the given options
+/// Returns the crawl results along with coverage metrics
+pub async fn execute_crawl_with_stats(
+    options: CrawlOptions,
+    progress_callback: Option<CrawlProgressCallback>,
+    result_callback: Option<CrawlResultCallback>,
+) -> Result<(Vec<CrawlResult>, CrawlStats), String> {
     let CrawlOptions {
         urls,
         threads,
         max_depth,
         follow_mode,
         show_progress_bars,
+        entry_paths,
+        idle_timeout_secs,
+        max_pages,
+        max_queue_size,
+        max_path_depth,
+        scope_domain,
+        resume_from_url,
+        user_agent,
+        request,
+        request_delay_ms,
+        login,
+        max_per_host,
+        max_body_bytes,
+        skip_binary_bodies,
+        pool_size,
+        pool_idle_timeout_secs,
+        paused,
+        stop,
+        client,
     } = options;
 
     // Set up single progress bar for overall crawl progress (only if enabled)
@@ -78,18 +264,22 @@ pub async fn execute_crawl(
     // Counter for tracking processed URLs
     let processed_count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
 
-    // Progress callback for worker updates (only if progress bars enabled)
-    let internal_progress_callback: rinzler_scanner::ProgressCallback = if show_progress_bars {
-        let pb_clone = progress_bar.clone().unwrap();
+    // URLs processed by each worker, summarized once the crawl completes
+    let worker_stats = Arc::new(StdMutex::new(WorkerStats::new(threads)));
+
+    // Progress callback for worker updates (progress bar only if enabled)
+    let internal_progress_callback: rinzler_scanner::ProgressCallback = {
+        let pb_clone = progress_bar.clone();
         let count_clone = processed_count.clone();
-        Arc::new(move |_worker_id: usize, _url: String| {
-            let count = count_clone.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
-            pb_clone.set_message(format!("Crawling... {} URLs processed", count));
-            pb_clone.tick();
+        let worker_stats_clone = worker_stats.clone();
+        Arc::new(move |worker_id: usize, _url: String| {
+            worker_stats_clone.lock().unwrap().record(worker_id);
+            if let Some(ref pb) = pb_clone {
+                let count = count_clone.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+                pb.set_message(format!("Crawling... {} URLs processed", count));
+                pb.tick();
+            }
         })
-    } else {
-        // No-op callback when progress bars are disabled
-        Arc::new(|_worker_id: usize, _url: String| {})
     };
 
     // Cross-domain callback (changes behavior based on follow_mode)
@@ -98,10 +288,14 @@ pub async fn execute_crawl(
             // Auto-follow mode: always accept cross-domain links
             Arc::new(|_url: String, _base: String| -> bool { true })
         }
-        FollowMode::Prompt => {
-            // Prompt mode: ask user and remember decisions
-            // Note: This only works when show_progress_bars is true (CLI mode)
-            // In TUI mode, use FollowMode::Disabled instead
+        FollowMode::Prompt | FollowMode::PromptWith(_) => {
+            // Prompt mode: ask user and remember decisions. Plain Prompt reads stdin,
+            // which only works when show_progress_bars is true (CLI mode); a TUI
+            // supplies its own prompt through PromptWith
+            let prompt = match follow_mode {
+                FollowMode::PromptWith(prompt) => Some(prompt),
+                _ => None,
+            };
             let domain_decisions: Arc<StdMutex<(HashSet<String>, HashSet<String>)>> =
                 Arc::new(StdMutex::new((HashSet::new(), HashSet::new())));
 
@@ -128,7 +322,9 @@ pub async fn execute_crawl(
                 }
 
                 // Not in either set - ask the user (only if progress bar is available)
-                let result = if let Some(ref pb) = pb_clone {
+                let result = if let Some(ref prompt) = prompt {
+                    prompt(&domain)
+                } else if let Some(ref pb) = pb_clone {
                     pb.suspend(|| {
                         print!(
                             "\n[!] Cross-domain link detected: {}\nFollow this link? [y/N]: ",
@@ -143,7 +339,7 @@ pub async fn execute_crawl(
                         response == "y" || response == "yes"
                     })
                 } else {
-                    // No progress bars available (TUI mode) - deny by default
+                    // No prompt or progress bar to ask through - deny by default
                     false
                 };
 
@@ -165,11 +361,55 @@ pub async fn execute_crawl(
 
     // Create crawler with callbacks
     let mut crawler = Crawler::new()
+        .with_pool_settings(pool_size, pool_idle_timeout_secs)
+        .map_err(|e| e.to_string())?
+        .with_request_settings(request)
+        .map_err(|e| e.to_string())?
         .with_max_depth(max_depth)
+        .with_max_per_host(max_per_host)
+        .with_max_body_bytes(max_body_bytes)
+        .with_skip_binary_bodies(skip_binary_bodies)
         .with_auto_follow(false) // We handle cross-domain logic in the callback now
         .with_progress_callback(internal_progress_callback)
         .with_cross_domain_callback(cross_domain_callback);
 
+    if let Some(secs) = idle_timeout_secs {
+        crawler = crawler.with_idle_timeout(std::time::Duration::from_secs(secs));
+    }
+    if let Some(max_pages) = max_pages {
+        crawler = crawler.with_max_pages(max_pages);
+    }
+    if let Some(max_queue_size) = max_queue_size {
+        crawler = crawler.with_max_queue_size(max_queue_size);
+    }
+    if let Some(max_path_depth) = max_path_depth {
+        crawler = crawler.with_max_path_depth(max_path_depth);
+    }
+    if let Some(user_agent) = user_agent {
+        crawler = crawler.with_user_agent(user_agent).map_err(|e| e.to_string())?;
+    }
+    if let Some(delay_ms) = request_delay_ms {
+        crawler = crawler.with_request_delay(std::time::Duration::from_millis(delay_ms));
+    }
+    if let Some(domain) = scope_domain {
+        crawler = crawler.with_base_domain(domain);
+    }
+    if let Some(paused) = paused {
+        crawler = crawler.with_pause_flag(paused);
+    }
+    if let Some(ref stop) = stop {
+        crawler = crawler.with_stop_flag(stop.clone());
+    }
+    // Last, since a supplied or logged-in client replaces the one the builders above configured
+    if let Some(client) = client {
+        crawler = crawler.with_client(client);
+    } else if let Some(ref login) = login {
+        let client = rinzler_scanner::establish_session(crawler.client_config(), login)
+            .await
+            .map_err(|e| format!("Login failed: {}", e))?;
+        crawler = crawler.with_client(client);
+    }
+
     // Add result callback if provided (converts CrawlResultCallback to ResultCallback)
     if let Some(ref cb) = result_callback {
         let cb_clone = cb.clone();
@@ -182,6 +422,9 @@ pub async fn execute_crawl(
     // Crawl each URL
     let mut all_results = Vec::new();
     for (idx, url_str) in urls.iter().enumerate() {
+        if stop.as_ref().is_some_and(|stop| stop.load(Ordering::Relaxed)) {
+            break;
+        }
         if let Some(ref callback) = progress_callback
             && urls.len() > 1
         {
@@ -193,7 +436,14 @@ pub async fn execute_crawl(
             ));
         }
 
-        match crawler.crawl(url_str, threads).await {
+        let mut roots = resolve_entry_paths(url_str, &entry_paths)?;
+        if idx == 0
+            && let Some(ref resume_url) = resume_from_url
+        {
+            roots = resume_roots(&roots, resume_url);
+        }
+
+        match crawler.crawl_from(&roots, threads).await {
             Ok(results) => {
                 all_results.extend(results);
             }
@@ -211,11 +461,22 @@ pub async fn execute_crawl(
         pb.finish_with_message(format!("Crawl complete! {} URLs processed", total));
     }
 
-    Ok(all_results)
+    if let Some(ref callback) = progress_callback {
+        callback(worker_stats.lock().unwrap().summary());
+    }
+
+    let stats = crawler.get_stats().await;
+    Ok((all_results, stats))
 }
 
-/// Generate a crawl report from results
+/// Generate a plain crawl report from results, safe to write to a file
 pub fn generate_crawl_report(results: &[CrawlResult]) -> String {
+    generate_crawl_report_with_color(results, false)
+}
+
+/// Generate the crawl report, optionally coloring status codes and MIME types
+/// with ANSI escapes. Color should only be enabled when stdout is a terminal.
+pub fn generate_crawl_report_with_color(results: &[CrawlResult], color: bool) -> String {
     // Filter out 404s
     let filtered_results: Vec<&CrawlResult> =
         results.iter().filter(|r| r.status_code != 404).collect();
@@ -257,11 +518,11 @@ pub fn generate_crawl_report(results: &[CrawlResult]) -> String {
 
             // Color code based on status
             let status_str = match result.status_code {
-                100..=199 => format!("\x1b[37m{}\x1b[0m", result.status_code), // White
-                200..=299 => format!("\x1b[32m{}\x1b[0m", result.status_code), // Green
-                300..=399 => format!("\x1b[36m{}\x1b[0m", result.status_code), // Cyan
-                400..=499 => format!("\x1b[33m{}\x1b[0m", result.status_code), // Orange/Yellow
-                500..=599 => format!("\x1b[31m{}\x1b[0m", result.status_code), // Red
+                100..=199 if color => format!("\x1b[37m{}\x1b[0m", result.status_code), // White
+                200..=299 if color => format!("\x1b[32m{}\x1b[0m", result.status_code), // Green
+                300..=399 if color => format!("\x1b[36m{}\x1b[0m", result.status_code), // Cyan
+                400..=499 if color => format!("\x1b[33m{}\x1b[0m", result.status_code), // Orange/Yellow
+                500..=599 if color => format!("\x1b[31m{}\x1b[0m", result.status_code), // Red
                 _ => format!("{}", result.status_code),
             };
 
@@ -272,7 +533,11 @@ pub fn generate_crawl_report(results: &[CrawlResult]) -> String {
             if let Some(ref content_type) = result.content_type
                 && content_type != "text/html"
             {
-                line.push_str(&format!(" \x1b[90m{}\x1b[0m", content_type));
+                if color {
+                    line.push_str(&format!(" \x1b[90m{}\x1b[0m", content_type));
+                } else {
+                    line.push_str(&format!(" {}", content_type));
+                }
             }
 
             report.push_str(&line);
@@ -1,5 +1,7 @@
-use rusqlite::{Connection, OptionalExtension, Result, params};
+use crate::fuzz::{FuzzHit, FuzzResult, FuzzSource};
+use rusqlite::{Connection, OptionalExtension, Result, Statement, params};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
 use std::fs;
 use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -27,6 +29,32 @@ impl Severity {
             Severity::Info => "info",
         }
     }
+
+    /// Rank from info (0) up to critical (4), for threshold comparisons
+    pub fn rank(&self) -> u8 {
+        match self {
+            Severity::Critical => 4,
+            Severity::High => 3,
+            Severity::Medium => 2,
+            Severity::Low => 1,
+            Severity::Info => 0,
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "critical" => Ok(Severity::Critical),
+            "high" => Ok(Severity::High),
+            "medium" => Ok(Severity::Medium),
+            "low" => Ok(Severity::Low),
+            "info" => Ok(Severity::Info),
+            _ => Err(format!("Unknown severity: {}", s)),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -60,7 +88,29 @@ impl FindingType {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// How sure a check is that its finding is real
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Confidence {
+    /// Directly observed in the response (e.g. a .env file returned 200)
+    Confirmed,
+    Likely,
+    /// A candidate that needs manual or active verification
+    Possible,
+    FalsePositive,
+}
+
+impl Confidence {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Confidence::Confirmed => "confirmed",
+            Confidence::Likely => "likely",
+            Confidence::Possible => "possible",
+            Confidence::FalsePositive => "false_positive",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ServiceType {
     Web,
     RestApi,
@@ -85,6 +135,30 @@ impl ServiceType {
     }
 }
 
+/// How one node links to another in the site graph
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeType {
+    Navigation,
+    Reference,
+    Redirect,
+    FormAction,
+    ApiCall,
+    Resource,
+}
+
+impl EdgeType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EdgeType::Navigation => "navigation",
+            EdgeType::Reference => "reference",
+            EdgeType::Redirect => "redirect",
+            EdgeType::FormAction => "form_action",
+            EdgeType::ApiCall => "api_call",
+            EdgeType::Resource => "resource",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CrawlNode {
     pub url: String,
@@ -95,9 +169,46 @@ pub struct CrawlNode {
     pub response_time_ms: Option<u64>,
     pub title: Option<String>,
     pub forms_count: usize,
+    pub inputs_count: usize,
+    pub parameters: Option<String>, // JSON array of parameter names
     pub service_type: Option<ServiceType>,
     pub headers: Option<String>, // JSON
     pub body_sample: Option<String>,
+    /// Hex SHA-256 of the response body
+    pub content_hash: Option<String>,
+}
+
+/// A stored node as shown by lookups such as the REPL `node` command
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeDetails {
+    pub id: i64,
+    pub url: String,
+    pub status_code: Option<i64>,
+    pub content_type: Option<String>,
+    pub service_type: Option<String>,
+    pub title: Option<String>,
+}
+
+/// The last crawl of a URL in an earlier session, for re-crawl change detection
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviousNode {
+    pub session_id: String,
+    pub status_code: Option<i64>,
+    pub content_length: Option<i64>,
+    pub content_hash: Option<String>,
+}
+
+/// A logged request and the response it got
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpTransaction {
+    pub method: String,
+    pub url: String,
+    pub request_headers: Vec<(String, String)>,
+    pub request_body: Option<String>,
+    pub response_code: u16,
+    pub response_headers: Vec<(String, String)>,
+    /// Body hash of the node the transaction belongs to, if one was recorded
+    pub content_hash: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +223,70 @@ pub struct Finding {
     pub evidence: Option<String>, // JSON
     pub cwe_id: Option<String>,
     pub owasp_category: Option<String>,
+    /// CVSS v3.1 base score
+    pub cvss_score: Option<f32>,
+    pub confidence: Confidence,
+    /// The HTTP request that produced the finding, as raw text
+    pub request_sample: Option<String>,
+    /// Status line, headers and the start of the response body
+    pub response_sample: Option<String>,
+}
+
+/// A stored finding as listed by `findings list`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FindingSummary {
+    pub id: i64,
+    pub severity: String,
+    pub finding_type: String,
+    pub title: String,
+    pub url: String,
+}
+
+/// Which sessions `purge_sessions` deletes; every set condition must match
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionPurgeFilter {
+    /// Sessions started before this Unix timestamp
+    pub before: Option<i64>,
+    /// Sessions with this status (running, completed, failed, cancelled)
+    pub status: Option<String>,
+}
+
+/// A passive-check plugin registered with `rinzler plugin register`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plugin {
+    pub name: String,
+    pub path: String,
+    pub registered_at: i64,
+}
+
+/// Parse a `YYYY-MM-DD` date (midnight UTC) or an RFC 3339 timestamp into Unix seconds
+pub fn parse_date_timestamp(date: &str) -> std::result::Result<i64, String> {
+    if let Ok(day) = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        return Ok(day.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp());
+    }
+    chrono::DateTime::parse_from_rfc3339(date)
+        .map(|time| time.timestamp())
+        .map_err(|_| {
+            format!(
+                "expected YYYY-MM-DD or an RFC 3339 timestamp, got '{}'",
+                date
+            )
+        })
+}
+
+/// Parse stored headers (a JSON array of `[name, value]` pairs)
+fn header_pairs(headers: Option<String>) -> Vec<(String, String)> {
+    headers
+        .and_then(|json| serde_json::from_str::<Vec<(String, String)>>(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Convert stored headers into HAR name/value objects
+fn har_headers(headers: Option<String>) -> Vec<serde_json::Value> {
+    header_pairs(headers)
+        .into_iter()
+        .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+        .collect()
 }
 
 fn current_timestamp() -> i64 {
@@ -121,37 +296,127 @@ fn current_timestamp() -> i64 {
         .as_secs() as i64
 }
 
-impl Database {
-    pub fn drop(path: &Path) {
-        fs::remove_file(path).unwrap();
-    }
-    pub fn exists(path: &Path) -> bool {
-        path.exists()
-    }
-    pub fn new(path: &Path) -> Result<Self> {
-        let conn = Connection::open(path)?;
+const INSERT_NODE_SQL: &str = "INSERT INTO nodes (
+    map_id, url, domain, node_type, status, depth, discovered_at,
+    last_crawled, response_code, response_time_ms, content_type,
+    content_length, title, forms_count, service_type, headers, body_sample,
+    content_hash, inputs_count, parameters
+) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20)";
+
+const INSERT_FINDING_SQL: &str = "INSERT INTO findings (
+    session_id, node_id, finding_type, severity, confidence,
+    title, description, impact, remediation, evidence,
+    cwe_id, owasp_category, discovered_at, cvss_score, request_sample, response_sample
+) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)";
+
+fn insert_node_with(stmt: &mut Statement<'_>, map_id: &str, node: &CrawlNode) -> Result<i64> {
+    let timestamp = current_timestamp();
+    let service_type_str = node.service_type.as_ref().map(|st| st.as_str());
+
+    stmt.insert(params![
+        map_id,
+        &node.url,
+        &node.domain,
+        "endpoint",
+        "crawled",
+        0,
+        timestamp,
+        timestamp,
+        node.status_code,
+        node.response_time_ms,
+        &node.content_type,
+        node.content_length.map(|l| l as i64),
+        &node.title,
+        node.forms_count as i64,
+        service_type_str,
+        &node.headers,
+        &node.body_sample,
+        &node.content_hash,
+        node.inputs_count as i64,
+        &node.parameters,
+    ])
+}
 
-        // Optimize for concurrent writes
-        conn.execute_batch(
-            "
-            PRAGMA journal_mode = WAL;
-            PRAGMA synchronous = NORMAL;
-            PRAGMA cache_size = -64000;  -- 64MB cache
-            PRAGMA temp_store = MEMORY;
-            PRAGMA foreign_keys = ON;
-            ",
-        )?;
+fn insert_finding_with(stmt: &mut Statement<'_>, session_id: &str, finding: &Finding) -> Result<i64> {
+    stmt.insert(params![
+        session_id,
+        finding.node_id,
+        finding.finding_type.as_str(),
+        finding.severity.as_str(),
+        finding.confidence.as_str(),
+        &finding.title,
+        &finding.description,
+        &finding.impact,
+        &finding.remediation,
+        &finding.evidence,
+        &finding.cwe_id,
+        &finding.owasp_category,
+        current_timestamp(),
+        finding.cvss_score,
+        &finding.request_sample,
+        &finding.response_sample,
+    ])
+}
 
-        let db = Database { conn };
-        db.init_schema()?;
-        Ok(db)
-    }
+/// Ordered schema migrations; entry N upgrades a version N database to N + 1.
+/// Append new migrations, never edit an existing one.
+const MIGRATIONS: &[&str] = &[
+    SCHEMA_V1, SCHEMA_V2, SCHEMA_V3, SCHEMA_V4, SCHEMA_V5, SCHEMA_V6,
+];
 
-    fn init_schema(&self) -> Result<()> {
-        self.conn.execute_batch(
-            "
-            -- Scan sessions
-            CREATE TABLE IF NOT EXISTS crawl_sessions (
+/// Schema version a freshly opened database is migrated to
+pub const SCHEMA_VERSION: i64 = MIGRATIONS.len() as i64;
+
+/// Responses each fuzz session reported or expanded, re-reported and
+/// re-expanded when an interrupted run is resumed
+const SCHEMA_V6: &str = "
+CREATE TABLE IF NOT EXISTS fuzz_hits (
+    session_id TEXT NOT NULL,
+    url TEXT NOT NULL,
+    status_code INTEGER NOT NULL,
+    content_length INTEGER,
+    content_type TEXT,
+    source TEXT NOT NULL,
+    depth INTEGER NOT NULL,
+    PRIMARY KEY(session_id, url),
+    FOREIGN KEY(session_id) REFERENCES crawl_sessions(id) ON DELETE CASCADE
+);
+";
+
+/// Earlier crawls of a URL are looked up for every stored page
+const SCHEMA_V5: &str = "
+CREATE INDEX IF NOT EXISTS idx_nodes_url ON nodes(url);
+";
+
+/// Passive-check plugins run alongside the built-in checks
+const SCHEMA_V4: &str = "
+CREATE TABLE IF NOT EXISTS plugins (
+    name TEXT PRIMARY KEY,
+    path TEXT NOT NULL,
+    registered_at INTEGER NOT NULL
+);
+";
+
+/// URLs each fuzz session has requested, so an interrupted run can be resumed
+const SCHEMA_V3: &str = "
+CREATE TABLE IF NOT EXISTS fuzz_tested_urls (
+    session_id TEXT NOT NULL,
+    url TEXT NOT NULL,
+    tested_at INTEGER NOT NULL,
+    PRIMARY KEY(session_id, url),
+    FOREIGN KEY(session_id) REFERENCES crawl_sessions(id) ON DELETE CASCADE
+);
+";
+
+/// Track findings dropped by a --max-findings cap
+const SCHEMA_V2: &str = "
+ALTER TABLE crawl_sessions ADD COLUMN findings_dropped INTEGER NOT NULL DEFAULT 0;
+";
+
+/// Initial schema (tables created with IF NOT EXISTS so pre-versioning databases upgrade cleanly)
+const SCHEMA_V1: &str = "
+-- Scan sessions
+CREATE TABLE IF NOT EXISTS crawl_sessions (
     id TEXT PRIMARY KEY,
     start_time INTEGER NOT NULL,
     end_time INTEGER,
@@ -380,9 +645,70 @@ CREATE TABLE IF NOT EXISTS http_transactions (
 CREATE INDEX IF NOT EXISTS idx_http_transactions_session ON http_transactions(session_id);
 CREATE INDEX IF NOT EXISTS idx_http_transactions_node ON http_transactions(node_id);
 CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(timestamp);
+";
+
+impl Database {
+    /// Delete the database file; a missing file is not an error
+    pub fn drop(path: &Path) -> std::io::Result<()> {
+        match fs::remove_file(path) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+    pub fn exists(path: &Path) -> bool {
+        path.exists()
+    }
+    pub fn new(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)?;
+
+        // Optimize for concurrent writes
+        conn.execute_batch(
+            "
+            PRAGMA journal_mode = WAL;
+            PRAGMA synchronous = NORMAL;
+            PRAGMA cache_size = -64000;  -- 64MB cache
+            PRAGMA temp_store = MEMORY;
+            PRAGMA foreign_keys = ON;
             ",
         )?;
-        Ok(())
+
+        let db = Database { conn };
+        db.run_migrations()?;
+        Ok(db)
+    }
+
+    /// Apply any pending schema migrations in a single transaction
+    pub fn run_migrations(&self) -> Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS schema_version (
+                version INTEGER PRIMARY KEY,
+                applied_at INTEGER NOT NULL
+            );",
+        )?;
+
+        let current = self.schema_version()?;
+        if current >= SCHEMA_VERSION {
+            return Ok(());
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        for (idx, migration) in MIGRATIONS.iter().enumerate().skip(current as usize) {
+            tx.execute_batch(migration)?;
+            tx.execute(
+                "INSERT INTO schema_version (version, applied_at) VALUES (?1, ?2)",
+                params![idx as i64 + 1, current_timestamp()],
+            )?;
+        }
+        tx.commit()
+    }
+
+    /// The most recently applied migration (0 for a database that predates versioning)
+    pub fn schema_version(&self) -> Result<i64> {
+        self.conn.query_row(
+            "SELECT COALESCE(MAX(version), 0) FROM schema_version",
+            [],
+            |row| row.get(0),
+        )
     }
 
     // Session management
@@ -398,6 +724,19 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(session_id)
     }
 
+    /// All session ids, most recent first
+    pub fn list_sessions(&self) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id FROM crawl_sessions ORDER BY start_time DESC, id")?;
+
+        let ids = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ids)
+    }
+
     pub fn complete_session(&self, session_id: &str) -> Result<()> {
         let timestamp = current_timestamp();
         self.conn.execute(
@@ -407,6 +746,45 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(())
     }
 
+    /// Record how many findings a session discarded after hitting its findings cap
+    pub fn set_findings_dropped(&self, session_id: &str, dropped: i64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE crawl_sessions SET findings_dropped = ?1 WHERE id = ?2",
+            params![dropped, session_id],
+        )?;
+        Ok(())
+    }
+
+    /// Mark a session the user stopped before it finished
+    pub fn cancel_session(&self, session_id: &str) -> Result<()> {
+        let timestamp = current_timestamp();
+        self.conn.execute(
+            "UPDATE crawl_sessions SET status = ?1, end_time = ?2 WHERE id = ?3",
+            params!["cancelled", timestamp, session_id],
+        )?;
+        Ok(())
+    }
+
+    /// Scan type of a session (`crawl`, `fuzz` or `manual`), or None if there is no such session
+    pub fn get_session_scan_type(&self, session_id: &str) -> Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT scan_type FROM crawl_sessions WHERE id = ?1",
+                params![session_id],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    /// Mark a stopped session as running again so it can be continued
+    pub fn resume_session(&self, session_id: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE crawl_sessions SET status = ?1, end_time = NULL WHERE id = ?2",
+            params!["running", session_id],
+        )?;
+        Ok(())
+    }
+
     pub fn fail_session(&self, session_id: &str) -> Result<()> {
         let timestamp = current_timestamp();
         self.conn.execute(
@@ -416,6 +794,20 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(())
     }
 
+    /// Delete the sessions matching `filter` along with their maps, nodes and
+    /// findings, then VACUUM to reclaim the space; returns how many were deleted
+    pub fn purge_sessions(&self, filter: &SessionPurgeFilter) -> Result<usize> {
+        let deleted = self.conn.execute(
+            "DELETE FROM crawl_sessions
+             WHERE (?1 IS NULL OR start_time < ?1) AND (?2 IS NULL OR status = ?2)",
+            params![filter.before, filter.status],
+        )?;
+        if deleted > 0 {
+            self.conn.execute_batch("VACUUM")?;
+        }
+        Ok(deleted)
+    }
+
     // Map management
     pub fn create_map(&self, session_id: &str) -> Result<String> {
         let map_id = uuid::Uuid::new_v4().to_string();
@@ -431,37 +823,23 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
 
     // Node operations
     pub fn insert_node(&self, map_id: &str, node: &CrawlNode) -> Result<i64> {
-        let timestamp = current_timestamp();
-        let service_type_str = node.service_type.as_ref().map(|st| st.as_str());
-
-        self.conn.execute(
-            "INSERT INTO nodes (
-                map_id, url, domain, node_type, status, depth, discovered_at,
-                last_crawled, response_code, response_time_ms, content_type,
-                content_length, title, forms_count, service_type, headers, body_sample
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
-            params![
-                map_id,
-                &node.url,
-                &node.domain,
-                "endpoint",
-                "crawled",
-                0,
-                timestamp,
-                timestamp,
-                node.status_code,
-                node.response_time_ms,
-                &node.content_type,
-                node.content_length.map(|l| l as i64),
-                &node.title,
-                node.forms_count as i64,
-                service_type_str,
-                &node.headers,
-                &node.body_sample,
-            ],
-        )?;
+        let mut stmt = self.conn.prepare_cached(INSERT_NODE_SQL)?;
+        insert_node_with(&mut stmt, map_id, node)
+    }
 
-        Ok(self.conn.last_insert_rowid())
+    /// Insert several nodes in one transaction, reusing a single prepared statement.
+    /// Returns the new node ids in the order of `nodes`.
+    pub fn insert_nodes_batch(&self, map_id: &str, nodes: &[CrawlNode]) -> Result<Vec<i64>> {
+        let tx = self.conn.unchecked_transaction()?;
+        let ids = {
+            let mut stmt = tx.prepare_cached(INSERT_NODE_SQL)?;
+            nodes
+                .iter()
+                .map(|node| insert_node_with(&mut stmt, map_id, node))
+                .collect::<Result<Vec<_>>>()?
+        };
+        tx.commit()?;
+        Ok(ids)
     }
 
     pub fn get_node_by_url(&self, map_id: &str, url: &str) -> Result<Option<i64>> {
@@ -475,34 +853,92 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(result)
     }
 
-    // Finding operations
-    pub fn insert_finding(&self, session_id: &str, finding: &Finding) -> Result<i64> {
-        let timestamp = current_timestamp();
-
+    /// Link two nodes of a map; an edge that already exists is left as is
+    pub fn insert_edge(
+        &self,
+        map_id: &str,
+        source_node_id: i64,
+        target_node_id: i64,
+        edge_type: EdgeType,
+    ) -> Result<()> {
         self.conn.execute(
-            "INSERT INTO findings (
-                session_id, node_id, finding_type, severity, confidence,
-                title, description, impact, remediation, evidence,
-                cwe_id, owasp_category, discovered_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
-            params![
-                session_id,
-                finding.node_id,
-                finding.finding_type.as_str(),
-                finding.severity.as_str(),
-                "likely", // default confidence
-                &finding.title,
-                &finding.description,
-                &finding.impact,
-                &finding.remediation,
-                &finding.evidence,
-                &finding.cwe_id,
-                &finding.owasp_category,
-                timestamp,
-            ],
+            "INSERT OR IGNORE INTO edges (map_id, source_node_id, target_node_id, edge_type, discovered_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![map_id, source_node_id, target_node_id, edge_type.as_str(), current_timestamp()],
         )?;
+        Ok(())
+    }
 
-        Ok(self.conn.last_insert_rowid())
+    /// Most recently crawled node with this URL across all sessions
+    pub fn get_latest_node_by_url(&self, url: &str) -> Result<Option<NodeDetails>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, url, response_code, content_type, service_type, title
+             FROM nodes WHERE url = ?1
+             ORDER BY last_crawled DESC, id DESC LIMIT 1",
+        )?;
+
+        let result = stmt
+            .query_row(params![url], |row| {
+                Ok(NodeDetails {
+                    id: row.get(0)?,
+                    url: row.get(1)?,
+                    status_code: row.get(2)?,
+                    content_type: row.get(3)?,
+                    service_type: row.get(4)?,
+                    title: row.get(5)?,
+                })
+            })
+            .optional()?;
+        Ok(result)
+    }
+
+    /// Node for `url` from the most recent session that started before `session_id`.
+    /// `None` when the URL was never crawled before it or the session is unknown.
+    pub fn get_previous_node(&self, url: &str, session_id: &str) -> Result<Option<PreviousNode>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT m.session_id, n.response_code, n.content_length, n.content_hash
+             FROM nodes n
+             JOIN maps m ON n.map_id = m.id
+             JOIN crawl_sessions s ON m.session_id = s.id
+             JOIN crawl_sessions cur ON cur.id = ?2
+             WHERE n.url = ?1
+               AND (s.start_time, s.rowid) < (cur.start_time, cur.rowid)
+             ORDER BY s.start_time DESC, s.rowid DESC, n.id DESC
+             LIMIT 1",
+        )?;
+
+        let result = stmt
+            .query_row(params![url, session_id], |row| {
+                Ok(PreviousNode {
+                    session_id: row.get(0)?,
+                    status_code: row.get(1)?,
+                    content_length: row.get(2)?,
+                    content_hash: row.get(3)?,
+                })
+            })
+            .optional()?;
+        Ok(result)
+    }
+
+    // Finding operations
+    pub fn insert_finding(&self, session_id: &str, finding: &Finding) -> Result<i64> {
+        let mut stmt = self.conn.prepare_cached(INSERT_FINDING_SQL)?;
+        insert_finding_with(&mut stmt, session_id, finding)
+    }
+
+    /// Insert several findings in one transaction, reusing a single prepared statement.
+    /// Returns the new finding ids in the order of `findings`.
+    pub fn insert_findings_batch(&self, session_id: &str, findings: &[Finding]) -> Result<Vec<i64>> {
+        let tx = self.conn.unchecked_transaction()?;
+        let ids = {
+            let mut stmt = tx.prepare_cached(INSERT_FINDING_SQL)?;
+            findings
+                .iter()
+                .map(|finding| insert_finding_with(&mut stmt, session_id, finding))
+                .collect::<Result<Vec<_>>>()?
+        };
+        tx.commit()?;
+        Ok(ids)
     }
 
     pub fn get_findings_by_session(
@@ -528,6 +964,77 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(findings)
     }
 
+    /// A session's findings, most severe first, optionally narrowed to one
+    /// severity and/or finding type; false positives are left out
+    pub fn query_findings(
+        &self,
+        session_id: &str,
+        severity: Option<&str>,
+        finding_type: Option<&str>,
+    ) -> Result<Vec<FindingSummary>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT f.id, f.severity, f.finding_type, f.title, n.url
+             FROM findings f
+             JOIN nodes n ON f.node_id = n.id
+             WHERE f.session_id = ?1 AND f.false_positive = 0
+               AND (?2 IS NULL OR f.severity = ?2)
+               AND (?3 IS NULL OR f.finding_type = ?3)
+             ORDER BY CASE f.severity
+                WHEN 'critical' THEN 1
+                WHEN 'high' THEN 2
+                WHEN 'medium' THEN 3
+                WHEN 'low' THEN 4
+                WHEN 'info' THEN 5
+            END, f.id",
+        )?;
+
+        let findings = stmt
+            .query_map(params![session_id, severity, finding_type], |row| {
+                Ok(FindingSummary {
+                    id: row.get(0)?,
+                    severity: row.get(1)?,
+                    finding_type: row.get(2)?,
+                    title: row.get(3)?,
+                    url: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(findings)
+    }
+
+    /// Findings recorded against a node as (severity, title), most severe first
+    pub fn get_findings_by_node(&self, node_id: i64) -> Result<Vec<(String, String)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT severity, title FROM findings WHERE node_id = ?1 AND false_positive = 0 ORDER BY CASE severity
+                WHEN 'critical' THEN 1
+                WHEN 'high' THEN 2
+                WHEN 'medium' THEN 3
+                WHEN 'low' THEN 4
+                WHEN 'info' THEN 5
+            END, id"
+        )?;
+
+        let findings = stmt
+            .query_map(params![node_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(findings)
+    }
+
+    /// Flag (or unflag) a finding as a false positive so reports exclude it
+    pub fn mark_false_positive(&self, finding_id: i64, value: bool) -> Result<()> {
+        let updated = self.conn.execute(
+            "UPDATE findings SET false_positive = ?1 WHERE id = ?2",
+            params![value, finding_id],
+        )?;
+
+        if updated == 0 {
+            return Err(rusqlite::Error::QueryReturnedNoRows);
+        }
+        Ok(())
+    }
+
     pub fn get_findings_count_by_severity(&self, session_id: &str) -> Result<Vec<(String, i64)>> {
         let mut stmt = self.conn.prepare(
             "SELECT severity, COUNT(*) FROM findings WHERE session_id = ?1 AND false_positive = 0 GROUP BY severity"
@@ -540,6 +1047,26 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(counts)
     }
 
+    /// Count findings per severity, collapsing duplicates with the same type and title
+    /// Returns (severity, distinct findings, total occurrences)
+    pub fn get_unique_findings_count_by_severity(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<(String, i64, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT severity, COUNT(DISTINCT finding_type || '|' || title), COUNT(*)
+             FROM findings WHERE session_id = ?1 AND false_positive = 0 GROUP BY severity",
+        )?;
+
+        let counts = stmt
+            .query_map(params![session_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(counts)
+    }
+
     // Technology detection
     #[allow(clippy::too_many_arguments)]
     pub fn insert_technology(
@@ -580,6 +1107,32 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(techs)
     }
 
+    /// Distinct technologies detected in a session as (name, version), one entry per name,
+    /// keeping the version from the strongest evidence
+    pub fn get_technologies_by_session(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<(String, Option<String>)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT t.name, t.version
+             FROM technologies t
+             JOIN nodes n ON t.node_id = n.id
+             JOIN maps m ON n.map_id = m.id
+             WHERE m.session_id = ?1
+             ORDER BY t.confidence DESC, t.id",
+        )?;
+
+        let rows = stmt
+            .query_map(params![session_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<(String, Option<String>)>>>()?;
+
+        let mut seen = HashSet::new();
+        Ok(rows
+            .into_iter()
+            .filter(|(name, _)| seen.insert(name.to_lowercase()))
+            .collect())
+    }
+
     // HTTP transaction logging
     #[allow(clippy::too_many_arguments)]
     pub fn log_http_transaction(
@@ -616,6 +1169,114 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(self.conn.last_insert_rowid())
     }
 
+    /// Every HTTP transaction logged for a session, in the order it was recorded
+    pub fn get_http_transactions(&self, session_id: &str) -> Result<Vec<HttpTransaction>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT t.request_method, t.request_url, t.request_headers, t.request_body,
+                    t.response_code, t.response_headers, n.content_hash
+             FROM http_transactions t
+             LEFT JOIN nodes n ON t.node_id = n.id
+             WHERE t.session_id = ?1
+             ORDER BY t.id",
+        )?;
+
+        let transactions = stmt
+            .query_map(params![session_id], |row| {
+                Ok(HttpTransaction {
+                    method: row.get(0)?,
+                    url: row.get(1)?,
+                    request_headers: header_pairs(row.get(2)?),
+                    request_body: row.get(3)?,
+                    response_code: row.get(4)?,
+                    response_headers: header_pairs(row.get(5)?),
+                    content_hash: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(transactions)
+    }
+
+    /// Serialize a session's recorded HTTP transactions as a HAR 1.2 document
+    pub fn export_har(&self, session_id: &str) -> Result<String> {
+        let mut stmt = self.conn.prepare(
+            "SELECT request_method, request_url, request_headers, response_code,
+                    response_headers, response_time_ms, response_size, timestamp
+             FROM http_transactions WHERE session_id = ?1 ORDER BY timestamp, id",
+        )?;
+
+        let entries = stmt
+            .query_map(params![session_id], |row| {
+                let url: String = row.get(1)?;
+                let response_headers = har_headers(row.get::<_, Option<String>>(4)?);
+                let mime_type = response_headers
+                    .iter()
+                    .find(|h| {
+                        h["name"]
+                            .as_str()
+                            .is_some_and(|name| name.eq_ignore_ascii_case("content-type"))
+                    })
+                    .and_then(|h| h["value"].as_str())
+                    .unwrap_or("")
+                    .to_string();
+                let time = row.get::<_, Option<i64>>(5)?.unwrap_or(0);
+                let started = chrono::DateTime::from_timestamp(row.get(7)?, 0)
+                    .unwrap_or_default()
+                    .to_rfc3339();
+                let query_string: Vec<serde_json::Value> = url::Url::parse(&url)
+                    .map(|u| {
+                        u.query_pairs()
+                            .map(|(name, value)| {
+                                serde_json::json!({ "name": name, "value": value })
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                Ok(serde_json::json!({
+                    "startedDateTime": started,
+                    "time": time,
+                    "request": {
+                        "method": row.get::<_, String>(0)?,
+                        "url": url,
+                        "httpVersion": "HTTP/1.1",
+                        "cookies": [],
+                        "headers": har_headers(row.get(2)?),
+                        "queryString": query_string,
+                        "headersSize": -1,
+                        "bodySize": -1,
+                    },
+                    "response": {
+                        "status": row.get::<_, i64>(3)?,
+                        "statusText": "",
+                        "httpVersion": "HTTP/1.1",
+                        "cookies": [],
+                        "headers": response_headers,
+                        "content": {
+                            "size": row.get::<_, Option<i64>>(6)?.unwrap_or(0),
+                            "mimeType": mime_type,
+                        },
+                        "redirectURL": "",
+                        "headersSize": -1,
+                        "bodySize": -1,
+                    },
+                    "cache": {},
+                    "timings": { "send": 0, "wait": time, "receive": 0 },
+                }))
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        let har = serde_json::json!({
+            "log": {
+                "version": "1.2",
+                "creator": { "name": "Rinzler", "version": env!("CARGO_PKG_VERSION") },
+                "entries": entries,
+            }
+        });
+
+        Ok(format!("{:#}", har))
+    }
+
     // Query methods
     pub fn get_nodes_by_session(
         &self,
@@ -637,7 +1298,300 @@ CREATE INDEX IF NOT EXISTS idx_http_transactions_timestamp ON http_transactions(
         Ok(nodes)
     }
 
+    /// Findings on every node of a session's map as (node id, severity, title), most
+    /// severe first within each node; false positives are left out
+    pub fn get_node_findings_by_session(
+        &self,
+        session_id: &str,
+    ) -> Result<Vec<(i64, String, String)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT f.node_id, f.severity, f.title
+             FROM findings f
+             JOIN nodes n ON f.node_id = n.id
+             JOIN maps m ON n.map_id = m.id
+             WHERE m.session_id = ?1 AND f.false_positive = 0
+             ORDER BY f.node_id, CASE f.severity
+                WHEN 'critical' THEN 1
+                WHEN 'high' THEN 2
+                WHEN 'medium' THEN 3
+                WHEN 'low' THEN 4
+                WHEN 'info' THEN 5
+            END, f.id",
+        )?;
+
+        let findings = stmt
+            .query_map(params![session_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(findings)
+    }
+
+    /// All edges of a session's map as (source node id, target node id, edge type)
+    pub fn get_edges_by_session(&self, session_id: &str) -> Result<Vec<(i64, i64, String)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT e.source_node_id, e.target_node_id, e.edge_type
+             FROM edges e
+             JOIN maps m ON e.map_id = m.id
+             WHERE m.session_id = ?1
+             ORDER BY e.id",
+        )?;
+
+        let edges = stmt
+            .query_map(params![session_id], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(edges)
+    }
+
+    /// Distinct page URLs crawled in a session
+    pub fn get_session_urls(&self, session_id: &str) -> Result<BTreeSet<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT n.url
+             FROM nodes n
+             JOIN maps m ON n.map_id = m.id
+             WHERE m.session_id = ?1",
+        )?;
+
+        let urls = stmt
+            .query_map(params![session_id], |row| row.get(0))?
+            .collect::<Result<BTreeSet<_>>>()?;
+
+        Ok(urls)
+    }
+
+    /// A session's findings as distinct (url, title) pairs; false positives are left out
+    pub fn get_session_finding_titles(&self, session_id: &str) -> Result<BTreeSet<(String, String)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT n.url, f.title
+             FROM findings f
+             JOIN nodes n ON f.node_id = n.id
+             WHERE f.session_id = ?1 AND f.false_positive = 0",
+        )?;
+
+        let findings = stmt
+            .query_map(params![session_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<BTreeSet<_>>>()?;
+
+        Ok(findings)
+    }
+
+    /// Remember URLs a fuzz session has requested, in one transaction; URLs
+    /// already recorded for the session are ignored
+    pub fn record_tested_urls(&self, session_id: &str, urls: &[String]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT OR IGNORE INTO fuzz_tested_urls (session_id, url, tested_at) VALUES (?1, ?2, ?3)",
+            )?;
+            let timestamp = current_timestamp();
+            for url in urls {
+                stmt.execute(params![session_id, url, timestamp])?;
+            }
+        }
+        tx.commit()
+    }
+
+    /// URLs a fuzz session has already requested, skipped when it is resumed
+    pub fn get_tested_urls(&self, session_id: &str) -> Result<HashSet<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT url FROM fuzz_tested_urls WHERE session_id = ?1")?;
+
+        let urls = stmt
+            .query_map(params![session_id], |row| row.get(0))?
+            .collect::<Result<HashSet<_>>>()?;
+
+        Ok(urls)
+    }
+
+    /// Remember responses a fuzz session reported or expanded, in one transaction
+    pub fn record_fuzz_hits(&self, session_id: &str, hits: &[FuzzHit]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT OR REPLACE INTO fuzz_hits
+                 (session_id, url, status_code, content_length, content_type, source, depth)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )?;
+            for hit in hits {
+                stmt.execute(params![
+                    session_id,
+                    &hit.result.url,
+                    hit.result.status_code,
+                    hit.result.content_length.map(|length| length as i64),
+                    &hit.result.content_type,
+                    hit.result.source.as_str(),
+                    hit.depth as i64,
+                ])?;
+            }
+        }
+        tx.commit()
+    }
+
+    /// Responses a fuzz session reported or expanded, in URL order
+    pub fn get_fuzz_hits(&self, session_id: &str) -> Result<Vec<FuzzHit>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT url, status_code, content_length, content_type, source, depth
+             FROM fuzz_hits WHERE session_id = ?1 ORDER BY url",
+        )?;
+
+        let hits = stmt
+            .query_map(params![session_id], |row| {
+                let source: String = row.get(4)?;
+                Ok(FuzzHit {
+                    result: FuzzResult {
+                        url: row.get(0)?,
+                        status_code: row.get(1)?,
+                        content_length: row.get::<_, Option<i64>>(2)?.map(|length| length as u64),
+                        content_type: row.get(3)?,
+                        source: source.parse().unwrap_or(FuzzSource::Discovered),
+                    },
+                    depth: row.get::<_, i64>(5)? as usize,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(hits)
+    }
+
+    /// Seed URLs a session was started with, as the stored JSON array,
+    /// or None if there is no such session
+    pub fn get_session_seed_urls(&self, session_id: &str) -> Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT seed_urls FROM crawl_sessions WHERE id = ?1",
+                params![session_id],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    /// Register a plugin file under `name`, replacing the path of an existing one
+    pub fn register_plugin(&self, name: &str, path: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO plugins (name, path, registered_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(name) DO UPDATE SET path = excluded.path, registered_at = excluded.registered_at",
+            params![name, path, current_timestamp()],
+        )?;
+        Ok(())
+    }
+
+    /// Registered plugins, by name
+    pub fn list_plugins(&self) -> Result<Vec<Plugin>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT name, path, registered_at FROM plugins ORDER BY name")?;
+
+        let plugins = stmt
+            .query_map([], |row| {
+                Ok(Plugin {
+                    name: row.get(0)?,
+                    path: row.get(1)?,
+                    registered_at: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(plugins)
+    }
+
+    /// Remove a plugin; false when no plugin has that name
+    pub fn unregister_plugin(&self, name: &str) -> Result<bool> {
+        let removed = self
+            .conn
+            .execute("DELETE FROM plugins WHERE name = ?1", params![name])?;
+        Ok(removed > 0)
+    }
+
+    /// Content hashes shared by more than one page in a session, with their page counts
+    /// (most duplicated first). Templated error pages typically show up here.
+    pub fn count_nodes_by_hash(&self, session_id: &str) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT n.content_hash, COUNT(*)
+             FROM nodes n
+             JOIN maps m ON n.map_id = m.id
+             WHERE m.session_id = ?1 AND n.content_hash IS NOT NULL
+             GROUP BY n.content_hash
+             HAVING COUNT(*) > 1
+             ORDER BY COUNT(*) DESC, n.content_hash",
+        )?;
+
+        let counts = stmt
+            .query_map(params![session_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(counts)
+    }
+
     pub fn get_connection(&self) -> &Connection {
         &self.conn
     }
 }
+
+/// Stores a session's findings with a running count, dropping new ones once
+/// an optional cap is reached
+pub struct FindingRecorder<'a> {
+    db: &'a Database,
+    session_id: &'a str,
+    max_findings: Option<usize>,
+    recorded: usize,
+    dropped: usize,
+}
+
+impl<'a> FindingRecorder<'a> {
+    pub fn new(db: &'a Database, session_id: &'a str, max_findings: Option<usize>) -> Self {
+        Self {
+            db,
+            session_id,
+            max_findings,
+            recorded: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Insert the finding unless the cap is reached; returns whether it was stored
+    pub fn record(&mut self, finding: &Finding) -> Result<bool> {
+        if self.max_findings.is_some_and(|max| self.recorded >= max) {
+            self.dropped += 1;
+            return Ok(false);
+        }
+
+        self.db.insert_finding(self.session_id, finding)?;
+        self.recorded += 1;
+        Ok(true)
+    }
+
+    /// Insert as many findings as the cap allows in one transaction; returns how many were stored
+    pub fn record_batch(&mut self, findings: &[Finding]) -> Result<usize> {
+        let room = self
+            .max_findings
+            .map_or(findings.len(), |max| max.saturating_sub(self.recorded));
+        let (stored, dropped) = findings.split_at(room.min(findings.len()));
+
+        self.db.insert_findings_batch(self.session_id, stored)?;
+        self.recorded += stored.len();
+        self.dropped += dropped.len();
+        Ok(stored.len())
+    }
+
+    pub fn recorded(&self) -> usize {
+        self.recorded
+    }
+
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Persist the dropped count so reports can note the cap
+    pub fn finish(&self) -> Result<()> {
+        if self.dropped > 0 {
+            self.db
+                .set_findings_dropped(self.session_id, self.dropped as i64)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+// Comparison of two scan sessions of the same target
+
+use crate::data::{Database, PreviousNode};
+use rusqlite::Result;
+use std::collections::BTreeSet;
+
+/// What changed between a base session and a newer one
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionDiff {
+    /// URLs crawled in the new session but not the base
+    pub new_urls: Vec<String>,
+    /// URLs crawled in the base session but no longer in the new one
+    pub removed_urls: Vec<String>,
+    /// Findings as (url, title) that the base session did not have
+    pub new_findings: Vec<(String, String)>,
+}
+
+impl SessionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.new_urls.is_empty() && self.removed_urls.is_empty() && self.new_findings.is_empty()
+    }
+}
+
+/// Set differences between two sessions' URLs and findings, each list sorted.
+/// A finding counts as new when its (url, title) pair is absent from the base,
+/// so a known issue appearing on a newly crawled page is reported.
+pub fn compute_session_diff(
+    base_urls: &BTreeSet<String>,
+    new_urls: &BTreeSet<String>,
+    base_findings: &BTreeSet<(String, String)>,
+    new_findings: &BTreeSet<(String, String)>,
+) -> SessionDiff {
+    SessionDiff {
+        new_urls: new_urls.difference(base_urls).cloned().collect(),
+        removed_urls: base_urls.difference(new_urls).cloned().collect(),
+        new_findings: new_findings.difference(base_findings).cloned().collect(),
+    }
+}
+
+/// How a crawled page compares with its last crawl in an earlier session
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeChange {
+    /// Not crawled by any earlier session
+    New,
+    Changed,
+    Unchanged,
+}
+
+impl NodeChange {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NodeChange::New => "new",
+            NodeChange::Changed => "changed",
+            NodeChange::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// Compare a page with its previous crawl. A different status code is a change;
+/// otherwise the body hashes decide when both are known, then the content lengths.
+/// A page with nothing to compare counts as unchanged.
+pub fn classify_node_change(
+    previous: Option<&PreviousNode>,
+    status_code: u16,
+    content_length: Option<i64>,
+    content_hash: Option<&str>,
+) -> NodeChange {
+    let Some(previous) = previous else {
+        return NodeChange::New;
+    };
+
+    let changed = if previous.status_code.is_some_and(|code| code != i64::from(status_code)) {
+        true
+    } else if let (Some(before), Some(now)) = (previous.content_hash.as_deref(), content_hash) {
+        before != now
+    } else if let (Some(before), Some(now)) = (previous.content_length, content_length) {
+        before != now
+    } else {
+        false
+    };
+
+    if changed {
+        NodeChange::Changed
+    } else {
+        NodeChange::Unchanged
+    }
+}
+
+/// Pages of a crawl tallied by how they compare with their previous crawl
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NodeChangeCounts {
+    pub new: usize,
+    pub changed: usize,
+    pub unchanged: usize,
+}
+
+impl NodeChangeCounts {
+    pub fn record(&mut self, change: NodeChange) {
+        match change {
+            NodeChange::New => self.new += 1,
+            NodeChange::Changed => self.changed += 1,
+            NodeChange::Unchanged => self.unchanged += 1,
+        }
+    }
+
+    /// Whether any page had been crawled before, i.e. this is a re-crawl
+    pub fn is_recrawl(&self) -> bool {
+        self.changed + self.unchanged > 0
+    }
+}
+
+/// Load both sessions from the database and compare them
+pub fn diff_sessions(db: &Database, base_session: &str, new_session: &str) -> Result<SessionDiff> {
+    Ok(compute_session_diff(
+        &db.get_session_urls(base_session)?,
+        &db.get_session_urls(new_session)?,
+        &db.get_session_finding_titles(base_session)?,
+        &db.get_session_finding_titles(new_session)?,
+    ))
+}
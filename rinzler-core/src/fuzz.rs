@@ -1,21 +1,47 @@
 // Fuzzing module for forced browsing / directory enumeration
 
+use crate::data::Finding;
+use crate::security::check_interesting_files;
 use colored::Colorize;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use reqwest::Client;
-use std::collections::{HashMap, HashSet, VecDeque};
+pub use reqwest::Method;
+use rinzler_scanner::client::USER_AGENT;
+use rinzler_scanner::result::CrawlResult;
+use rinzler_scanner::{ClientConfig, RequestSettings, build_client, retry_after};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fs;
-use std::path::Path;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
 use url::Url;
 
+/// Queued fuzz target: (url, base_url, source, recursion depth of base_url)
+type FuzzWorkItem = (String, String, FuzzSource, usize);
+
 /// Type alias for worker queue structure
-type WorkerQueues = Arc<Vec<Mutex<VecDeque<(String, FuzzSource)>>>>;
+type WorkerQueues = Arc<Vec<Mutex<VecDeque<FuzzWorkItem>>>>;
+
+/// Called with each URL once a response for it has been received
+pub type TestedCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Called with each response that is reported or expanded, before its URL counts as tested
+pub type HitCallback = Arc<dyn Fn(&FuzzHit) + Send + Sync>;
+
+/// Default number of times a discovered endpoint is re-fuzzed beneath itself
+pub const DEFAULT_MAX_RECURSION_DEPTH: usize = 2;
+
+/// Wildcard baselines keyed by the base URL they were calibrated against
+type WildcardBaselines = Arc<Mutex<HashMap<String, WildcardBaseline>>>;
+
+/// Minimum content-length difference (in bytes) still treated as the same soft-404 page
+const WILDCARD_LENGTH_TOLERANCE: u64 = 50;
 
 /// Result of a fuzz attempt
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct FuzzResult {
     pub url: String,
     pub status_code: u16,
@@ -24,36 +50,147 @@ pub struct FuzzResult {
     pub source: FuzzSource,
 }
 
-/// Source of the fuzz target
+/// A reported or expanded response, with the recursion depth it was found at,
+/// so a resumed run can report it again and expand beneath it
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzHit {
+    pub result: FuzzResult,
+    pub depth: usize,
+}
+
+/// Response to a random nonexistent path, used to recognise soft-404 / wildcard servers
 #[derive(Debug, Clone, PartialEq)]
+pub struct WildcardBaseline {
+    pub status_code: u16,
+    pub content_length: Option<u64>,
+}
+
+impl WildcardBaseline {
+    /// Check whether a response looks like the same catch-all page as the baseline.
+    /// Content lengths may differ by 2% (or 50 bytes) since soft-404s often echo the path.
+    pub fn matches(&self, status_code: u16, content_length: Option<u64>) -> bool {
+        if status_code != self.status_code {
+            return false;
+        }
+
+        match (self.content_length, content_length) {
+            (Some(expected), Some(actual)) => {
+                let tolerance = (expected / 50).max(WILDCARD_LENGTH_TOLERANCE);
+                expected.abs_diff(actual) <= tolerance
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Source of the fuzz target
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum FuzzSource {
     Initial,    // From command line
     Database,   // From previous crawl
     Discovered, // Found during fuzzing
 }
 
+impl FuzzSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FuzzSource::Initial => "initial",
+            FuzzSource::Database => "database",
+            FuzzSource::Discovered => "discovered",
+        }
+    }
+}
+
+impl std::str::FromStr for FuzzSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "initial" => Ok(FuzzSource::Initial),
+            "database" => Ok(FuzzSource::Database),
+            "discovered" => Ok(FuzzSource::Discovered),
+            _ => Err(format!("Unknown fuzz source: {}", s)),
+        }
+    }
+}
+
+/// Layout switches for the text fuzz report
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FuzzReportStyle {
+    /// Color status labels with ANSI escapes
+    pub color: bool,
+    /// List each status bucket's results under their content type
+    pub group_by_content_type: bool,
+}
+
 /// Options for configuring a fuzz operation
 pub struct FuzzOptions {
     pub base_urls: Vec<String>,
     pub wordlist: Vec<String>,
     pub threads: usize,
+    /// Draw progress bars and print status lines; disable when another UI owns the terminal
     pub show_progress_bars: bool,
-    pub use_head_requests: bool,
+    /// HTTP method for every probe (HEAD skips body downloads during enumeration)
+    pub method: Method,
+    /// Request body sent with every probe
+    pub body: Option<String>,
     pub timeout_secs: u64,
     pub db_path: Option<std::path::PathBuf>,
+    /// Only keep responses with these status codes (default: everything below 500)
+    pub include_status: Option<Vec<u16>>,
+    /// Drop responses with these status codes
+    pub exclude_status: Option<Vec<u16>>,
+    /// Idle connections kept open per host (default: one per worker)
+    pub pool_size: Option<usize>,
+    /// Seconds an idle pooled connection is kept alive
+    pub pool_idle_timeout_secs: u64,
+    /// Calibrate each base URL against a random path and drop soft-404 responses
+    pub wildcard_filter: bool,
+    /// How many levels of discovered endpoints are expanded with the wordlist (0 disables recursion)
+    pub max_recursion_depth: usize,
+    /// Sent instead of the default Rinzler User-Agent
+    pub user_agent: Option<String>,
+    /// Extra headers and proxy applied to every probe
+    pub request: RequestSettings,
+    /// Milliseconds each worker waits before every probe
+    pub request_delay_ms: Option<u64>,
+    /// URLs an interrupted run already requested; they are not sent again
+    pub skip_urls: HashSet<String>,
+    /// Told about every URL that got a response, so progress can be persisted
+    pub on_tested: Option<TestedCallback>,
+    /// Hits of an interrupted run: reported again, and expanded beneath
+    /// without requesting anything in `skip_urls`
+    pub resumed_hits: Vec<FuzzHit>,
+    /// Told about every response that is reported or expanded, before `on_tested`
+    pub on_hit: Option<HitCallback>,
 }
 
-/// Execute fuzzing with given options
-pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, String> {
+/// Every URL the fuzz starts with and where its base came from: each wordlist
+/// entry under the database endpoints for the targets, then under the targets
+/// themselves. Sends no requests, so `--dry-run` can preview a run with it.
+pub fn build_fuzz_targets(options: &FuzzOptions) -> Result<Vec<(String, FuzzSource)>, String> {
+    let (items, _) = initial_work_items(options)?;
+    Ok(items
+        .into_iter()
+        .map(|(url, _, source, _)| (url, source))
+        .collect())
+}
+
+/// Queue items for the first pass of a fuzz, plus how many database endpoints were added
+fn initial_work_items(options: &FuzzOptions) -> Result<(Vec<FuzzWorkItem>, usize), String> {
     let FuzzOptions {
         base_urls,
         wordlist,
-        threads,
         show_progress_bars,
-        use_head_requests,
-        timeout_secs,
         db_path,
+        skip_urls,
+        resumed_hits,
+        max_recursion_depth,
+        ..
     } = options;
+    let show_progress_bars = *show_progress_bars;
 
     if base_urls.is_empty() {
         return Err("No base URLs provided".to_string());
@@ -65,15 +202,21 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
 
     // Query database for known endpoints from previous crawls
     let mut db_endpoints = Vec::new();
-    if let Some(ref db_path) = db_path
-        && let Ok(db_urls) = query_database_endpoints(db_path, &base_urls)
-    {
-        db_endpoints = db_urls;
-        if !db_endpoints.is_empty() {
-            println!(
-                "✓ Found {} endpoints from previous crawls in database",
-                db_endpoints.len()
-            );
+    if let Some(db_path) = db_path {
+        match query_database_endpoints(db_path, base_urls) {
+            Ok(db_urls) => {
+                db_endpoints = db_urls;
+                if show_progress_bars && !db_endpoints.is_empty() {
+                    println!(
+                        "✓ Found {} endpoints from previous crawls in database",
+                        db_endpoints.len()
+                    );
+                }
+            }
+            Err(e) if show_progress_bars => {
+                eprintln!("⚠ Skipping endpoints from previous crawls: {}", e)
+            }
+            Err(_) => {}
         }
     }
 
@@ -89,32 +232,97 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
     }
 
     // Add command-line URLs last (will be tested first due to pop())
-    for url in &base_urls {
+    for url in base_urls {
         base_urls_with_source.push((url.clone(), FuzzSource::Initial));
     }
 
     // Build full URLs to test
     let mut urls_to_test = Vec::new();
     for (base_url, source) in &base_urls_with_source {
-        for word in &wordlist {
+        for word in wordlist {
             let test_url = build_test_url(base_url, word)?;
-            urls_to_test.push((test_url, source.clone()));
+            if !skip_urls.contains(&test_url) {
+                urls_to_test.push((test_url, base_url.clone(), source.clone(), 0));
+            }
         }
     }
 
+    // Pick up recursion beneath the hits of an interrupted run
+    for (base_url, depth) in resumed_bases(resumed_hits, *max_recursion_depth) {
+        for word in wordlist {
+            let test_url = build_test_url(&base_url, word)?;
+            if !skip_urls.contains(&test_url) {
+                urls_to_test.push((
+                    test_url,
+                    base_url.clone(),
+                    FuzzSource::Discovered,
+                    depth + 1,
+                ));
+            }
+        }
+    }
+
+    Ok((urls_to_test, db_endpoints.len()))
+}
+
+/// Base URLs (with their depth) the worker loop would have expanded for `hits`
+fn resumed_bases(hits: &[FuzzHit], max_recursion_depth: usize) -> Vec<(String, usize)> {
+    let mut seen = HashSet::new();
+    hits.iter()
+        .filter(|hit| {
+            (200..400).contains(&hit.result.status_code) && hit.depth < max_recursion_depth
+        })
+        .filter_map(|hit| {
+            let base_url = extract_base_url(&hit.result.url).ok()?;
+            seen.insert(base_url.clone())
+                .then_some((base_url, hit.depth))
+        })
+        .collect()
+}
+
+/// Execute fuzzing with given options
+pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, String> {
+    let (urls_to_test, db_endpoint_count) = initial_work_items(&options)?;
+
+    let FuzzOptions {
+        base_urls,
+        wordlist,
+        threads,
+        show_progress_bars,
+        method,
+        body,
+        timeout_secs,
+        db_path: _,
+        include_status,
+        exclude_status,
+        wildcard_filter,
+        pool_size,
+        pool_idle_timeout_secs,
+        max_recursion_depth,
+        user_agent,
+        request,
+        request_delay_ms,
+        skip_urls,
+        on_tested,
+        resumed_hits,
+        on_hit,
+    } = options;
+
     let initial_count = urls_to_test.len();
-    println!(
-        "Testing {} initial URLs with {} workers",
-        initial_count, threads
-    );
-    if !db_endpoints.is_empty() {
+    if show_progress_bars {
         println!(
-            "  {} from command line, {} from database",
-            base_urls.len() * wordlist.len(),
-            db_endpoints.len() * wordlist.len()
+            "Testing {} initial URLs with {} workers",
+            initial_count, threads
         );
+        if db_endpoint_count > 0 {
+            println!(
+                "  {} from command line, {} from database",
+                base_urls.len() * wordlist.len(),
+                db_endpoint_count * wordlist.len()
+            );
+        }
+        println!();
     }
-    println!();
 
     // Set up multi-progress for worker tracking
     let m = if show_progress_bars {
@@ -123,26 +331,52 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
         None
     };
 
-    // Create shared results vector and hits display
-    let results: Arc<Mutex<Vec<FuzzResult>>> = Arc::new(Mutex::new(Vec::new()));
+    // Create shared results vector and hits display; earlier hits that pass
+    // this run's status filter are reported again
+    let resumed_results: Vec<FuzzResult> = resumed_hits
+        .iter()
+        .filter(|hit| {
+            status_passes_filter(
+                hit.result.status_code,
+                include_status.as_deref(),
+                exclude_status.as_deref(),
+            )
+        })
+        .map(|hit| hit.result.clone())
+        .collect();
+    let results: Arc<Mutex<Vec<FuzzResult>>> = Arc::new(Mutex::new(resumed_results));
     let hits_display: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
 
     // Create worker-owned queues with work stealing
-    // Each worker has its own queue: VecDeque<(url, source)>
+    // Each worker has its own queue: VecDeque<(url, base_url, source, depth)>
     let worker_queues: WorkerQueues =
         Arc::new((0..threads).map(|_| Mutex::new(VecDeque::new())).collect());
 
-    // Distribute initial URLs evenly across workers
-    for (idx, (url, source)) in urls_to_test.into_iter().enumerate() {
+    // Initial items are grouped by base URL, database endpoints first
+    let mut initial_bases: Vec<String> = Vec::new();
+    for (_, base_url, _, _) in &urls_to_test {
+        if initial_bases.last() != Some(base_url) {
+            initial_bases.push(base_url.clone());
+        }
+    }
+
+    // Distribute initial URLs evenly across workers (see worker_totals)
+    for (idx, item) in urls_to_test.into_iter().enumerate() {
         let worker_id = idx % threads;
-        worker_queues[worker_id]
-            .try_lock()
-            .unwrap()
-            .push_back((url, source));
+        worker_queues[worker_id].try_lock().unwrap().push_back(item);
     }
 
-    let tested_urls: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+    // Bases already queued beneath resumed hits are not expanded twice
+    let tested_urls: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(
+        resumed_bases(&resumed_hits, max_recursion_depth)
+            .into_iter()
+            .map(|(base_url, _)| base_url)
+            .collect(),
+    ));
     let wordlist_arc = Arc::new(wordlist);
+    let include_status = Arc::new(include_status);
+    let exclude_status = Arc::new(exclude_status);
+    let skip_urls = Arc::new(skip_urls);
 
     // Create hits display progress bar (sticky at top)
     let hits_pb = if show_progress_bars && let Some(ref multi_progress) = m {
@@ -156,37 +390,82 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
 
     // Create optimized HTTP client with HTTP/2 and connection pooling
     let client = Arc::new(
-        Client::builder()
-            .timeout(Duration::from_secs(timeout_secs))
-            .connect_timeout(Duration::from_secs(timeout_secs / 2))
-            .pool_max_idle_per_host(threads) // Connection pooling
-            .pool_idle_timeout(Duration::from_secs(90))
-            .http2_adaptive_window(true) // Enable HTTP/2 with adaptive flow control
-            .tcp_keepalive(Duration::from_secs(60))
-            .redirect(reqwest::redirect::Policy::limited(3))
-            .user_agent("Rinzler/0.1 (https://github.com/trapdoorsec/rinzler)")
-            .build()
-            .map_err(|e| format!("Failed to create HTTP client: {}", e))?,
+        build_client(&ClientConfig {
+            timeout_secs,
+            pool_size: pool_size.unwrap_or(threads),
+            pool_idle_timeout_secs,
+            max_redirects: 3,
+            user_agent: user_agent.unwrap_or_else(|| USER_AGENT.to_string()),
+            request,
+            cookies: false,
+        })
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?,
     );
 
+    // Calibrate soft-404 baselines for every initial base URL
+    let wildcard_baselines: WildcardBaselines = Arc::new(Mutex::new(HashMap::new()));
+    if wildcard_filter {
+        let mut calibrations = Vec::new();
+        for base_url in &initial_bases {
+            let client_clone = client.clone();
+            let base_url = base_url.clone();
+            let method = method.clone();
+            let body = body.clone();
+            calibrations.push(tokio::spawn(async move {
+                let baseline =
+                    calibrate_wildcard(&client_clone, &base_url, &method, body.as_deref()).await;
+                (base_url, baseline)
+            }));
+        }
+
+        let mut baselines = wildcard_baselines.lock().await;
+        for calibration in calibrations {
+            if let Ok((base_url, Some(baseline))) = calibration.await {
+                if show_progress_bars {
+                    println!(
+                        "⚠ Wildcard responses detected for {} ([{}] {} bytes), filtering matches",
+                        base_url,
+                        baseline.status_code,
+                        baseline
+                            .content_length
+                            .map(|l| l.to_string())
+                            .unwrap_or_else(|| "?".to_string())
+                    );
+                }
+                baselines.insert(base_url, baseline);
+            }
+        }
+    }
+
+    // One bar per worker, sized to its share of the initial URLs; lengths grow as
+    // discovered endpoints are queued and move with stolen work, so the ETA holds
+    let worker_bars: Option<Arc<Vec<ProgressBar>>> = match m {
+        Some(ref multi_progress) if show_progress_bars => Some(Arc::new(
+            worker_totals(initial_count, threads)
+                .into_iter()
+                .enumerate()
+                .map(|(worker_id, total)| {
+                    let progress_bar = multi_progress.add(ProgressBar::new(total));
+                    progress_bar.set_style(
+                        ProgressStyle::default_bar()
+                            .template("[{bar:40.cyan/blue}] {pos}/{len} {per_sec} ETA {eta} Worker {msg}")
+                            .unwrap()
+                            .progress_chars("=>-"),
+                    );
+                    progress_bar.set_message(format!("{} idle", worker_id));
+                    progress_bar
+                })
+                .collect(),
+        )),
+        _ => None,
+    };
+
     // Spawn workers with work stealing
     let mut worker_tasks = Vec::new();
 
     for worker_id in 0..threads {
-        // Create progress bar for this worker
-        let pb = if show_progress_bars && let Some(ref multi_progress) = m {
-            let progress_bar = multi_progress.add(ProgressBar::new(initial_count as u64));
-            progress_bar.set_style(
-                ProgressStyle::default_bar()
-                    .template("[{bar:40.cyan/blue}] Worker {msg}")
-                    .unwrap()
-                    .progress_chars("=>-"),
-            );
-            progress_bar.set_message(format!("{} idle", worker_id));
-            Some(progress_bar)
-        } else {
-            None
-        };
+        let bars = worker_bars.clone();
+        let pb = worker_bars.as_ref().map(|bars| bars[worker_id].clone());
 
         let client_clone = client.clone();
         let results_clone = results.clone();
@@ -195,10 +474,16 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
         let worker_queues_clone = worker_queues.clone();
         let tested_urls_clone = tested_urls.clone();
         let wordlist_clone = wordlist_arc.clone();
+        let include_status_clone = include_status.clone();
+        let exclude_status_clone = exclude_status.clone();
+        let skip_urls_clone = skip_urls.clone();
+        let on_tested_clone = on_tested.clone();
+        let on_hit_clone = on_hit.clone();
+        let wildcard_baselines_clone = wildcard_baselines.clone();
+        let method_clone = method.clone();
+        let body_clone = body.clone();
 
         let task = tokio::spawn(async move {
-            let mut processed = 0;
-
             loop {
                 // Try to get work from own queue
                 let work_item = {
@@ -206,12 +491,18 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
                     queue.pop_front()
                 };
 
-                let (url, source) = if let Some(item) = work_item {
+                let (url, base_url, source, depth) = if let Some(item) = work_item {
                     item
                 } else {
                     // Own queue is empty - try to steal from other workers
                     let stolen = try_steal_fuzz_work(worker_id, &worker_queues_clone).await;
-                    if let Some(item) = stolen {
+                    if let Some((victim_id, item)) = stolen {
+                        // The item now counts toward this worker's total, not the victim's
+                        if let Some(ref bars) = bars {
+                            let victim = &bars[victim_id];
+                            victim.set_length(victim.length().unwrap_or(0).saturating_sub(1));
+                            bars[worker_id].inc_length(1);
+                        }
                         item
                     } else {
                         // No work available anywhere - check if all queues are truly empty
@@ -229,29 +520,72 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
 
                 // Update progress bar with current URL in orange
                 if let Some(ref pb) = pb {
-                    processed += 1;
                     let msg = format!(
-                        "{} {} {}",
+                        "{} {}",
                         worker_id,
-                        processed,
                         format!("[{}]", url_path).truecolor(255, 165, 0) // Orange
                     );
                     pb.set_message(msg);
                 }
 
+                if let Some(delay_ms) = request_delay_ms {
+                    tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                }
+
                 // Make request
                 if let Ok(mut result) =
-                    make_fuzz_request(&client_clone, &url, use_head_requests).await
+                    make_fuzz_request(&client_clone, &url, &method_clone, body_clone.as_deref())
+                        .await
                 {
                     result.source = source.clone();
 
-                    // Save all responses < 500 to results for final report
-                    if result.status_code < 500 {
+                    // Skip responses that look like the base URL's catch-all page
+                    let is_wildcard = wildcard_baselines_clone
+                        .lock()
+                        .await
+                        .get(&base_url)
+                        .is_some_and(|b| b.matches(result.status_code, result.content_length));
+                    if is_wildcard {
+                        if let Some(ref callback) = on_tested_clone {
+                            callback(&url);
+                        }
+                        if let Some(ref pb) = pb {
+                            pb.inc(1);
+                        }
+                        continue;
+                    }
+
+                    // Save responses that pass the status filter to results for final report
+                    let keep = status_passes_filter(
+                        result.status_code,
+                        include_status_clone.as_deref(),
+                        exclude_status_clone.as_deref(),
+                    );
+                    let expands =
+                        (200..400).contains(&result.status_code) && depth < max_recursion_depth;
+
+                    // Persist the hit before the URL is marked tested, so a resume never skips it
+                    if (keep || expands)
+                        && let Some(ref callback) = on_hit_clone
+                    {
+                        callback(&FuzzHit {
+                            result: result.clone(),
+                            depth,
+                        });
+                    }
+                    if let Some(ref callback) = on_tested_clone {
+                        callback(&url);
+                    }
+
+                    if keep {
                         results_clone.lock().await.push(result.clone());
                     }
 
-                    // If we found a new endpoint (200-399), add it to this worker's queue
-                    if (200..400).contains(&result.status_code) {
+                    // Explicitly matched codes are always hits, otherwise only 200-399
+                    let is_hit = keep
+                        && (include_status_clone.is_some()
+                            || (200..400).contains(&result.status_code));
+                    if is_hit {
                         // Display the hit
                         let hit_display = format_hit(&result);
                         hits_display_clone.lock().await.push(hit_display);
@@ -262,22 +596,54 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
                             let formatted = format_hits_display(&hits);
                             hits_pb.set_message(formatted);
                         }
+                    }
 
+                    // If we found a new endpoint (200-399) within the recursion limit,
+                    // add it to this worker's queue
+                    if expands {
                         // Extract base path for this discovered endpoint
-                        if let Ok(base_url) = extract_base_url(&result.url) {
+                        if let Ok(new_base) = extract_base_url(&result.url) {
                             let mut tested = tested_urls_clone.lock().await;
 
                             // Only add if we haven't tested this base yet
-                            if !tested.contains(&base_url) {
-                                tested.insert(base_url.clone());
+                            let is_new_base = tested.insert(new_base.clone());
+                            drop(tested);
+
+                            if is_new_base {
+                                // Calibrate the new base before queuing anything under it
+                                if wildcard_filter
+                                    && let Some(baseline) = calibrate_wildcard(
+                                        &client_clone,
+                                        &new_base,
+                                        &method_clone,
+                                        body_clone.as_deref(),
+                                    )
+                                    .await
+                                {
+                                    wildcard_baselines_clone
+                                        .lock()
+                                        .await
+                                        .insert(new_base.clone(), baseline);
+                                }
 
                                 // Generate new fuzz targets and add to this worker's queue (route affinity)
                                 let mut queue = worker_queues_clone[worker_id].lock().await;
+                                let queued_before = queue.len();
                                 for word in wordlist_clone.iter() {
-                                    if let Ok(new_url) = build_test_url(&base_url, word) {
-                                        queue.push_back((new_url, FuzzSource::Discovered));
+                                    if let Ok(new_url) = build_test_url(&new_base, word)
+                                        && !skip_urls_clone.contains(&new_url)
+                                    {
+                                        queue.push_back((
+                                            new_url,
+                                            new_base.clone(),
+                                            FuzzSource::Discovered,
+                                            depth + 1,
+                                        ));
                                     }
                                 }
+                                if let Some(ref pb) = pb {
+                                    pb.inc_length((queue.len() - queued_before) as u64);
+                                }
                             }
                         }
                     }
@@ -313,11 +679,21 @@ pub async fn execute_fuzz(options: FuzzOptions) -> Result<Vec<FuzzResult>, Strin
     Ok(final_results)
 }
 
-/// Try to steal work from other workers
+/// URLs each worker starts with when `items` are dealt round-robin across `workers`
+pub fn worker_totals(items: usize, workers: usize) -> Vec<u64> {
+    if workers == 0 {
+        return Vec::new();
+    }
+    (0..workers)
+        .map(|worker_id| (items / workers + usize::from(worker_id < items % workers)) as u64)
+        .collect()
+}
+
+/// Try to steal work from other workers, returning the victim's id with the item
 async fn try_steal_fuzz_work(
     worker_id: usize,
     worker_queues: &WorkerQueues,
-) -> Option<(String, FuzzSource)> {
+) -> Option<(usize, FuzzWorkItem)> {
     // Try to steal from each other worker
     for target_id in 0..worker_queues.len() {
         if target_id == worker_id {
@@ -326,7 +702,7 @@ async fn try_steal_fuzz_work(
 
         let mut target_queue = worker_queues[target_id].lock().await;
         if let Some(item) = target_queue.pop_back() {
-            return Some(item);
+            return Some((target_id, item));
         }
     }
 
@@ -430,27 +806,29 @@ fn format_hits_display(hits: &[String]) -> String {
     }
 }
 
-/// Make a single fuzz request
+/// Make a single fuzz request. A throttled response (429/503 with `Retry-After`)
+/// holds the worker for the requested time and is then retried once; if the retry
+/// is throttled too the probe fails, so it is neither reported nor marked tested.
 async fn make_fuzz_request(
     client: &Client,
     url: &str,
-    use_head: bool,
+    method: &Method,
+    body: Option<&str>,
 ) -> Result<FuzzResult, String> {
-    let response = if use_head {
-        // Use HEAD request to skip body download
-        client
-            .head(url)
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?
-    } else {
-        // Use GET request
-        client
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| format!("Request failed: {}", e))?
-    };
+    let mut response = send_fuzz_request(client, url, method, body).await?;
+    if let Some(delay) = retry_after(response.status().as_u16(), response.headers()) {
+        tokio::time::sleep(delay).await;
+        response = send_fuzz_request(client, url, method, body).await?;
+
+        let status_code = response.status().as_u16();
+        if let Some(delay) = retry_after(status_code, response.headers()) {
+            tokio::time::sleep(delay).await;
+            return Err(format!(
+                "Throttled: {} returned {} after a retry",
+                url, status_code
+            ));
+        }
+    }
 
     let status_code = response.status().as_u16();
     let content_length = response.content_length();
@@ -469,6 +847,45 @@ async fn make_fuzz_request(
     })
 }
 
+async fn send_fuzz_request(
+    client: &Client,
+    url: &str,
+    method: &Method,
+    body: Option<&str>,
+) -> Result<reqwest::Response, String> {
+    let mut request = client.request(method.clone(), url);
+    if let Some(body) = body {
+        request = request.body(body.to_string());
+    }
+    request
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))
+}
+
+/// Request a random nonexistent path under the base URL and return a baseline
+/// if the server answers with anything other than a 404
+async fn calibrate_wildcard(
+    client: &Client,
+    base_url: &str,
+    method: &Method,
+    body: Option<&str>,
+) -> Option<WildcardBaseline> {
+    let probe_url = build_test_url(base_url, &format!("zzz-{}", uuid::Uuid::new_v4())).ok()?;
+    let probe = make_fuzz_request(client, &probe_url, method, body)
+        .await
+        .ok()?;
+
+    if probe.status_code == 404 {
+        return None;
+    }
+
+    Some(WildcardBaseline {
+        status_code: probe.status_code,
+        content_length: probe.content_length,
+    })
+}
+
 /// Extract base URL from a full URL (removes query params and fragments)
 pub fn extract_base_url(url: &str) -> Result<String, String> {
     let parsed = Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
@@ -478,8 +895,9 @@ pub fn extract_base_url(url: &str) -> Result<String, String> {
     Ok(base.to_string())
 }
 
-/// Query database for known endpoints from previous crawls
-fn query_database_endpoints(
+/// Query database for known endpoints from previous crawls.
+/// An empty Ok means no matching nodes; Err means the database could not be read.
+pub fn query_database_endpoints(
     db_path: &std::path::Path,
     target_urls: &[String],
 ) -> Result<Vec<String>, String> {
@@ -504,18 +922,20 @@ fn query_database_endpoints(
     for domain in &target_domains {
         // Simple query - get all nodes for this domain
         let query = "SELECT url FROM nodes WHERE domain = ?";
-
-        if let Ok(mut stmt) = db.get_connection().prepare(query)
-            && let Ok(rows) = stmt.query_map([domain], |row| row.get::<_, String>(0))
-        {
-            for url_result in rows.flatten() {
-                // Only include if it's a valid URL for the target
-                if let Ok(parsed) = Url::parse(&url_result)
-                    && let Some(host) = parsed.host_str()
-                    && (host == domain || host.ends_with(&format!(".{}", domain)))
-                {
-                    endpoints.push(url_result);
-                }
+        let query_error = |e: rusqlite::Error| format!("Failed to query nodes: {}", e);
+
+        let mut stmt = db.get_connection().prepare(query).map_err(query_error)?;
+        let rows = stmt
+            .query_map([domain], |row| row.get::<_, String>(0))
+            .map_err(query_error)?;
+
+        for url_result in rows {
+            let url_result = url_result.map_err(query_error)?;
+            // Only include if it's a valid URL for the target
+            if let Ok(parsed) = Url::parse(&url_result)
+                && rinzler_scanner::host_in_scope(&parsed, domain)
+            {
+                endpoints.push(url_result);
             }
         }
     }
@@ -523,6 +943,53 @@ fn query_database_endpoints(
     Ok(endpoints)
 }
 
+/// Parse a comma-separated list of status codes and ranges (e.g. "200,301,400-499")
+pub fn parse_status_codes(spec: &str) -> Result<Vec<u16>, String> {
+    let mut codes = Vec::new();
+
+    for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        if let Some((start, end)) = part.split_once('-') {
+            let start: u16 = start
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid status code range '{}'", part))?;
+            let end: u16 = end
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid status code range '{}'", part))?;
+            if start > end {
+                return Err(format!("Invalid status code range '{}'", part));
+            }
+            codes.extend(start..=end);
+        } else {
+            let code: u16 = part
+                .parse()
+                .map_err(|_| format!("Invalid status code '{}'", part))?;
+            codes.push(code);
+        }
+    }
+
+    if codes.is_empty() {
+        return Err("No status codes specified".to_string());
+    }
+
+    codes.sort_unstable();
+    codes.dedup();
+    Ok(codes)
+}
+
+/// Check whether a status code passes the include/exclude filters
+/// Without an include list, every status below 500 is kept
+pub fn status_passes_filter(status: u16, include: Option<&[u16]>, exclude: Option<&[u16]>) -> bool {
+    let included = match include {
+        Some(codes) => codes.contains(&status),
+        None => status < 500,
+    };
+    let excluded = exclude.is_some_and(|codes| codes.contains(&status));
+
+    included && !excluded
+}
+
 /// Build a test URL from base URL and wordlist entry
 pub fn build_test_url(base_url: &str, word: &str) -> Result<String, String> {
     let mut url =
@@ -551,6 +1018,38 @@ pub fn load_wordlist(path: &Path) -> Result<Vec<String>, String> {
     let content = fs::read_to_string(path)
         .map_err(|e| format!("Failed to read wordlist {}: {}", path.display(), e))?;
 
+    parse_wordlist(&content, &path.display().to_string())
+}
+
+/// Load and merge several wordlists, keeping the first occurrence of each word.
+/// A path of `-` reads words from stdin.
+pub fn load_wordlists(paths: &[PathBuf]) -> Result<Vec<String>, String> {
+    let mut seen = HashSet::new();
+    let mut words = Vec::new();
+
+    for path in paths {
+        let list = if path.as_os_str() == "-" {
+            let mut content = String::new();
+            std::io::stdin()
+                .read_to_string(&mut content)
+                .map_err(|e| format!("Failed to read wordlist from stdin: {}", e))?;
+            parse_wordlist(&content, "from stdin")?
+        } else {
+            load_wordlist(path)?
+        };
+
+        words.extend(list.into_iter().filter(|word| seen.insert(word.clone())));
+    }
+
+    if words.is_empty() {
+        return Err("No wordlists provided".to_string());
+    }
+
+    Ok(words)
+}
+
+/// Split wordlist content into trimmed words, skipping blank lines and `#` comments
+fn parse_wordlist(content: &str, label: &str) -> Result<Vec<String>, String> {
     let words: Vec<String> = content
         .lines()
         .filter(|line| !line.trim().is_empty())
@@ -561,15 +1060,36 @@ pub fn load_wordlist(path: &Path) -> Result<Vec<String>, String> {
     if words.is_empty() {
         return Err(format!(
             "Wordlist {} is empty or contains only comments",
-            path.display()
+            label
         ));
     }
 
     Ok(words)
 }
 
+/// Findings for hits on interesting files (e.g. `.env`, `.git/`) by the same path
+/// rules the crawl applies; fuzz hits aren't map nodes, so `node_id` is 0
+pub fn fuzz_findings(results: &[FuzzResult]) -> Vec<Finding> {
+    results
+        .iter()
+        .flat_map(|result| {
+            let mut crawl_result = CrawlResult::new(result.url.clone());
+            crawl_result.status_code = result.status_code;
+            crawl_result.content_type = result.content_type.clone();
+            check_interesting_files(&crawl_result, 0)
+        })
+        .collect()
+}
+
 /// Generate a simple fuzz report
 pub fn generate_fuzz_report(results: &[FuzzResult]) -> String {
+    generate_fuzz_report_with_style(results, FuzzReportStyle::default())
+}
+
+/// Generate the text fuzz report with aligned URL/size/type columns, optionally
+/// colored and sub-grouped by content type. Color should only be enabled for
+/// terminal output, never for saved files.
+pub fn generate_fuzz_report_with_style(results: &[FuzzResult], style: FuzzReportStyle) -> String {
     // Filter out 404s
     let filtered_results: Vec<&FuzzResult> =
         results.iter().filter(|r| r.status_code != 404).collect();
@@ -626,6 +1146,18 @@ pub fn generate_fuzz_report(results: &[FuzzResult]) -> String {
     }
     report.push('\n');
 
+    // Column widths shared by every bucket so the whole report lines up
+    let url_width = filtered_results
+        .iter()
+        .map(|r| fuzz_url_cell(r).chars().count())
+        .max()
+        .unwrap_or(0);
+    let size_width = filtered_results
+        .iter()
+        .map(|r| fuzz_size_cell(r).len())
+        .max()
+        .unwrap_or(0);
+
     for status_code in status_codes {
         if let Some(status_results) = by_status.get(&status_code) {
             let status_label = match status_code {
@@ -634,6 +1166,11 @@ pub fn generate_fuzz_report(results: &[FuzzResult]) -> String {
                 400..=499 => format!("[{}] Client Error", status_code),
                 _ => format!("[{}]", status_code),
             };
+            let status_label = if style.color {
+                colorize_status_label(status_code, &status_label)
+            } else {
+                status_label
+            };
 
             report.push_str(&format!(
                 "{} ({} findings)\n",
@@ -644,23 +1181,38 @@ pub fn generate_fuzz_report(results: &[FuzzResult]) -> String {
                 "───────────────────────────────────────────────────────────────────────────────\n",
             );
 
-            for result in status_results {
-                // Add source indicator
-                let source_marker = match result.source {
-                    FuzzSource::Initial => "",
-                    FuzzSource::Database => " [DB]",
-                    FuzzSource::Discovered => " [DISC]",
-                };
-
-                report.push_str(&format!("  {}{}", result.url, source_marker));
-                if let Some(length) = result.content_length {
-                    report.push_str(&format!(" ({} bytes)", length));
+            if style.group_by_content_type {
+                let mut by_type: BTreeMap<&str, Vec<&FuzzResult>> = BTreeMap::new();
+                for result in status_results {
+                    by_type
+                        .entry(short_content_type(result).unwrap_or("unknown"))
+                        .or_default()
+                        .push(*result);
                 }
-                if let Some(ref ct) = result.content_type {
-                    let short_ct = ct.split(';').next().unwrap_or(ct);
-                    report.push_str(&format!(" [{}]", short_ct));
+                for (content_type, type_results) in by_type {
+                    report.push_str(&format!("  {} ({})\n", content_type, type_results.len()));
+                    for result in type_results {
+                        report.push_str(&format!(
+                            "    {:<url_width$}  {:>size_width$}\n",
+                            fuzz_url_cell(result),
+                            fuzz_size_cell(result),
+                        ));
+                    }
+                }
+            } else {
+                for result in status_results {
+                    let type_cell = short_content_type(result)
+                        .map(|ct| format!("[{}]", ct))
+                        .unwrap_or_default();
+                    let line = format!(
+                        "  {:<url_width$}  {:>size_width$}  {}",
+                        fuzz_url_cell(result),
+                        fuzz_size_cell(result),
+                        type_cell,
+                    );
+                    report.push_str(line.trim_end());
+                    report.push('\n');
                 }
-                report.push('\n');
             }
             report.push('\n');
         }
@@ -682,3 +1234,66 @@ pub fn generate_fuzz_report(results: &[FuzzResult]) -> String {
 
     report
 }
+
+/// URL column of the text fuzz report: the URL plus its source marker
+fn fuzz_url_cell(result: &FuzzResult) -> String {
+    let source_marker = match result.source {
+        FuzzSource::Initial => "",
+        FuzzSource::Database => " [DB]",
+        FuzzSource::Discovered => " [DISC]",
+    };
+    format!("{}{}", result.url, source_marker)
+}
+
+/// Size column of the text fuzz report (`-` when the length is unknown)
+fn fuzz_size_cell(result: &FuzzResult) -> String {
+    result
+        .content_length
+        .map(|length| format!("{} bytes", length))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Media type without parameters, e.g. `text/html` for `text/html; charset=utf-8`
+fn short_content_type(result: &FuzzResult) -> Option<&str> {
+    result
+        .content_type
+        .as_deref()
+        .map(|ct| ct.split(';').next().unwrap_or(ct).trim())
+}
+
+/// Wrap a status bucket label in the ANSI color used for hits (2xx green,
+/// 3xx yellow, 4xx red)
+fn colorize_status_label(status_code: u16, label: &str) -> String {
+    let code = match status_code {
+        200..=299 => "32",
+        300..=399 => "33",
+        400..=499 => "31",
+        _ => "37",
+    };
+    format!("\x1b[{}m{}\x1b[0m", code, label)
+}
+
+/// Generate a JSON fuzz report (404s excluded, matching the text report)
+pub fn generate_fuzz_json_report(results: &[FuzzResult]) -> Result<String, serde_json::Error> {
+    let filtered_results: Vec<&FuzzResult> =
+        results.iter().filter(|r| r.status_code != 404).collect();
+
+    let json_report = serde_json::json!({
+        "report": {
+            "metadata": {
+                "generator": "Rinzler",
+                "version": env!("CARGO_PKG_VERSION"),
+                "generated_at": chrono::Utc::now().to_rfc3339(),
+                "format": "json",
+                "disclaimer": "For authorized security testing only"
+            },
+            "summary": {
+                "total_requests": results.len(),
+                "total_findings": filtered_results.len()
+            },
+            "results": filtered_results
+        }
+    });
+
+    serde_json::to_string_pretty(&json_report)
+}
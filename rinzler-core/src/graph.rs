@@ -0,0 +1,268 @@
+// Interactive HTML site graph of a session's nodes, edges and findings
+
+use crate::data::Database;
+use rusqlite::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Above this many nodes the HTML layout runs fewer steps and comes out rougher
+pub const LARGE_GRAPH_NODES: usize = 1000;
+
+/// A stored page in the site graph, with the findings raised on it
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+    pub id: i64,
+    pub url: String,
+    pub status_code: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_type: Option<String>,
+    pub findings: Vec<GraphFinding>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphFinding {
+    pub severity: String,
+    pub title: String,
+}
+
+/// A link between two nodes, by node id
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphEdge {
+    pub source: i64,
+    pub target: i64,
+    pub edge_type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphData {
+    pub session_id: String,
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+/// Collect a session's nodes, edges and per-node findings
+pub fn gather_graph_data(db: &Database, session_id: &str) -> Result<GraphData> {
+    let mut findings_by_node: HashMap<i64, Vec<GraphFinding>> = HashMap::new();
+    for (node_id, severity, title) in db.get_node_findings_by_session(session_id)? {
+        findings_by_node
+            .entry(node_id)
+            .or_default()
+            .push(GraphFinding { severity, title });
+    }
+
+    let nodes = db
+        .get_nodes_by_session(session_id)?
+        .into_iter()
+        .map(|(id, url, status_code, service_type)| GraphNode {
+            id,
+            url,
+            status_code,
+            service_type,
+            findings: findings_by_node.remove(&id).unwrap_or_default(),
+        })
+        .collect();
+
+    let edges = db
+        .get_edges_by_session(session_id)?
+        .into_iter()
+        .map(|(source, target, edge_type)| GraphEdge {
+            source,
+            target,
+            edge_type,
+        })
+        .collect();
+
+    Ok(GraphData {
+        session_id: session_id.to_string(),
+        nodes,
+        edges,
+    })
+}
+
+/// Render the graph as a self-contained HTML page: inline SVG laid out by a small
+/// force-directed simulation, nodes filled by status and outlined by service type.
+/// The simulation runs fewer steps the more nodes there are.
+pub fn generate_html_graph(data: &GraphData) -> Result<String, serde_json::Error> {
+    Ok(HTML_GRAPH_TEMPLATE
+        .replace("{{SESSION_ID}}", &escape_html(&data.session_id))
+        .replace("{{EDGES}}", &script_json(&data.edges)?)
+        .replace("{{NODES}}", &script_json(&data.nodes)?))
+}
+
+/// JSON that is safe to embed inside a `<script>` element
+fn script_json<T: Serialize>(value: &T) -> Result<String, serde_json::Error> {
+    Ok(serde_json::to_string(value)?.replace("</", "<\\/"))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const HTML_GRAPH_TEMPLATE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Rinzler site graph - {{SESSION_ID}}</title>
+<style>
+  html, body { margin: 0; height: 100%; }
+  body { display: flex; font-family: sans-serif; background: #111; color: #ddd; }
+  svg { flex: 1; height: 100%; }
+  #panel { width: 340px; padding: 12px; overflow-y: auto; background: #1b1b1b; border-left: 1px solid #333; }
+  #panel a { color: #6cf; word-break: break-all; }
+  .edge { stroke: #555; stroke-width: 1; }
+  .edge.reference { stroke: #a6f; }
+  .edge.redirect { stroke: #cc3; stroke-dasharray: 4 3; }
+  .node { cursor: pointer; stroke-width: 2; }
+  .legend div { margin: 2px 0; }
+  .swatch { display: inline-block; width: 10px; height: 10px; margin-right: 6px; border-radius: 50%; }
+  .sev-critical { color: #f0f; }
+  .sev-high { color: #f44; }
+  .sev-medium { color: #fc3; }
+  .sev-low, .sev-info { color: #6cf; }
+</style>
+</head>
+<body>
+<svg id="graph"></svg>
+<div id="panel">
+  <h3>Session {{SESSION_ID}}</h3>
+  <div class="legend">
+    <div><span class="swatch" style="background:#4c4"></span>2xx</div>
+    <div><span class="swatch" style="background:#cc3"></span>3xx</div>
+    <div><span class="swatch" style="background:#e44"></span>4xx</div>
+    <div><span class="swatch" style="background:#888"></span>5xx / other</div>
+    <div>Outline: service type. Size: number of findings.</div>
+  </div>
+  <p>Click a node to see its findings.</p>
+  <div id="details"></div>
+</div>
+<script>
+const NODES = {{NODES}};
+const EDGES = {{EDGES}};
+
+const SERVICE_COLORS = {
+  web: "#ddd", rest_api: "#39f", graphql: "#e535ab", soap: "#f90",
+  websocket: "#0cc", static: "#777", redirect: "#cc3"
+};
+
+function statusColor(code) {
+  if (code >= 200 && code < 300) return "#4c4";
+  if (code >= 300 && code < 400) return "#cc3";
+  if (code >= 400 && code < 500) return "#e44";
+  return "#888";
+}
+
+const svg = document.getElementById("graph");
+const width = svg.clientWidth || 960;
+const height = svg.clientHeight || 720;
+const byId = new Map();
+NODES.forEach((n, i) => {
+  const angle = 2 * Math.PI * i / Math.max(NODES.length, 1);
+  n.x = width / 2 + Math.cos(angle) * width / 4;
+  n.y = height / 2 + Math.sin(angle) * height / 4;
+  n.vx = 0;
+  n.vy = 0;
+  byId.set(n.id, n);
+});
+const links = EDGES.filter(e => byId.has(e.source) && byId.has(e.target));
+
+// Force-directed layout: nodes repel, edges act as springs, everything drifts to the centre.
+// Every step compares each pair of nodes, so large graphs get fewer steps.
+const STEPS = Math.max(20, Math.min(300, Math.floor(30000000 / Math.max(NODES.length * NODES.length, 1))));
+for (let step = 0; step < STEPS; step++) {
+  const alpha = 1 - step / STEPS;
+  for (let i = 0; i < NODES.length; i++) {
+    for (let j = i + 1; j < NODES.length; j++) {
+      const a = NODES[i], b = NODES[j];
+      const dx = b.x - a.x, dy = b.y - a.y;
+      const d2 = Math.max(dx * dx + dy * dy, 1);
+      const d = Math.sqrt(d2);
+      const f = 2000 * alpha / d2;
+      a.vx -= dx / d * f; a.vy -= dy / d * f;
+      b.vx += dx / d * f; b.vy += dy / d * f;
+    }
+  }
+  links.forEach(e => {
+    const a = byId.get(e.source), b = byId.get(e.target);
+    const dx = b.x - a.x, dy = b.y - a.y;
+    const d = Math.sqrt(dx * dx + dy * dy) || 1;
+    const f = (d - 80) * 0.05 * alpha;
+    a.vx += dx / d * f; a.vy += dy / d * f;
+    b.vx -= dx / d * f; b.vy -= dy / d * f;
+  });
+  NODES.forEach(n => {
+    n.vx += (width / 2 - n.x) * 0.01 * alpha;
+    n.vy += (height / 2 - n.y) * 0.01 * alpha;
+    n.x += n.vx; n.y += n.vy;
+    n.vx *= 0.6; n.vy *= 0.6;
+  });
+}
+
+// Fit the finished layout into view
+if (NODES.length) {
+  const xs = NODES.map(n => n.x), ys = NODES.map(n => n.y);
+  const minX = Math.min(...xs) - 40, minY = Math.min(...ys) - 40;
+  const w = Math.max(...xs) - minX + 40, h = Math.max(...ys) - minY + 40;
+  svg.setAttribute("viewBox", minX + " " + minY + " " + w + " " + h);
+}
+
+const NS = "http://www.w3.org/2000/svg";
+function svgElement(name, attrs) {
+  const element = document.createElementNS(NS, name);
+  for (const key in attrs) element.setAttribute(key, attrs[key]);
+  return element;
+}
+
+links.forEach(e => {
+  const a = byId.get(e.source), b = byId.get(e.target);
+  svg.appendChild(svgElement("line", { class: "edge " + e.edge_type, x1: a.x, y1: a.y, x2: b.x, y2: b.y }));
+});
+
+NODES.forEach(n => {
+  const circle = svgElement("circle", {
+    class: "node", cx: n.x, cy: n.y,
+    r: 6 + Math.min(n.findings.length, 6),
+    fill: statusColor(n.status_code),
+    stroke: SERVICE_COLORS[n.service_type] || "#444"
+  });
+  const tooltip = svgElement("title", {});
+  tooltip.textContent = n.status_code + " " + n.url;
+  circle.appendChild(tooltip);
+  circle.addEventListener("click", () => showNode(n));
+  svg.appendChild(circle);
+});
+
+function showNode(n) {
+  const details = document.getElementById("details");
+  details.replaceChildren();
+
+  const link = document.createElement("a");
+  link.href = n.url;
+  link.target = "_blank";
+  link.rel = "noopener";
+  link.textContent = n.url;
+  details.appendChild(link);
+
+  const meta = document.createElement("p");
+  meta.textContent = "Status " + n.status_code + (n.service_type ? " - " + n.service_type : "");
+  details.appendChild(meta);
+
+  const heading = document.createElement("h4");
+  heading.textContent = "Findings (" + n.findings.length + ")";
+  details.appendChild(heading);
+
+  const list = document.createElement("ul");
+  n.findings.forEach(f => {
+    const item = document.createElement("li");
+    item.className = "sev-" + f.severity;
+    item.textContent = "[" + f.severity.toUpperCase() + "] " + f.title;
+    list.appendChild(item);
+  });
+  details.appendChild(list);
+}
+</script>
+</body>
+</html>
+"##;
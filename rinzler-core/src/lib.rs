@@ -1,10 +1,22 @@
+pub mod batch;
+pub mod config;
 pub mod crawl;
 pub mod data;
+pub mod diff;
 pub mod fuzz;
+pub mod graph;
 mod map;
+pub mod metrics;
 mod model;
+pub mod openapi;
+pub mod plugin;
+pub mod probe;
+pub mod replay;
 pub mod report;
 pub mod security;
+pub mod serve;
+pub mod tech;
+pub mod webhook;
 
 pub fn print_banner() {
     let version = env!("CARGO_PKG_VERSION");
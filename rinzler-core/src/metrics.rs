@@ -0,0 +1,158 @@
+// Prometheus text-format metrics for a running crawl
+
+use crate::data::{Finding, Severity};
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use rinzler_scanner::result::CrawlResult;
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+use tokio::net::TcpListener;
+
+const SEVERITIES: [Severity; 5] = [
+    Severity::Critical,
+    Severity::High,
+    Severity::Medium,
+    Severity::Low,
+    Severity::Info,
+];
+
+/// Counters updated from the crawl callbacks while the scan runs
+pub struct CrawlMetrics {
+    pages_crawled: AtomicU64,
+    errors: AtomicU64,
+    findings: [AtomicU64; 5],
+    started: Instant,
+}
+
+/// Point-in-time copy of the counters, as rendered by [`format_metrics`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    pub pages_crawled: u64,
+    pub errors: u64,
+    /// Finding counts in critical, high, medium, low, info order
+    pub findings: [u64; 5],
+    pub requests_per_second: f64,
+}
+
+impl Default for CrawlMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CrawlMetrics {
+    pub fn new() -> Self {
+        Self {
+            pages_crawled: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+            findings: Default::default(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Count a fetched page, or an error when the request failed
+    pub fn record_result(&self, result: &CrawlResult) {
+        if result.error.is_some() {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.pages_crawled.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn record_findings(&self, findings: &[Finding]) {
+        for finding in findings {
+            if let Some(index) = SEVERITIES.iter().position(|s| *s == finding.severity) {
+                self.findings[index].fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let pages_crawled = self.pages_crawled.load(Ordering::Relaxed);
+        let errors = self.errors.load(Ordering::Relaxed);
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let requests_per_second = if elapsed > 0.0 {
+            (pages_crawled + errors) as f64 / elapsed
+        } else {
+            0.0
+        };
+
+        MetricsSnapshot {
+            pages_crawled,
+            errors,
+            findings: std::array::from_fn(|i| self.findings[i].load(Ordering::Relaxed)),
+            requests_per_second,
+        }
+    }
+}
+
+/// Render a snapshot in the Prometheus text exposition format
+pub fn format_metrics(snapshot: &MetricsSnapshot) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# HELP rinzler_pages_crawled_total Pages fetched successfully");
+    let _ = writeln!(out, "# TYPE rinzler_pages_crawled_total counter");
+    let _ = writeln!(out, "rinzler_pages_crawled_total {}", snapshot.pages_crawled);
+
+    let _ = writeln!(out, "# HELP rinzler_findings_total Security findings recorded, by severity");
+    let _ = writeln!(out, "# TYPE rinzler_findings_total counter");
+    for (severity, count) in SEVERITIES.iter().zip(snapshot.findings) {
+        let _ = writeln!(
+            out,
+            "rinzler_findings_total{{severity=\"{}\"}} {}",
+            severity.as_str(),
+            count
+        );
+    }
+
+    let _ = writeln!(out, "# HELP rinzler_errors_total Requests that failed without a response");
+    let _ = writeln!(out, "# TYPE rinzler_errors_total counter");
+    let _ = writeln!(out, "rinzler_errors_total {}", snapshot.errors);
+
+    let _ = writeln!(out, "# HELP rinzler_requests_per_second Average request rate since the crawl started");
+    let _ = writeln!(out, "# TYPE rinzler_requests_per_second gauge");
+    let _ = writeln!(out, "rinzler_requests_per_second {:.3}", snapshot.requests_per_second);
+
+    out
+}
+
+/// Serve `GET /metrics` on `listener` until the task is cancelled
+pub async fn serve_metrics(metrics: Arc<CrawlMetrics>, listener: TcpListener) -> std::io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let metrics = metrics.clone();
+
+        tokio::spawn(async move {
+            let service = service_fn(move |req: Request<Incoming>| {
+                let metrics = metrics.clone();
+                async move { Ok::<_, Infallible>(respond(&metrics, &req)) }
+            });
+
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+fn respond(metrics: &CrawlMetrics, req: &Request<Incoming>) -> Response<Full<Bytes>> {
+    if req.uri().path() != "/metrics" {
+        let mut response = Response::new(Full::new(Bytes::from_static(b"not found\n")));
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        return response;
+    }
+
+    let mut response = Response::new(Full::new(Bytes::from(format_metrics(&metrics.snapshot()))));
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("text/plain; version=0.0.4"),
+    );
+    response
+}
@@ -0,0 +1,177 @@
+use serde_json::Value;
+use std::collections::HashSet;
+use url::Url;
+
+/// Placeholder for integer and number path parameters
+const NUMERIC_PLACEHOLDER: &str = "1";
+/// Placeholder for every other path parameter
+const STRING_PLACEHOLDER: &str = "test";
+
+/// Load a spec from a local file or an http(s) URL
+pub async fn load_spec(source: &str) -> Result<String, String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let response = reqwest::get(source)
+            .await
+            .map_err(|e| format!("Failed to fetch OpenAPI spec {}: {}", source, e))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "Failed to fetch OpenAPI spec {}: HTTP {}",
+                source,
+                response.status()
+            ));
+        }
+        response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read OpenAPI spec {}: {}", source, e))
+    } else {
+        std::fs::read_to_string(source)
+            .map_err(|e| format!("Failed to read OpenAPI spec {}: {}", source, e))
+    }
+}
+
+/// Extract one concrete URL per path in an OpenAPI 3 or Swagger 2 spec (JSON or YAML).
+/// Relative servers resolve against `base`; path parameters get placeholder values.
+pub fn extract_urls_from_spec(spec: &str, base: &Url) -> Result<Vec<String>, String> {
+    let doc: Value = match serde_json::from_str(spec) {
+        Ok(doc) => doc,
+        Err(_) => serde_yaml::from_str(spec)
+            .map_err(|e| format!("Failed to parse OpenAPI spec: {}", e))?,
+    };
+
+    let servers = if doc.get("openapi").is_some() {
+        v3_servers(&doc, base)
+    } else if doc.get("swagger").is_some() {
+        v2_servers(&doc, base)
+    } else {
+        return Err("Not an OpenAPI spec: missing 'openapi' or 'swagger' field".to_string());
+    };
+
+    let paths = doc
+        .get("paths")
+        .and_then(Value::as_object)
+        .ok_or_else(|| "OpenAPI spec has no 'paths' object".to_string())?;
+
+    let mut seen = HashSet::new();
+    let mut urls = Vec::new();
+    for server in &servers {
+        let prefix = server.as_str().trim_end_matches('/');
+        for (template, item) in paths {
+            let path = expand_path(template, item);
+            let candidate = format!("{}/{}", prefix, path.trim_start_matches('/'));
+            if let Ok(url) = Url::parse(&candidate)
+                && seen.insert(url.to_string())
+            {
+                urls.push(url.to_string());
+            }
+        }
+    }
+
+    Ok(urls)
+}
+
+/// Server roots from an OpenAPI 3 `servers` list, falling back to `base`
+fn v3_servers(doc: &Value, base: &Url) -> Vec<Url> {
+    let servers: Vec<Url> = doc
+        .get("servers")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|server| {
+            let url = server.get("url")?.as_str()?;
+            let url = substitute_server_variables(url, server.get("variables"));
+            base.join(&url).ok()
+        })
+        .collect();
+
+    if servers.is_empty() {
+        vec![base.clone()]
+    } else {
+        servers
+    }
+}
+
+/// Replace `{name}` in a server URL with the variable's default
+fn substitute_server_variables(url: &str, variables: Option<&Value>) -> String {
+    let mut url = url.to_string();
+    if let Some(variables) = variables.and_then(Value::as_object) {
+        for (name, variable) in variables {
+            if let Some(default) = variable.get("default").and_then(Value::as_str) {
+                url = url.replace(&format!("{{{}}}", name), default);
+            }
+        }
+    }
+    url
+}
+
+/// Server roots from Swagger 2 `host`, `basePath` and `schemes`, falling back to `base`
+fn v2_servers(doc: &Value, base: &Url) -> Vec<Url> {
+    let base_path = doc.get("basePath").and_then(Value::as_str).unwrap_or("/");
+    let Some(host) = doc.get("host").and_then(Value::as_str) else {
+        return base.join(base_path).into_iter().collect();
+    };
+
+    let schemes: Vec<&str> = doc
+        .get("schemes")
+        .and_then(Value::as_array)
+        .map(|schemes| schemes.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let schemes = if schemes.is_empty() {
+        vec![base.scheme()]
+    } else {
+        schemes
+    };
+
+    schemes
+        .into_iter()
+        .filter_map(|scheme| Url::parse(&format!("{}://{}{}", scheme, host, base_path)).ok())
+        .collect()
+}
+
+/// Fill `{param}` segments of a path template with placeholder values
+fn expand_path(template: &str, item: &Value) -> String {
+    let mut path = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        let name = &rest[start + 1..start + end];
+        path.push_str(&rest[..start]);
+        path.push_str(placeholder_for(name, item));
+        rest = &rest[start + end + 1..];
+    }
+    path.push_str(rest);
+    path
+}
+
+/// Pick a placeholder from the declared type of a path parameter
+fn placeholder_for(name: &str, item: &Value) -> &'static str {
+    let path_level = item.get("parameters").and_then(Value::as_array);
+    let operation_level = item
+        .as_object()
+        .into_iter()
+        .flat_map(|ops| ops.values())
+        .filter_map(|op| op.get("parameters").and_then(Value::as_array));
+
+    let declared_type = path_level
+        .into_iter()
+        .chain(operation_level)
+        .flatten()
+        .find(|param| {
+            param.get("name").and_then(Value::as_str) == Some(name)
+                && param.get("in").and_then(Value::as_str) == Some("path")
+        })
+        .and_then(|param| {
+            // Swagger 2 puts the type on the parameter, OpenAPI 3 under `schema`
+            param
+                .get("type")
+                .or_else(|| param.get("schema").and_then(|schema| schema.get("type")))
+                .and_then(Value::as_str)
+        });
+
+    match declared_type {
+        Some("integer") | Some("number") => NUMERIC_PLACEHOLDER,
+        _ => STRING_PLACEHOLDER,
+    }
+}
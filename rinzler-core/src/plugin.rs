@@ -0,0 +1,385 @@
+// Custom passive checks run alongside the built-in security checks
+
+use crate::data::{Confidence, Database, Finding, FindingType, Severity};
+use regex::Regex;
+use rhai::{AST, Dynamic, Engine, Map, Scope};
+use rinzler_scanner::result::CrawlResult;
+use serde::Deserialize;
+use std::path::Path;
+
+/// A check that inspects a crawled page without sending any requests
+pub trait PassiveCheck: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn analyze(&self, result: &CrawlResult, node_id: i64) -> Vec<Finding>;
+}
+
+/// The custom checks the crawl runs after `security::analyze_crawl_result`
+#[derive(Default)]
+pub struct CheckRegistry {
+    checks: Vec<Box<dyn PassiveCheck>>,
+}
+
+impl CheckRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, check: Box<dyn PassiveCheck>) {
+        self.checks.push(check);
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.checks.iter().map(|check| check.name()).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+
+    /// Findings from every registered check, in registration order
+    pub fn analyze(&self, result: &CrawlResult, node_id: i64) -> Vec<Finding> {
+        self.checks
+            .iter()
+            .flat_map(|check| check.analyze(result, node_id))
+            .collect()
+    }
+}
+
+/// Operations a script may run per page before it is stopped
+pub const SCRIPT_MAX_OPERATIONS: u64 = 100_000;
+
+/// Load a plugin file: `.rhai` files are scripts, anything else a TOML rule file
+pub fn load_check(name: &str, path: &Path) -> Result<Box<dyn PassiveCheck>, String> {
+    if path.extension().is_some_and(|ext| ext == "rhai") {
+        Ok(Box::new(ScriptCheck::load(name, path)?))
+    } else {
+        Ok(Box::new(RuleCheck::load(name, path)?))
+    }
+}
+
+/// Load every plugin registered in the database. A plugin that fails to load is
+/// left out and its error returned alongside the registry.
+pub fn load_registry(db: &Database) -> rusqlite::Result<(CheckRegistry, Vec<String>)> {
+    let mut registry = CheckRegistry::new();
+    let mut errors = Vec::new();
+
+    for plugin in db.list_plugins()? {
+        match load_check(&plugin.name, Path::new(&plugin.path)) {
+            Ok(check) => registry.register(check),
+            Err(e) => errors.push(format!("plugin '{}': {}", plugin.name, e)),
+        }
+    }
+
+    Ok((registry, errors))
+}
+
+/// Rule file as written by the user; see `RuleCheck`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleFile {
+    #[serde(default, rename = "rule")]
+    rules: Vec<RuleSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleSpec {
+    title: String,
+    severity: String,
+    #[serde(default)]
+    description: String,
+    finding_type: Option<String>,
+    remediation: Option<String>,
+    cwe: Option<String>,
+    url: Option<String>,
+    #[serde(default)]
+    status: Vec<u16>,
+    content_type: Option<String>,
+    header: Option<String>,
+    header_value: Option<String>,
+}
+
+struct Rule {
+    title: String,
+    severity: Severity,
+    description: String,
+    finding_type: FindingType,
+    remediation: Option<String>,
+    cwe: Option<String>,
+    url: Option<Regex>,
+    status: Vec<u16>,
+    content_type: Option<Regex>,
+    header: Option<String>,
+    header_value: Option<Regex>,
+}
+
+impl Rule {
+    fn from_spec(spec: RuleSpec) -> Result<Self, String> {
+        let severity = spec.severity.parse::<Severity>()?;
+        let finding_type = match spec.finding_type {
+            Some(name) => parse_finding_type(&name)?,
+            None => FindingType::Other,
+        };
+        if spec.header_value.is_some() && spec.header.is_none() {
+            return Err(format!("rule '{}': header_value needs header", spec.title));
+        }
+        if spec.url.is_none()
+            && spec.status.is_empty()
+            && spec.content_type.is_none()
+            && spec.header.is_none()
+        {
+            return Err(format!(
+                "rule '{}': needs at least one of url, status, content_type or header",
+                spec.title
+            ));
+        }
+
+        let compile = |pattern: Option<String>| {
+            pattern
+                .map(|p| Regex::new(&p).map_err(|e| format!("rule '{}': {}", spec.title, e)))
+                .transpose()
+        };
+
+        Ok(Self {
+            url: compile(spec.url)?,
+            content_type: compile(spec.content_type)?,
+            header_value: compile(spec.header_value)?,
+            title: spec.title,
+            severity,
+            description: spec.description,
+            finding_type,
+            remediation: spec.remediation,
+            cwe: spec.cwe,
+            status: spec.status,
+            header: spec.header,
+        })
+    }
+
+    /// Every condition the rule sets must hold
+    fn matches(&self, result: &CrawlResult) -> bool {
+        if let Some(ref url) = self.url
+            && !url.is_match(&result.url)
+        {
+            return false;
+        }
+        if !self.status.is_empty() && !self.status.contains(&result.status_code) {
+            return false;
+        }
+        if let Some(ref content_type) = self.content_type
+            && !result
+                .content_type
+                .as_deref()
+                .is_some_and(|value| content_type.is_match(value))
+        {
+            return false;
+        }
+        if let Some(ref header) = self.header {
+            let value = result
+                .headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(header))
+                .map(|(_, value)| value);
+            match (value, &self.header_value) {
+                (None, _) => return false,
+                (Some(value), Some(pattern)) if !pattern.is_match(value) => return false,
+                _ => {}
+            }
+        }
+        true
+    }
+
+    fn finding(&self, result: &CrawlResult, node_id: i64) -> Finding {
+        Finding {
+            node_id,
+            finding_type: self.finding_type.clone(),
+            severity: self.severity.clone(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            impact: None,
+            remediation: self.remediation.clone(),
+            evidence: Some(format!("{} {}", result.status_code, result.url)),
+            cwe_id: self.cwe.clone(),
+            owasp_category: None,
+            cvss_score: None,
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
+        }
+    }
+}
+
+/// A plugin defined as a TOML file of `[[rule]]` tables. Each rule matches on any of
+/// `url` (regex), `status` (list of codes), `content_type` (regex) and `header`
+/// (name, optionally with a `header_value` regex) and reports `title`/`severity`
+/// on every page where all of its conditions hold.
+pub struct RuleCheck {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl RuleCheck {
+    pub fn from_toml(name: &str, source: &str) -> Result<Self, String> {
+        let file: RuleFile = toml::from_str(source).map_err(|e| e.to_string())?;
+        if file.rules.is_empty() {
+            return Err("no [[rule]] entries".to_string());
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            rules: file
+                .rules
+                .into_iter()
+                .map(Rule::from_spec)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn load(name: &str, path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        Self::from_toml(name, &source)
+    }
+}
+
+impl PassiveCheck for RuleCheck {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn analyze(&self, result: &CrawlResult, node_id: i64) -> Vec<Finding> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(result))
+            .map(|rule| rule.finding(result, node_id))
+            .collect()
+    }
+}
+
+/// A plugin written in Rhai. The script defines
+/// `fn analyze(url, status, content_type, body)` and returns a map, an array of
+/// maps, or `()` for no findings. Each map needs `title` and may set `severity`
+/// (default `info`), `description`, `finding_type`, `remediation`, `evidence` and
+/// `cwe`. `content_type` is `""` when unknown and `body` holds the first
+/// 64 KB of the response. A script that errors or exceeds
+/// `SCRIPT_MAX_OPERATIONS` reports nothing for that page.
+pub struct ScriptCheck {
+    name: String,
+    engine: Engine,
+    ast: AST,
+}
+
+impl ScriptCheck {
+    pub fn from_source(name: &str, source: &str) -> Result<Self, String> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(SCRIPT_MAX_OPERATIONS);
+        // Keep script output off the crawl monitor
+        engine.on_print(|_| {});
+        engine.on_debug(|_, _, _| {});
+
+        let ast = engine.compile(source).map_err(|e| e.to_string())?;
+        if !ast
+            .iter_functions()
+            .any(|f| f.name == "analyze" && f.params.len() == 4)
+        {
+            return Err("script must define fn analyze(url, status, content_type, body)".to_string());
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            engine,
+            ast,
+        })
+    }
+
+    pub fn load(name: &str, path: &Path) -> Result<Self, String> {
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        Self::from_source(name, &source)
+    }
+}
+
+impl PassiveCheck for ScriptCheck {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn analyze(&self, result: &CrawlResult, node_id: i64) -> Vec<Finding> {
+        let args = (
+            result.url.clone(),
+            result.status_code as i64,
+            result.content_type.clone().unwrap_or_default(),
+            result.body_sample.clone().unwrap_or_default(),
+        );
+        let returned = match self
+            .engine
+            .call_fn::<Dynamic>(&mut Scope::new(), &self.ast, "analyze", args)
+        {
+            Ok(returned) => returned,
+            Err(_) => return Vec::new(),
+        };
+
+        let maps: Vec<Map> = if let Some(map) = returned.clone().try_cast::<Map>() {
+            vec![map]
+        } else if let Some(array) = returned.try_cast::<rhai::Array>() {
+            array
+                .into_iter()
+                .filter_map(|item| item.try_cast::<Map>())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        maps.iter()
+            .filter_map(|map| script_finding(map, result, node_id))
+            .collect()
+    }
+}
+
+/// Convert one map returned by a script; `None` when it has no usable title
+fn script_finding(map: &Map, result: &CrawlResult, node_id: i64) -> Option<Finding> {
+    let field = |key: &str| {
+        map.get(key)
+            .and_then(|value| value.clone().into_string().ok())
+            .filter(|value| !value.is_empty())
+    };
+
+    Some(Finding {
+        node_id,
+        finding_type: field("finding_type")
+            .and_then(|name| parse_finding_type(&name).ok())
+            .unwrap_or(FindingType::Other),
+        severity: field("severity")
+            .and_then(|severity| severity.parse().ok())
+            .unwrap_or(Severity::Info),
+        title: field("title")?,
+        description: field("description").unwrap_or_default(),
+        impact: None,
+        remediation: field("remediation"),
+        evidence: field("evidence").or_else(|| Some(format!("{} {}", result.status_code, result.url))),
+        cwe_id: field("cwe"),
+        owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
+    })
+}
+
+fn parse_finding_type(name: &str) -> Result<FindingType, String> {
+    [
+        FindingType::Vulnerability,
+        FindingType::Misconfiguration,
+        FindingType::InformationDisclosure,
+        FindingType::InterestingFile,
+        FindingType::SecurityHeaderMissing,
+        FindingType::InsecureTransport,
+        FindingType::AuthenticationIssue,
+        FindingType::AuthorizationIssue,
+        FindingType::InjectionPoint,
+        FindingType::Other,
+    ]
+    .into_iter()
+    .find(|finding_type| finding_type.as_str() == name)
+    .ok_or_else(|| format!("unknown finding_type: {}", name))
+}
@@ -0,0 +1,134 @@
+// Active checks that send additional requests to crawled endpoints
+
+use crate::data::{Confidence, Finding, FindingType, ServiceType, Severity};
+use crate::tech::classify_service;
+use reqwest::{Client, Method};
+use rinzler_scanner::result::CrawlResult;
+use url::Url;
+
+/// Foreign origin sent with CORS preflight probes
+pub const PROBE_ORIGIN: &str = "https://rinzler-probe.invalid";
+
+/// Methods that modify state and should not be offered by a read-only endpoint
+const WRITE_METHODS: &[&str] = &["PUT", "PATCH", "DELETE"];
+
+/// Path segments suggesting an endpoint is meant to accept writes
+const WRITE_SEGMENTS: &[&str] = &[
+    "create", "update", "delete", "edit", "upload", "submit", "remove", "save",
+];
+
+/// Send a CORS preflight to a REST API node and flag permissive responses:
+/// a wildcard or reflected origin combined with credentials, or write methods
+/// offered on an endpoint that looks read-only. Other service types are skipped.
+pub async fn check_cors_preflight(
+    client: &Client,
+    result: &CrawlResult,
+    node_id: i64,
+) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    let service_type = classify_service(
+        result.content_type.as_deref(),
+        &result.url,
+        result.status_code,
+    );
+    if service_type != Some(ServiceType::RestApi) {
+        return findings;
+    }
+
+    let response = match client
+        .request(Method::OPTIONS, &result.url)
+        .header("Origin", PROBE_ORIGIN)
+        .header("Access-Control-Request-Method", "PUT")
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        Err(_) => return findings,
+    };
+
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().to_string())
+    };
+    let allow_origin = header("access-control-allow-origin");
+    let allow_credentials = header("access-control-allow-credentials")
+        .is_some_and(|value| value.eq_ignore_ascii_case("true"));
+    let mut methods: Vec<String> = [header("access-control-allow-methods"), header("allow")]
+        .into_iter()
+        .flatten()
+        .flat_map(|list| {
+            list.split(',')
+                .map(|m| m.trim().to_ascii_uppercase())
+                .collect::<Vec<_>>()
+        })
+        .filter(|m| !m.is_empty())
+        .collect();
+    methods.sort();
+    methods.dedup();
+
+    let evidence = serde_json::json!({
+        "url": result.url,
+        "allow_origin": allow_origin.as_deref().unwrap_or(""),
+        "allow_credentials": allow_credentials,
+        "allowed_methods": methods.join(", "),
+    })
+    .to_string();
+
+    let origin_open = allow_origin
+        .as_deref()
+        .is_some_and(|origin| origin == "*" || origin == PROBE_ORIGIN);
+    if origin_open && allow_credentials {
+        findings.push(Finding {
+            node_id,
+            finding_type: FindingType::Misconfiguration,
+            severity: Severity::Medium,
+            title: "Permissive CORS Policy With Credentials".to_string(),
+            description: format!("{} allows any origin ({}) together with Access-Control-Allow-Credentials: true.", result.url, allow_origin.as_deref().unwrap_or("")),
+            impact: Some("A malicious site may be able to make authenticated requests to the API on behalf of a logged-in user and read the responses.".to_string()),
+            remediation: Some("Restrict Access-Control-Allow-Origin to an explicit allowlist of trusted origins and only allow credentials for those origins.".to_string()),
+            evidence: Some(evidence.clone()),
+            cwe_id: Some("CWE-942".to_string()),
+            owasp_category: Some("A05:2021 - Security Misconfiguration".to_string()),
+            cvss_score: Some(6.5),
+            confidence: Confidence::Confirmed,
+            request_sample: None,
+            response_sample: None,
+        });
+    }
+
+    let write_methods: Vec<&str> = WRITE_METHODS
+        .iter()
+        .copied()
+        .filter(|method| methods.iter().any(|m| m == method))
+        .collect();
+    let read_looking = (200..300).contains(&result.status_code)
+        && Url::parse(&result.url).is_ok_and(|url| {
+            url.path_segments().is_none_or(|mut segments| {
+                !segments.any(|s| WRITE_SEGMENTS.contains(&s.to_ascii_lowercase().as_str()))
+            })
+        });
+    if read_looking && !write_methods.is_empty() {
+        findings.push(Finding {
+            node_id,
+            finding_type: FindingType::Misconfiguration,
+            severity: Severity::Medium,
+            title: "Write Methods Allowed on Read-Only Endpoint".to_string(),
+            description: format!("{} serves data over GET but its OPTIONS response also allows {}.", result.url, write_methods.join(", ")),
+            impact: Some("Unexpected write methods may let attackers modify or delete resources if the handlers are not properly authorized.".to_string()),
+            remediation: Some("Only advertise and accept the HTTP methods the endpoint actually needs.".to_string()),
+            evidence: Some(evidence),
+            cwe_id: Some("CWE-749".to_string()),
+            owasp_category: Some("A05:2021 - Security Misconfiguration".to_string()),
+            cvss_score: Some(6.5),
+            confidence: Confidence::Possible,
+            request_sample: None,
+            response_sample: None,
+        });
+    }
+
+    findings
+}
@@ -0,0 +1,143 @@
+use crate::data::HttpTransaction;
+use reqwest::{Client, Method};
+use rinzler_scanner::{ClientConfig, RequestSettings, build_client, content_hash};
+
+/// Stored in place of a credential header's value when a request is logged
+pub const REDACTED: &str = "<redacted>";
+
+/// Headers whose values are credentials and are never written to the database
+const CREDENTIAL_HEADERS: &[&str] = &["authorization", "proxy-authorization", "cookie"];
+
+/// Copy of `headers` with every credential header's value replaced by `REDACTED`,
+/// so logged transactions and HAR exports don't leak the scan's auth
+pub fn redact_credentials(headers: &[(String, String)]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let secret = CREDENTIAL_HEADERS
+                .iter()
+                .any(|credential| name.eq_ignore_ascii_case(credential));
+            let value = if secret {
+                REDACTED.to_string()
+            } else {
+                value.clone()
+            };
+            (name.clone(), value)
+        })
+        .collect()
+}
+
+/// How a replayed request compared to the response recorded for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayOutcome {
+    pub method: String,
+    pub url: String,
+    /// Differences from the recorded response; empty when nothing changed
+    pub drift: Vec<String>,
+}
+
+impl ReplayOutcome {
+    pub fn has_drift(&self) -> bool {
+        !self.drift.is_empty()
+    }
+}
+
+/// Re-issue each recorded request (same method, URL, headers and body) in order
+/// and compare the status, content type and body hash to what was stored.
+/// Redacted credential headers are left out; `request` supplies the current
+/// auth and headers instead.
+pub async fn replay_transactions(
+    transactions: &[HttpTransaction],
+    timeout_secs: u64,
+    request: RequestSettings,
+) -> Result<Vec<ReplayOutcome>, String> {
+    let client = build_client(&ClientConfig {
+        timeout_secs,
+        request,
+        ..ClientConfig::default()
+    })
+    .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let mut outcomes = Vec::with_capacity(transactions.len());
+    for transaction in transactions {
+        let drift = match replay_one(&client, transaction).await {
+            Ok(drift) => drift,
+            Err(e) => vec![format!("request failed: {}", e)],
+        };
+        outcomes.push(ReplayOutcome {
+            method: transaction.method.clone(),
+            url: transaction.url.clone(),
+            drift,
+        });
+    }
+
+    Ok(outcomes)
+}
+
+async fn replay_one(client: &Client, transaction: &HttpTransaction) -> Result<Vec<String>, String> {
+    let method = Method::from_bytes(transaction.method.as_bytes())
+        .map_err(|_| format!("invalid method {}", transaction.method))?;
+
+    let mut request = client.request(method, &transaction.url);
+    for (name, value) in &transaction.request_headers {
+        if value == REDACTED {
+            continue;
+        }
+        request = request.header(name, value);
+    }
+    if let Some(ref body) = transaction.request_body {
+        request = request.body(body.clone());
+    }
+
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    let status = response.status().as_u16();
+    let content_type = response
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let body = response.text().await.map_err(|e| e.to_string())?;
+
+    Ok(compare_response(
+        transaction,
+        status,
+        content_type.as_deref(),
+        &content_hash(body.as_bytes()),
+    ))
+}
+
+/// Describe how a fresh response differs from the recorded one
+pub fn compare_response(
+    recorded: &HttpTransaction,
+    status: u16,
+    content_type: Option<&str>,
+    body_hash: &str,
+) -> Vec<String> {
+    let mut drift = Vec::new();
+
+    if recorded.response_code != status {
+        drift.push(format!("status {} -> {}", recorded.response_code, status));
+    }
+
+    // Only compare headers the recording actually captured
+    if let Some((_, recorded_type)) = recorded
+        .response_headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+        && Some(recorded_type.as_str()) != content_type
+    {
+        drift.push(format!(
+            "content-type {} -> {}",
+            recorded_type,
+            content_type.unwrap_or("(none)")
+        ));
+    }
+
+    if let Some(ref recorded_hash) = recorded.content_hash
+        && recorded_hash != body_hash
+    {
+        drift.push("body changed".to_string());
+    }
+
+    drift
+}
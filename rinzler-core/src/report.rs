@@ -1,10 +1,13 @@
 // Report generation from database
 
-use crate::data::Database;
+use crate::data::{Database, Severity};
+use crate::diff::{NodeChange, NodeChangeCounts, classify_node_change};
+use rinzler_scanner::CrawlStats;
 use rusqlite::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufRead, Write};
 use std::path::Path;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +17,11 @@ pub enum ReportFormat {
     Csv,
     Html,
     Markdown,
+    Jsonl,
+    Sarif,
+    Toml,
+    GithubIssues,
+    JUnit,
 }
 
 impl std::str::FromStr for ReportFormat {
@@ -26,6 +34,11 @@ impl std::str::FromStr for ReportFormat {
             "csv" => Ok(ReportFormat::Csv),
             "html" => Ok(ReportFormat::Html),
             "markdown" | "md" => Ok(ReportFormat::Markdown),
+            "jsonl" | "ndjson" => Ok(ReportFormat::Jsonl),
+            "sarif" => Ok(ReportFormat::Sarif),
+            "toml" => Ok(ReportFormat::Toml),
+            "github-issues" => Ok(ReportFormat::GithubIssues),
+            "junit" => Ok(ReportFormat::JUnit),
             _ => Err(format!("Unknown format: {}", s)),
         }
     }
@@ -37,9 +50,70 @@ pub struct ReportData {
     pub total_nodes: usize,
     pub findings: Vec<FindingData>,
     pub severity_counts: SeverityCounts,
+    /// Raw per-node occurrence counts, set when `severity_counts` holds distinct findings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occurrence_counts: Option<SeverityCounts>,
     pub scan_info: ScanInfo,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sitemap_nodes: Option<Vec<SitemapNode>>,
+    /// Sitemap of an earlier session; when set the text sitemap is annotated with +/- changes
+    #[serde(skip)]
+    pub sitemap_baseline: Option<Vec<SitemapNode>>,
+    /// Crawl coverage metrics, only available for a live crawl or a capture that recorded them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<CrawlStats>,
+    /// Technologies fingerprinted during the scan, strongest evidence first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub technologies: Vec<TechnologyData>,
+    /// Per-phase timing, only available for a live crawl or a capture that recorded it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<ScanTimings>,
+    /// Findings collapsed by title and type; when set the text report lists these instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouped_findings: Option<Vec<GroupedFinding>>,
+    /// Pages that are new or changed since the previous scan; empty on a first scan
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed_nodes: Vec<ChangedNode>,
+}
+
+/// A page whose status or content differs from its crawl in the previous session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangedNode {
+    pub url: String,
+    /// `new` or `changed`
+    pub change: String,
+    pub status_code: Option<i64>,
+    pub content_length: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_status_code: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_content_length: Option<i64>,
+}
+
+/// Where a scan spent its time, in seconds
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScanTimings {
+    /// Crawl wall-time spent fetching pages
+    pub crawl_secs: f64,
+    /// Security checks, probes and technology detection
+    pub analysis_secs: f64,
+    /// Database writes
+    pub persistence_secs: f64,
+    /// The whole scan, including time not attributed to a phase
+    pub total_secs: f64,
+    /// Analysis and persistence ran alongside the crawl, so the phases add up to
+    /// more than the total
+    #[serde(default)]
+    pub overlapping: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TechnologyData {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub category: String,
+    pub confidence: u8,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,6 +140,69 @@ pub struct FindingData {
     pub impact: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remediation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cvss_score: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_sample: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_sample: Option<String>,
+}
+
+/// Findings sharing a title and type, reported once with every affected URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupedFinding {
+    pub severity: String,
+    pub title: String,
+    pub description: String,
+    pub finding_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwe_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owasp_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impact: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<String>,
+    /// Number of findings collapsed into this group
+    pub count: usize,
+    /// Distinct affected URLs, in order of first appearance
+    pub urls: Vec<String>,
+}
+
+/// Collapse findings with the same title and type into one group each. Groups keep
+/// the order and details of their first finding, so severity-sorted input stays sorted.
+pub fn group_findings(findings: &[FindingData]) -> Vec<GroupedFinding> {
+    let mut groups: Vec<GroupedFinding> = Vec::new();
+
+    for finding in findings {
+        let existing = groups
+            .iter_mut()
+            .find(|g| g.title == finding.title && g.finding_type == finding.finding_type);
+        match existing {
+            Some(group) => {
+                group.count += 1;
+                if !group.urls.contains(&finding.url) {
+                    group.urls.push(finding.url.clone());
+                }
+            }
+            None => groups.push(GroupedFinding {
+                severity: finding.severity.clone(),
+                title: finding.title.clone(),
+                description: finding.description.clone(),
+                finding_type: finding.finding_type.clone(),
+                cwe_id: finding.cwe_id.clone(),
+                owasp_category: finding.owasp_category.clone(),
+                impact: finding.impact.clone(),
+                remediation: finding.remediation.clone(),
+                count: 1,
+                urls: vec![finding.url.clone()],
+            }),
+        }
+    }
+
+    groups
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,6 +214,35 @@ pub struct SeverityCounts {
     pub info: i64,
 }
 
+impl SeverityCounts {
+    fn from_rows(rows: impl IntoIterator<Item = (String, i64)>) -> Self {
+        let mut counts = SeverityCounts {
+            critical: 0,
+            high: 0,
+            medium: 0,
+            low: 0,
+            info: 0,
+        };
+
+        for (severity, count) in rows {
+            match severity.as_str() {
+                "critical" => counts.critical = count,
+                "high" => counts.high = count,
+                "medium" => counts.medium = count,
+                "low" => counts.low = count,
+                "info" => counts.info = count,
+                _ => {}
+            }
+        }
+
+        counts
+    }
+
+    pub fn total(&self) -> i64 {
+        self.critical + self.high + self.medium + self.low + self.info
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScanInfo {
     pub start_time: i64,
@@ -84,18 +250,67 @@ pub struct ScanInfo {
     pub end_time: Option<i64>,
     pub status: String,
     pub seed_urls: String,
+    /// Findings discarded after the session hit its --max-findings cap
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub findings_dropped: i64,
+}
+
+/// Overview of a stored session, as listed by `rinzler serve`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSummary {
+    pub session_id: String,
+    pub total_nodes: usize,
+    pub severity_counts: SeverityCounts,
+    #[serde(flatten)]
+    pub scan_info: ScanInfo,
+}
+
+impl From<ReportData> for SessionSummary {
+    fn from(data: ReportData) -> Self {
+        Self {
+            session_id: data.session_id,
+            total_nodes: data.total_nodes,
+            severity_counts: data.severity_counts,
+            scan_info: data.scan_info,
+        }
+    }
+}
+
+fn is_zero(value: &i64) -> bool {
+    *value == 0
+}
+
+/// A single line of a streamed NDJSON scan capture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsonlRecord {
+    Session {
+        session_id: String,
+        total_nodes: usize,
+        #[serde(flatten)]
+        scan_info: ScanInfo,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        coverage: Option<CrawlStats>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timings: Option<ScanTimings>,
+    },
+    Node(SitemapNode),
+    Technology(TechnologyData),
+    Finding(FindingData),
 }
 
 pub fn gather_report_data(
     db: &Database,
     session_id: &str,
     include_sitemap: bool,
+    unique_findings: bool,
 ) -> Result<ReportData> {
     // Get session info
     let scan_info = {
         let conn = db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT start_time, end_time, status, seed_urls FROM crawl_sessions WHERE id = ?1",
+            "SELECT start_time, end_time, status, seed_urls, findings_dropped
+             FROM crawl_sessions WHERE id = ?1",
         )?;
 
         stmt.query_row([session_id], |row| {
@@ -104,6 +319,7 @@ pub fn gather_report_data(
                 end_time: row.get(1)?,
                 status: row.get(2)?,
                 seed_urls: row.get(3)?,
+                findings_dropped: row.get(4)?,
             })
         })?
     };
@@ -112,32 +328,29 @@ pub fn gather_report_data(
     let nodes = db.get_nodes_by_session(session_id)?;
     let total_nodes = nodes.len();
 
-    // Get severity counts
-    let severity_counts_raw = db.get_findings_count_by_severity(session_id)?;
-    let mut severity_counts = SeverityCounts {
-        critical: 0,
-        high: 0,
-        medium: 0,
-        low: 0,
-        info: 0,
+    // Get severity counts (distinct type+title pairs in unique mode)
+    let (severity_counts, occurrence_counts) = if unique_findings {
+        let rows = db.get_unique_findings_count_by_severity(session_id)?;
+        let distinct = SeverityCounts::from_rows(
+            rows.iter()
+                .map(|(severity, distinct, _)| (severity.clone(), *distinct)),
+        );
+        let occurrences = SeverityCounts::from_rows(
+            rows.into_iter()
+                .map(|(severity, _, total)| (severity, total)),
+        );
+        (distinct, Some(occurrences))
+    } else {
+        let rows = db.get_findings_count_by_severity(session_id)?;
+        (SeverityCounts::from_rows(rows), None)
     };
 
-    for (severity, count) in severity_counts_raw {
-        match severity.as_str() {
-            "critical" => severity_counts.critical = count,
-            "high" => severity_counts.high = count,
-            "medium" => severity_counts.medium = count,
-            "low" => severity_counts.low = count,
-            "info" => severity_counts.info = count,
-            _ => {}
-        }
-    }
-
     // Get detailed findings
     let conn = db.get_connection();
     let mut stmt = conn.prepare(
         "SELECT f.id, f.severity, f.title, f.description, n.url, f.finding_type,
-                f.cwe_id, f.owasp_category, f.impact, f.remediation
+                f.cwe_id, f.owasp_category, f.impact, f.remediation, f.cvss_score,
+                f.confidence, f.request_sample, f.response_sample
          FROM findings f
          JOIN nodes n ON f.node_id = n.id
          WHERE f.session_id = ?1 AND f.false_positive = 0
@@ -161,49 +374,146 @@ pub fn gather_report_data(
                 finding_type: row.get(5)?,
                 cwe_id: row.get(6)?,
                 owasp_category: row.get(7)?,
+                cvss_score: row.get(10)?,
+                confidence: row.get(11)?,
                 impact: row.get(8)?,
                 remediation: row.get(9)?,
+                request_sample: row.get(12)?,
+                response_sample: row.get(13)?,
             })
         })?
         .collect::<Result<Vec<_>>>()?;
 
     // Optionally gather sitemap data
     let sitemap_nodes = if include_sitemap {
+        Some(gather_sitemap_nodes(db, session_id)?)
+    } else {
+        None
+    };
+
+    // Distinct technologies across the session, keeping the strongest evidence
+    let technologies = {
         let conn = db.get_connection();
         let mut stmt = conn.prepare(
-            "SELECT n.url, n.response_code, n.content_type
-             FROM nodes n
+            "SELECT t.name, t.version, t.category, MAX(t.confidence)
+             FROM technologies t
+             JOIN nodes n ON t.node_id = n.id
              JOIN maps m ON n.map_id = m.id
              WHERE m.session_id = ?1
-             ORDER BY n.url",
+             GROUP BY t.name, t.version, t.category
+             ORDER BY MAX(t.confidence) DESC, t.name",
         )?;
 
-        let nodes = stmt
-            .query_map([session_id], |row| {
-                Ok(SitemapNode {
-                    url: row.get(0)?,
-                    status_code: row.get::<_, Option<u16>>(1)?.unwrap_or(0),
-                    content_type: row.get(2)?,
-                })
-            })?
-            .collect::<Result<Vec<_>>>()?;
-
-        Some(nodes)
-    } else {
-        None
+        stmt.query_map([session_id], |row| {
+            Ok(TechnologyData {
+                name: row.get(0)?,
+                version: row.get(1)?,
+                category: row.get(2)?,
+                confidence: row.get::<_, Option<u8>>(3)?.unwrap_or(0),
+            })
+        })?
+        .collect::<Result<Vec<_>>>()?
     };
 
+    let changed_nodes = gather_changed_nodes(db, session_id)?;
+
     Ok(ReportData {
         session_id: session_id.to_string(),
         total_nodes,
         findings,
         severity_counts,
+        occurrence_counts,
         scan_info,
         sitemap_nodes,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies,
+        timings: None,
+        grouped_findings: None,
+        changed_nodes,
     })
 }
 
+/// Every page recorded for a session, ordered by URL
+pub fn gather_sitemap_nodes(db: &Database, session_id: &str) -> Result<Vec<SitemapNode>> {
+    let conn = db.get_connection();
+    let mut stmt = conn.prepare(
+        "SELECT n.url, n.response_code, n.content_type
+         FROM nodes n
+         JOIN maps m ON n.map_id = m.id
+         WHERE m.session_id = ?1
+         ORDER BY n.url",
+    )?;
+
+    stmt.query_map([session_id], |row| {
+        Ok(SitemapNode {
+            url: row.get(0)?,
+            status_code: row.get::<_, Option<u16>>(1)?.unwrap_or(0),
+            content_type: row.get(2)?,
+        })
+    })?
+    .collect()
+}
+
+/// Pages of a session that are new or changed compared with their most recent
+/// crawl in an earlier session. Empty when none of its pages were crawled before.
+pub fn gather_changed_nodes(db: &Database, session_id: &str) -> Result<Vec<ChangedNode>> {
+    let conn = db.get_connection();
+    let mut stmt = conn.prepare(
+        "SELECT n.url, n.response_code, n.content_length, n.content_hash
+         FROM nodes n
+         JOIN maps m ON n.map_id = m.id
+         WHERE m.session_id = ?1
+         ORDER BY n.url",
+    )?;
+    let nodes = stmt
+        .query_map([session_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<i64>>(1)?,
+                row.get::<_, Option<i64>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut counts = NodeChangeCounts::default();
+    let mut changed = Vec::new();
+    for (url, status_code, content_length, content_hash) in nodes {
+        let previous = db.get_previous_node(&url, session_id)?;
+        let change = classify_node_change(
+            previous.as_ref(),
+            status_code.unwrap_or(0) as u16,
+            content_length,
+            content_hash.as_deref(),
+        );
+        counts.record(change);
+        if change != NodeChange::Unchanged {
+            changed.push(ChangedNode {
+                url,
+                change: change.as_str().to_string(),
+                status_code,
+                content_length,
+                previous_status_code: previous.as_ref().and_then(|p| p.status_code),
+                previous_content_length: previous.as_ref().and_then(|p| p.content_length),
+            });
+        }
+    }
+
+    // On a first scan every page is new, which says nothing
+    if !counts.is_recrawl() {
+        changed.clear();
+    }
+    Ok(changed)
+}
+
 pub fn generate_text_report(data: &ReportData) -> String {
+    generate_text_report_with_color(data, false)
+}
+
+/// Generate the text report, optionally coloring severity tags with ANSI escapes.
+/// Color should only be enabled for terminal output, never for saved files.
+pub fn generate_text_report_with_color(data: &ReportData, color: bool) -> String {
     let mut report = String::new();
 
     // Header
@@ -232,6 +542,67 @@ pub fn generate_text_report(data: &ReportData) -> String {
     report.push_str(&format!("Pages Found:  {}\n", data.total_nodes));
     report.push('\n');
 
+    // Include crawl coverage if present
+    if let Some(ref coverage) = data.coverage {
+        report.push_str(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n",
+        );
+        report.push_str("COVERAGE\n");
+        report.push_str(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n",
+        );
+        report.push_str(&generate_coverage_summary(coverage));
+        report.push('\n');
+    }
+
+    // Include the phase timing breakdown if present
+    if let Some(ref timings) = data.timings {
+        report.push_str(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n",
+        );
+        report.push_str("SCAN DURATION BREAKDOWN\n");
+        report.push_str(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n",
+        );
+        report.push_str(&generate_timing_summary(timings));
+        report.push('\n');
+    }
+
+    // Include fingerprinted technologies if any were detected
+    if !data.technologies.is_empty() {
+        report.push_str(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n",
+        );
+        report.push_str("TECHNOLOGIES\n");
+        report.push_str(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n",
+        );
+        for technology in &data.technologies {
+            let name = match technology.version {
+                Some(ref version) => format!("{} {}", technology.name, version),
+                None => technology.name.clone(),
+            };
+            report.push_str(&format!(
+                "  {:<30} {:<20} {}% confidence\n",
+                name, technology.category, technology.confidence
+            ));
+        }
+        report.push('\n');
+    }
+
+    // Pages that differ from the previous scan of the same URLs
+    if !data.changed_nodes.is_empty() {
+        report.push_str(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n",
+        );
+        report.push_str("CHANGED SINCE LAST SCAN\n");
+        report.push_str(
+            "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n",
+        );
+        report.push_str(&generate_changed_nodes(&data.changed_nodes));
+        report.push('\n');
+    }
+
     // Include sitemap if present
     if let Some(ref sitemap_nodes) = data.sitemap_nodes {
         report.push_str(
@@ -241,7 +612,13 @@ pub fn generate_text_report(data: &ReportData) -> String {
         report.push_str(
             "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n",
         );
-        report.push_str(&generate_sitemap_tree(sitemap_nodes));
+        if data.sitemap_baseline.is_some() {
+            report.push_str("(+ added since baseline, - removed since baseline)\n\n");
+        }
+        report.push_str(&generate_sitemap_tree(
+            sitemap_nodes,
+            data.sitemap_baseline.as_deref(),
+        ));
         report.push('\n');
     }
 
@@ -254,48 +631,80 @@ pub fn generate_text_report(data: &ReportData) -> String {
         "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n",
     );
 
-    let total_findings = data.severity_counts.critical
-        + data.severity_counts.high
-        + data.severity_counts.medium
-        + data.severity_counts.low
-        + data.severity_counts.info;
+    let total_findings = data.severity_counts.total();
 
-    report.push_str(&format!("Total Findings: {}\n\n", total_findings));
-
-    if data.severity_counts.critical > 0 {
-        report.push_str(&format!(
-            "  [CRITICAL] {}  (Immediate action required)\n",
-            data.severity_counts.critical
-        ));
-    }
-    if data.severity_counts.high > 0 {
-        report.push_str(&format!(
-            "  [HIGH]     {}  (High priority)\n",
-            data.severity_counts.high
-        ));
-    }
-    if data.severity_counts.medium > 0 {
+    if let Some(ref occurrences) = data.occurrence_counts {
         report.push_str(&format!(
-            "  [MEDIUM]   {}  (Should be addressed)\n",
-            data.severity_counts.medium
+            "Total Findings: {} unique ({} occurrences)\n\n",
+            total_findings,
+            occurrences.total()
         ));
+    } else {
+        report.push_str(&format!("Total Findings: {}\n\n", total_findings));
     }
-    if data.severity_counts.low > 0 {
+
+    if data.scan_info.findings_dropped > 0 {
         report.push_str(&format!(
-            "  [LOW]      {}  (Minor issues)\n",
-            data.severity_counts.low
+            "Findings cap reached: {} more findings were not recorded\n\n",
+            data.scan_info.findings_dropped
         ));
     }
-    if data.severity_counts.info > 0 {
-        report.push_str(&format!(
-            "  [INFO]     {}  (Informational)\n",
-            data.severity_counts.info
-        ));
+
+    let severity_rows = [
+        (
+            "[CRITICAL]",
+            data.severity_counts.critical,
+            "Immediate action required",
+            data.occurrence_counts.as_ref().map(|o| o.critical),
+        ),
+        (
+            "[HIGH]    ",
+            data.severity_counts.high,
+            "High priority",
+            data.occurrence_counts.as_ref().map(|o| o.high),
+        ),
+        (
+            "[MEDIUM]  ",
+            data.severity_counts.medium,
+            "Should be addressed",
+            data.occurrence_counts.as_ref().map(|o| o.medium),
+        ),
+        (
+            "[LOW]     ",
+            data.severity_counts.low,
+            "Minor issues",
+            data.occurrence_counts.as_ref().map(|o| o.low),
+        ),
+        (
+            "[INFO]    ",
+            data.severity_counts.info,
+            "Informational",
+            data.occurrence_counts.as_ref().map(|o| o.info),
+        ),
+    ];
+
+    for (tag, count, label, occurrences) in severity_rows {
+        if count > 0 {
+            let tag = if color {
+                colorize_severity_tag(tag)
+            } else {
+                tag.to_string()
+            };
+            match occurrences {
+                Some(occurrences) => report.push_str(&format!(
+                    "  {} {}  ({}, {} occurrences)\n",
+                    tag, count, label, occurrences
+                )),
+                None => report.push_str(&format!("  {} {}  ({})\n", tag, count, label)),
+            }
+        }
     }
     report.push('\n');
 
     // Detailed findings
-    if !data.findings.is_empty() {
+    if let Some(ref groups) = data.grouped_findings {
+        report.push_str(&generate_grouped_findings(groups));
+    } else if !data.findings.is_empty() {
         report.push_str(
             "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n",
         );
@@ -315,6 +724,9 @@ pub fn generate_text_report(data: &ReportData) -> String {
                 format_finding_type(&finding.finding_type)
             ));
             report.push_str(&format!("URL:          {}\n", finding.url));
+            if let Some(ref confidence) = finding.confidence {
+                report.push_str(&format!("Confidence:   {}\n", format_finding_type(confidence)));
+            }
 
             if let Some(ref cwe) = finding.cwe_id {
                 report.push_str(&format!("CWE:          {}\n", cwe));
@@ -322,6 +734,9 @@ pub fn generate_text_report(data: &ReportData) -> String {
             if let Some(ref owasp) = finding.owasp_category {
                 report.push_str(&format!("OWASP:        {}\n", owasp));
             }
+            if let Some(cvss) = finding.cvss_score {
+                report.push_str(&format!("CVSS:         {:.1}\n", cvss));
+            }
 
             report.push_str("\nDescription:\n");
             report.push_str(&wrap_text(&finding.description, 80, "  "));
@@ -339,6 +754,23 @@ pub fn generate_text_report(data: &ReportData) -> String {
                 report.push_str("\n\n");
             }
 
+            // Raw exchange, indented as-is so headers stay one per line
+            if finding.request_sample.is_some() || finding.response_sample.is_some() {
+                report.push_str("Evidence:\n");
+                for (label, sample) in [
+                    ("Request", &finding.request_sample),
+                    ("Response", &finding.response_sample),
+                ] {
+                    if let Some(sample) = sample {
+                        report.push_str(&format!("  {}:\n", label));
+                        for line in sample.lines() {
+                            report.push_str(&format!("    {}\n", line));
+                        }
+                    }
+                }
+                report.push('\n');
+            }
+
             report.push_str("────────────────────────────────────────────────────────────────────────────────\n\n");
         }
     }
@@ -359,7 +791,7 @@ pub fn generate_text_report(data: &ReportData) -> String {
 
 pub fn generate_json_report(data: &ReportData) -> Result<String, serde_json::Error> {
     // Create a structured JSON report with enhanced metadata
-    let json_report = serde_json::json!({
+    let mut json_report = serde_json::json!({
         "report": {
             "metadata": {
                 "generator": "Rinzler",
@@ -378,20 +810,33 @@ pub fn generate_json_report(data: &ReportData) -> Result<String, serde_json::Err
             },
             "summary": {
                 "total_pages": data.total_nodes,
-                "total_findings": data.severity_counts.critical
-                    + data.severity_counts.high
-                    + data.severity_counts.medium
-                    + data.severity_counts.low
-                    + data.severity_counts.info,
+                "total_findings": data.severity_counts.total(),
                 "severity_breakdown": {
                     "critical": data.severity_counts.critical,
                     "high": data.severity_counts.high,
                     "medium": data.severity_counts.medium,
                     "low": data.severity_counts.low,
                     "info": data.severity_counts.info
-                }
+                },
+                "findings_dropped": data.scan_info.findings_dropped
             },
+            "coverage": data.coverage.as_ref().map(|coverage| {
+                serde_json::json!({
+                    "max_depth_reached": coverage.max_depth_reached,
+                    "depth_limit": coverage.depth_limit,
+                    "unique_hosts": coverage.unique_hosts,
+                    "links_discovered": coverage.links_discovered,
+                    "pages_crawled": coverage.pages_crawled,
+                    "max_pages": coverage.max_pages,
+                    "truncated": coverage.page_limit_hit,
+                    "limits_hit": coverage.limits_hit()
+                })
+            }),
+            "timings": data.timings,
+            "technologies": data.technologies,
             "findings": data.findings,
+            "grouped_findings": data.grouped_findings,
+            "changed_since_last_scan": data.changed_nodes,
             "sitemap": data.sitemap_nodes.as_ref().map(|nodes| {
                 serde_json::json!({
                     "total_nodes": nodes.len(),
@@ -400,16 +845,371 @@ pub fn generate_json_report(data: &ReportData) -> Result<String, serde_json::Err
             })
         }
     });
+    // Only present when the severity breakdown counts distinct findings
+    if let Some(ref occurrences) = data.occurrence_counts {
+        json_report["report"]["summary"]["occurrence_breakdown"] = serde_json::to_value(occurrences)?;
+    }
 
     serde_json::to_string_pretty(&json_report)
 }
 
+/// Generate a SARIF 2.1.0 log for code scanning consumers
+pub fn generate_sarif_report(data: &ReportData) -> Result<String, serde_json::Error> {
+    // One rule per finding type, in order of first appearance
+    let mut rule_ids: Vec<&str> = Vec::new();
+    for finding in &data.findings {
+        if !rule_ids.contains(&finding.finding_type.as_str()) {
+            rule_ids.push(&finding.finding_type);
+        }
+    }
+
+    let rules: Vec<serde_json::Value> = rule_ids
+        .iter()
+        .map(|rule_id| {
+            serde_json::json!({
+                "id": rule_id,
+                "name": format_finding_type(rule_id).replace(' ', ""),
+                "shortDescription": { "text": format_finding_type(rule_id) }
+            })
+        })
+        .collect();
+
+    let results: Vec<serde_json::Value> = data
+        .findings
+        .iter()
+        .map(|finding| {
+            let level = match finding.severity.as_str() {
+                "critical" | "high" => "error",
+                "medium" => "warning",
+                _ => "note",
+            };
+
+            serde_json::json!({
+                "ruleId": finding.finding_type,
+                "ruleIndex": rule_ids.iter().position(|id| *id == finding.finding_type),
+                "level": level,
+                "message": { "text": format!("{}: {}", finding.title, finding.description) },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": finding.url }
+                    }
+                }],
+                "properties": {
+                    "severity": finding.severity,
+                    "cwe": finding.cwe_id,
+                    "owasp": finding.owasp_category
+                }
+            })
+        })
+        .collect();
+
+    let sarif = serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "rinzler",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/trapdoorsec/rinzler",
+                    "rules": rules
+                }
+            },
+            "automationDetails": { "id": data.session_id },
+            "results": results
+        }]
+    });
+
+    serde_json::to_string_pretty(&sarif)
+}
+
+/// Generate a JUnit XML report for CI, failing findings of medium severity or above
+pub fn generate_junit_report(data: &ReportData) -> String {
+    generate_junit_report_with_threshold(data, &Severity::Medium)
+}
+
+/// Generate a JUnit XML report: one test case per finding (named by its title, classed by
+/// its URL) that fails at or above `threshold`, plus a passing case per page without findings.
+/// Clean pages are only known when the report data carries sitemap nodes.
+pub fn generate_junit_report_with_threshold(data: &ReportData, threshold: &Severity) -> String {
+    let mut cases = String::new();
+    let mut failures = 0;
+
+    for finding in &data.findings {
+        let fails = finding
+            .severity
+            .parse::<Severity>()
+            .is_ok_and(|severity| severity.rank() >= threshold.rank());
+
+        cases.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"{}\"",
+            escape_xml(&finding.title),
+            escape_xml(&finding.url)
+        ));
+        if fails {
+            failures += 1;
+            cases.push_str(&format!(
+                ">\n      <failure message=\"{}\" type=\"{}\">{}</failure>\n    </testcase>\n",
+                escape_xml(&finding.title),
+                escape_xml(&finding.severity),
+                escape_xml(&finding.description)
+            ));
+        } else {
+            cases.push_str("/>\n");
+        }
+    }
+
+    let finding_urls: HashSet<&str> = data.findings.iter().map(|f| f.url.as_str()).collect();
+    let clean_nodes: Vec<&SitemapNode> = data
+        .sitemap_nodes
+        .iter()
+        .flatten()
+        .filter(|node| !finding_urls.contains(node.url.as_str()))
+        .collect();
+    for node in &clean_nodes {
+        cases.push_str(&format!(
+            "    <testcase name=\"no findings\" classname=\"{}\"/>\n",
+            escape_xml(&node.url)
+        ));
+    }
+
+    let time = data
+        .scan_info
+        .end_time
+        .map(|end| end - data.scan_info.start_time)
+        .unwrap_or(0);
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuite name=\"rinzler\" id=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{}\" timestamp=\"{}\">\n\
+         {}</testsuite>\n",
+        escape_xml(&data.session_id),
+        data.findings.len() + clean_nodes.len(),
+        failures,
+        time,
+        format_iso8601_timestamp(data.scan_info.start_time),
+        cases
+    )
+}
+
+/// Serialize report data as a TOML document; findings become `[[findings]]` tables
+pub fn generate_toml_report(data: &ReportData) -> Result<String, toml::ser::Error> {
+    toml::to_string_pretty(data)
+}
+
+/// Render each finding as a ready-to-paste GitHub issue in Markdown, blocks separated by `---`
+pub fn generate_github_issues_report(data: &ReportData) -> String {
+    let blocks: Vec<String> = data
+        .findings
+        .iter()
+        .map(|finding| {
+            let mut block = format!(
+                "## [{}] {}\n\n",
+                finding.severity.to_uppercase(),
+                escape_markdown(&finding.title)
+            );
+            block.push_str(&format!(
+                "**URL:** `{}`\n\n",
+                finding.url.replace('`', "%60")
+            ));
+            block.push_str(&format!(
+                "### Description\n\n{}\n\n",
+                escape_markdown(&finding.description)
+            ));
+            if let Some(impact) = &finding.impact {
+                block.push_str(&format!("### Impact\n\n{}\n\n", escape_markdown(impact)));
+            }
+            if let Some(remediation) = &finding.remediation {
+                block.push_str(&format!(
+                    "### Remediation\n\n{}\n\n",
+                    escape_markdown(remediation)
+                ));
+            }
+            if finding.cwe_id.is_some() || finding.owasp_category.is_some() {
+                block.push_str("### References\n\n");
+                if let Some(cwe) = &finding.cwe_id {
+                    block.push_str(&format!("- [ ] {}\n", escape_markdown(cwe)));
+                }
+                if let Some(owasp) = &finding.owasp_category {
+                    block.push_str(&format!("- [ ] OWASP {}\n", escape_markdown(owasp)));
+                }
+                block.push('\n');
+            }
+            block
+        })
+        .collect();
+
+    blocks.join("---\n\n")
+}
+
+/// Serialize report data as NDJSON: one session record, then nodes, technologies and findings
+pub fn generate_jsonl_report(data: &ReportData) -> Result<String, serde_json::Error> {
+    let mut records = vec![JsonlRecord::Session {
+        session_id: data.session_id.clone(),
+        total_nodes: data.total_nodes,
+        scan_info: data.scan_info.clone(),
+        coverage: data.coverage.clone(),
+        timings: data.timings.clone(),
+    }];
+
+    if let Some(ref nodes) = data.sitemap_nodes {
+        records.extend(nodes.iter().cloned().map(JsonlRecord::Node));
+    }
+    records.extend(
+        data.technologies
+            .iter()
+            .cloned()
+            .map(JsonlRecord::Technology),
+    );
+    records.extend(data.findings.iter().cloned().map(JsonlRecord::Finding));
+
+    let mut output = String::new();
+    for record in &records {
+        output.push_str(&serde_json::to_string(record)?);
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+/// Rebuild report data from a previously streamed NDJSON capture, without a database
+pub fn read_jsonl_report(
+    reader: impl BufRead,
+    include_sitemap: bool,
+    unique_findings: bool,
+) -> Result<ReportData, String> {
+    let mut session = None;
+    let mut nodes = Vec::new();
+    let mut technologies = Vec::new();
+    let mut findings = Vec::new();
+
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| format!("Failed to read line {}: {}", idx + 1, e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record: JsonlRecord = serde_json::from_str(&line)
+            .map_err(|e| format!("Invalid record on line {}: {}", idx + 1, e))?;
+
+        match record {
+            JsonlRecord::Session {
+                session_id,
+                total_nodes,
+                scan_info,
+                coverage,
+                timings,
+            } => session = Some((session_id, total_nodes, scan_info, coverage, timings)),
+            JsonlRecord::Node(node) => nodes.push(node),
+            JsonlRecord::Technology(technology) => technologies.push(technology),
+            JsonlRecord::Finding(finding) => findings.push(finding),
+        }
+    }
+
+    let (session_id, total_nodes, scan_info, coverage, timings) =
+        session.ok_or_else(|| "No session record found in input".to_string())?;
+
+    // Mirror the database counts: raw per severity, or distinct type+title pairs
+    let count_rows = |unique: bool| {
+        let mut seen = HashSet::new();
+        let mut rows: Vec<(String, i64)> = Vec::new();
+        for finding in &findings {
+            if unique && !seen.insert((&finding.severity, &finding.finding_type, &finding.title)) {
+                continue;
+            }
+            match rows
+                .iter_mut()
+                .find(|(severity, _)| *severity == finding.severity)
+            {
+                Some((_, count)) => *count += 1,
+                None => rows.push((finding.severity.clone(), 1)),
+            }
+        }
+        SeverityCounts::from_rows(rows)
+    };
+
+    let (severity_counts, occurrence_counts) = if unique_findings {
+        (count_rows(true), Some(count_rows(false)))
+    } else {
+        (count_rows(false), None)
+    };
+
+    Ok(ReportData {
+        session_id,
+        total_nodes,
+        findings,
+        severity_counts,
+        occurrence_counts,
+        scan_info,
+        sitemap_nodes: include_sitemap.then_some(nodes),
+        sitemap_baseline: None,
+        coverage,
+        technologies,
+        timings,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
+    })
+}
+
 pub fn save_report(content: &str, path: &Path) -> std::io::Result<()> {
     let mut file = File::create(path)?;
     file.write_all(content.as_bytes())?;
     Ok(())
 }
 
+/// Reports written by `crawl --output-dir`, with their file names
+pub const OUTPUT_DIR_REPORTS: &[(ReportFormat, &str)] = &[
+    (ReportFormat::Text, "report.txt"),
+    (ReportFormat::Json, "report.json"),
+    (ReportFormat::Html, "report.html"),
+    (ReportFormat::Sarif, "report.sarif"),
+];
+
+/// What happened to one file of an output directory
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportFileOutcome {
+    Written(std::path::PathBuf),
+    /// The format has no generator yet
+    Skipped(String),
+    Failed(String),
+}
+
+/// Write every `OUTPUT_DIR_REPORTS` format into `dir`, creating it if needed.
+/// Only failing to create the directory is an error; each file's outcome is
+/// returned with its file name.
+pub fn write_report_dir(
+    data: &ReportData,
+    dir: &Path,
+) -> std::io::Result<Vec<(&'static str, ReportFileOutcome)>> {
+    std::fs::create_dir_all(dir)?;
+
+    Ok(OUTPUT_DIR_REPORTS
+        .iter()
+        .map(|(format, file_name)| {
+            let content = match format {
+                ReportFormat::Text => Ok(generate_text_report(data)),
+                ReportFormat::Json => generate_json_report(data).map_err(|e| e.to_string()),
+                ReportFormat::Sarif => generate_sarif_report(data).map_err(|e| e.to_string()),
+                _ => {
+                    return (
+                        *file_name,
+                        ReportFileOutcome::Skipped(format!("{:?} format not yet implemented", format)),
+                    );
+                }
+            };
+
+            let path = dir.join(file_name);
+            let outcome = match content.and_then(|content| {
+                save_report(&content, &path).map_err(|e| e.to_string())
+            }) {
+                Ok(()) => ReportFileOutcome::Written(path),
+                Err(e) => ReportFileOutcome::Failed(e),
+            };
+            (*file_name, outcome)
+        })
+        .collect())
+}
+
 // Helper functions
 impl ReportData {
     fn status_to_string(&self) -> &str {
@@ -442,6 +1242,54 @@ impl ReportData {
     }
 }
 
+/// Wrap a padded severity tag like `[HIGH]    ` in the ANSI color for its severity,
+/// matching the TUI palette (critical/high red, medium yellow, low/info cyan)
+fn colorize_severity_tag(tag: &str) -> String {
+    let trimmed = tag.trim_end();
+    let padding = &tag[trimmed.len()..];
+    let code = match trimmed {
+        "[CRITICAL]" => "1;31",
+        "[HIGH]" => "31",
+        "[MEDIUM]" => "33",
+        _ => "36",
+    };
+
+    format!("\x1b[{}m{}\x1b[0m{}", code, trimmed, padding)
+}
+
+/// Escape text for use in XML attribute values and element content
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Backslash-escape characters that Markdown would otherwise interpret, including
+/// line-leading block markers so finding text cannot open headings, lists or rules
+fn escape_markdown(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let mut escaped = String::with_capacity(line.len());
+            for (i, c) in line.chars().enumerate() {
+                let block_marker = i == 0 && matches!(c, '#' | '-' | '+' | '=' | '>');
+                if block_marker
+                    || matches!(
+                        c,
+                        '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~'
+                    )
+                {
+                    escaped.push('\\');
+                }
+                escaped.push(c);
+            }
+            escaped
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn format_finding_type(finding_type: &str) -> String {
     finding_type
         .replace('_', " ")
@@ -484,105 +1332,303 @@ fn wrap_text(text: &str, width: usize, indent: &str) -> String {
     result
 }
 
-fn generate_sitemap_tree(nodes: &[SitemapNode]) -> String {
-    use std::collections::HashMap;
+/// A path segment in the sitemap tree, with the crawled page at that path (if any)
+#[derive(Default)]
+struct SitemapTreeNode<'a> {
+    children: BTreeMap<String, SitemapTreeNode<'a>>,
+    page: Option<&'a SitemapNode>,
+    /// Diff marker for the page: '+' added, '-' removed, ' ' unchanged
+    marker: Option<char>,
+}
 
-    if nodes.is_empty() {
-        return "  (empty)\n".to_string();
+/// Render the sitemap as a tree. With a baseline, every line is prefixed with a
+/// diff marker and pages only present in the baseline are shown as removed.
+fn generate_sitemap_tree(nodes: &[SitemapNode], baseline: Option<&[SitemapNode]>) -> String {
+    let baseline_urls: HashSet<&str> = baseline
+        .unwrap_or_default()
+        .iter()
+        .map(|node| node.url.as_str())
+        .collect();
+    let current_urls: HashSet<&str> = nodes.iter().map(|node| node.url.as_str()).collect();
+
+    let mut entries: Vec<(&SitemapNode, Option<char>)> = nodes
+        .iter()
+        .map(|node| {
+            let marker = baseline.map(|_| {
+                if baseline_urls.contains(node.url.as_str()) {
+                    ' '
+                } else {
+                    '+'
+                }
+            });
+            (node, marker)
+        })
+        .collect();
+    if let Some(baseline) = baseline {
+        entries.extend(
+            baseline
+                .iter()
+                .filter(|node| !current_urls.contains(node.url.as_str()))
+                .map(|node| (node, Some('-'))),
+        );
     }
 
-    // Build a tree structure from URLs
-    let mut tree: HashMap<String, Vec<(String, &SitemapNode)>> = HashMap::new();
+    if entries.is_empty() {
+        return "  (empty)\n".to_string();
+    }
 
-    for node in nodes {
-        if let Ok(parsed) = url::Url::parse(&node.url) {
-            let domain = parsed.host_str().unwrap_or("unknown").to_string();
-            let path = parsed.path().to_string();
-
-            // Split path into segments
-            let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
-
-            // Build hierarchical path
-            let mut current_path = domain.clone();
-            tree.entry(current_path.clone()).or_default();
-
-            for (i, segment) in segments.iter().enumerate() {
-                let parent_path = current_path.clone();
-                current_path = format!("{}/{}", current_path, segment);
-
-                // Only add the leaf node with metadata
-                if i == segments.len() - 1 {
-                    tree.entry(parent_path)
-                        .or_default()
-                        .push((current_path.clone(), node));
-                } else {
-                    tree.entry(parent_path)
-                        .or_default()
-                        .push((current_path.clone(), node));
-                    tree.entry(current_path.clone()).or_default();
-                }
+    // Group by host, then nest each path segment under its parent
+    let mut hosts: BTreeMap<String, SitemapTreeNode> = BTreeMap::new();
+
+    for (node, marker) in entries {
+        let (host, segments) = match url::Url::parse(&node.url) {
+            Ok(parsed) => {
+                let host = match parsed.port() {
+                    Some(port) => format!("{}:{}", parsed.host_str().unwrap_or("unknown"), port),
+                    None => parsed.host_str().unwrap_or("unknown").to_string(),
+                };
+                let segments: Vec<String> = parsed
+                    .path()
+                    .split('/')
+                    .filter(|s| !s.is_empty())
+                    .map(String::from)
+                    .collect();
+                (host, segments)
             }
+            Err(_) => ("unknown".to_string(), vec![node.url.clone()]),
+        };
 
-            // If root path, add it directly to domain
-            if segments.is_empty() {
-                tree.entry(domain.clone())
-                    .or_default()
-                    .push((domain.clone(), node));
-            }
+        let mut current = hosts.entry(host).or_default();
+        for segment in segments {
+            current = current.children.entry(segment).or_default();
         }
+        current.page = Some(node);
+        current.marker = marker;
     }
 
-    // Simple flat list representation for now (tree structure is complex)
+    let diff = baseline.is_some();
     let mut result = String::new();
+    for (host, root) in &hosts {
+        push_diff_marker(root, diff, &mut result);
+        result.push_str(host);
+        if let Some(page) = root.page {
+            result.push_str(&format_sitemap_status(page));
+        }
+        result.push('\n');
+        render_sitemap_children(root, "", diff, &mut result);
+    }
 
-    for (i, node) in nodes.iter().enumerate() {
-        let is_last = i == nodes.len() - 1;
-        let prefix = if is_last { "└── " } else { "├── " };
+    result
+}
 
-        // Extract path from URL
-        let display_url = if let Ok(parsed) = url::Url::parse(&node.url) {
-            let host = parsed.host_str().unwrap_or("unknown");
-            let path = parsed.path();
-            if i == 0 {
-                format!("{}{}", host, path)
-            } else {
-                // Check if same host as previous
-                let prev_host = url::Url::parse(&nodes[i - 1].url)
-                    .ok()
-                    .and_then(|u| u.host_str().map(String::from));
-                if prev_host.as_deref() == Some(host) {
-                    format!("    {}", path)
-                } else {
-                    format!("{}{}", host, path)
-                }
-            }
+fn render_sitemap_children(node: &SitemapTreeNode, prefix: &str, diff: bool, result: &mut String) {
+    let count = node.children.len();
+
+    for (i, (segment, child)) in node.children.iter().enumerate() {
+        let is_last = i == count - 1;
+        let connector = if is_last { "└── " } else { "├── " };
+        let suffix = if child.children.is_empty() { "" } else { "/" };
+
+        push_diff_marker(child, diff, result);
+        result.push_str(&format!("{}{}{}{}", prefix, connector, segment, suffix));
+        if let Some(page) = child.page {
+            result.push_str(&format_sitemap_status(page));
+        }
+        result.push('\n');
+
+        let child_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+        render_sitemap_children(child, &child_prefix, diff, result);
+    }
+}
+
+/// In diff mode, start the line with the page's marker (blank for directories)
+fn push_diff_marker(node: &SitemapTreeNode, diff: bool, result: &mut String) {
+    if diff {
+        result.push(node.marker.unwrap_or(' '));
+        result.push(' ');
+    }
+}
+
+fn format_sitemap_status(node: &SitemapNode) -> String {
+    // Status code with indicator
+    let status_indicator = match node.status_code {
+        200..=299 => "✓",
+        300..=399 => "→",
+        400..=499 => "⚠",
+        500..=599 => "✗",
+        _ => "?",
+    };
+
+    let content_type_short = node
+        .content_type
+        .as_ref()
+        .and_then(|ct| ct.split(';').next())
+        .and_then(|ct| ct.split('/').nth(1))
+        .unwrap_or("?");
+
+    format!(
+        "  [{} {}] {}",
+        status_indicator, node.status_code, content_type_short
+    )
+}
+
+fn generate_changed_nodes(nodes: &[ChangedNode]) -> String {
+    let format_node = |status: Option<i64>, length: Option<i64>| {
+        let status = status.map_or("-".to_string(), |code| code.to_string());
+        match length {
+            Some(length) => format!("{} {}B", status, length),
+            None => status,
+        }
+    };
+
+    let mut section = String::new();
+    for node in nodes {
+        let tag = if node.change == "new" { "[NEW]    " } else { "[CHANGED]" };
+        section.push_str(&format!("  {} {}", tag, node.url));
+        if node.change != "new" {
+            section.push_str(&format!(
+                "  ({} -> {})",
+                format_node(node.previous_status_code, node.previous_content_length),
+                format_node(node.status_code, node.content_length)
+            ));
+        }
+        section.push('\n');
+    }
+    section
+}
+
+fn generate_grouped_findings(groups: &[GroupedFinding]) -> String {
+    let mut section = String::new();
+    if groups.is_empty() {
+        return section;
+    }
+
+    section.push_str(
+        "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n",
+    );
+    section.push_str("GROUPED FINDINGS\n");
+    section.push_str(
+        "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n",
+    );
+
+    for (idx, group) in groups.iter().enumerate() {
+        section.push_str(&format!("[{}] {} (x{})\n", idx + 1, group.title, group.count));
+        section.push_str(&format!("Severity:     {}\n", group.severity.to_uppercase()));
+        section.push_str(&format!(
+            "Type:         {}\n",
+            format_finding_type(&group.finding_type)
+        ));
+        if let Some(ref cwe) = group.cwe_id {
+            section.push_str(&format!("CWE:          {}\n", cwe));
+        }
+        if let Some(ref owasp) = group.owasp_category {
+            section.push_str(&format!("OWASP:        {}\n", owasp));
+        }
+
+        section.push_str(&format!("\nAffected URLs ({}):\n", group.urls.len()));
+        for url in &group.urls {
+            section.push_str(&format!("  {}\n", url));
+        }
+
+        section.push_str("\nDescription:\n");
+        section.push_str(&wrap_text(&group.description, 80, "  "));
+        section.push_str("\n\n");
+
+        if let Some(ref impact) = group.impact {
+            section.push_str("Impact:\n");
+            section.push_str(&wrap_text(impact, 80, "  "));
+            section.push_str("\n\n");
+        }
+
+        if let Some(ref remediation) = group.remediation {
+            section.push_str("Remediation:\n");
+            section.push_str(&wrap_text(remediation, 80, "  "));
+            section.push_str("\n\n");
+        }
+
+        section.push_str("────────────────────────────────────────────────────────────────────────────────\n\n");
+    }
+
+    section
+}
+
+fn generate_timing_summary(timings: &ScanTimings) -> String {
+    let share = |secs: f64| {
+        if timings.total_secs > 0.0 {
+            secs / timings.total_secs * 100.0
         } else {
-            node.url.clone()
-        };
+            0.0
+        }
+    };
 
-        // Format status code with color indicator
-        let status_indicator = match node.status_code {
-            200..=299 => "✓",
-            300..=399 => "→",
-            400..=499 => "⚠",
-            500..=599 => "✗",
-            _ => "?",
-        };
+    let mut summary = String::new();
+    summary.push_str(&format!(
+        "{:<14}{:.2}s ({:.0}%)\n",
+        "Crawl:",
+        timings.crawl_secs,
+        share(timings.crawl_secs)
+    ));
+    for (label, secs) in [
+        ("Analysis:", timings.analysis_secs),
+        ("Persistence:", timings.persistence_secs),
+    ] {
+        // A share of the total means nothing for time spent during the crawl
+        if timings.overlapping {
+            summary.push_str(&format!("{:<14}{:.2}s (during crawl)\n", label, secs));
+        } else {
+            summary.push_str(&format!(
+                "{:<14}{:.2}s ({:.0}%)\n",
+                label,
+                secs,
+                share(secs)
+            ));
+        }
+    }
+    summary.push_str(&format!("{:<14}{:.2}s\n", "Total:", timings.total_secs));
+    summary
+}
+
+fn generate_coverage_summary(coverage: &CrawlStats) -> String {
+    let mut summary = String::new();
 
-        let content_type_short = node
-            .content_type
-            .as_ref()
-            .and_then(|ct| ct.split(';').next())
-            .and_then(|ct| ct.split('/').nth(1))
-            .unwrap_or("?");
+    summary.push_str(&format!(
+        "Max Depth:    {} of {}\n",
+        coverage.max_depth_reached, coverage.depth_limit
+    ));
+    summary.push_str(&format!("Hosts:        {}\n", coverage.unique_hosts));
+    summary.push_str(&format!(
+        "Links:        {} discovered, {} crawled ({} not crawled)\n",
+        coverage.links_discovered,
+        coverage.pages_crawled,
+        coverage
+            .links_discovered
+            .saturating_sub(coverage.pages_crawled)
+    ));
 
-        result.push_str(&format!(
-            "{}{}  [{} {}] {}\n",
-            prefix, display_url, status_indicator, node.status_code, content_type_short
+    let limits = coverage.limits_hit();
+    if limits.is_empty() {
+        summary.push_str("Limits Hit:   none\n");
+    } else {
+        summary.push_str(&format!("Limits Hit:   {}\n", limits.join(", ")));
+    }
+    if coverage.page_limit_hit
+        && let Some(max_pages) = coverage.max_pages
+    {
+        summary.push_str(&format!(
+            "Truncated:    page budget of {} reached, remaining links not crawled\n",
+            max_pages
+        ));
+    }
+    if coverage.queue_dropped > 0
+        && let Some(max_queue_size) = coverage.max_queue_size
+    {
+        summary.push_str(&format!(
+            "Queue:        cap of {} reached, {} discovered links dropped\n",
+            max_queue_size, coverage.queue_dropped
         ));
     }
 
-    result
+    summary
 }
 
 fn format_iso8601_timestamp(timestamp: i64) -> String {
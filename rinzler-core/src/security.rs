@@ -1,9 +1,47 @@
 // Passive security checks for crawled endpoints
 
-use crate::data::{Finding, FindingType, Severity};
+use crate::data::{Confidence, Finding, FindingType, Severity};
+use regex::Regex;
 use rinzler_scanner::result::CrawlResult;
+use std::sync::LazyLock;
 use url::Url;
 
+/// A dotted version number such as `1.18` or `2.4.41`
+static VERSION_PATTERN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\d+\.\d+").unwrap());
+
+/// Body characters kept in a finding's response sample
+pub const RESPONSE_SAMPLE_CHARS: usize = 512;
+
+/// The GET the crawler sent for a page, as raw HTTP
+fn request_sample(result: &CrawlResult) -> Option<String> {
+    let url = Url::parse(&result.url).ok()?;
+    let host = match url.port() {
+        Some(port) => format!("{}:{}", url.host_str()?, port),
+        None => url.host_str()?.to_string(),
+    };
+    let target = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    Some(format!("GET {} HTTP/1.1\nHost: {}", target, host))
+}
+
+/// Status line, headers and the start of the body of a page's response
+fn response_sample(result: &CrawlResult) -> String {
+    let mut sample = format!("HTTP/1.1 {}", result.status_code);
+    for (name, value) in &result.headers {
+        sample.push_str(&format!("\n{}: {}", name, value));
+    }
+    if let Some(body) = result.body_sample.as_deref().filter(|body| !body.is_empty()) {
+        sample.push_str("\n\n");
+        sample.extend(body.chars().take(RESPONSE_SAMPLE_CHARS));
+        if body.chars().nth(RESPONSE_SAMPLE_CHARS).is_some() {
+            sample.push_str("\n[truncated]");
+        }
+    }
+    sample
+}
+
 pub fn check_security_headers(result: &CrawlResult, node_id: i64) -> Vec<Finding> {
     let mut findings = Vec::new();
 
@@ -29,6 +67,10 @@ pub fn check_security_headers(result: &CrawlResult, node_id: i64) -> Vec<Finding
                     evidence: None,
                     cwe_id: Some("CWE-1021".to_string()),
                     owasp_category: Some("A05:2021 - Security Misconfiguration".to_string()),
+                    cvss_score: Some(3.1),
+                    confidence: Confidence::Likely,
+                    request_sample: None,
+                    response_sample: None,
                 });
     }
 
@@ -57,6 +99,10 @@ pub fn check_insecure_transport(result: &CrawlResult, node_id: i64) -> Vec<Findi
                         evidence: Some(format!("{{\"url\": \"{}\", \"scheme\": \"http\"}}", result.url)),
                         cwe_id: Some("CWE-319".to_string()),
                         owasp_category: Some("A02:2021 - Cryptographic Failures".to_string()),
+                        cvss_score: Some(5.3),
+                        confidence: Confidence::Confirmed,
+                        request_sample: None,
+                        response_sample: None,
                     });
         }
     }
@@ -112,6 +158,14 @@ pub fn check_interesting_files(result: &CrawlResult, node_id: i64) -> Vec<Findin
 
         for (pattern, title, severity, cwe) in interesting_patterns {
             if path.contains(pattern) && result.status_code >= 200 && result.status_code < 300 {
+                // Unauthenticated network read of the file's contents
+                let cvss_score = match severity {
+                    Severity::Critical => Some(9.1),
+                    Severity::High => Some(7.5),
+                    Severity::Medium => Some(5.3),
+                    Severity::Low => Some(3.7),
+                    Severity::Info => None,
+                };
                 findings.push(Finding {
                     node_id,
                     finding_type: FindingType::InterestingFile,
@@ -123,6 +177,10 @@ pub fn check_interesting_files(result: &CrawlResult, node_id: i64) -> Vec<Findin
                     evidence: Some(format!("{{\"url\": \"{}\", \"status_code\": {}}}", result.url, result.status_code)),
                     cwe_id: Some(cwe.to_string()),
                     owasp_category: Some("A01:2021 - Broken Access Control".to_string()),
+                    cvss_score,
+                    confidence: Confidence::Confirmed,
+                    request_sample: request_sample(result),
+                    response_sample: Some(response_sample(result)),
                 });
                 break; // Only report once per URL
             }
@@ -148,6 +206,223 @@ pub fn check_error_messages(result: &CrawlResult, node_id: i64) -> Vec<Finding>
             evidence: Some(format!("{{\"url\": \"{}\", \"status_code\": {}}}", result.url, result.status_code)),
             cwe_id: Some("CWE-209".to_string()),
             owasp_category: Some("A05:2021 - Security Misconfiguration".to_string()),
+            cvss_score: Some(3.7),
+            confidence: Confidence::Confirmed,
+            request_sample: request_sample(result),
+            response_sample: Some(response_sample(result)),
+        });
+    }
+
+    findings
+}
+
+/// Flag login forms and administrative paths that respond without requiring authentication
+pub fn check_auth_surface(result: &CrawlResult, node_id: i64) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    if result.password_forms_found > 0 {
+        findings.push(Finding {
+            node_id,
+            finding_type: FindingType::AuthenticationIssue,
+            severity: Severity::Info,
+            title: "Login Form Detected".to_string(),
+            description: format!("Found {} form(s) with a password field at {}.", result.password_forms_found, result.url),
+            impact: Some("Login pages are a target for credential stuffing and brute-force attacks.".to_string()),
+            remediation: Some("Ensure the form is served over HTTPS and protected by rate limiting and account lockout.".to_string()),
+            evidence: Some(format!("{{\"url\": \"{}\", \"password_forms\": {}}}", result.url, result.password_forms_found)),
+            cwe_id: Some("CWE-307".to_string()),
+            owasp_category: Some("A07:2021 - Identification and Authentication Failures".to_string()),
+            cvss_score: None,
+            confidence: Confidence::Confirmed,
+            request_sample: None,
+            response_sample: None,
+        });
+    }
+
+    let admin_segments = [
+        "admin",
+        "administrator",
+        "dashboard",
+        "wp-admin",
+        "manage",
+        "console",
+    ];
+    let login_markers = ["login", "signin", "sign-in", "sso", "auth"];
+
+    if let Ok(parsed_url) = Url::parse(&result.url)
+        && result.status_code >= 200
+        && result.status_code < 300
+        && result.password_forms_found == 0
+        && parsed_url.path_segments().is_some_and(|mut segments| {
+            segments.any(|s| admin_segments.contains(&s.to_lowercase().as_str()))
+        })
+    {
+        // A redirect to a login page means the path is protected
+        let redirected_to_login = result.redirected_to.as_ref().is_some_and(|target| {
+            let target = target.to_lowercase();
+            login_markers.iter().any(|marker| target.contains(marker))
+        });
+
+        if !redirected_to_login {
+            findings.push(Finding {
+                node_id,
+                finding_type: FindingType::AuthorizationIssue,
+                severity: Severity::Low,
+                title: "Administrative Path Accessible Without Authentication".to_string(),
+                description: format!("{} returned {} without redirecting to a login page.", result.url, result.status_code),
+                impact: Some("Administrative functionality reachable without authentication may allow unauthorized access to privileged operations.".to_string()),
+                remediation: Some("Require authentication for administrative paths and redirect unauthenticated users to login.".to_string()),
+                evidence: Some(format!("{{\"url\": \"{}\", \"status_code\": {}}}", result.url, result.status_code)),
+                cwe_id: Some("CWE-306".to_string()),
+                owasp_category: Some("A01:2021 - Broken Access Control".to_string()),
+                cvss_score: Some(3.7),
+                confidence: Confidence::Possible,
+                request_sample: None,
+                response_sample: None,
+            });
+        }
+    }
+
+    findings
+}
+
+/// Flag responses carrying user-influenced content (API data, plain text, uploads)
+/// without `X-Content-Type-Options: nosniff`, which lets browsers MIME-sniff them as HTML
+pub fn check_mime_sniffing(result: &CrawlResult, node_id: i64) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    // Results without captured headers (e.g. older captures) can't be judged
+    if result.headers.is_empty() || result.status_code < 200 || result.status_code >= 300 {
+        return findings;
+    }
+
+    let has_nosniff = result.headers.iter().any(|(name, value)| {
+        name.eq_ignore_ascii_case("x-content-type-options")
+            && value.trim().eq_ignore_ascii_case("nosniff")
+    });
+    if has_nosniff {
+        return findings;
+    }
+
+    let content_type = result
+        .content_type
+        .as_deref()
+        .unwrap_or("")
+        .to_lowercase();
+    let sniffable_types = [
+        "application/json",
+        "text/plain",
+        "text/csv",
+        "application/xml",
+        "text/xml",
+        "application/octet-stream",
+    ];
+    let is_sniffable = content_type.is_empty()
+        || content_type.contains("+json")
+        || sniffable_types.iter().any(|t| content_type.starts_with(t));
+
+    // Uploaded files served back as HTML are the classic sniffing vector too
+    let upload_segments = ["upload", "uploads", "files", "attachments", "media"];
+    let is_upload = Url::parse(&result.url).is_ok_and(|parsed_url| {
+        parsed_url.path_segments().is_some_and(|mut segments| {
+            segments.any(|s| upload_segments.contains(&s.to_lowercase().as_str()))
+        })
+    });
+
+    if is_sniffable || is_upload {
+        let served_as = if content_type.is_empty() {
+            "no content type".to_string()
+        } else {
+            content_type.clone()
+        };
+        findings.push(Finding {
+            node_id,
+            finding_type: FindingType::SecurityHeaderMissing,
+            severity: Severity::Low,
+            title: "Missing X-Content-Type-Options Header".to_string(),
+            description: format!("{} serves user-influenced content ({}) without 'X-Content-Type-Options: nosniff'.", result.url, served_as),
+            impact: Some("Browsers may MIME-sniff the response and render attacker-controlled data as HTML or script, enabling cross-site scripting.".to_string()),
+            remediation: Some("Send 'X-Content-Type-Options: nosniff' on all responses and set an accurate Content-Type.".to_string()),
+            evidence: Some(format!("{{\"url\": \"{}\", \"content_type\": \"{}\"}}", result.url, served_as)),
+            cwe_id: Some("CWE-430".to_string()),
+            owasp_category: Some("A05:2021 - Security Misconfiguration".to_string()),
+            cvss_score: Some(3.1),
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
+        });
+    }
+
+    findings
+}
+
+/// Flag endpoints that take query parameters or form inputs as candidates for active injection testing
+pub fn check_injection_points(result: &CrawlResult, node_id: i64) -> Vec<Finding> {
+    let mut findings = Vec::new();
+
+    // The endpoint's own query string plus the form fields and link parameters found on it
+    let mut parameters: Vec<String> = Vec::new();
+    if let Ok(parsed_url) = Url::parse(&result.url) {
+        for (name, _) in parsed_url.query_pairs() {
+            if !name.is_empty() && !parameters.iter().any(|p| *p == name) {
+                parameters.push(name.into_owned());
+            }
+        }
+    }
+    for name in &result.parameters {
+        if !parameters.contains(name) {
+            parameters.push(name.clone());
+        }
+    }
+
+    if !parameters.is_empty() {
+        findings.push(Finding {
+            node_id,
+            finding_type: FindingType::InjectionPoint,
+            severity: Severity::Info,
+            title: "Potential Injection Point".to_string(),
+            description: format!("{} accepts {} parameter(s): {}.", result.url, parameters.len(), parameters.join(", ")),
+            impact: Some("User-controlled parameters are where injection flaws such as SQL injection and cross-site scripting occur.".to_string()),
+            remediation: Some("Validate and encode every parameter server-side and use parameterized queries.".to_string()),
+            evidence: Some(serde_json::json!({ "url": result.url, "parameters": parameters }).to_string()),
+            cwe_id: Some("CWE-20".to_string()),
+            owasp_category: Some("A03:2021 - Injection".to_string()),
+            cvss_score: None,
+            confidence: Confidence::Possible,
+            request_sample: None,
+            response_sample: None,
+        });
+    }
+
+    findings
+}
+
+/// Flag `Server`, `X-Powered-By` and `X-AspNet-Version` headers that reveal a version number
+pub fn check_version_disclosure(result: &CrawlResult, node_id: i64) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let banner_headers = ["server", "x-powered-by", "x-aspnet-version"];
+
+    for (name, value) in &result.headers {
+        if !banner_headers.iter().any(|banner| name.eq_ignore_ascii_case(banner))
+            || !VERSION_PATTERN.is_match(value)
+        {
+            continue;
+        }
+        findings.push(Finding {
+            node_id,
+            finding_type: FindingType::InformationDisclosure,
+            severity: Severity::Low,
+            title: "Server Version Disclosed".to_string(),
+            description: format!("{} reveals a software version in its {} header: {}.", result.url, name, value),
+            impact: Some("Exact versions let attackers look up known vulnerabilities for the software in use.".to_string()),
+            remediation: Some("Remove version details from response headers (e.g. 'server_tokens off' in nginx, 'ServerTokens Prod' in Apache).".to_string()),
+            evidence: Some(serde_json::json!({ "url": result.url, "header": name, "value": value }).to_string()),
+            cwe_id: Some("CWE-200".to_string()),
+            owasp_category: Some("A05:2021 - Security Misconfiguration".to_string()),
+            cvss_score: Some(3.7),
+            confidence: Confidence::Confirmed,
+            request_sample: None,
+            response_sample: None,
         });
     }
 
@@ -161,6 +436,10 @@ pub fn analyze_crawl_result(result: &CrawlResult, node_id: i64) -> Vec<Finding>
     all_findings.extend(check_insecure_transport(result, node_id));
     all_findings.extend(check_interesting_files(result, node_id));
     all_findings.extend(check_error_messages(result, node_id));
+    all_findings.extend(check_auth_surface(result, node_id));
+    all_findings.extend(check_mime_sniffing(result, node_id));
+    all_findings.extend(check_injection_points(result, node_id));
+    all_findings.extend(check_version_disclosure(result, node_id));
     // check_security_headers would need actual headers from the scanner
     // all_findings.extend(check_security_headers(result, node_id));
 
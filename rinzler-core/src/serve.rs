@@ -0,0 +1,161 @@
+use crate::data::Database;
+use crate::report::{
+    SessionSummary, gather_report_data, gather_sitemap_nodes, generate_json_report,
+    generate_sarif_report,
+};
+use http_body_util::Full;
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+
+/// Port `rinzler serve` listens on unless `--port` is given
+pub const DEFAULT_SERVE_PORT: u16 = 8787;
+
+/// Status code and JSON body produced for an API request
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl ApiResponse {
+    fn ok(body: String) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl std::fmt::Display) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message.to_string() }).to_string(),
+        }
+    }
+}
+
+/// Route a request to the matching read-only query.
+///
+/// Routes:
+/// - `GET /sessions`
+/// - `GET /sessions/{id}/nodes`
+/// - `GET /sessions/{id}/findings`
+/// - `GET /sessions/{id}/report?format=json|sarif`
+pub fn handle_request(db: &Database, method: &str, path: &str, query: Option<&str>) -> ApiResponse {
+    if method != "GET" {
+        return ApiResponse::error(405, "method not allowed");
+    }
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["sessions"] => list_sessions(db),
+        ["sessions", session_id, resource] => {
+            // Load the session first so unknown ids are a 404 on every route
+            let include_sitemap = *resource == "report";
+            let data = match gather_report_data(db, session_id, include_sitemap, false) {
+                Ok(data) => data,
+                Err(rusqlite::Error::QueryReturnedNoRows) => {
+                    return ApiResponse::error(404, format!("session {} not found", session_id));
+                }
+                Err(e) => return ApiResponse::error(500, e),
+            };
+
+            match *resource {
+                "nodes" => match gather_sitemap_nodes(db, session_id) {
+                    Ok(nodes) => to_json(&nodes),
+                    Err(e) => ApiResponse::error(500, e),
+                },
+                "findings" => to_json(&data.findings),
+                "report" => match query_param(query, "format").unwrap_or("json") {
+                    "json" => generate_json_report(&data)
+                        .map(ApiResponse::ok)
+                        .unwrap_or_else(|e| ApiResponse::error(500, e)),
+                    "sarif" => generate_sarif_report(&data)
+                        .map(ApiResponse::ok)
+                        .unwrap_or_else(|e| ApiResponse::error(500, e)),
+                    other => ApiResponse::error(400, format!("unsupported format: {}", other)),
+                },
+                _ => ApiResponse::error(404, "not found"),
+            }
+        }
+        _ => ApiResponse::error(404, "not found"),
+    }
+}
+
+fn list_sessions(db: &Database) -> ApiResponse {
+    let summaries: rusqlite::Result<Vec<SessionSummary>> = db.list_sessions().and_then(|ids| {
+        ids.iter()
+            .map(|id| gather_report_data(db, id, false, false).map(SessionSummary::from))
+            .collect()
+    });
+
+    match summaries {
+        Ok(summaries) => to_json(&summaries),
+        Err(e) => ApiResponse::error(500, e),
+    }
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> ApiResponse {
+    serde_json::to_string_pretty(value)
+        .map(ApiResponse::ok)
+        .unwrap_or_else(|e| ApiResponse::error(500, e))
+}
+
+fn query_param<'a>(query: Option<&'a str>, name: &str) -> Option<&'a str> {
+    query?
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+/// Serve the JSON API on `listener` until the task is cancelled
+pub async fn serve(db: Database, listener: TcpListener) -> std::io::Result<()> {
+    let db = Arc::new(Mutex::new(db));
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let db = db.clone();
+
+        tokio::spawn(async move {
+            let service = service_fn(move |req: Request<Incoming>| {
+                let db = db.clone();
+                async move { Ok::<_, Infallible>(respond(&db, &req)) }
+            });
+
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+fn respond(db: &Mutex<Database>, req: &Request<Incoming>) -> Response<Full<Bytes>> {
+    let api_response = match db.lock() {
+        Ok(db) => handle_request(
+            &db,
+            req.method().as_str(),
+            req.uri().path(),
+            req.uri().query(),
+        ),
+        Err(_) => ApiResponse::error(500, "database unavailable"),
+    };
+
+    let mut response = Response::new(Full::new(Bytes::from(api_response.body)));
+    *response.status_mut() =
+        StatusCode::from_u16(api_response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("application/json"),
+    );
+    if *req.method() != Method::GET {
+        response.headers_mut().insert(
+            hyper::header::ALLOW,
+            hyper::header::HeaderValue::from_static("GET"),
+        );
+    }
+
+    response
+}
@@ -0,0 +1,242 @@
+use crate::data::ServiceType;
+use rinzler_scanner::result::CrawlResult;
+use url::Url;
+
+/// Confidence for a header that names the product and its version
+pub const CONFIDENCE_VERSIONED_HEADER: u8 = 90;
+/// Confidence for a header that names the product without a version
+pub const CONFIDENCE_HEADER: u8 = 70;
+/// Confidence for a product inferred from a generic session cookie name
+pub const CONFIDENCE_COOKIE: u8 = 40;
+
+/// A technology fingerprinted from response headers
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectedTechnology {
+    /// One of the `technologies.category` values in the database schema
+    pub category: &'static str,
+    pub name: String,
+    pub version: Option<String>,
+    /// One of the `technologies.detection_method` values in the database schema
+    pub detection_method: &'static str,
+    pub evidence: String,
+    /// 0-100, higher means stronger evidence
+    pub confidence: u8,
+}
+
+/// Known products: (lowercase token, display name, category)
+const KNOWN_PRODUCTS: &[(&str, &str, &str)] = &[
+    ("nginx", "nginx", "web_server"),
+    ("openresty", "OpenResty", "web_server"),
+    ("apache", "Apache", "web_server"),
+    ("microsoft-iis", "Microsoft IIS", "web_server"),
+    ("litespeed", "LiteSpeed", "web_server"),
+    ("caddy", "Caddy", "web_server"),
+    ("cloudflare", "Cloudflare", "cdn"),
+    ("gunicorn", "Gunicorn", "application_server"),
+    ("jetty", "Jetty", "application_server"),
+    ("kestrel", "Kestrel", "application_server"),
+    ("werkzeug", "Werkzeug", "application_server"),
+    ("php", "PHP", "framework"),
+    ("express", "Express", "framework"),
+    ("asp.net", "ASP.NET", "framework"),
+    ("next.js", "Next.js", "framework"),
+    ("servlet", "Java Servlet", "application_server"),
+    ("drupal", "Drupal", "cms"),
+    ("wordpress", "WordPress", "cms"),
+    ("joomla!", "Joomla", "cms"),
+];
+
+/// Session cookies that hint at a platform: (cookie name, display name, category)
+const KNOWN_COOKIES: &[(&str, &str, &str)] = &[
+    ("phpsessid", "PHP", "framework"),
+    ("jsessionid", "Java Servlet", "application_server"),
+    ("asp.net_sessionid", "ASP.NET", "framework"),
+    ("laravel_session", "Laravel", "framework"),
+    ("connect.sid", "Express", "framework"),
+    ("csrftoken", "Django", "framework"),
+];
+
+/// Classify the kind of service a crawled page belongs to from its response status,
+/// URL and content type. Returns `None` when the response gives nothing to go on
+/// (failed fetches, or an unknown content type outside an API path).
+pub fn classify_service(content_type: Option<&str>, url: &str, status: u16) -> Option<ServiceType> {
+    if status == 0 {
+        return None;
+    }
+
+    let content_type = content_type.unwrap_or("").to_ascii_lowercase();
+    let segments: Vec<String> = Url::parse(url)
+        .ok()
+        .and_then(|url| {
+            url.path_segments()
+                .map(|segments| segments.map(str::to_ascii_lowercase).collect())
+        })
+        .unwrap_or_default();
+    let has_segment = |name: &str| segments.iter().any(|s| s == name);
+
+    let service_type = if (300..400).contains(&status) {
+        ServiceType::Redirect
+    } else if has_segment("graphql") {
+        ServiceType::GraphQL
+    } else if content_type.contains("soap") || (content_type.contains("xml") && has_segment("soap"))
+    {
+        ServiceType::Soap
+    } else if content_type.contains("json") {
+        ServiceType::RestApi
+    } else if content_type.starts_with("text/html") {
+        ServiceType::Web
+    } else if content_type.starts_with("text/css")
+        || content_type.contains("javascript")
+        || content_type.starts_with("image/")
+        || content_type.starts_with("font/")
+    {
+        ServiceType::Static
+    } else if has_segment("api") {
+        ServiceType::RestApi
+    } else {
+        return None;
+    };
+
+    Some(service_type)
+}
+
+/// One-line stack fingerprint such as "Technologies: nginx, PHP/7.4, WordPress",
+/// or None when nothing was detected
+pub fn technology_summary(technologies: &[(String, Option<String>)]) -> Option<String> {
+    if technologies.is_empty() {
+        return None;
+    }
+
+    let names: Vec<String> = technologies
+        .iter()
+        .map(|(name, version)| match version {
+            Some(version) => format!("{}/{}", name, version),
+            None => name.clone(),
+        })
+        .collect();
+    Some(format!("Technologies: {}", names.join(", ")))
+}
+
+/// Fingerprint technologies from the headers of a crawl result
+pub fn detect_technologies(result: &CrawlResult) -> Vec<DetectedTechnology> {
+    detect_technologies_from_headers(&result.headers)
+}
+
+/// Fingerprint technologies from (name, value) header pairs.
+/// Each product is reported once, keeping the strongest evidence.
+pub fn detect_technologies_from_headers(headers: &[(String, String)]) -> Vec<DetectedTechnology> {
+    let mut detected: Vec<DetectedTechnology> = Vec::new();
+
+    for (name, value) in headers {
+        let candidates = match name.to_ascii_lowercase().as_str() {
+            "server" => parse_product_header(value, "web_server", name),
+            "x-powered-by" => parse_product_header(value, "framework", name),
+            "x-generator" => parse_product_header(value, "cms", name),
+            "x-aspnet-version" | "x-aspnetmvc-version" => {
+                let version = value.trim();
+                vec![DetectedTechnology {
+                    category: "framework",
+                    name: if name.eq_ignore_ascii_case("x-aspnetmvc-version") {
+                        "ASP.NET MVC".to_string()
+                    } else {
+                        "ASP.NET".to_string()
+                    },
+                    version: (!version.is_empty()).then(|| version.to_string()),
+                    detection_method: "header",
+                    evidence: format!("{}: {}", name, value),
+                    confidence: if version.is_empty() {
+                        CONFIDENCE_HEADER
+                    } else {
+                        CONFIDENCE_VERSIONED_HEADER
+                    },
+                }]
+            }
+            "set-cookie" => parse_cookie_header(value),
+            _ => Vec::new(),
+        };
+
+        for candidate in candidates {
+            match detected.iter_mut().find(|t| t.name == candidate.name) {
+                Some(existing) if existing.confidence < candidate.confidence => {
+                    *existing = candidate
+                }
+                Some(_) => {}
+                None => detected.push(candidate),
+            }
+        }
+    }
+
+    detected
+}
+
+/// Parse a product header such as `nginx/1.18.0 (Ubuntu)` or `Drupal 10 (https://www.drupal.org)`.
+/// Only the first product token is used; comments in parentheses are ignored.
+fn parse_product_header(
+    value: &str,
+    default_category: &'static str,
+    header_name: &str,
+) -> Vec<DetectedTechnology> {
+    let mut tokens = value.split_whitespace().filter(|t| !t.starts_with('('));
+    let Some(product) = tokens.next() else {
+        return Vec::new();
+    };
+
+    let (raw_name, version) = match product.split_once('/') {
+        Some((name, version)) => (name, parse_version(version)),
+        None => (product, tokens.next().and_then(parse_version)),
+    };
+    if raw_name.is_empty() {
+        return Vec::new();
+    }
+
+    let (name, category) = KNOWN_PRODUCTS
+        .iter()
+        .find(|(token, _, _)| raw_name.eq_ignore_ascii_case(token))
+        .map(|(_, name, category)| (name.to_string(), *category))
+        .unwrap_or_else(|| (raw_name.to_string(), default_category));
+
+    let confidence = if version.is_some() {
+        CONFIDENCE_VERSIONED_HEADER
+    } else {
+        CONFIDENCE_HEADER
+    };
+
+    vec![DetectedTechnology {
+        category,
+        name,
+        version,
+        detection_method: "header",
+        evidence: format!("{}: {}", header_name, value),
+        confidence,
+    }]
+}
+
+/// Match the cookie name in a `Set-Cookie` value against known session cookies
+fn parse_cookie_header(value: &str) -> Vec<DetectedTechnology> {
+    let cookie_name = value.split(['=', ';']).next().unwrap_or("").trim();
+
+    KNOWN_COOKIES
+        .iter()
+        .filter(|(cookie, _, _)| cookie_name.eq_ignore_ascii_case(cookie))
+        .map(|(_, name, category)| DetectedTechnology {
+            category,
+            name: name.to_string(),
+            version: None,
+            detection_method: "cookie",
+            evidence: format!("set-cookie: {}", cookie_name),
+            confidence: CONFIDENCE_COOKIE,
+        })
+        .collect()
+}
+
+/// Accept version strings that start with a digit, e.g. `1.18.0` or `8.1.2-1ubuntu2`
+fn parse_version(raw: &str) -> Option<String> {
+    let version: String = raw
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        .collect();
+
+    version
+        .starts_with(|c: char| c.is_ascii_digit())
+        .then_some(version)
+}
@@ -0,0 +1,146 @@
+// Webhook alerts for findings recorded during a crawl
+
+use crate::batch::BatchSettings;
+use crate::data::{Finding, Severity};
+use serde::Serialize;
+use std::time::Duration;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// One finding as it appears in the webhook payload
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookFinding {
+    pub title: String,
+    pub severity: String,
+    pub url: String,
+}
+
+/// JSON body POSTed to the webhook
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookPayload {
+    pub session_id: String,
+    pub findings: Vec<WebhookFinding>,
+}
+
+/// Cheap handle the crawl callbacks use to queue findings for the webhook
+#[derive(Clone)]
+pub struct WebhookSender {
+    tx: UnboundedSender<WebhookFinding>,
+    threshold: Severity,
+}
+
+impl WebhookSender {
+    /// Queue the findings for `url` that are at or above the threshold
+    pub fn notify(&self, url: &str, findings: &[Finding]) {
+        for finding in findings
+            .iter()
+            .filter(|f| f.severity.rank() >= self.threshold.rank())
+        {
+            let _ = self.tx.send(WebhookFinding {
+                title: finding.title.clone(),
+                severity: finding.severity.as_str().to_string(),
+                url: url.to_string(),
+            });
+        }
+    }
+}
+
+/// Background task that debounces queued findings into batched POSTs
+pub struct WebhookNotifier {
+    sender: WebhookSender,
+    handle: JoinHandle<()>,
+}
+
+impl WebhookNotifier {
+    /// Start delivering alerts for `session_id` to `webhook_url`, at most
+    /// `batch.size` findings per POST and none held longer than `batch.flush_interval`.
+    /// Delivery failures are passed to `on_error` and never stop the notifier.
+    pub fn spawn(
+        webhook_url: String,
+        session_id: String,
+        threshold: Severity,
+        batch: BatchSettings,
+        on_error: impl Fn(String) + Send + 'static,
+    ) -> Self {
+        let (tx, rx) = unbounded_channel();
+        let handle = tokio::spawn(deliver_batches(rx, webhook_url, session_id, batch, on_error));
+
+        Self {
+            sender: WebhookSender { tx, threshold },
+            handle,
+        }
+    }
+
+    pub fn sender(&self) -> WebhookSender {
+        self.sender.clone()
+    }
+
+    /// Send whatever is still queued and stop. Every cloned sender must be
+    /// dropped first, or this waits for them.
+    pub async fn finish(self) {
+        drop(self.sender);
+        let _ = self.handle.await;
+    }
+}
+
+async fn deliver_batches(
+    mut rx: UnboundedReceiver<WebhookFinding>,
+    webhook_url: String,
+    session_id: String,
+    batch: BatchSettings,
+    on_error: impl Fn(String),
+) {
+    let client = match reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            on_error(format!("Webhook disabled: {}", e));
+            return;
+        }
+    };
+
+    while let Some(first) = rx.recv().await {
+        // Hold the batch open for the flush interval so a burst becomes one POST
+        let mut findings = vec![first];
+        let deadline = Instant::now() + batch.flush_interval;
+        while findings.len() < batch.size {
+            match tokio::time::timeout_at(deadline, rx.recv()).await {
+                Ok(Some(finding)) => findings.push(finding),
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        let payload = WebhookPayload {
+            session_id: session_id.clone(),
+            findings,
+        };
+        if let Err(e) = post_payload(&client, &webhook_url, &payload).await {
+            on_error(format!(
+                "Webhook delivery of {} finding(s) failed: {}",
+                payload.findings.len(),
+                e
+            ));
+        }
+    }
+}
+
+async fn post_payload(
+    client: &reqwest::Client,
+    webhook_url: &str,
+    payload: &WebhookPayload,
+) -> Result<(), String> {
+    let response = client
+        .post(webhook_url)
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("webhook returned {}", response.status()))
+    }
+}
@@ -0,0 +1,176 @@
+// Tests for rinzler.toml loading and merging
+
+use rinzler_core::config::{
+    CONFIG_FILE_NAME, DEFAULT_CONFIG, RinzlerConfig, config_search_paths, load_config,
+    parse_config, parse_header, resolve,
+};
+use std::collections::BTreeMap;
+use tempfile::TempDir;
+
+#[test]
+fn test_parse_config_all_fields() {
+    let config = parse_config(
+        r#"
+threads = 25
+depth = 5
+delay_ms = 200
+user_agent = "Engagement-42"
+proxy = "http://127.0.0.1:8080"
+
+[headers]
+X-Engagement-Id = "acme"
+Authorization = "Bearer token"
+"#,
+    )
+    .unwrap();
+
+    let mut headers = BTreeMap::new();
+    headers.insert("X-Engagement-Id".to_string(), "acme".to_string());
+    headers.insert("Authorization".to_string(), "Bearer token".to_string());
+    assert_eq!(
+        config,
+        RinzlerConfig {
+            threads: Some(25),
+            depth: Some(5),
+            delay_ms: Some(200),
+            user_agent: Some("Engagement-42".to_string()),
+            headers,
+            proxy: Some("http://127.0.0.1:8080".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_parse_config_empty_is_default() {
+    assert_eq!(parse_config("").unwrap(), RinzlerConfig::default());
+}
+
+#[test]
+fn test_default_config_template_parses_to_defaults() {
+    assert_eq!(
+        parse_config(DEFAULT_CONFIG).unwrap(),
+        RinzlerConfig::default()
+    );
+}
+
+#[test]
+fn test_parse_config_rejects_unknown_fields() {
+    let err = parse_config("thread = 4").unwrap_err();
+    assert!(err.contains("thread"));
+}
+
+#[test]
+fn test_parse_config_rejects_wrong_types() {
+    assert!(parse_config("threads = \"many\"").is_err());
+}
+
+#[test]
+fn test_load_config_prefers_working_directory() {
+    let cwd = TempDir::new().unwrap();
+    let config_dir = TempDir::new().unwrap();
+    std::fs::write(cwd.path().join(CONFIG_FILE_NAME), "threads = 4").unwrap();
+    std::fs::write(config_dir.path().join(CONFIG_FILE_NAME), "threads = 8").unwrap();
+
+    let paths = config_search_paths(cwd.path(), config_dir.path());
+    let (path, config) = load_config(&paths).unwrap().unwrap();
+
+    assert_eq!(path, cwd.path().join(CONFIG_FILE_NAME));
+    assert_eq!(config.threads, Some(4));
+}
+
+#[test]
+fn test_load_config_falls_back_to_config_dir() {
+    let cwd = TempDir::new().unwrap();
+    let config_dir = TempDir::new().unwrap();
+    std::fs::write(config_dir.path().join(CONFIG_FILE_NAME), "depth = 6").unwrap();
+
+    let paths = config_search_paths(cwd.path(), config_dir.path());
+    let (_, config) = load_config(&paths).unwrap().unwrap();
+
+    assert_eq!(config.depth, Some(6));
+}
+
+#[test]
+fn test_load_config_missing_is_none() {
+    let cwd = TempDir::new().unwrap();
+    let config_dir = TempDir::new().unwrap();
+
+    let paths = config_search_paths(cwd.path(), config_dir.path());
+    assert!(load_config(&paths).unwrap().is_none());
+}
+
+#[test]
+fn test_load_config_reports_path_on_parse_error() {
+    let cwd = TempDir::new().unwrap();
+    let config_dir = TempDir::new().unwrap();
+    std::fs::write(cwd.path().join(CONFIG_FILE_NAME), "threads = [").unwrap();
+
+    let paths = config_search_paths(cwd.path(), config_dir.path());
+    let err = load_config(&paths).unwrap_err();
+
+    assert!(err.contains(CONFIG_FILE_NAME));
+}
+
+#[test]
+fn test_resolve_precedence() {
+    assert_eq!(resolve(Some(20), Some(5), 10), 20);
+    assert_eq!(resolve(None, Some(5), 10), 5);
+    assert_eq!(resolve(None, None, 10), 10);
+}
+
+#[test]
+fn test_parse_header() {
+    assert_eq!(
+        parse_header("X-Api-Key:  secret "),
+        Ok(("X-Api-Key".to_string(), "secret".to_string()))
+    );
+    assert_eq!(
+        parse_header("Authorization: Bearer a:b"),
+        Ok(("Authorization".to_string(), "Bearer a:b".to_string()))
+    );
+    assert!(parse_header("no-colon").is_err());
+    assert!(parse_header(": value").is_err());
+}
+
+#[test]
+fn test_request_settings_flags_override_config() {
+    let config = parse_config(
+        r#"
+proxy = "http://config-proxy:8080"
+
+[headers]
+Authorization = "Bearer config"
+X-Engagement-Id = "acme"
+"#,
+    )
+    .unwrap();
+
+    let settings = config.request_settings(
+        &[("authorization".to_string(), "Bearer flag".to_string())],
+        Some("http://flag-proxy:8080".to_string()),
+    );
+
+    assert_eq!(settings.proxy.as_deref(), Some("http://flag-proxy:8080"));
+    assert_eq!(
+        settings.headers,
+        vec![
+            ("X-Engagement-Id".to_string(), "acme".to_string()),
+            ("authorization".to_string(), "Bearer flag".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_request_settings_fall_back_to_config() {
+    let config = parse_config(
+        r#"
+proxy = "http://config-proxy:8080"
+"#,
+    )
+    .unwrap();
+
+    let settings = config.request_settings(&[], None);
+
+    assert_eq!(settings.proxy.as_deref(), Some("http://config-proxy:8080"));
+    assert!(settings.headers.is_empty());
+}
@@ -1,6 +1,15 @@
 // Tests for crawl functionality
 
-use rinzler_core::crawl::{FollowMode, extract_url_path};
+use rinzler_core::crawl::{
+    CrawlOptions, DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_PER_HOST, DEFAULT_POOL_IDLE_TIMEOUT_SECS, DEFAULT_POOL_SIZE,
+    FollowMode, RequestSettings, WorkerStats, build_crawl_client, crawl_client_config, execute_crawl,
+    extract_url_path, generate_crawl_report, generate_crawl_report_with_color, resolve_entry_paths,
+    resume_roots,
+};
+use rinzler_scanner::result::CrawlResult;
+use std::sync::{Arc, Mutex};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 // ============================================================================
 // URL Path Extraction Tests
@@ -105,6 +114,55 @@ fn test_extract_url_path_with_username() {
     assert_eq!(path, "/api");
 }
 
+// ============================================================================
+// Entry Path Tests
+// ============================================================================
+
+#[test]
+fn test_resolve_entry_paths() {
+    let roots = resolve_entry_paths(
+        "http://example.com/",
+        &["/api".to_string(), "docs/".to_string()],
+    )
+    .unwrap();
+    assert_eq!(
+        roots,
+        vec![
+            "http://example.com/",
+            "http://example.com/api",
+            "http://example.com/docs/"
+        ]
+    );
+}
+
+#[test]
+fn test_resolve_entry_paths_dedups_base() {
+    let roots = resolve_entry_paths("http://example.com/", &["/".to_string()]).unwrap();
+    assert_eq!(roots, vec!["http://example.com/"]);
+}
+
+#[test]
+fn test_resume_roots_replaces_base() {
+    let roots = resolve_entry_paths("http://example.com/", &["/api".to_string()]).unwrap();
+    let roots = resume_roots(&roots, "http://example.com/app/settings");
+    assert_eq!(
+        roots,
+        vec!["http://example.com/app/settings", "http://example.com/api"]
+    );
+}
+
+#[test]
+fn test_resume_roots_dedups_entry_path() {
+    let roots = resolve_entry_paths("http://example.com/", &["/deep".to_string()]).unwrap();
+    let roots = resume_roots(&roots, "http://example.com/deep");
+    assert_eq!(roots, vec!["http://example.com/deep"]);
+}
+
+#[test]
+fn test_resolve_entry_paths_invalid_base() {
+    assert!(resolve_entry_paths("not a url", &["/api".to_string()]).is_err());
+}
+
 // ============================================================================
 // FollowMode Tests
 // ============================================================================
@@ -156,7 +214,7 @@ fn test_extract_url_path_dot_segments() {
 
 #[test]
 fn test_extract_url_path_very_long() {
-    let long_path = "/".to_string() + &"a/".repeat(100);
+    let long_path = format!("/{}", "a/".repeat(100));
     let url = format!("http://example.com{}", long_path);
     let path = extract_url_path(&url);
     assert!(path.len() > 100);
@@ -204,3 +262,255 @@ fn test_extract_url_path_ipv6() {
     let path = extract_url_path(url);
     assert_eq!(path, "/api");
 }
+
+// ============================================================================
+// Worker Statistics Tests
+// ============================================================================
+
+#[test]
+fn test_worker_stats_reports_idle_workers() {
+    let mut stats = WorkerStats::new(3);
+    stats.record(0);
+    stats.record(0);
+    stats.record(2);
+
+    assert_eq!(stats.total(), 3);
+    assert_eq!(stats.counts().get(&1), Some(&0));
+    assert_eq!(
+        stats.summary(),
+        "Worker stats: 3 URLs across 3 workers (min 0, max 2) [#0: 2, #1: 0, #2: 1]"
+    );
+}
+
+#[tokio::test]
+async fn test_worker_stats_sum_to_processed_urls() {
+    let mock_server = MockServer::start().await;
+
+    let mut root_html = String::from("<html><body>");
+    for i in 0..6 {
+        root_html.push_str(&format!(r#"<a href="/page{}">{}</a>"#, i, i));
+    }
+    root_html.push_str("</body></html>");
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/html")
+                .set_body_bytes(root_html.as_bytes()),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/html")
+                .set_body_bytes(b"<html><body>Page</body></html>"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let messages_clone = messages.clone();
+    let options = CrawlOptions {
+        urls: vec![format!("{}/", mock_server.uri())],
+        threads: 3,
+        max_depth: 2,
+        follow_mode: FollowMode::Disabled,
+        show_progress_bars: false,
+        entry_paths: Vec::new(),
+        idle_timeout_secs: None,
+        max_pages: None,
+        max_queue_size: None,
+        max_path_depth: None,
+        scope_domain: None,
+        resume_from_url: None,
+        user_agent: None,
+        request: RequestSettings::default(),
+        request_delay_ms: None,
+        login: None,
+        max_per_host: DEFAULT_MAX_PER_HOST,
+        max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        skip_binary_bodies: true,
+        pool_size: DEFAULT_POOL_SIZE,
+        pool_idle_timeout_secs: DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+        paused: None,
+        stop: None,
+        client: None,
+    };
+
+    let results = execute_crawl(
+        options,
+        Some(Arc::new(move |msg: String| {
+            messages_clone.lock().unwrap().push(msg)
+        })),
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(results.len(), 7);
+
+    let messages = messages.lock().unwrap();
+    let summary = messages
+        .iter()
+        .find(|msg| msg.starts_with("Worker stats:"))
+        .expect("completion log should include worker stats");
+
+    // Sum the per-worker counts listed between the brackets
+    let per_worker = &summary[summary.find('[').unwrap() + 1..summary.len() - 1];
+    let worker_total: usize = per_worker
+        .split(", ")
+        .map(|entry| entry.split(": ").nth(1).unwrap().parse::<usize>().unwrap())
+        .sum();
+    assert_eq!(worker_total, results.len());
+    assert!(summary.contains("across 3 workers"));
+}
+
+#[tokio::test]
+async fn test_crawl_resume_from_url_starts_at_deep_page() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/html")
+                .set_body_bytes(b"<html><body><a href=\"/home-only\">Home</a></body></html>"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/deep"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/html")
+                .set_body_bytes(b"<html><body><a href=\"/deep/child\">Child</a></body></html>"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/html")
+                .set_body_bytes(b"<html><body>Page</body></html>"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let options = CrawlOptions {
+        urls: vec![format!("{}/", mock_server.uri())],
+        threads: 2,
+        max_depth: 2,
+        follow_mode: FollowMode::Disabled,
+        show_progress_bars: false,
+        entry_paths: Vec::new(),
+        idle_timeout_secs: None,
+        max_pages: None,
+        max_queue_size: None,
+        max_path_depth: None,
+        scope_domain: None,
+        resume_from_url: Some(format!("{}/deep", mock_server.uri())),
+        user_agent: None,
+        request: RequestSettings::default(),
+        request_delay_ms: None,
+        login: None,
+        max_per_host: DEFAULT_MAX_PER_HOST,
+        max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        skip_binary_bodies: true,
+        pool_size: DEFAULT_POOL_SIZE,
+        pool_idle_timeout_secs: DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+        paused: None,
+        stop: None,
+        client: None,
+    };
+
+    let results = execute_crawl(options, None, None).await.unwrap();
+    let urls: Vec<String> = results.iter().map(|r| r.url.clone()).collect();
+
+    assert!(urls.iter().any(|url| url.ends_with("/deep")));
+    assert!(urls.iter().any(|url| url.ends_with("/deep/child")));
+    // The base URL is skipped, so nothing only it links to is discovered
+    assert!(!urls.iter().any(|url| url.ends_with("/home-only")));
+    assert!(!urls.contains(&format!("{}/", mock_server.uri())));
+}
+
+#[tokio::test]
+async fn test_build_crawl_client_carries_crawl_settings() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api"))
+        .and(header("user-agent", "custom-agent"))
+        .and(header("x-api-key", "secret"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+
+    let options = CrawlOptions {
+        urls: vec![format!("{}/", mock_server.uri())],
+        threads: 1,
+        max_depth: 1,
+        follow_mode: FollowMode::Disabled,
+        show_progress_bars: false,
+        entry_paths: Vec::new(),
+        idle_timeout_secs: None,
+        max_pages: None,
+        max_queue_size: None,
+        max_path_depth: None,
+        scope_domain: None,
+        resume_from_url: None,
+        user_agent: Some("custom-agent".to_string()),
+        request: RequestSettings {
+            headers: vec![("X-Api-Key".to_string(), "secret".to_string())],
+            ..RequestSettings::default()
+        },
+        request_delay_ms: None,
+        login: None,
+        max_per_host: DEFAULT_MAX_PER_HOST,
+        max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        skip_binary_bodies: true,
+        pool_size: 4,
+        pool_idle_timeout_secs: 30,
+        paused: None,
+        stop: None,
+        client: None,
+    };
+
+    let config = crawl_client_config(&options);
+    assert_eq!(config.user_agent, "custom-agent");
+    assert_eq!(config.request, options.request);
+    assert_eq!(config.pool_size, 4);
+    assert_eq!(config.pool_idle_timeout_secs, 30);
+    assert!(config.cookies);
+
+    let client = build_crawl_client(&options).await.unwrap();
+    let response = client
+        .get(format!("{}/api", mock_server.uri()))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+}
+
+fn report_result(url: &str, status_code: u16, content_type: &str) -> CrawlResult {
+    let mut result = CrawlResult::new(url.to_string());
+    result.status_code = status_code;
+    result.content_type = Some(content_type.to_string());
+    result
+}
+
+#[test]
+fn test_crawl_report_for_files_has_no_ansi() {
+    let results = vec![
+        report_result("http://example.com/", 200, "text/html"),
+        report_result("http://example.com/api", 500, "application/json"),
+    ];
+
+    let plain = generate_crawl_report(&results);
+    assert!(!plain.contains('\x1b'));
+    assert!(plain.contains("  500 /api application/json"));
+
+    let colored = generate_crawl_report_with_color(&results, true);
+    assert!(colored.contains("\x1b[31m500\x1b[0m /api"));
+    assert!(colored.contains("\x1b[90mapplication/json\x1b[0m"));
+}
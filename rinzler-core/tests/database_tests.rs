@@ -1,6 +1,11 @@
 // Tests for database functionality
 
-use rinzler_core::data::{CrawlNode, Database, Finding, FindingType, ServiceType, Severity};
+use rinzler_core::data::{
+    Confidence, CrawlNode, Database, Finding, FindingRecorder, FindingType, SCHEMA_VERSION, ServiceType,
+    SessionPurgeFilter, Severity, parse_date_timestamp,
+};
+use rinzler_core::diff::{NodeChange, classify_node_change};
+use rinzler_core::fuzz::{FuzzHit, FuzzResult, FuzzSource};
 use tempfile::TempDir;
 
 fn create_test_db() -> (TempDir, Database) {
@@ -43,7 +48,16 @@ fn test_database_drop() {
     let _db = Database::new(&db_path).unwrap();
     assert!(Database::exists(&db_path));
 
-    Database::drop(&db_path);
+    Database::drop(&db_path).unwrap();
+    assert!(!Database::exists(&db_path));
+}
+
+#[test]
+fn test_database_drop_missing_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("missing.db");
+
+    assert!(Database::drop(&db_path).is_ok());
     assert!(!Database::exists(&db_path));
 }
 
@@ -87,6 +101,112 @@ fn test_complete_session() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_cancel_session() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    db.cancel_session(&session_id).unwrap();
+
+    let (status, end_time): (String, Option<i64>) = db
+        .get_connection()
+        .query_row(
+            "SELECT status, end_time FROM crawl_sessions WHERE id = ?1",
+            [&session_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(status, "cancelled");
+    assert!(end_time.is_some());
+}
+
+fn set_start_time(db: &Database, session_id: &str, start_time: i64) {
+    db.get_connection()
+        .execute(
+            "UPDATE crawl_sessions SET start_time = ?1 WHERE id = ?2",
+            rusqlite::params![start_time, session_id],
+        )
+        .unwrap();
+}
+
+#[test]
+fn test_purge_sessions_before_date() {
+    let (_temp_dir, db) = create_test_db();
+
+    let old = db.create_session("crawl", "[]").unwrap();
+    let older = db.create_session("crawl", "[]").unwrap();
+    let recent = db.create_session("crawl", "[]").unwrap();
+    set_start_time(&db, &old, 1_600_000_000);
+    set_start_time(&db, &older, 1_500_000_000);
+    set_start_time(&db, &recent, 1_750_000_000);
+
+    let map_id = db.create_map(&old).unwrap();
+    let node = CrawlNode {
+        url: "http://example.com/".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: None,
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    db.insert_node(&map_id, &node).unwrap();
+
+    let filter = SessionPurgeFilter {
+        before: Some(parse_date_timestamp("2021-01-01").unwrap()),
+        status: None,
+    };
+    assert_eq!(db.purge_sessions(&filter).unwrap(), 2);
+    assert_eq!(db.list_sessions().unwrap(), vec![recent]);
+    assert!(db.get_nodes_by_session(&old).unwrap().is_empty());
+}
+
+#[test]
+fn test_purge_sessions_by_status() {
+    let (_temp_dir, db) = create_test_db();
+
+    let completed = db.create_session("crawl", "[]").unwrap();
+    let failed = db.create_session("crawl", "[]").unwrap();
+    let old_failed = db.create_session("crawl", "[]").unwrap();
+    db.complete_session(&completed).unwrap();
+    db.fail_session(&failed).unwrap();
+    db.fail_session(&old_failed).unwrap();
+    set_start_time(&db, &old_failed, 1_500_000_000);
+
+    let filter = SessionPurgeFilter {
+        before: Some(1_600_000_000),
+        status: Some("failed".to_string()),
+    };
+    assert_eq!(db.purge_sessions(&filter).unwrap(), 1);
+
+    let filter = SessionPurgeFilter {
+        before: None,
+        status: Some("failed".to_string()),
+    };
+    assert_eq!(db.purge_sessions(&filter).unwrap(), 1);
+    assert_eq!(db.list_sessions().unwrap(), vec![completed]);
+    assert_eq!(db.purge_sessions(&filter).unwrap(), 0);
+}
+
+#[test]
+fn test_parse_date_timestamp() {
+    assert_eq!(parse_date_timestamp("2021-01-01").unwrap(), 1_609_459_200);
+    assert_eq!(
+        parse_date_timestamp("2021-01-01T01:00:00+01:00").unwrap(),
+        1_609_459_200
+    );
+    assert!(parse_date_timestamp("last tuesday").is_err());
+}
+
 // ============================================================================
 // Node Tests
 // ============================================================================
@@ -109,15 +229,57 @@ fn test_insert_node() {
         response_time_ms: Some(150),
         title: Some("API Endpoint".to_string()),
         forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
         service_type: Some(ServiceType::RestApi),
         headers: Some("{}".to_string()),
         body_sample: Some("{}".to_string()),
+        content_hash: None,
     };
 
     let node_id = db.insert_node(&map_id, &node).unwrap();
     assert!(node_id > 0);
 }
 
+#[test]
+fn test_insert_node_stores_parameters() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    let node = CrawlNode {
+        url: "http://example.com/login".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: Some("text/html".to_string()),
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 1,
+        inputs_count: 2,
+        parameters: Some("[\"user\",\"pass\"]".to_string()),
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    let node_id = db.insert_node(&map_id, &node).unwrap();
+
+    let (inputs_count, parameters): (i64, Option<String>) = db
+        .get_connection()
+        .query_row(
+            "SELECT inputs_count, parameters FROM nodes WHERE id = ?1",
+            [node_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(inputs_count, 2);
+    assert_eq!(parameters.as_deref(), Some("[\"user\",\"pass\"]"));
+}
+
 #[test]
 fn test_insert_multiple_nodes() {
     let (_temp_dir, db) = create_test_db();
@@ -136,9 +298,12 @@ fn test_insert_multiple_nodes() {
         response_time_ms: Some(150),
         title: None,
         forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
         service_type: Some(ServiceType::RestApi),
         headers: None,
         body_sample: None,
+        content_hash: None,
     };
 
     let node2 = CrawlNode {
@@ -150,9 +315,12 @@ fn test_insert_multiple_nodes() {
         response_time_ms: Some(200),
         title: Some("Login".to_string()),
         forms_count: 1,
+        inputs_count: 0,
+        parameters: None,
         service_type: Some(ServiceType::Web),
         headers: None,
         body_sample: None,
+        content_hash: None,
     };
 
     let node_id1 = db.insert_node(&map_id, &node1).unwrap();
@@ -163,6 +331,55 @@ fn test_insert_multiple_nodes() {
     assert_ne!(node_id1, node_id2);
 }
 
+#[test]
+fn test_insert_nodes_batch() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    let nodes: Vec<CrawlNode> = (0..100)
+        .map(|i| CrawlNode {
+            url: format!("http://example.com/page{}", i),
+            domain: "example.com".to_string(),
+            status_code: 200,
+            content_type: Some("text/html".to_string()),
+            content_length: None,
+            response_time_ms: None,
+            title: None,
+            forms_count: 0,
+            inputs_count: 0,
+            parameters: None,
+            service_type: None,
+            headers: None,
+            body_sample: None,
+            content_hash: None,
+        })
+        .collect();
+
+    let ids = db.insert_nodes_batch(&map_id, &nodes).unwrap();
+    assert_eq!(ids.len(), 100);
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let stored = db.get_nodes_by_session(&session_id).unwrap();
+    assert_eq!(stored.len(), 100);
+}
+
+#[test]
+fn test_insert_nodes_batch_empty() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    let ids = db.insert_nodes_batch(&map_id, &[]).unwrap();
+    assert!(ids.is_empty());
+}
+
 // ============================================================================
 // Finding Tests
 // ============================================================================
@@ -185,9 +402,12 @@ fn test_insert_finding() {
         response_time_ms: Some(150),
         title: None,
         forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
         service_type: Some(ServiceType::RestApi),
         headers: None,
         body_sample: None,
+        content_hash: None,
     };
 
     let node_id = db.insert_node(&map_id, &node).unwrap();
@@ -203,6 +423,10 @@ fn test_insert_finding() {
         evidence: Some("{\"scheme\": \"http\"}".to_string()),
         cwe_id: Some("CWE-319".to_string()),
         owasp_category: Some("A02:2021".to_string()),
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
     };
 
     let result = db.insert_finding(&session_id, &finding);
@@ -227,9 +451,12 @@ fn test_insert_multiple_findings() {
         response_time_ms: Some(100),
         title: None,
         forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
         service_type: None,
         headers: None,
         body_sample: None,
+        content_hash: None,
     };
 
     let node_id = db.insert_node(&map_id, &node).unwrap();
@@ -245,6 +472,10 @@ fn test_insert_multiple_findings() {
         evidence: None,
         cwe_id: Some("CWE-319".to_string()),
         owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
     };
 
     let finding2 = Finding {
@@ -258,6 +489,10 @@ fn test_insert_multiple_findings() {
         evidence: None,
         cwe_id: Some("CWE-200".to_string()),
         owasp_category: Some("A01:2021".to_string()),
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
     };
 
     let result1 = db.insert_finding(&session_id, &finding1);
@@ -267,6 +502,283 @@ fn test_insert_multiple_findings() {
     assert!(result2.is_ok());
 }
 
+fn batch_test_findings(node_id: i64, count: usize) -> Vec<Finding> {
+    (0..count)
+        .map(|i| Finding {
+            node_id,
+            finding_type: FindingType::InformationDisclosure,
+            severity: Severity::Low,
+            title: format!("Finding {}", i),
+            description: "Batch finding".to_string(),
+            impact: None,
+            remediation: None,
+            evidence: None,
+            cwe_id: None,
+            owasp_category: None,
+            cvss_score: None,
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
+        })
+        .collect()
+}
+
+fn batch_test_node(db: &Database, session_id: &str) -> i64 {
+    let map_id = db.create_map(session_id).unwrap();
+    let node = CrawlNode {
+        url: "http://example.com/".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: Some("text/html".to_string()),
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    db.insert_node(&map_id, &node).unwrap()
+}
+
+#[test]
+fn test_insert_findings_batch() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let node_id = batch_test_node(&db, &session_id);
+
+    let ids = db
+        .insert_findings_batch(&session_id, &batch_test_findings(node_id, 25))
+        .unwrap();
+    assert_eq!(ids.len(), 25);
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let stored = db.get_findings_by_session(&session_id).unwrap();
+    assert_eq!(stored.len(), 25);
+}
+
+#[test]
+fn test_insert_finding_stores_cvss_score() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let node_id = batch_test_node(&db, &session_id);
+
+    let mut findings = batch_test_findings(node_id, 2);
+    findings[0].cvss_score = Some(7.5);
+    let scored = db.insert_finding(&session_id, &findings[0]).unwrap();
+    let unscored = db.insert_finding(&session_id, &findings[1]).unwrap();
+
+    let cvss_of = |id: i64| -> Option<f32> {
+        db.get_connection()
+            .query_row(
+                "SELECT cvss_score FROM findings WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .unwrap()
+    };
+    assert_eq!(cvss_of(scored), Some(7.5));
+    assert_eq!(cvss_of(unscored), None);
+}
+
+#[test]
+fn test_insert_finding_stores_http_samples() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let node_id = batch_test_node(&db, &session_id);
+
+    let mut findings = batch_test_findings(node_id, 3);
+    findings[0].request_sample = Some("GET / HTTP/1.1\nHost: example.com".to_string());
+    findings[0].response_sample = Some("HTTP/1.1 500\ncontent-type: text/html\n\nTraceback".to_string());
+    let sampled = db.insert_finding(&session_id, &findings[0]).unwrap();
+    let unsampled = db.insert_finding(&session_id, &findings[1]).unwrap();
+    findings[2].response_sample = Some("HTTP/1.1 200".to_string());
+    let batched = db
+        .insert_findings_batch(&session_id, &findings[2..])
+        .unwrap()[0];
+
+    let samples_of = |id: i64| -> (Option<String>, Option<String>) {
+        db.get_connection()
+            .query_row(
+                "SELECT request_sample, response_sample FROM findings WHERE id = ?1",
+                [id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap()
+    };
+    assert_eq!(
+        samples_of(sampled),
+        (
+            Some("GET / HTTP/1.1\nHost: example.com".to_string()),
+            Some("HTTP/1.1 500\ncontent-type: text/html\n\nTraceback".to_string())
+        )
+    );
+    assert_eq!(samples_of(unsampled), (None, None));
+    assert_eq!(samples_of(batched), (None, Some("HTTP/1.1 200".to_string())));
+}
+
+#[test]
+fn test_insert_finding_stores_confidence() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let node_id = batch_test_node(&db, &session_id);
+
+    let mut findings = batch_test_findings(node_id, 3);
+    findings[0].confidence = Confidence::Confirmed;
+    findings[1].confidence = Confidence::Likely;
+    findings[2].confidence = Confidence::Possible;
+    let ids: Vec<i64> = findings
+        .iter()
+        .map(|f| db.insert_finding(&session_id, f).unwrap())
+        .collect();
+
+    let confidence_of = |id: i64| -> String {
+        db.get_connection()
+            .query_row(
+                "SELECT confidence FROM findings WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .unwrap()
+    };
+    assert_eq!(confidence_of(ids[0]), "confirmed");
+    assert_eq!(confidence_of(ids[1]), "likely");
+    assert_eq!(confidence_of(ids[2]), "possible");
+}
+
+#[test]
+fn test_finding_recorder_record_batch_respects_cap() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let node_id = batch_test_node(&db, &session_id);
+
+    let mut recorder = FindingRecorder::new(&db, &session_id, Some(5));
+    assert_eq!(
+        recorder
+            .record_batch(&batch_test_findings(node_id, 3))
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        recorder
+            .record_batch(&batch_test_findings(node_id, 4))
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        recorder
+            .record_batch(&batch_test_findings(node_id, 2))
+            .unwrap(),
+        0
+    );
+
+    assert_eq!(recorder.recorded(), 5);
+    assert_eq!(recorder.dropped(), 4);
+    assert_eq!(db.get_findings_by_session(&session_id).unwrap().len(), 5);
+}
+
+fn query_test_session(db: &Database) -> String {
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let node_id = batch_test_node(db, &session_id);
+    let finding = |finding_type: FindingType, severity: Severity, title: &str| Finding {
+        node_id,
+        finding_type,
+        severity,
+        title: title.to_string(),
+        description: "Query finding".to_string(),
+        impact: None,
+        remediation: None,
+        evidence: None,
+        cwe_id: None,
+        owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
+    };
+    db.insert_findings_batch(
+        &session_id,
+        &[
+            finding(FindingType::InterestingFile, Severity::High, ".git exposed"),
+            finding(FindingType::InterestingFile, Severity::Low, "robots.txt"),
+            finding(
+                FindingType::Misconfiguration,
+                Severity::High,
+                "CORS wildcard",
+            ),
+            finding(FindingType::SecurityHeaderMissing, Severity::Info, "No CSP"),
+        ],
+    )
+    .unwrap();
+    session_id
+}
+
+#[test]
+fn test_query_findings_by_severity() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = query_test_session(&db);
+
+    let findings = db.query_findings(&session_id, Some("high"), None).unwrap();
+    let titles: Vec<&str> = findings.iter().map(|f| f.title.as_str()).collect();
+    assert_eq!(titles, vec![".git exposed", "CORS wildcard"]);
+    assert!(findings.iter().all(|f| f.url == "http://example.com/"));
+}
+
+#[test]
+fn test_query_findings_by_type() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = query_test_session(&db);
+
+    let findings = db
+        .query_findings(&session_id, None, Some("interesting_file"))
+        .unwrap();
+    let titles: Vec<&str> = findings.iter().map(|f| f.title.as_str()).collect();
+    assert_eq!(titles, vec![".git exposed", "robots.txt"]);
+}
+
+#[test]
+fn test_query_findings_by_severity_and_type() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = query_test_session(&db);
+
+    let findings = db
+        .query_findings(&session_id, Some("high"), Some("interesting_file"))
+        .unwrap();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].title, ".git exposed");
+    assert_eq!(findings[0].finding_type, "interesting_file");
+
+    let unfiltered = db.query_findings(&session_id, None, None).unwrap();
+    let severities: Vec<&str> = unfiltered.iter().map(|f| f.severity.as_str()).collect();
+    assert_eq!(severities, vec!["high", "high", "low", "info"]);
+
+    assert!(
+        db.query_findings(&session_id, Some("critical"), Some("interesting_file"))
+            .unwrap()
+            .is_empty()
+    );
+}
+
 #[test]
 fn test_get_findings_count_by_severity() {
     let (_temp_dir, db) = create_test_db();
@@ -285,9 +797,12 @@ fn test_get_findings_count_by_severity() {
         response_time_ms: None,
         title: None,
         forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
         service_type: None,
         headers: None,
         body_sample: None,
+        content_hash: None,
     };
 
     let node_id = db.insert_node(&map_id, &node).unwrap();
@@ -304,6 +819,10 @@ fn test_get_findings_count_by_severity() {
         evidence: None,
         cwe_id: None,
         owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
     };
 
     let medium_finding = Finding {
@@ -317,6 +836,10 @@ fn test_get_findings_count_by_severity() {
         evidence: None,
         cwe_id: None,
         owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
     };
 
     db.insert_finding(&session_id, &critical_finding).unwrap();
@@ -342,6 +865,71 @@ fn test_get_findings_count_by_severity() {
     assert_eq!(medium_count, Some(1));
 }
 
+#[test]
+fn test_unique_findings_count_by_severity() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    // The same missing-header finding on three different nodes, plus one distinct finding
+    for i in 1..=3 {
+        let node = CrawlNode {
+            url: format!("http://example.com/page{}", i),
+            domain: "example.com".to_string(),
+            status_code: 200,
+            content_type: Some("text/html".to_string()),
+            content_length: None,
+            response_time_ms: None,
+            title: None,
+            forms_count: 0,
+            inputs_count: 0,
+            parameters: None,
+            service_type: None,
+            headers: None,
+            body_sample: None,
+            content_hash: None,
+        };
+        let node_id = db.insert_node(&map_id, &node).unwrap();
+
+        let finding = Finding {
+            node_id,
+            finding_type: FindingType::SecurityHeaderMissing,
+            severity: Severity::Medium,
+            title: "Missing X-Frame-Options Header".to_string(),
+            description: "Header missing".to_string(),
+            impact: None,
+            remediation: None,
+            evidence: None,
+            cwe_id: None,
+            owasp_category: None,
+            cvss_score: None,
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
+        };
+        db.insert_finding(&session_id, &finding).unwrap();
+
+        if i == 1 {
+            let other = Finding {
+                title: "Missing Content-Security-Policy Header".to_string(),
+                ..finding
+            };
+            db.insert_finding(&session_id, &other).unwrap();
+        }
+    }
+
+    let raw_counts = db.get_findings_count_by_severity(&session_id).unwrap();
+    assert_eq!(raw_counts, vec![("medium".to_string(), 4)]);
+
+    let unique_counts = db
+        .get_unique_findings_count_by_severity(&session_id)
+        .unwrap();
+    assert_eq!(unique_counts, vec![("medium".to_string(), 2, 4)]);
+}
+
 // ============================================================================
 // Enum Conversion Tests
 // ============================================================================
@@ -420,9 +1008,12 @@ fn test_complete_workflow() {
             response_time_ms: Some(100 + i as u64),
             title: Some(format!("Page {}", i)),
             forms_count: 0,
+            inputs_count: 0,
+            parameters: None,
             service_type: Some(ServiceType::Web),
             headers: None,
             body_sample: None,
+            content_hash: None,
         };
 
         let node_id = db.insert_node(&map_id, &node).unwrap();
@@ -439,6 +1030,10 @@ fn test_complete_workflow() {
             evidence: None,
             cwe_id: Some("CWE-319".to_string()),
             owasp_category: None,
+            cvss_score: None,
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
         };
 
         db.insert_finding(&session_id, &finding).unwrap();
@@ -455,3 +1050,457 @@ fn test_complete_workflow() {
         .map(|(_, count)| *count);
     assert_eq!(medium_count, Some(5));
 }
+
+#[test]
+fn test_mark_false_positive_hides_finding() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    let node = CrawlNode {
+        url: "http://example.com/admin".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: Some("text/html".to_string()),
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    let node_id = db.insert_node(&map_id, &node).unwrap();
+
+    let finding = Finding {
+        node_id,
+        finding_type: FindingType::InterestingFile,
+        severity: Severity::Info,
+        title: "Admin Interface".to_string(),
+        description: "Admin page found".to_string(),
+        impact: None,
+        remediation: None,
+        evidence: None,
+        cwe_id: None,
+        owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
+    };
+    let finding_id = db.insert_finding(&session_id, &finding).unwrap();
+    assert_eq!(db.get_findings_by_session(&session_id).unwrap().len(), 1);
+
+    db.mark_false_positive(finding_id, true).unwrap();
+    assert!(db.get_findings_by_session(&session_id).unwrap().is_empty());
+
+    db.mark_false_positive(finding_id, false).unwrap();
+    assert_eq!(db.get_findings_by_session(&session_id).unwrap().len(), 1);
+}
+
+#[test]
+fn test_mark_false_positive_unknown_finding() {
+    let (_temp_dir, db) = create_test_db();
+    assert!(db.mark_false_positive(9999, true).is_err());
+}
+
+// ============================================================================
+// Migration Tests
+// ============================================================================
+
+#[test]
+fn test_new_database_is_at_current_schema_version() {
+    let (_temp_dir, db) = create_test_db();
+    assert_eq!(db.schema_version().unwrap(), SCHEMA_VERSION);
+}
+
+#[test]
+fn test_v0_database_is_migrated() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("legacy.db");
+
+    // A database created before schema versioning: tables but no schema_version
+    {
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE crawl_sessions (
+                id TEXT PRIMARY KEY,
+                start_time INTEGER NOT NULL,
+                end_time INTEGER,
+                status TEXT NOT NULL,
+                scan_type TEXT NOT NULL,
+                seed_urls TEXT NOT NULL,
+                configuration TEXT
+            );
+            INSERT INTO crawl_sessions (id, start_time, status, scan_type, seed_urls)
+            VALUES ('legacy-session', 1234567890, 'completed', 'crawl', '[]');",
+        )
+        .unwrap();
+    }
+
+    let db = Database::new(&db_path).unwrap();
+    assert_eq!(db.schema_version().unwrap(), SCHEMA_VERSION);
+
+    // Existing data survives and the remaining tables were created
+    let legacy_count: i64 = db
+        .get_connection()
+        .query_row("SELECT COUNT(*) FROM crawl_sessions", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(legacy_count, 1);
+    assert!(db.create_map("legacy-session").is_ok());
+
+    // Reopening does not re-apply migrations
+    drop(db);
+    let db = Database::new(&db_path).unwrap();
+    let applied: i64 = db
+        .get_connection()
+        .query_row("SELECT COUNT(*) FROM schema_version", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(applied, SCHEMA_VERSION);
+}
+
+#[test]
+fn test_count_nodes_by_hash() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    let error_page = rinzler_scanner::content_hash(b"<h1>Not Found</h1>");
+    let home_page = rinzler_scanner::content_hash(b"<h1>Welcome</h1>");
+    assert_ne!(error_page, home_page);
+
+    let pages = [
+        ("/missing-a", Some(error_page.clone())),
+        ("/missing-b", Some(error_page.clone())),
+        ("/missing-c", Some(error_page.clone())),
+        ("/", Some(home_page)),
+        ("/unhashed", None),
+    ];
+    for (path, content_hash) in pages {
+        let node = CrawlNode {
+            url: format!("http://example.com{}", path),
+            domain: "example.com".to_string(),
+            status_code: 200,
+            content_type: Some("text/html".to_string()),
+            content_length: None,
+            response_time_ms: None,
+            title: None,
+            forms_count: 0,
+            inputs_count: 0,
+            parameters: None,
+            service_type: None,
+            headers: None,
+            body_sample: None,
+            content_hash,
+        };
+        db.insert_node(&map_id, &node).unwrap();
+    }
+
+    let duplicates = db.count_nodes_by_hash(&session_id).unwrap();
+    assert_eq!(duplicates, vec![(error_page, 3)]);
+}
+
+#[test]
+fn test_export_har() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let headers = r#"[["content-type","application/json"],["server","nginx"]]"#;
+    db.log_http_transaction(
+        &session_id,
+        None,
+        "GET",
+        "http://example.com/api?page=2",
+        None,
+        200,
+        Some(headers),
+        Some(42),
+    )
+    .unwrap();
+    db.log_http_transaction(
+        &session_id,
+        None,
+        "GET",
+        "http://example.com/missing",
+        None,
+        404,
+        None,
+        None,
+    )
+    .unwrap();
+
+    // Transactions from other sessions are not exported
+    let other_session = db.create_session("crawl", "[]").unwrap();
+    db.log_http_transaction(
+        &other_session,
+        None,
+        "GET",
+        "http://other.example/",
+        None,
+        200,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let har: serde_json::Value =
+        serde_json::from_str(&db.export_har(&session_id).unwrap()).unwrap();
+    assert_eq!(har["log"]["version"], "1.2");
+    let entries = har["log"]["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+
+    let first = &entries[0];
+    assert_eq!(first["request"]["method"], "GET");
+    assert_eq!(first["request"]["url"], "http://example.com/api?page=2");
+    assert_eq!(first["request"]["queryString"][0]["name"], "page");
+    assert_eq!(first["response"]["status"], 200);
+    assert_eq!(first["response"]["headers"][1]["value"], "nginx");
+    assert_eq!(first["response"]["content"]["mimeType"], "application/json");
+    assert_eq!(first["time"], 42);
+    assert_eq!(first["timings"]["wait"], 42);
+    assert_eq!(entries[1]["response"]["status"], 404);
+}
+
+#[test]
+fn test_technology_summary_lists_each_technology_once() {
+    let (_temp_dir, db) = create_test_db();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    for path in ["/", "/blog"] {
+        let node = CrawlNode {
+            url: format!("http://example.com{}", path),
+            domain: "example.com".to_string(),
+            status_code: 200,
+            content_type: Some("text/html".to_string()),
+            content_length: None,
+            response_time_ms: None,
+            title: None,
+            forms_count: 0,
+            inputs_count: 0,
+            parameters: None,
+            service_type: None,
+            headers: None,
+            body_sample: None,
+            content_hash: None,
+        };
+        let node_id = db.insert_node(&map_id, &node).unwrap();
+        db.insert_technology(node_id, "web_server", "nginx", None, "header", None, 70)
+            .unwrap();
+        db.insert_technology(node_id, "framework", "PHP", Some("7.4"), "header", None, 90)
+            .unwrap();
+        db.insert_technology(node_id, "cms", "WordPress", None, "header", None, 70)
+            .unwrap();
+    }
+
+    let technologies = db.get_technologies_by_session(&session_id).unwrap();
+    assert_eq!(technologies.len(), 3);
+    assert_eq!(
+        rinzler_core::tech::technology_summary(&technologies).unwrap(),
+        "Technologies: PHP/7.4, nginx, WordPress"
+    );
+}
+
+#[test]
+fn test_tested_urls_round_trip() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = db.create_session("fuzz", "[]").unwrap();
+    let other_session = db.create_session("fuzz", "[]").unwrap();
+
+    db.record_tested_urls(
+        &session_id,
+        &[
+            "http://example.com/admin".to_string(),
+            "http://example.com/login".to_string(),
+        ],
+    )
+    .unwrap();
+    // Recording a URL twice is not an error
+    db.record_tested_urls(&session_id, &["http://example.com/admin".to_string()])
+        .unwrap();
+
+    let tested = db.get_tested_urls(&session_id).unwrap();
+    assert_eq!(tested.len(), 2);
+    assert!(tested.contains("http://example.com/login"));
+    assert!(db.get_tested_urls(&other_session).unwrap().is_empty());
+}
+
+#[test]
+fn test_fuzz_hits_round_trip() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = db
+        .create_session("fuzz", r#"["http://example.com"]"#)
+        .unwrap();
+    let admin = FuzzHit {
+        result: FuzzResult {
+            url: "http://example.com/admin".to_string(),
+            status_code: 301,
+            content_length: None,
+            content_type: Some("text/html".to_string()),
+            source: FuzzSource::Initial,
+        },
+        depth: 0,
+    };
+    let backup = FuzzHit {
+        result: FuzzResult {
+            url: "http://example.com/admin/backup".to_string(),
+            status_code: 200,
+            content_length: Some(512),
+            content_type: None,
+            source: FuzzSource::Discovered,
+        },
+        depth: 1,
+    };
+
+    db.record_fuzz_hits(&session_id, &[backup.clone(), admin.clone()])
+        .unwrap();
+    db.record_fuzz_hits(&session_id, &[admin.clone()]).unwrap();
+
+    assert_eq!(db.get_fuzz_hits(&session_id).unwrap(), vec![admin, backup]);
+    assert_eq!(
+        db.get_session_seed_urls(&session_id).unwrap().as_deref(),
+        Some(r#"["http://example.com"]"#)
+    );
+    assert_eq!(db.get_session_seed_urls("missing").unwrap(), None);
+}
+
+#[test]
+fn test_get_session_scan_type_and_resume() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = db.create_session("fuzz", "[]").unwrap();
+
+    assert_eq!(
+        db.get_session_scan_type(&session_id).unwrap().as_deref(),
+        Some("fuzz")
+    );
+    assert_eq!(db.get_session_scan_type("missing").unwrap(), None);
+
+    db.fail_session(&session_id).unwrap();
+    db.resume_session(&session_id).unwrap();
+    let (status, end_time): (String, Option<i64>) = db
+        .get_connection()
+        .query_row(
+            "SELECT status, end_time FROM crawl_sessions WHERE id = ?1",
+            [&session_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(status, "running");
+    assert_eq!(end_time, None);
+}
+
+// ============================================================================
+// Re-crawl Change Detection Tests
+// ============================================================================
+
+fn page_node(url: &str, content_length: usize) -> CrawlNode {
+    CrawlNode {
+        url: url.to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: Some("text/html".to_string()),
+        content_length: Some(content_length),
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    }
+}
+
+fn crawl_session(db: &Database, start_time: i64, nodes: &[CrawlNode]) -> String {
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    set_start_time(db, &session_id, start_time);
+    let map_id = db.create_map(&session_id).unwrap();
+    for node in nodes {
+        db.insert_node(&map_id, node).unwrap();
+    }
+    session_id
+}
+
+#[test]
+fn test_get_previous_node_detects_changed_content_length() {
+    let (_temp_dir, db) = create_test_db();
+
+    let first = crawl_session(&db, 1_000, &[page_node("http://example.com/", 1024)]);
+    let current = crawl_session(&db, 2_000, &[page_node("http://example.com/", 2048)]);
+
+    let previous = db
+        .get_previous_node("http://example.com/", &current)
+        .unwrap()
+        .expect("the first session crawled this page");
+    assert_eq!(previous.session_id, first);
+    assert_eq!(previous.status_code, Some(200));
+    assert_eq!(previous.content_length, Some(1024));
+
+    assert_eq!(
+        classify_node_change(Some(&previous), 200, Some(2048), None),
+        NodeChange::Changed
+    );
+    assert_eq!(
+        classify_node_change(Some(&previous), 200, Some(1024), None),
+        NodeChange::Unchanged
+    );
+}
+
+#[test]
+fn test_get_previous_node_uses_most_recent_earlier_session() {
+    let (_temp_dir, db) = create_test_db();
+
+    crawl_session(&db, 1_000, &[page_node("http://example.com/", 100)]);
+    let middle = crawl_session(&db, 2_000, &[page_node("http://example.com/", 200)]);
+    let current = crawl_session(&db, 3_000, &[page_node("http://example.com/", 200)]);
+    // Sessions started after the current one are never the baseline
+    crawl_session(&db, 4_000, &[page_node("http://example.com/", 400)]);
+
+    let previous = db
+        .get_previous_node("http://example.com/", &current)
+        .unwrap()
+        .unwrap();
+    assert_eq!(previous.session_id, middle);
+    assert_eq!(previous.content_length, Some(200));
+}
+
+#[test]
+fn test_get_previous_node_none_for_new_page() {
+    let (_temp_dir, db) = create_test_db();
+
+    let first = crawl_session(&db, 1_000, &[page_node("http://example.com/", 1024)]);
+    let current = crawl_session(
+        &db,
+        2_000,
+        &[
+            page_node("http://example.com/", 1024),
+            page_node("http://example.com/new", 10),
+        ],
+    );
+
+    assert!(db.get_previous_node("http://example.com/", &first).unwrap().is_none());
+
+    let previous = db.get_previous_node("http://example.com/new", &current).unwrap();
+    assert!(previous.is_none());
+    assert_eq!(
+        classify_node_change(previous.as_ref(), 200, Some(10), None),
+        NodeChange::New
+    );
+}
@@ -0,0 +1,160 @@
+// Tests for comparing two scan sessions
+
+use rinzler_core::data::{
+    Confidence, CrawlNode, Database, Finding, FindingType, ServiceType, Severity,
+};
+use rinzler_core::diff::{SessionDiff, compute_session_diff, diff_sessions};
+use std::collections::BTreeSet;
+use tempfile::TempDir;
+
+fn urls(list: &[&str]) -> BTreeSet<String> {
+    list.iter().map(|u| u.to_string()).collect()
+}
+
+fn findings(list: &[(&str, &str)]) -> BTreeSet<(String, String)> {
+    list.iter()
+        .map(|(url, title)| (url.to_string(), title.to_string()))
+        .collect()
+}
+
+fn node(url: &str) -> CrawlNode {
+    CrawlNode {
+        url: url.to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: None,
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: Some(ServiceType::Web),
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    }
+}
+
+fn finding(node_id: i64, title: &str) -> Finding {
+    Finding {
+        node_id,
+        finding_type: FindingType::Misconfiguration,
+        severity: Severity::Medium,
+        title: title.to_string(),
+        description: String::new(),
+        impact: None,
+        remediation: None,
+        evidence: None,
+        cwe_id: None,
+        owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
+    }
+}
+
+#[test]
+fn test_compute_session_diff_url_changes() {
+    let base = urls(&["http://example.com/", "http://example.com/old"]);
+    let new = urls(&[
+        "http://example.com/",
+        "http://example.com/b",
+        "http://example.com/a",
+    ]);
+
+    let diff = compute_session_diff(&base, &new, &BTreeSet::new(), &BTreeSet::new());
+
+    assert_eq!(
+        diff.new_urls,
+        vec!["http://example.com/a", "http://example.com/b"]
+    );
+    assert_eq!(diff.removed_urls, vec!["http://example.com/old"]);
+    assert!(diff.new_findings.is_empty());
+}
+
+#[test]
+fn test_compute_session_diff_new_findings_only() {
+    let pages = urls(&["http://example.com/", "http://example.com/api"]);
+    let base = findings(&[
+        ("http://example.com/", "Missing Security Headers"),
+        ("http://example.com/api", "Fixed Issue"),
+    ]);
+    let new = findings(&[
+        ("http://example.com/", "Missing Security Headers"),
+        ("http://example.com/api", "Missing Security Headers"),
+        ("http://example.com/api", "Permissive CORS"),
+    ]);
+
+    let diff = compute_session_diff(&pages, &pages, &base, &new);
+
+    // A known title on another page is still new; resolved findings are not listed
+    assert_eq!(
+        diff.new_findings,
+        vec![
+            (
+                "http://example.com/api".to_string(),
+                "Missing Security Headers".to_string()
+            ),
+            (
+                "http://example.com/api".to_string(),
+                "Permissive CORS".to_string()
+            ),
+        ]
+    );
+    assert!(diff.new_urls.is_empty());
+    assert!(diff.removed_urls.is_empty());
+}
+
+#[test]
+fn test_compute_session_diff_identical_sessions_is_empty() {
+    let pages = urls(&["http://example.com/"]);
+    let found = findings(&[("http://example.com/", "Insecure Transport (HTTP)")]);
+
+    let diff = compute_session_diff(&pages, &pages, &found, &found);
+
+    assert!(diff.is_empty());
+    assert_eq!(diff, SessionDiff::default());
+}
+
+#[test]
+fn test_diff_sessions_from_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+
+    let base_session = db.create_session("crawl", "[]").unwrap();
+    let base_map = db.create_map(&base_session).unwrap();
+    let home = db.insert_node(&base_map, &node("http://example.com/")).unwrap();
+    db.insert_node(&base_map, &node("http://example.com/old"))
+        .unwrap();
+    db.insert_finding(&base_session, &finding(home, "Missing Security Headers"))
+        .unwrap();
+
+    let new_session = db.create_session("crawl", "[]").unwrap();
+    let new_map = db.create_map(&new_session).unwrap();
+    let home = db.insert_node(&new_map, &node("http://example.com/")).unwrap();
+    let api = db
+        .insert_node(&new_map, &node("http://example.com/api"))
+        .unwrap();
+    db.insert_finding(&new_session, &finding(home, "Missing Security Headers"))
+        .unwrap();
+    db.insert_finding(&new_session, &finding(api, "Permissive CORS"))
+        .unwrap();
+    let ignored = db
+        .insert_finding(&new_session, &finding(api, "Noise"))
+        .unwrap();
+    db.mark_false_positive(ignored, true).unwrap();
+
+    let diff = diff_sessions(&db, &base_session, &new_session).unwrap();
+
+    assert_eq!(diff.new_urls, vec!["http://example.com/api"]);
+    assert_eq!(diff.removed_urls, vec!["http://example.com/old"]);
+    assert_eq!(
+        diff.new_findings,
+        vec![(
+            "http://example.com/api".to_string(),
+            "Permissive CORS".to_string()
+        )]
+    );
+}
@@ -1,9 +1,20 @@
 // Tests for fuzzing functionality
 
-use rinzler_core::fuzz::{FuzzSource, build_test_url, extract_base_url, load_wordlist};
+use rinzler_core::data::{CrawlNode, Database, ServiceType, Severity};
+use rinzler_core::fuzz::{
+    FuzzHit, FuzzOptions, FuzzReportStyle, FuzzResult, FuzzSource, Method, WildcardBaseline,
+    build_fuzz_targets, build_test_url, execute_fuzz, fuzz_findings, generate_fuzz_report_with_style,
+    extract_base_url, load_wordlist, load_wordlists, parse_status_codes, query_database_endpoints,
+    status_passes_filter, worker_totals,
+};
+use rinzler_scanner::RequestSettings;
+use std::collections::HashSet;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tempfile::TempDir;
+use wiremock::matchers::{body_string, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
 fn test_build_test_url_basic() {
@@ -179,6 +190,40 @@ fn test_load_wordlist_nonexistent_file() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_load_wordlists_merges_overlapping_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let first = temp_dir.path().join("first.txt");
+    let second = temp_dir.path().join("second.txt");
+
+    fs::write(&first, "api\nadmin\n# comment\nconfig\n").unwrap();
+    fs::write(&second, "admin\n\nbackup\napi\nv1\n").unwrap();
+
+    let words = load_wordlists(&[first, second]).unwrap();
+    assert_eq!(words, vec!["api", "admin", "config", "backup", "v1"]);
+}
+
+#[test]
+fn test_load_wordlists_dedups_within_a_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let wordlist_path = temp_dir.path().join("dupes.txt");
+
+    fs::write(&wordlist_path, "api\n  api  \nadmin\napi\n").unwrap();
+
+    let words = load_wordlists(&[wordlist_path.clone(), wordlist_path]).unwrap();
+    assert_eq!(words, vec!["api", "admin"]);
+}
+
+#[test]
+fn test_load_wordlists_propagates_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let good = temp_dir.path().join("good.txt");
+    fs::write(&good, "api\n").unwrap();
+
+    assert!(load_wordlists(&[good, PathBuf::from("/nonexistent/wordlist.txt")]).is_err());
+    assert!(load_wordlists(&[]).is_err());
+}
+
 #[test]
 fn test_fuzz_source_clone() {
     let source = FuzzSource::Initial;
@@ -204,3 +249,687 @@ fn test_fuzz_source_equality() {
     assert_ne!(FuzzSource::Database, FuzzSource::Discovered);
     assert_ne!(FuzzSource::Initial, FuzzSource::Discovered);
 }
+
+// ============================================================================
+// Status Code Filter Tests
+// ============================================================================
+
+fn synthetic_results() -> Vec<FuzzResult> {
+    [200, 204, 301, 401, 403, 404, 500]
+        .iter()
+        .map(|&status_code| FuzzResult {
+            url: format!("http://example.com/{}", status_code),
+            status_code,
+            content_length: None,
+            content_type: None,
+            source: FuzzSource::Initial,
+        })
+        .collect()
+}
+
+fn filtered_codes(include: Option<&[u16]>, exclude: Option<&[u16]>) -> Vec<u16> {
+    synthetic_results()
+        .iter()
+        .filter(|r| status_passes_filter(r.status_code, include, exclude))
+        .map(|r| r.status_code)
+        .collect()
+}
+
+#[test]
+fn test_parse_status_codes_list() {
+    let codes = parse_status_codes("200,301, 403").unwrap();
+    assert_eq!(codes, vec![200, 301, 403]);
+}
+
+#[test]
+fn test_parse_status_codes_range() {
+    let codes = parse_status_codes("200-204,404").unwrap();
+    assert_eq!(codes, vec![200, 201, 202, 203, 204, 404]);
+}
+
+#[test]
+fn test_parse_status_codes_invalid() {
+    assert!(parse_status_codes("abc").is_err());
+    assert!(parse_status_codes("299-200").is_err());
+    assert!(parse_status_codes("").is_err());
+}
+
+#[test]
+fn test_status_filter_default_drops_server_errors() {
+    assert_eq!(
+        filtered_codes(None, None),
+        vec![200, 204, 301, 401, 403, 404]
+    );
+}
+
+#[test]
+fn test_status_filter_inclusion() {
+    let include = parse_status_codes("200-299,500").unwrap();
+    assert_eq!(filtered_codes(Some(&include), None), vec![200, 204, 500]);
+}
+
+#[test]
+fn test_status_filter_exclusion() {
+    let exclude = parse_status_codes("401,403").unwrap();
+    assert_eq!(
+        filtered_codes(None, Some(&exclude)),
+        vec![200, 204, 301, 404]
+    );
+}
+
+#[test]
+fn test_status_filter_exclusion_overrides_inclusion() {
+    let include = parse_status_codes("200-299").unwrap();
+    let exclude = parse_status_codes("204").unwrap();
+    assert_eq!(filtered_codes(Some(&include), Some(&exclude)), vec![200]);
+}
+
+// ============================================================================
+// Wildcard Baseline Tests
+// ============================================================================
+
+#[test]
+fn test_wildcard_baseline_exact_match() {
+    let baseline = WildcardBaseline {
+        status_code: 200,
+        content_length: Some(1234),
+    };
+    assert!(baseline.matches(200, Some(1234)));
+}
+
+#[test]
+fn test_wildcard_baseline_length_within_tolerance() {
+    let baseline = WildcardBaseline {
+        status_code: 200,
+        content_length: Some(1000),
+    };
+    // Small pages get a fixed 50 byte window
+    assert!(baseline.matches(200, Some(1050)));
+    assert!(baseline.matches(200, Some(950)));
+    assert!(!baseline.matches(200, Some(1051)));
+    assert!(!baseline.matches(200, Some(949)));
+}
+
+#[test]
+fn test_wildcard_baseline_large_page_tolerance() {
+    let baseline = WildcardBaseline {
+        status_code: 200,
+        content_length: Some(100_000),
+    };
+    // Large pages get a 2% window
+    assert!(baseline.matches(200, Some(102_000)));
+    assert!(!baseline.matches(200, Some(102_001)));
+}
+
+#[test]
+fn test_wildcard_baseline_status_mismatch() {
+    let baseline = WildcardBaseline {
+        status_code: 302,
+        content_length: Some(0),
+    };
+    assert!(!baseline.matches(200, Some(0)));
+    assert!(baseline.matches(302, Some(0)));
+}
+
+#[test]
+fn test_wildcard_baseline_unknown_length() {
+    let baseline = WildcardBaseline {
+        status_code: 200,
+        content_length: None,
+    };
+    assert!(baseline.matches(200, None));
+    assert!(!baseline.matches(200, Some(500)));
+
+    let sized = WildcardBaseline {
+        status_code: 200,
+        content_length: Some(500),
+    };
+    assert!(!sized.matches(200, None));
+}
+
+#[tokio::test]
+async fn test_execute_fuzz_with_post_method_and_body() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/login"))
+        .and(body_string(r#"{"user":"admin"}"#))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let options = FuzzOptions {
+        base_urls: vec![server.uri()],
+        wordlist: vec!["login".to_string(), "missing".to_string()],
+        threads: 2,
+        show_progress_bars: false,
+        method: Method::POST,
+        body: Some(r#"{"user":"admin"}"#.to_string()),
+        timeout_secs: 5,
+        db_path: None,
+        include_status: None,
+        exclude_status: None,
+        pool_size: None,
+        pool_idle_timeout_secs: 90,
+        wildcard_filter: true,
+        max_recursion_depth: 2,
+        user_agent: None,
+        request: RequestSettings::default(),
+        request_delay_ms: None,
+        skip_urls: HashSet::new(),
+        on_tested: None,
+        resumed_hits: Vec::new(),
+        on_hit: None,
+    };
+
+    let results = execute_fuzz(options).await.unwrap();
+    let login = format!("{}/login", server.uri());
+    let hit = results
+        .iter()
+        .find(|r| r.url == login)
+        .expect("POST endpoint should be recorded");
+    assert_eq!(hit.status_code, 200);
+    assert_eq!(results.iter().filter(|r| r.status_code == 200).count(), 1);
+}
+
+#[tokio::test]
+async fn test_execute_fuzz_sends_custom_user_agent() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(header("user-agent", "engagement-42"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let options = FuzzOptions {
+        user_agent: Some("engagement-42".to_string()),
+        ..recursion_options(server.uri(), 0)
+    };
+
+    let results = execute_fuzz(options).await.unwrap();
+    let hit = format!("{}/a", server.uri());
+    assert!(results.iter().any(|r| r.url == hit && r.status_code == 200));
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(!requests.is_empty());
+    for request in requests {
+        assert_eq!(request.headers.get("user-agent").unwrap(), "engagement-42");
+    }
+}
+
+fn recursion_options(base_url: String, max_recursion_depth: usize) -> FuzzOptions {
+    FuzzOptions {
+        base_urls: vec![base_url],
+        wordlist: vec!["a".to_string()],
+        threads: 1,
+        show_progress_bars: false,
+        method: Method::GET,
+        body: None,
+        timeout_secs: 5,
+        db_path: None,
+        include_status: None,
+        exclude_status: None,
+        pool_size: None,
+        pool_idle_timeout_secs: 90,
+        wildcard_filter: false,
+        max_recursion_depth,
+        user_agent: None,
+        request: RequestSettings::default(),
+        request_delay_ms: None,
+        skip_urls: HashSet::new(),
+        on_tested: None,
+        resumed_hits: Vec::new(),
+        on_hit: None,
+    }
+}
+
+#[tokio::test]
+async fn test_execute_fuzz_stops_expanding_at_recursion_depth() {
+    // Every path answers 200, so discovery would recurse forever without a cap
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let mut urls: Vec<String> = execute_fuzz(recursion_options(server.uri(), 2))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.url)
+        .collect();
+    urls.sort();
+    assert_eq!(
+        urls,
+        vec![
+            format!("{}/a", server.uri()),
+            format!("{}/a/a", server.uri()),
+            format!("{}/a/a/a", server.uri()),
+        ]
+    );
+
+    let results = execute_fuzz(recursion_options(server.uri(), 0))
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].source, FuzzSource::Initial);
+}
+
+#[test]
+fn test_query_database_endpoints_empty_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("rinzler.db");
+    Database::new(&db_path).unwrap();
+
+    let endpoints =
+        query_database_endpoints(&db_path, &["http://example.com".to_string()]).unwrap();
+    assert!(endpoints.is_empty());
+}
+
+#[test]
+fn test_query_database_endpoints_returns_matching_nodes() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("rinzler.db");
+    let db = Database::new(&db_path).unwrap();
+    let session_id = db.create_session("crawl", "[]").unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+    for url in ["http://example.com/api", "http://other.com/admin"] {
+        let parsed = url::Url::parse(url).unwrap();
+        let node = CrawlNode {
+            url: url.to_string(),
+            domain: parsed.host_str().unwrap().to_string(),
+            status_code: 200,
+            content_type: None,
+            content_length: None,
+            response_time_ms: None,
+            title: None,
+            forms_count: 0,
+            inputs_count: 0,
+            parameters: None,
+            service_type: Some(ServiceType::Web),
+            headers: None,
+            body_sample: None,
+            content_hash: None,
+        };
+        db.insert_node(&map_id, &node).unwrap();
+    }
+
+    let endpoints =
+        query_database_endpoints(&db_path, &["http://example.com".to_string()]).unwrap();
+    assert_eq!(endpoints, vec!["http://example.com/api"]);
+}
+
+#[test]
+fn test_query_database_endpoints_reports_unreadable_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let targets = ["http://example.com".to_string()];
+
+    // Not an SQLite file at all
+    let garbage_path = temp_dir.path().join("garbage.db");
+    fs::write(
+        &garbage_path,
+        "this is not a database, just some text padding it out",
+    )
+    .unwrap();
+    assert!(query_database_endpoints(&garbage_path, &targets).is_err());
+
+    // Valid database whose schema no longer has a nodes table
+    let db_path = temp_dir.path().join("rinzler.db");
+    let db = Database::new(&db_path).unwrap();
+    db.get_connection()
+        .execute_batch("PRAGMA foreign_keys = OFF; DROP TABLE nodes;")
+        .unwrap();
+    drop(db);
+
+    let err = query_database_endpoints(&db_path, &targets).unwrap_err();
+    assert!(err.contains("Failed to query nodes"), "{}", err);
+}
+
+#[test]
+fn test_build_fuzz_targets_counts_every_base_and_word() {
+    let options = FuzzOptions {
+        base_urls: vec![
+            "http://example.com".to_string(),
+            "http://other.com/app".to_string(),
+        ],
+        wordlist: vec!["admin".to_string(), "login".to_string(), "backup".to_string()],
+        ..recursion_options(String::new(), 0)
+    };
+
+    let targets = build_fuzz_targets(&options).unwrap();
+    assert_eq!(targets.len(), 6);
+    assert!(targets.iter().all(|(_, source)| *source == FuzzSource::Initial));
+    assert!(
+        targets
+            .iter()
+            .any(|(url, _)| url == "http://other.com/app/backup")
+    );
+}
+
+#[test]
+fn test_build_fuzz_targets_includes_database_endpoints() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("rinzler.db");
+    let db = Database::new(&db_path).unwrap();
+    let session_id = db.create_session("crawl", "[]").unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+    let node = CrawlNode {
+        url: "http://example.com/api".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: None,
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: Some(ServiceType::Web),
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    db.insert_node(&map_id, &node).unwrap();
+
+    let options = FuzzOptions {
+        base_urls: vec!["http://example.com".to_string()],
+        wordlist: vec!["a".to_string(), "b".to_string()],
+        db_path: Some(db_path),
+        ..recursion_options(String::new(), 0)
+    };
+
+    let targets = build_fuzz_targets(&options).unwrap();
+    assert_eq!(
+        targets,
+        vec![
+            ("http://example.com/api/a".to_string(), FuzzSource::Database),
+            ("http://example.com/api/b".to_string(), FuzzSource::Database),
+            ("http://example.com/a".to_string(), FuzzSource::Initial),
+            ("http://example.com/b".to_string(), FuzzSource::Initial),
+        ]
+    );
+}
+
+#[test]
+fn test_build_fuzz_targets_rejects_empty_wordlist() {
+    let options = FuzzOptions {
+        wordlist: Vec::new(),
+        ..recursion_options("http://example.com".to_string(), 0)
+    };
+    assert!(build_fuzz_targets(&options).is_err());
+}
+
+#[tokio::test]
+async fn test_resumed_fuzz_skips_tested_urls() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let tested = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorder = tested.clone();
+    let options = FuzzOptions {
+        wordlist: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        skip_urls: HashSet::from([format!("{}/a", server.uri()), format!("{}/b", server.uri())]),
+        on_tested: Some(Arc::new(move |url: &str| {
+            recorder.lock().unwrap().push(url.to_string())
+        })),
+        ..recursion_options(server.uri(), 0)
+    };
+
+    execute_fuzz(options).await.unwrap();
+
+    let requested: Vec<String> = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| request.url.path().to_string())
+        .collect();
+    assert_eq!(requested, vec!["/c"]);
+    assert_eq!(
+        *tested.lock().unwrap(),
+        vec![format!("{}/c", server.uri())]
+    );
+}
+
+#[tokio::test]
+async fn test_resumed_fuzz_expands_earlier_hits() {
+    // The interrupted run found /admin but stopped before testing /admin/x
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/admin"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/x"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let admin = FuzzResult {
+        url: format!("{}/admin", server.uri()),
+        status_code: 200,
+        content_length: Some(0),
+        content_type: None,
+        source: FuzzSource::Initial,
+    };
+    let hits = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorder = hits.clone();
+    let options = FuzzOptions {
+        wordlist: vec!["admin".to_string(), "x".to_string()],
+        skip_urls: HashSet::from([admin.url.clone()]),
+        resumed_hits: vec![FuzzHit {
+            result: admin.clone(),
+            depth: 0,
+        }],
+        on_hit: Some(Arc::new(move |hit: &FuzzHit| {
+            recorder.lock().unwrap().push(hit.clone())
+        })),
+        ..recursion_options(server.uri(), 1)
+    };
+
+    let results = execute_fuzz(options).await.unwrap();
+
+    let mut requested: Vec<String> = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| request.url.path().to_string())
+        .collect();
+    requested.sort();
+    assert_eq!(requested, vec!["/admin/admin", "/admin/x", "/x"]);
+
+    let admin_x = format!("{}/admin/x", server.uri());
+    let reported: Vec<&str> = results
+        .iter()
+        .filter(|r| r.status_code == 200)
+        .map(|r| r.url.as_str())
+        .collect();
+    assert_eq!(reported, vec![admin.url.as_str(), admin_x.as_str()]);
+
+    // The new hit is persisted one level below /admin, which is not recorded twice
+    let hits = hits.lock().unwrap();
+    assert!(hits.iter().all(|hit| hit.result.url != admin.url));
+    let hit = hits
+        .iter()
+        .find(|hit| hit.result.url == admin_x)
+        .expect("/admin/x should be persisted");
+    assert_eq!(hit.result.source, FuzzSource::Discovered);
+    assert_eq!(hit.depth, 1);
+}
+
+#[tokio::test]
+async fn test_throttled_probe_is_retried_after_backoff() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/a"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/a"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let tested = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorder = tested.clone();
+    let options = FuzzOptions {
+        on_tested: Some(Arc::new(move |url: &str| {
+            recorder.lock().unwrap().push(url.to_string())
+        })),
+        ..recursion_options(server.uri(), 0)
+    };
+
+    let results = execute_fuzz(options).await.unwrap();
+    let url = format!("{}/a", server.uri());
+    let statuses: Vec<u16> = results.iter().map(|r| r.status_code).collect();
+    assert_eq!(statuses, vec![200]);
+    assert_eq!(*tested.lock().unwrap(), vec![url]);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_probe_still_throttled_after_retry_is_not_recorded() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(503).insert_header("retry-after", "0"))
+        .mount(&server)
+        .await;
+
+    let tested = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorder = tested.clone();
+    let options = FuzzOptions {
+        include_status: Some(vec![503]),
+        on_tested: Some(Arc::new(move |url: &str| {
+            recorder.lock().unwrap().push(url.to_string())
+        })),
+        ..recursion_options(server.uri(), 0)
+    };
+
+    // Left untested so a resumed session probes it again
+    let results = execute_fuzz(options).await.unwrap();
+    assert!(results.is_empty());
+    assert!(tested.lock().unwrap().is_empty());
+}
+
+fn report_results() -> Vec<FuzzResult> {
+    vec![
+        FuzzResult {
+            url: "http://example.com/admin".to_string(),
+            status_code: 200,
+            content_length: Some(1024),
+            content_type: Some("text/html; charset=utf-8".to_string()),
+            source: FuzzSource::Initial,
+        },
+        FuzzResult {
+            url: "http://example.com/api/v1/users".to_string(),
+            status_code: 200,
+            content_length: Some(56),
+            content_type: Some("application/json".to_string()),
+            source: FuzzSource::Discovered,
+        },
+        FuzzResult {
+            url: "http://example.com/login".to_string(),
+            status_code: 302,
+            content_length: None,
+            content_type: None,
+            source: FuzzSource::Initial,
+        },
+    ]
+}
+
+#[test]
+fn test_fuzz_findings_flag_interesting_file_hits() {
+    let hit = |url: &str, status_code: u16| FuzzResult {
+        url: url.to_string(),
+        status_code,
+        content_length: None,
+        content_type: None,
+        source: FuzzSource::Initial,
+    };
+    let results = vec![
+        hit("http://example.com/.env", 200),
+        hit("http://example.com/.git/config", 403),
+        hit("http://example.com/about", 200),
+    ];
+
+    let findings = fuzz_findings(&results);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].title, "Environment File Exposed");
+    assert_eq!(findings[0].severity, Severity::Critical);
+    assert!(fuzz_findings(&report_results()).iter().all(|f| f.severity == Severity::Info));
+}
+
+#[test]
+fn test_fuzz_report_without_color_has_no_ansi() {
+    let report = generate_fuzz_report_with_style(
+        &report_results(),
+        FuzzReportStyle {
+            color: false,
+            group_by_content_type: true,
+        },
+    );
+    assert!(!report.contains('\x1b'));
+
+    let colored = generate_fuzz_report_with_style(
+        &report_results(),
+        FuzzReportStyle {
+            color: true,
+            group_by_content_type: false,
+        },
+    );
+    assert!(colored.contains("\x1b[32m[200] Success\x1b[0m"));
+    assert!(colored.contains("\x1b[33m[302] Redirect\x1b[0m"));
+}
+
+#[test]
+fn test_fuzz_report_columns_are_aligned() {
+    let report = generate_fuzz_report_with_style(&report_results(), FuzzReportStyle::default());
+
+    // The size column ends at the same offset on every result line
+    let size_ends: Vec<usize> = ["1024 bytes", "56 bytes"]
+        .iter()
+        .map(|size| {
+            let line = report.lines().find(|line| line.contains(size)).unwrap();
+            line.find(size).unwrap() + size.len()
+        })
+        .collect();
+    assert_eq!(size_ends[0], size_ends[1]);
+    assert!(report.contains("1024 bytes  [text/html]"));
+    assert!(report.contains("http://example.com/api/v1/users [DISC]"));
+}
+
+#[test]
+fn test_fuzz_report_groups_by_content_type() {
+    let report = generate_fuzz_report_with_style(
+        &report_results(),
+        FuzzReportStyle {
+            color: false,
+            group_by_content_type: true,
+        },
+    );
+
+    assert!(report.contains("  application/json (1)\n    http://example.com/api/v1/users"));
+    assert!(report.contains("  text/html (1)\n    http://example.com/admin"));
+    assert!(report.contains("  unknown (1)\n    http://example.com/login"));
+    // Grouped lines drop the per-line type tag
+    assert!(!report.contains("[text/html]"));
+}
+
+#[test]
+fn test_worker_totals_split_initial_urls() {
+    // 10 URLs over 3 workers: round-robin gives the first worker the extra one
+    assert_eq!(worker_totals(10, 3), vec![4, 3, 3]);
+    assert_eq!(worker_totals(9, 3), vec![3, 3, 3]);
+    assert_eq!(worker_totals(2, 4), vec![1, 1, 0, 0]);
+    assert_eq!(worker_totals(0, 2), vec![0, 0]);
+    assert!(worker_totals(5, 0).is_empty());
+
+    let totals = worker_totals(99 * 7, 8);
+    assert_eq!(totals.iter().sum::<u64>(), 99 * 7);
+}
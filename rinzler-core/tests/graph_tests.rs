@@ -0,0 +1,138 @@
+// Tests for the interactive HTML site graph
+
+use rinzler_core::data::{
+    Confidence, CrawlNode, Database, EdgeType, Finding, FindingType, ServiceType, Severity,
+};
+use rinzler_core::graph::{gather_graph_data, generate_html_graph};
+use serde_json::Value;
+use tempfile::TempDir;
+
+fn node(url: &str, status_code: u16, service_type: ServiceType) -> CrawlNode {
+    CrawlNode {
+        url: url.to_string(),
+        domain: "example.com".to_string(),
+        status_code,
+        content_type: None,
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: Some(service_type),
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    }
+}
+
+/// Pull the JSON array assigned to `const <name> = ...;` out of the page
+fn embedded_array(html: &str, name: &str) -> Value {
+    let marker = format!("const {} = ", name);
+    let start = html.find(&marker).unwrap() + marker.len();
+    let end = start + html[start..].find(";\n").unwrap();
+    serde_json::from_str(&html[start..end]).unwrap()
+}
+
+#[test]
+fn test_html_graph_embeds_nodes_and_edges_from_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+    let session_id = db.create_session("crawl", "[]").unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    let home = db
+        .insert_node(&map_id, &node("http://example.com/", 200, ServiceType::Web))
+        .unwrap();
+    let api = db
+        .insert_node(
+            &map_id,
+            &node("http://example.com/api", 200, ServiceType::RestApi),
+        )
+        .unwrap();
+    let old = db
+        .insert_node(
+            &map_id,
+            &node("http://example.com/old", 301, ServiceType::Redirect),
+        )
+        .unwrap();
+
+    db.insert_edge(&map_id, home, api, EdgeType::Navigation)
+        .unwrap();
+    db.insert_edge(&map_id, old, home, EdgeType::Redirect)
+        .unwrap();
+    // Duplicates are ignored
+    db.insert_edge(&map_id, home, api, EdgeType::Navigation)
+        .unwrap();
+
+    db.insert_finding(
+        &session_id,
+        &Finding {
+            node_id: api,
+            finding_type: FindingType::Misconfiguration,
+            severity: Severity::High,
+            title: "Permissive CORS </script>".to_string(),
+            description: String::new(),
+            impact: None,
+            remediation: None,
+            evidence: None,
+            cwe_id: None,
+            owasp_category: None,
+            cvss_score: None,
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
+        },
+    )
+    .unwrap();
+
+    let data = gather_graph_data(&db, &session_id).unwrap();
+    let html = generate_html_graph(&data).unwrap();
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    // Finding titles cannot close the script element early
+    assert_eq!(html.matches("</script>").count(), 1);
+
+    let nodes = embedded_array(&html, "NODES");
+    let nodes = nodes.as_array().unwrap();
+    assert_eq!(nodes.len(), 3);
+    let urls: Vec<&str> = nodes.iter().map(|n| n["url"].as_str().unwrap()).collect();
+    assert_eq!(
+        urls,
+        vec![
+            "http://example.com/",
+            "http://example.com/api",
+            "http://example.com/old"
+        ]
+    );
+    assert_eq!(nodes[1]["id"], api);
+    assert_eq!(nodes[1]["service_type"], "rest_api");
+    assert_eq!(nodes[2]["status_code"], 301);
+    assert_eq!(nodes[1]["findings"][0]["severity"], "high");
+    assert_eq!(
+        nodes[1]["findings"][0]["title"],
+        "Permissive CORS </script>"
+    );
+    assert!(nodes[0]["findings"].as_array().unwrap().is_empty());
+
+    let edges = embedded_array(&html, "EDGES");
+    assert_eq!(
+        edges,
+        serde_json::json!([
+            { "source": home, "target": api, "edge_type": "navigation" },
+            { "source": old, "target": home, "edge_type": "redirect" },
+        ])
+    );
+}
+
+#[test]
+fn test_html_graph_for_empty_session() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+    let session_id = db.create_session("crawl", "[]").unwrap();
+
+    let html = generate_html_graph(&gather_graph_data(&db, &session_id).unwrap()).unwrap();
+    assert_eq!(embedded_array(&html, "NODES"), serde_json::json!([]));
+    assert_eq!(embedded_array(&html, "EDGES"), serde_json::json!([]));
+    assert!(html.contains(&format!("Session {}", session_id)));
+}
@@ -0,0 +1,95 @@
+// Tests for the Prometheus crawl metrics
+
+use rinzler_core::data::{Confidence, Finding, FindingType, Severity};
+use rinzler_core::metrics::{CrawlMetrics, MetricsSnapshot, format_metrics};
+use rinzler_scanner::result::CrawlResult;
+
+fn finding(severity: Severity) -> Finding {
+    Finding {
+        node_id: 1,
+        finding_type: FindingType::Misconfiguration,
+        severity,
+        title: "Test".to_string(),
+        description: String::new(),
+        impact: None,
+        remediation: None,
+        evidence: None,
+        cwe_id: None,
+        owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
+    }
+}
+
+#[test]
+fn test_format_metrics_type_lines_and_values() {
+    let snapshot = MetricsSnapshot {
+        pages_crawled: 42,
+        errors: 3,
+        findings: [1, 2, 0, 5, 7],
+        requests_per_second: 12.5,
+    };
+
+    let output = format_metrics(&snapshot);
+    let lines: Vec<&str> = output.lines().collect();
+
+    let type_lines: Vec<&str> = lines
+        .iter()
+        .copied()
+        .filter(|line| line.starts_with("# TYPE "))
+        .collect();
+    assert_eq!(
+        type_lines,
+        vec![
+            "# TYPE rinzler_pages_crawled_total counter",
+            "# TYPE rinzler_findings_total counter",
+            "# TYPE rinzler_errors_total counter",
+            "# TYPE rinzler_requests_per_second gauge",
+        ]
+    );
+
+    // Every sample belongs to a metric declared by a preceding TYPE line
+    let mut declared = Vec::new();
+    for line in &lines {
+        if let Some(rest) = line.strip_prefix("# TYPE ") {
+            declared.push(rest.split(' ').next().unwrap());
+        } else if !line.starts_with('#') {
+            let name = line.split(['{', ' ']).next().unwrap();
+            assert!(declared.contains(&name), "undeclared metric in {:?}", line);
+        }
+    }
+
+    assert!(lines.contains(&"rinzler_pages_crawled_total 42"));
+    assert!(lines.contains(&"rinzler_errors_total 3"));
+    assert!(lines.contains(&"rinzler_findings_total{severity=\"critical\"} 1"));
+    assert!(lines.contains(&"rinzler_findings_total{severity=\"high\"} 2"));
+    assert!(lines.contains(&"rinzler_findings_total{severity=\"medium\"} 0"));
+    assert!(lines.contains(&"rinzler_findings_total{severity=\"low\"} 5"));
+    assert!(lines.contains(&"rinzler_findings_total{severity=\"info\"} 7"));
+    assert!(lines.contains(&"rinzler_requests_per_second 12.500"));
+}
+
+#[test]
+fn test_crawl_metrics_counts_results_and_findings() {
+    let metrics = CrawlMetrics::new();
+
+    metrics.record_result(&CrawlResult::new("http://example.com/".to_string()));
+    metrics.record_result(&CrawlResult::new("http://example.com/a".to_string()));
+    metrics.record_result(&CrawlResult::with_error(
+        "http://example.com/b".to_string(),
+        "timed out".to_string(),
+    ));
+    metrics.record_findings(&[
+        finding(Severity::High),
+        finding(Severity::High),
+        finding(Severity::Info),
+    ]);
+
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.pages_crawled, 2);
+    assert_eq!(snapshot.errors, 1);
+    assert_eq!(snapshot.findings, [0, 2, 0, 0, 1]);
+    assert!(snapshot.requests_per_second > 0.0);
+}
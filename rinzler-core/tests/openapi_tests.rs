@@ -0,0 +1,108 @@
+// Tests for OpenAPI/Swagger spec import
+
+use rinzler_core::openapi::extract_urls_from_spec;
+use url::Url;
+
+fn base() -> Url {
+    Url::parse("http://fallback.test/").unwrap()
+}
+
+#[test]
+fn test_extract_urls_from_swagger_v2_json() {
+    let spec = r#"{
+        "swagger": "2.0",
+        "host": "api.example.com",
+        "basePath": "/v1",
+        "schemes": ["https"],
+        "paths": {
+            "/users": {"get": {}},
+            "/users/{userId}": {
+                "get": {
+                    "parameters": [{"name": "userId", "in": "path", "type": "integer"}]
+                }
+            }
+        }
+    }"#;
+
+    let urls = extract_urls_from_spec(spec, &base()).unwrap();
+    assert_eq!(
+        urls,
+        vec![
+            "https://api.example.com/v1/users",
+            "https://api.example.com/v1/users/1",
+        ]
+    );
+}
+
+#[test]
+fn test_extract_urls_from_swagger_v2_without_host_uses_base() {
+    let spec = r#"{"swagger": "2.0", "basePath": "/api", "paths": {"/health": {}}}"#;
+
+    let urls = extract_urls_from_spec(spec, &base()).unwrap();
+    assert_eq!(urls, vec!["http://fallback.test/api/health"]);
+}
+
+#[test]
+fn test_extract_urls_from_openapi_v3_yaml() {
+    let spec = r#"
+openapi: 3.0.0
+info:
+  title: Pets
+  version: "1.0"
+servers:
+  - url: https://{env}.example.com/api
+    variables:
+      env:
+        default: staging
+paths:
+  /pets:
+    get: {}
+  /pets/{name}/toys/{toyId}:
+    parameters:
+      - name: toyId
+        in: path
+        schema:
+          type: integer
+    get:
+      parameters:
+        - name: name
+          in: path
+          schema:
+            type: string
+"#;
+
+    let urls = extract_urls_from_spec(spec, &base()).unwrap();
+    assert_eq!(
+        urls,
+        vec![
+            "https://staging.example.com/api/pets",
+            "https://staging.example.com/api/pets/test/toys/1",
+        ]
+    );
+}
+
+#[test]
+fn test_extract_urls_from_openapi_v3_relative_server() {
+    let spec = r#"{"openapi": "3.1.0", "servers": [{"url": "/v2"}], "paths": {"/items": {}}}"#;
+
+    let urls = extract_urls_from_spec(spec, &base()).unwrap();
+    assert_eq!(urls, vec!["http://fallback.test/v2/items"]);
+}
+
+#[test]
+fn test_extract_urls_from_openapi_v3_without_servers_uses_base() {
+    let spec = r#"{"openapi": "3.0.3", "paths": {"/items": {}, "/items/": {}}}"#;
+
+    let urls = extract_urls_from_spec(spec, &base()).unwrap();
+    assert_eq!(
+        urls,
+        vec!["http://fallback.test/items", "http://fallback.test/items/"]
+    );
+}
+
+#[test]
+fn test_extract_urls_rejects_non_openapi_documents() {
+    assert!(extract_urls_from_spec(r#"{"paths": {}}"#, &base()).is_err());
+    assert!(extract_urls_from_spec(r#"{"openapi": "3.0.0"}"#, &base()).is_err());
+    assert!(extract_urls_from_spec("{ not: [valid", &base()).is_err());
+}
@@ -0,0 +1,229 @@
+// Tests for passive-check plugins and their registry
+
+use rinzler_core::data::{Database, FindingType, Severity};
+use rinzler_core::plugin::{
+    CheckRegistry, PassiveCheck, RuleCheck, ScriptCheck, load_check, load_registry,
+};
+use rinzler_scanner::result::CrawlResult;
+use std::fs;
+use tempfile::TempDir;
+
+const DEBUG_RULES: &str = r#"
+[[rule]]
+title = "Debug Header Exposed"
+severity = "medium"
+finding_type = "information_disclosure"
+header = "X-Debug-Token"
+
+[[rule]]
+title = "Admin Panel Reachable"
+severity = "high"
+url = "/admin"
+status = [200]
+"#;
+
+fn page(url: &str, status_code: u16, headers: &[(&str, &str)]) -> CrawlResult {
+    let mut result = CrawlResult::new(url.to_string());
+    result.status_code = status_code;
+    result.headers = headers
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    result
+}
+
+#[test]
+fn test_plugin_register_list_unregister_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+
+    assert!(db.list_plugins().unwrap().is_empty());
+
+    db.register_plugin("debug", "/plugins/debug.toml").unwrap();
+    db.register_plugin("admin", "/plugins/admin.toml").unwrap();
+    // Registering a name again points it at the new file
+    db.register_plugin("debug", "/plugins/debug-v2.toml").unwrap();
+
+    let plugins = db.list_plugins().unwrap();
+    let listed: Vec<(&str, &str)> = plugins
+        .iter()
+        .map(|p| (p.name.as_str(), p.path.as_str()))
+        .collect();
+    assert_eq!(
+        listed,
+        vec![
+            ("admin", "/plugins/admin.toml"),
+            ("debug", "/plugins/debug-v2.toml"),
+        ]
+    );
+
+    assert!(db.unregister_plugin("debug").unwrap());
+    assert!(!db.unregister_plugin("debug").unwrap());
+    let names: Vec<String> = db
+        .list_plugins()
+        .unwrap()
+        .into_iter()
+        .map(|p| p.name)
+        .collect();
+    assert_eq!(names, vec!["admin"]);
+}
+
+#[test]
+fn test_rule_check_matches_every_condition() {
+    let check = RuleCheck::from_toml("debug", DEBUG_RULES).unwrap();
+    assert_eq!(check.name(), "debug");
+
+    let findings = check.analyze(
+        &page("http://example.com/", 200, &[("x-debug-token", "abc123")]),
+        7,
+    );
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].title, "Debug Header Exposed");
+    assert_eq!(findings[0].severity, Severity::Medium);
+    assert_eq!(findings[0].finding_type, FindingType::InformationDisclosure);
+    assert_eq!(findings[0].node_id, 7);
+
+    // The admin rule needs both the path and the status
+    assert_eq!(
+        check
+            .analyze(&page("http://example.com/admin", 200, &[]), 1)
+            .len(),
+        1
+    );
+    assert!(
+        check
+            .analyze(&page("http://example.com/admin", 403, &[]), 1)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_rule_check_rejects_invalid_rules() {
+    assert!(RuleCheck::from_toml("empty", "").is_err());
+    assert!(
+        RuleCheck::from_toml(
+            "no-condition",
+            "[[rule]]\ntitle = \"Always\"\nseverity = \"low\"\n"
+        )
+        .is_err()
+    );
+    assert!(
+        RuleCheck::from_toml(
+            "bad-severity",
+            "[[rule]]\ntitle = \"X\"\nseverity = \"urgent\"\nstatus = [200]\n"
+        )
+        .is_err()
+    );
+    assert!(
+        RuleCheck::from_toml(
+            "bad-regex",
+            "[[rule]]\ntitle = \"X\"\nseverity = \"low\"\nurl = \"(\"\n"
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_load_registry_skips_broken_plugins() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+    let rules_path = temp_dir.path().join("debug.toml");
+    fs::write(&rules_path, DEBUG_RULES).unwrap();
+
+    db.register_plugin("debug", &rules_path.to_string_lossy())
+        .unwrap();
+    db.register_plugin("missing", "/nonexistent/rules.toml")
+        .unwrap();
+
+    let (registry, errors) = load_registry(&db).unwrap();
+    assert_eq!(registry.names(), vec!["debug"]);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("missing"), "{}", errors[0]);
+
+    let findings = registry.analyze(&page("http://example.com/admin", 200, &[]), 1);
+    assert_eq!(findings.len(), 1);
+    assert!(CheckRegistry::new().is_empty());
+}
+
+const DEBUG_SCRIPT: &str = r#"
+fn analyze(url, status, content_type, body) {
+    if url.contains("/debug") {
+        #{ title: "Debug Endpoint Exposed", severity: "high", description: "Found " + url }
+    }
+}
+"#;
+
+#[test]
+fn test_script_check_flags_debug_urls() {
+    let check = ScriptCheck::from_source("debug-script", DEBUG_SCRIPT).unwrap();
+
+    let findings = check.analyze(&page("http://example.com/debug/vars", 200, &[]), 3);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].title, "Debug Endpoint Exposed");
+    assert_eq!(findings[0].severity, Severity::High);
+    assert_eq!(findings[0].description, "Found http://example.com/debug/vars");
+    assert_eq!(findings[0].finding_type, FindingType::Other);
+    assert_eq!(findings[0].node_id, 3);
+
+    assert!(
+        check
+            .analyze(&page("http://example.com/about", 200, &[]), 3)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_script_check_sees_body_and_returns_arrays() {
+    let script = r#"
+        fn analyze(url, status, content_type, body) {
+            let found = [];
+            if body.contains("stack trace") { found.push(#{ title: "Stack Trace" }); }
+            if status == 500 { found.push(#{ title: "Server Error", severity: "low" }); }
+            found
+        }
+    "#;
+    let check = ScriptCheck::from_source("errors", script).unwrap();
+
+    let mut result = page("http://example.com/", 500, &[]);
+    result.body_sample = Some("<pre>stack trace</pre>".to_string());
+    let findings = check.analyze(&result, 1);
+
+    let titles: Vec<&str> = findings.iter().map(|f| f.title.as_str()).collect();
+    assert_eq!(titles, vec!["Stack Trace", "Server Error"]);
+    assert_eq!(findings[0].severity, Severity::Info);
+    assert_eq!(findings[1].severity, Severity::Low);
+}
+
+#[test]
+fn test_script_check_operation_limit_stops_runaway_scripts() {
+    let script = "fn analyze(url, status, content_type, body) { loop {} }";
+    let check = ScriptCheck::from_source("spin", script).unwrap();
+
+    assert!(
+        check
+            .analyze(&page("http://example.com/", 200, &[]), 1)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_script_check_requires_analyze_function() {
+    assert!(ScriptCheck::from_source("none", "fn other() { 1 }").is_err());
+    assert!(ScriptCheck::from_source("syntax", "fn analyze(url {").is_err());
+}
+
+#[test]
+fn test_load_check_picks_loader_by_extension() {
+    let temp_dir = TempDir::new().unwrap();
+    let script_path = temp_dir.path().join("debug.rhai");
+    fs::write(&script_path, DEBUG_SCRIPT).unwrap();
+
+    let check = load_check("debug-script", &script_path).unwrap();
+    assert_eq!(check.name(), "debug-script");
+    assert_eq!(
+        check
+            .analyze(&page("http://example.com/debug", 200, &[]), 1)
+            .len(),
+        1
+    );
+}
@@ -0,0 +1,125 @@
+// Tests for active endpoint probes
+
+use rinzler_core::data::{FindingType, Severity};
+use rinzler_core::probe::{PROBE_ORIGIN, check_cors_preflight};
+use rinzler_scanner::result::CrawlResult;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn api_result(url: String) -> CrawlResult {
+    let mut result = CrawlResult::new(url);
+    result.status_code = 200;
+    result.content_type = Some("application/json".to_string());
+    result
+}
+
+#[tokio::test]
+async fn test_permissive_api_preflight_is_flagged() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("OPTIONS"))
+        .and(path("/api/users"))
+        .and(header("origin", PROBE_ORIGIN))
+        .respond_with(
+            ResponseTemplate::new(204)
+                .insert_header("access-control-allow-origin", "*")
+                .insert_header("access-control-allow-credentials", "true")
+                .insert_header("access-control-allow-methods", "GET, PUT, DELETE"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = reqwest::Client::new();
+    let result = api_result(format!("{}/api/users", mock_server.uri()));
+    let findings = check_cors_preflight(&client, &result, 7).await;
+
+    assert_eq!(findings.len(), 2);
+    assert!(findings.iter().all(|f| f.severity == Severity::Medium));
+    assert!(findings.iter().all(|f| f.node_id == 7));
+    assert!(
+        findings
+            .iter()
+            .all(|f| matches!(f.finding_type, FindingType::Misconfiguration))
+    );
+
+    let cors = findings
+        .iter()
+        .find(|f| f.title.contains("CORS"))
+        .expect("missing CORS finding");
+    let evidence: serde_json::Value = serde_json::from_str(cors.evidence.as_deref().unwrap()).unwrap();
+    assert_eq!(evidence["url"], result.url);
+    assert_eq!(evidence["allow_origin"], "*");
+    assert_eq!(evidence["allow_credentials"], true);
+    assert_eq!(evidence["allowed_methods"], "DELETE, GET, PUT");
+
+    let methods = findings
+        .iter()
+        .find(|f| f.title.contains("Write Methods"))
+        .expect("missing write methods finding");
+    assert!(methods.description.contains("PUT, DELETE"));
+}
+
+#[tokio::test]
+async fn test_evidence_escapes_quoted_header_values() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("OPTIONS"))
+        .and(path("/api/users"))
+        .respond_with(
+            ResponseTemplate::new(204)
+                .insert_header("access-control-allow-origin", "*")
+                .insert_header("access-control-allow-credentials", "true")
+                .insert_header("access-control-allow-methods", r#"PUT, "X\"#),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = reqwest::Client::new();
+    let result = api_result(format!("{}/api/users", mock_server.uri()));
+    let findings = check_cors_preflight(&client, &result, 1).await;
+
+    assert_eq!(findings.len(), 2);
+    for finding in &findings {
+        let evidence: serde_json::Value =
+            serde_json::from_str(finding.evidence.as_deref().unwrap()).unwrap();
+        assert_eq!(evidence["allowed_methods"], r#""X\, PUT"#);
+    }
+}
+
+#[tokio::test]
+async fn test_strict_api_preflight_is_not_flagged() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("OPTIONS"))
+        .and(path("/api/users"))
+        .respond_with(
+            ResponseTemplate::new(204)
+                .insert_header("access-control-allow-origin", "https://app.example.com")
+                .insert_header("access-control-allow-credentials", "true")
+                .insert_header("access-control-allow-methods", "GET"),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = reqwest::Client::new();
+    let result = api_result(format!("{}/api/users", mock_server.uri()));
+
+    assert!(check_cors_preflight(&client, &result, 1).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_non_api_nodes_are_not_probed() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("OPTIONS"))
+        .respond_with(
+            ResponseTemplate::new(204)
+                .insert_header("access-control-allow-origin", "*")
+                .insert_header("access-control-allow-credentials", "true"),
+        )
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = reqwest::Client::new();
+    let mut result = api_result(format!("{}/about", mock_server.uri()));
+    result.content_type = Some("text/html".to_string());
+
+    assert!(check_cors_preflight(&client, &result, 1).await.is_empty());
+}
@@ -0,0 +1,198 @@
+// Tests for replaying recorded HTTP transactions
+
+use rinzler_core::crawl::{Auth, RequestSettings};
+use rinzler_core::data::{CrawlNode, Database};
+use rinzler_core::replay::{REDACTED, redact_credentials, replay_transactions};
+use tempfile::TempDir;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const BODY: &str = r#"{"status":"ok"}"#;
+
+async fn mount_api(server: &MockServer, status: u16, content_type: &str, body: &str) {
+    Mock::given(method("GET"))
+        .and(path("/api"))
+        .respond_with(
+            ResponseTemplate::new(status)
+                .insert_header("content-type", content_type)
+                .set_body_bytes(body.as_bytes()),
+        )
+        .mount(server)
+        .await;
+}
+
+/// Record one GET /api exchange the way a crawl does
+fn record_session(db: &Database, url: &str) -> String {
+    record_session_with_headers(db, url, None)
+}
+
+fn record_session_with_headers(db: &Database, url: &str, request_headers: Option<&str>) -> String {
+    let session_id = db.create_session("crawl", "[]").unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+    let node = CrawlNode {
+        url: url.to_string(),
+        domain: "127.0.0.1".to_string(),
+        status_code: 200,
+        content_type: Some("application/json".to_string()),
+        content_length: None,
+        response_time_ms: Some(5),
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: Some(rinzler_scanner::content_hash(BODY.as_bytes())),
+    };
+    let node_id = db.insert_node(&map_id, &node).unwrap();
+    db.log_http_transaction(
+        &session_id,
+        Some(node_id),
+        "GET",
+        url,
+        request_headers,
+        200,
+        Some(r#"[["content-type","application/json"]]"#),
+        Some(5),
+    )
+    .unwrap();
+    session_id
+}
+
+#[tokio::test]
+async fn test_replay_reports_no_drift_then_drift() {
+    let server = MockServer::start().await;
+    mount_api(&server, 200, "application/json", BODY).await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+    let url = format!("{}/api", server.uri());
+    let session_id = record_session(&db, &url);
+
+    let transactions = db.get_http_transactions(&session_id).unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].method, "GET");
+    assert_eq!(transactions[0].url, url);
+
+    // Unchanged target
+    let outcomes = replay_transactions(&transactions, 5, RequestSettings::default())
+        .await
+        .unwrap();
+    assert_eq!(outcomes.len(), 1);
+    assert!(!outcomes[0].has_drift(), "{:?}", outcomes[0].drift);
+
+    // Target changed status, content type and body
+    server.reset().await;
+    mount_api(&server, 500, "text/plain", "boom").await;
+
+    let outcomes = replay_transactions(&transactions, 5, RequestSettings::default())
+        .await
+        .unwrap();
+    assert!(outcomes[0].has_drift());
+    assert_eq!(
+        outcomes[0].drift,
+        vec![
+            "status 200 -> 500",
+            "content-type application/json -> text/plain",
+            "body changed",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_replay_sends_recorded_request_headers() {
+    let server = MockServer::start().await;
+    // Without the crawl's key and User-Agent the API refuses the request
+    Mock::given(method("GET"))
+        .and(path("/api"))
+        .and(header("x-api-key", "secret"))
+        .and(header("user-agent", "engagement-42"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/json")
+                .set_body_bytes(BODY.as_bytes()),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+    let url = format!("{}/api", server.uri());
+    let session_id = record_session_with_headers(
+        &db,
+        &url,
+        Some(r#"[["user-agent","engagement-42"],["x-api-key","secret"]]"#),
+    );
+
+    let transactions = db.get_http_transactions(&session_id).unwrap();
+    let outcomes = replay_transactions(&transactions, 5, RequestSettings::default())
+        .await
+        .unwrap();
+    assert!(!outcomes[0].has_drift(), "{:?}", outcomes[0].drift);
+}
+
+#[test]
+fn test_redact_credentials_hides_auth_and_cookies() {
+    let headers = vec![
+        ("user-agent".to_string(), "rinzler".to_string()),
+        (
+            "Authorization".to_string(),
+            "Bearer secret-token".to_string(),
+        ),
+        ("cookie".to_string(), "session=abc".to_string()),
+    ];
+    assert_eq!(
+        redact_credentials(&headers),
+        vec![
+            ("user-agent".to_string(), "rinzler".to_string()),
+            ("Authorization".to_string(), REDACTED.to_string()),
+            ("cookie".to_string(), REDACTED.to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_replay_sends_current_auth_for_redacted_header() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api"))
+        .and(header("authorization", "Bearer fresh-token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/json")
+                .set_body_bytes(BODY.as_bytes()),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+    let url = format!("{}/api", server.uri());
+    let session_id =
+        record_session_with_headers(&db, &url, Some(r#"[["authorization","<redacted>"]]"#));
+    let transactions = db.get_http_transactions(&session_id).unwrap();
+
+    // The placeholder itself is never sent
+    let outcomes = replay_transactions(&transactions, 5, RequestSettings::default())
+        .await
+        .unwrap();
+    assert_eq!(outcomes[0].drift, vec!["status 200 -> 401"]);
+
+    let request = RequestSettings {
+        auth: Some(Auth::Bearer("fresh-token".to_string())),
+        ..RequestSettings::default()
+    };
+    let outcomes = replay_transactions(&transactions, 5, request)
+        .await
+        .unwrap();
+    assert!(!outcomes[0].has_drift(), "{:?}", outcomes[0].drift);
+}
@@ -1,9 +1,20 @@
 // Tests for report generation functionality
 
+use rinzler_core::data::{
+    Confidence, CrawlNode, Database, Finding, FindingRecorder, FindingType, Severity,
+};
 use rinzler_core::report::{
-    FindingData, ReportData, ReportFormat, ScanInfo, SeverityCounts, SitemapNode,
+    ChangedNode, FindingData, ReportData, ReportFormat, ScanInfo, ScanTimings, SeverityCounts,
+    SitemapNode,
+    gather_report_data, generate_github_issues_report, generate_json_report, generate_jsonl_report,
+    generate_junit_report, generate_junit_report_with_threshold, generate_sarif_report,
+    generate_text_report, generate_text_report_with_color, generate_toml_report, group_findings,
+    read_jsonl_report, save_report,
 };
+use rinzler_scanner::CrawlStats;
+use std::io::Cursor;
 use std::str::FromStr;
+use tempfile::TempDir;
 
 // ============================================================================
 // Report Format Tests
@@ -45,6 +56,42 @@ fn test_report_format_from_str_md() {
     assert!(matches!(format, Ok(ReportFormat::Markdown)));
 }
 
+#[test]
+fn test_report_format_from_str_jsonl() {
+    assert!(matches!(
+        ReportFormat::from_str("jsonl"),
+        Ok(ReportFormat::Jsonl)
+    ));
+    assert!(matches!(
+        ReportFormat::from_str("ndjson"),
+        Ok(ReportFormat::Jsonl)
+    ));
+}
+
+#[test]
+fn test_report_format_from_str_sarif() {
+    assert!(matches!(
+        ReportFormat::from_str("sarif"),
+        Ok(ReportFormat::Sarif)
+    ));
+}
+
+#[test]
+fn test_report_format_from_str_toml() {
+    assert!(matches!(
+        ReportFormat::from_str("toml"),
+        Ok(ReportFormat::Toml)
+    ));
+}
+
+#[test]
+fn test_report_format_from_str_github_issues() {
+    assert!(matches!(
+        ReportFormat::from_str("github-issues"),
+        Ok(ReportFormat::GithubIssues)
+    ));
+}
+
 #[test]
 fn test_report_format_from_str_case_insensitive() {
     assert!(matches!(
@@ -116,8 +163,12 @@ fn test_finding_data_construction() {
         finding_type: "vulnerability".to_string(),
         cwe_id: Some("CWE-89".to_string()),
         owasp_category: Some("A03:2021".to_string()),
+        cvss_score: None,
+        confidence: None,
         impact: Some("Database compromise".to_string()),
         remediation: Some("Use parameterized queries".to_string()),
+        request_sample: None,
+        response_sample: None,
     };
 
     assert_eq!(finding.id, 1);
@@ -137,8 +188,12 @@ fn test_finding_data_minimal() {
         finding_type: "interesting_file".to_string(),
         cwe_id: None,
         owasp_category: None,
+        cvss_score: None,
+        confidence: None,
         impact: None,
         remediation: None,
+        request_sample: None,
+        response_sample: None,
     };
 
     assert_eq!(finding.id, 1);
@@ -153,6 +208,7 @@ fn test_scan_info_construction() {
         end_time: Some(1640001000),
         status: "completed".to_string(),
         seed_urls: "[\"http://example.com\"]".to_string(),
+        findings_dropped: 0,
     };
 
     assert_eq!(scan_info.start_time, 1640000000);
@@ -167,6 +223,7 @@ fn test_scan_info_running() {
         end_time: None,
         status: "running".to_string(),
         seed_urls: "[\"http://example.com\"]".to_string(),
+        findings_dropped: 0,
     };
 
     assert!(scan_info.end_time.is_none());
@@ -211,13 +268,21 @@ fn test_report_data_construction() {
             low: 0,
             info: 0,
         },
+        occurrence_counts: None,
         scan_info: ScanInfo {
             start_time: 1640000000,
             end_time: Some(1640001000),
             status: "completed".to_string(),
             seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
         },
         sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
     };
 
     assert_eq!(report.session_id, "test-session");
@@ -237,8 +302,12 @@ fn test_report_data_with_findings() {
         finding_type: "vulnerability".to_string(),
         cwe_id: None,
         owasp_category: None,
+        cvss_score: None,
+        confidence: None,
         impact: None,
         remediation: None,
+        request_sample: None,
+        response_sample: None,
     };
 
     let report = ReportData {
@@ -252,13 +321,21 @@ fn test_report_data_with_findings() {
             low: 0,
             info: 0,
         },
+        occurrence_counts: None,
         scan_info: ScanInfo {
             start_time: 1640000000,
             end_time: Some(1640001000),
             status: "completed".to_string(),
             seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
         },
         sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
     };
 
     assert_eq!(report.findings.len(), 1);
@@ -292,19 +369,107 @@ fn test_report_data_with_sitemap() {
             low: 0,
             info: 0,
         },
+        occurrence_counts: None,
         scan_info: ScanInfo {
             start_time: 1640000000,
             end_time: Some(1640001000),
             status: "completed".to_string(),
             seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
         },
         sitemap_nodes: Some(sitemap),
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
     };
 
     assert!(report.sitemap_nodes.is_some());
     assert_eq!(report.sitemap_nodes.as_ref().unwrap().len(), 2);
 }
 
+#[test]
+fn test_text_report_unique_findings_summary() {
+    let report = ReportData {
+        session_id: "test-session".to_string(),
+        total_nodes: 500,
+        findings: vec![],
+        severity_counts: SeverityCounts {
+            critical: 0,
+            high: 0,
+            medium: 1,
+            low: 0,
+            info: 0,
+        },
+        occurrence_counts: Some(SeverityCounts {
+            critical: 0,
+            high: 0,
+            medium: 500,
+            low: 0,
+            info: 0,
+        }),
+        scan_info: ScanInfo {
+            start_time: 1640000000,
+            end_time: Some(1640001000),
+            status: "completed".to_string(),
+            seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
+        },
+        sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
+    };
+
+    let text = generate_text_report(&report);
+    assert!(text.contains("Total Findings: 1 unique (500 occurrences)"));
+    assert!(text.contains("[MEDIUM]   1  (Should be addressed, 500 occurrences)"));
+
+    let json: serde_json::Value = serde_json::from_str(&generate_json_report(&report).unwrap()).unwrap();
+    assert_eq!(json["report"]["summary"]["occurrence_breakdown"]["medium"], 500);
+}
+
+#[test]
+fn test_json_report_omits_occurrence_breakdown_without_unique_findings() {
+    let data = ReportData {
+        session_id: "test-session".to_string(),
+        total_nodes: 1,
+        findings: vec![],
+        severity_counts: SeverityCounts {
+            critical: 0,
+            high: 0,
+            medium: 1,
+            low: 0,
+            info: 0,
+        },
+        occurrence_counts: None,
+        scan_info: ScanInfo {
+            start_time: 1640000000,
+            end_time: Some(1640001000),
+            status: "completed".to_string(),
+            seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
+        },
+        sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
+    };
+
+    let json: serde_json::Value = serde_json::from_str(&generate_json_report(&data).unwrap()).unwrap();
+    let summary = json["report"]["summary"].as_object().unwrap();
+    assert!(!summary.contains_key("occurrence_breakdown"));
+    assert_eq!(summary["total_findings"], 1);
+}
+
 // ============================================================================
 // Serialization Tests
 // ============================================================================
@@ -322,13 +487,21 @@ fn test_report_data_json_serialization() {
             low: 0,
             info: 0,
         },
+        occurrence_counts: None,
         scan_info: ScanInfo {
             start_time: 1640000000,
             end_time: Some(1640001000),
             status: "completed".to_string(),
             seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
         },
         sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
     };
 
     let json = serde_json::to_string(&report);
@@ -350,8 +523,12 @@ fn test_finding_data_json_serialization() {
         finding_type: "vulnerability".to_string(),
         cwe_id: Some("CWE-89".to_string()),
         owasp_category: Some("A03:2021".to_string()),
+        cvss_score: None,
+        confidence: None,
         impact: Some("High impact".to_string()),
         remediation: Some("Fix it".to_string()),
+        request_sample: None,
+        response_sample: None,
     };
 
     let json = serde_json::to_string(&finding);
@@ -373,8 +550,12 @@ fn test_finding_data_json_optional_fields() {
         finding_type: "interesting_file".to_string(),
         cwe_id: None,
         owasp_category: None,
+        cvss_score: None,
+        confidence: None,
         impact: None,
         remediation: None,
+        request_sample: None,
+        response_sample: None,
     };
 
     let json = serde_json::to_string(&finding).unwrap();
@@ -419,13 +600,21 @@ fn test_report_data_clone() {
             low: 0,
             info: 0,
         },
+        occurrence_counts: None,
         scan_info: ScanInfo {
             start_time: 1640000000,
             end_time: None,
             status: "running".to_string(),
             seed_urls: "[]".to_string(),
+            findings_dropped: 0,
         },
         sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
     };
 
     let cloned = report.clone();
@@ -448,8 +637,12 @@ fn test_finding_data_clone() {
         finding_type: "vuln".to_string(),
         cwe_id: Some("CWE-89".to_string()),
         owasp_category: None,
+        cvss_score: None,
+        confidence: None,
         impact: None,
         remediation: None,
+        request_sample: None,
+        response_sample: None,
     };
 
     let cloned = finding.clone();
@@ -457,3 +650,931 @@ fn test_finding_data_clone() {
     assert_eq!(cloned.severity, finding.severity);
     assert_eq!(cloned.cwe_id, finding.cwe_id);
 }
+
+// ============================================================================
+// JSONL Round-Trip Tests
+// ============================================================================
+
+#[test]
+fn test_jsonl_round_trip_matches_database_report() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    for (path, severity) in [("/", Severity::Medium), ("/admin", Severity::High)] {
+        let node = CrawlNode {
+            url: format!("http://example.com{}", path),
+            domain: "example.com".to_string(),
+            status_code: 200,
+            content_type: Some("text/html".to_string()),
+            content_length: None,
+            response_time_ms: None,
+            title: None,
+            forms_count: 0,
+            inputs_count: 0,
+            parameters: None,
+            service_type: None,
+            headers: None,
+            body_sample: None,
+            content_hash: None,
+        };
+        let node_id = db.insert_node(&map_id, &node).unwrap();
+
+        let finding = Finding {
+            node_id,
+            finding_type: FindingType::SecurityHeaderMissing,
+            severity,
+            title: "Missing X-Frame-Options Header".to_string(),
+            description: "The X-Frame-Options header is not set".to_string(),
+            impact: Some("Clickjacking".to_string()),
+            remediation: Some("Set X-Frame-Options: DENY".to_string()),
+            evidence: None,
+            cwe_id: Some("CWE-1021".to_string()),
+            owasp_category: None,
+            cvss_score: None,
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
+        };
+        db.insert_finding(&session_id, &finding).unwrap();
+    }
+    db.complete_session(&session_id).unwrap();
+
+    let from_db = gather_report_data(&db, &session_id, true, false).unwrap();
+    let ndjson = generate_jsonl_report(&from_db).unwrap();
+    assert_eq!(ndjson.lines().count(), 5);
+
+    let from_jsonl = read_jsonl_report(Cursor::new(ndjson), true, false).unwrap();
+    assert_eq!(
+        generate_text_report(&from_jsonl),
+        generate_text_report(&from_db)
+    );
+}
+
+#[test]
+fn test_report_lists_technology_versions() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+    let node = CrawlNode {
+        url: "http://example.com/".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: Some("text/html".to_string()),
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    let node_id = db.insert_node(&map_id, &node).unwrap();
+    db.insert_technology(
+        node_id,
+        "framework",
+        "PHP",
+        None,
+        "cookie",
+        Some("set-cookie: PHPSESSID"),
+        40,
+    )
+    .unwrap();
+    db.insert_technology(
+        node_id,
+        "web_server",
+        "nginx",
+        Some("1.18.0"),
+        "header",
+        Some("server: nginx/1.18.0"),
+        90,
+    )
+    .unwrap();
+
+    let data = gather_report_data(&db, &session_id, false, false).unwrap();
+    assert_eq!(data.technologies.len(), 2);
+    assert_eq!(data.technologies[0].name, "nginx");
+    assert_eq!(data.technologies[0].version.as_deref(), Some("1.18.0"));
+    assert_eq!(data.technologies[0].confidence, 90);
+
+    let text = generate_text_report(&data);
+    assert!(text.contains("TECHNOLOGIES"));
+    assert!(text.contains("nginx 1.18.0"));
+    assert!(text.contains("90% confidence"));
+
+    let ndjson = generate_jsonl_report(&data).unwrap();
+    let from_jsonl = read_jsonl_report(Cursor::new(ndjson), false, false).unwrap();
+    assert_eq!(from_jsonl.technologies.len(), 2);
+}
+
+#[test]
+fn test_max_findings_cap_is_enforced_and_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+    let node = CrawlNode {
+        url: "http://example.com/".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: Some("text/html".to_string()),
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    let node_id = db.insert_node(&map_id, &node).unwrap();
+
+    let mut recorder = FindingRecorder::new(&db, &session_id, Some(3));
+    for i in 0..10 {
+        let finding = Finding {
+            node_id,
+            finding_type: FindingType::InformationDisclosure,
+            severity: Severity::Info,
+            title: format!("Finding {}", i),
+            description: "Test finding".to_string(),
+            impact: None,
+            remediation: None,
+            evidence: None,
+            cwe_id: None,
+            owasp_category: None,
+            cvss_score: None,
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
+        };
+        recorder.record(&finding).unwrap();
+    }
+    recorder.finish().unwrap();
+    db.complete_session(&session_id).unwrap();
+
+    assert_eq!(recorder.recorded(), 3);
+    assert_eq!(recorder.dropped(), 7);
+
+    let data = gather_report_data(&db, &session_id, false, false).unwrap();
+    assert_eq!(data.findings.len(), 3);
+    assert_eq!(data.scan_info.findings_dropped, 7);
+
+    let text = generate_text_report(&data);
+    assert!(text.contains("Findings cap reached: 7 more findings were not recorded"));
+}
+
+#[test]
+fn test_cvss_score_in_text_and_json_reports() {
+    let temp_dir = TempDir::new().unwrap();
+    let db = Database::new(&temp_dir.path().join("test.db")).unwrap();
+
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+    let node = CrawlNode {
+        url: "http://example.com/".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: Some("text/html".to_string()),
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    let node_id = db.insert_node(&map_id, &node).unwrap();
+    let finding = Finding {
+        node_id,
+        finding_type: FindingType::InsecureTransport,
+        severity: Severity::Medium,
+        title: "Insecure Transport (HTTP)".to_string(),
+        description: "HTTP instead of HTTPS".to_string(),
+        impact: None,
+        remediation: None,
+        evidence: None,
+        cwe_id: None,
+        owasp_category: None,
+        cvss_score: Some(5.3),
+        confidence: Confidence::Likely,
+        request_sample: Some("GET / HTTP/1.1\nHost: example.com".to_string()),
+        response_sample: Some("HTTP/1.1 200\ncontent-type: text/html".to_string()),
+    };
+    db.insert_finding(&session_id, &finding).unwrap();
+    db.complete_session(&session_id).unwrap();
+
+    let data = gather_report_data(&db, &session_id, false, false).unwrap();
+    assert_eq!(data.findings[0].cvss_score, Some(5.3));
+
+    assert_eq!(data.findings[0].confidence.as_deref(), Some("likely"));
+
+    let text = generate_text_report(&data);
+    assert!(text.contains("CVSS:         5.3"));
+    assert!(text.contains("Confidence:   Likely"));
+    assert!(text.contains(
+        "Evidence:\n  Request:\n    GET / HTTP/1.1\n    Host: example.com\n  Response:\n    HTTP/1.1 200\n    content-type: text/html\n"
+    ));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&generate_json_report(&data).unwrap()).unwrap();
+    let cvss = json["report"]["findings"][0]["cvss_score"]
+        .as_f64()
+        .unwrap();
+    assert!((cvss - 5.3).abs() < 1e-6);
+}
+
+#[test]
+fn test_jsonl_requires_session_record() {
+    let input = r#"{"type":"node","url":"http://example.com/","status_code":200}"#;
+    let result = read_jsonl_report(Cursor::new(input), false, false);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Coverage Tests
+// ============================================================================
+
+#[test]
+fn test_text_report_coverage_section() {
+    let report = ReportData {
+        session_id: "test-session-coverage".to_string(),
+        total_nodes: 2,
+        findings: vec![],
+        severity_counts: SeverityCounts {
+            critical: 0,
+            high: 0,
+            medium: 0,
+            low: 0,
+            info: 0,
+        },
+        occurrence_counts: None,
+        scan_info: ScanInfo {
+            start_time: 1234567890,
+            end_time: Some(1234567900),
+            status: "completed".to_string(),
+            seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
+        },
+        sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: Some(CrawlStats {
+            max_depth_reached: 2,
+            depth_limit: 2,
+            unique_hosts: 1,
+            links_discovered: 10,
+            pages_crawled: 6,
+            depth_limit_hit: true,
+            max_pages: None,
+            page_limit_hit: false,
+            stalled: false,
+            ..CrawlStats::default()
+        }),
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
+    };
+
+    let text = generate_text_report(&report);
+    assert!(text.contains("COVERAGE"));
+    assert!(text.contains("Max Depth:    2 of 2"));
+    assert!(text.contains("Links:        10 discovered, 6 crawled (4 not crawled)"));
+    assert!(text.contains("Limits Hit:   depth"));
+}
+
+fn sample_timings() -> ScanTimings {
+    ScanTimings {
+        crawl_secs: 6.0,
+        analysis_secs: 2.5,
+        persistence_secs: 1.0,
+        total_secs: 10.0,
+        overlapping: false,
+    }
+}
+
+#[test]
+fn test_text_report_timing_breakdown() {
+    let mut report = severity_report();
+    report.timings = Some(sample_timings());
+
+    let text = generate_text_report(&report);
+    assert!(text.contains("SCAN DURATION BREAKDOWN"));
+    assert!(text.contains("Crawl:        6.00s (60%)"));
+    assert!(text.contains("Analysis:     2.50s (25%)"));
+    assert!(text.contains("Persistence:  1.00s (10%)"));
+    assert!(text.contains("Total:        10.00s"));
+}
+
+#[test]
+fn test_text_report_marks_phases_run_during_crawl() {
+    let mut report = severity_report();
+    report.timings = Some(ScanTimings {
+        crawl_secs: 8.0,
+        analysis_secs: 5.0,
+        persistence_secs: 4.0,
+        total_secs: 9.0,
+        overlapping: true,
+    });
+
+    let text = generate_text_report(&report);
+    assert!(text.contains("Crawl:        8.00s (89%)"));
+    assert!(text.contains("Analysis:     5.00s (during crawl)"));
+    assert!(text.contains("Persistence:  4.00s (during crawl)"));
+    assert!(text.contains("Total:        9.00s"));
+
+    let json: serde_json::Value = serde_json::from_str(&generate_json_report(&report).unwrap()).unwrap();
+    assert_eq!(json["timings"]["overlapping"], true);
+}
+
+#[test]
+fn test_text_report_omits_timing_without_data() {
+    let text = generate_text_report(&severity_report());
+    assert!(!text.contains("SCAN DURATION BREAKDOWN"));
+}
+
+#[test]
+fn test_jsonl_round_trip_keeps_timings() {
+    let mut report = severity_report();
+    report.timings = Some(sample_timings());
+
+    let jsonl = generate_jsonl_report(&report).unwrap();
+    let restored = read_jsonl_report(Cursor::new(jsonl), false, false).unwrap();
+    assert_eq!(restored.timings, Some(sample_timings()));
+}
+
+#[test]
+fn test_text_report_notes_page_budget_truncation() {
+    let coverage = CrawlStats {
+        max_depth_reached: 1,
+        depth_limit: 2,
+        unique_hosts: 1,
+        links_discovered: 5,
+        pages_crawled: 5,
+        depth_limit_hit: false,
+        max_pages: Some(5),
+        page_limit_hit: true,
+        stalled: false,
+        ..CrawlStats::default()
+    };
+
+    assert_eq!(coverage.limits_hit(), vec!["max-pages"]);
+
+    let mut report = severity_report();
+    report.coverage = Some(coverage);
+
+    let text = generate_text_report(&report);
+    assert!(text.contains("Limits Hit:   max-pages"));
+    assert!(text.contains("Truncated:    page budget of 5 reached"));
+}
+
+#[test]
+fn test_text_report_notes_queue_cap() {
+    let coverage = CrawlStats {
+        links_discovered: 8,
+        pages_crawled: 8,
+        max_queue_size: Some(5),
+        peak_queue_len: 5,
+        queue_dropped: 32,
+        ..CrawlStats::default()
+    };
+
+    assert_eq!(coverage.limits_hit(), vec!["max-queue-size"]);
+
+    let mut report = severity_report();
+    report.coverage = Some(coverage);
+
+    let text = generate_text_report(&report);
+    assert!(text.contains("Queue:        cap of 5 reached, 32 discovered links dropped"));
+}
+
+// ============================================================================
+// Sitemap Tree Tests
+// ============================================================================
+
+fn sitemap_report(nodes: Vec<SitemapNode>) -> String {
+    sitemap_diff_report(nodes, None)
+}
+
+fn sitemap_diff_report(nodes: Vec<SitemapNode>, baseline: Option<Vec<SitemapNode>>) -> String {
+    let report = ReportData {
+        session_id: "test-session-sitemap".to_string(),
+        total_nodes: nodes.len(),
+        findings: vec![],
+        severity_counts: SeverityCounts {
+            critical: 0,
+            high: 0,
+            medium: 0,
+            low: 0,
+            info: 0,
+        },
+        occurrence_counts: None,
+        scan_info: ScanInfo {
+            start_time: 1234567890,
+            end_time: Some(1234567900),
+            status: "completed".to_string(),
+            seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
+        },
+        sitemap_nodes: Some(nodes),
+        sitemap_baseline: baseline,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
+    };
+
+    generate_text_report(&report)
+}
+
+fn sitemap_node(url: &str, status_code: u16) -> SitemapNode {
+    SitemapNode {
+        url: url.to_string(),
+        status_code,
+        content_type: Some("text/html".to_string()),
+    }
+}
+
+#[test]
+fn test_sitemap_tree_shares_parent_directory() {
+    let text = sitemap_report(vec![
+        sitemap_node("http://example.com/a/b", 200),
+        sitemap_node("http://example.com/a/c", 404),
+    ]);
+
+    let expected = concat!(
+        "example.com\n",
+        "└── a/\n",
+        "    ├── b  [✓ 200] html\n",
+        "    └── c  [⚠ 404] html\n",
+    );
+    assert!(text.contains(expected), "unexpected sitemap:\n{}", text);
+    assert_eq!(text.matches("a/\n").count(), 1);
+}
+
+#[test]
+fn test_sitemap_tree_diff_against_baseline() {
+    let baseline = vec![
+        sitemap_node("http://example.com/", 200),
+        sitemap_node("http://example.com/api/old", 200),
+        sitemap_node("http://example.com/api/users", 200),
+    ];
+    let current = vec![
+        sitemap_node("http://example.com/", 200),
+        sitemap_node("http://example.com/api/users", 200),
+        sitemap_node("http://example.com/api/v2", 200),
+    ];
+
+    let text = sitemap_diff_report(current, Some(baseline));
+
+    let expected = concat!(
+        "  example.com  [✓ 200] html\n",
+        "  └── api/\n",
+        "-     ├── old  [✓ 200] html\n",
+        "      ├── users  [✓ 200] html\n",
+        "+     └── v2  [✓ 200] html\n",
+    );
+    assert!(text.contains(expected), "unexpected sitemap:\n{}", text);
+    assert!(text.contains("(+ added since baseline, - removed since baseline)"));
+}
+
+#[test]
+fn test_sitemap_tree_multiple_hosts_and_deep_paths() {
+    let text = sitemap_report(vec![
+        sitemap_node("http://example.com/", 200),
+        sitemap_node("http://example.com/api/v1/users/list", 200),
+        sitemap_node("http://example.com/login", 302),
+        sitemap_node("http://other.example.org/", 500),
+    ]);
+
+    let expected = concat!(
+        "example.com  [✓ 200] html\n",
+        "├── api/\n",
+        "│   └── v1/\n",
+        "│       └── users/\n",
+        "│           └── list  [✓ 200] html\n",
+        "└── login  [→ 302] html\n",
+        "other.example.org  [✗ 500] html\n",
+    );
+    assert!(text.contains(expected), "unexpected sitemap:\n{}", text);
+}
+
+// ============================================================================
+// SARIF Tests
+// ============================================================================
+
+#[test]
+fn test_sarif_report_structure() {
+    let report = ReportData {
+        session_id: "test-session-sarif".to_string(),
+        total_nodes: 1,
+        findings: vec![FindingData {
+            id: 1,
+            severity: "high".to_string(),
+            title: "Sensitive File Exposed".to_string(),
+            description: "A .env file is publicly readable".to_string(),
+            url: "http://example.com/.env".to_string(),
+            finding_type: "sensitive_file".to_string(),
+            cwe_id: Some("CWE-538".to_string()),
+            owasp_category: None,
+            cvss_score: None,
+            confidence: None,
+            impact: None,
+            remediation: None,
+            request_sample: None,
+            response_sample: None,
+        }],
+        severity_counts: SeverityCounts {
+            critical: 0,
+            high: 1,
+            medium: 0,
+            low: 0,
+            info: 0,
+        },
+        occurrence_counts: None,
+        scan_info: ScanInfo {
+            start_time: 1234567890,
+            end_time: Some(1234567900),
+            status: "completed".to_string(),
+            seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
+        },
+        sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
+    };
+
+    let sarif = generate_sarif_report(&report).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+
+    assert!(parsed["$schema"].as_str().unwrap().contains("sarif-2.1.0"));
+    assert_eq!(parsed["version"], "2.1.0");
+
+    let run = &parsed["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "rinzler");
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "sensitive_file");
+
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "sensitive_file");
+    assert_eq!(result["level"], "error");
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "http://example.com/.env"
+    );
+}
+
+// ============================================================================
+// Severity Color Tests
+// ============================================================================
+
+fn severity_report() -> ReportData {
+    ReportData {
+        session_id: "test-session-color".to_string(),
+        total_nodes: 1,
+        findings: vec![],
+        severity_counts: SeverityCounts {
+            critical: 1,
+            high: 2,
+            medium: 3,
+            low: 0,
+            info: 4,
+        },
+        occurrence_counts: None,
+        scan_info: ScanInfo {
+            start_time: 1234567890,
+            end_time: Some(1234567900),
+            status: "completed".to_string(),
+            seed_urls: "[\"http://example.com\"]".to_string(),
+            findings_dropped: 0,
+        },
+        sitemap_nodes: None,
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
+    }
+}
+
+#[test]
+fn test_toml_round_trip_preserves_findings() {
+    let mut report = severity_report();
+    report.findings = (1..=3)
+        .map(|id| FindingData {
+            id,
+            severity: "medium".to_string(),
+            title: format!("Finding {}", id),
+            description: "Multi-line\ndescription with \"quotes\"".to_string(),
+            url: format!("http://example.com/{}", id),
+            finding_type: "security_header_missing".to_string(),
+            cwe_id: if id == 1 {
+                Some("CWE-1021".to_string())
+            } else {
+                None
+            },
+            owasp_category: None,
+            cvss_score: None,
+            confidence: None,
+            impact: None,
+            remediation: Some("Fix it".to_string()),
+            request_sample: None,
+            response_sample: None,
+        })
+        .collect();
+    report.sitemap_nodes = Some(vec![sitemap_node("http://example.com/", 200)]);
+    report.coverage = Some(CrawlStats {
+        pages_crawled: 1,
+        ..CrawlStats::default()
+    });
+
+    let toml = generate_toml_report(&report).unwrap();
+    assert_eq!(toml.matches("[[findings]]").count(), 3);
+
+    let parsed: ReportData = toml::from_str(&toml).unwrap();
+    assert_eq!(parsed.findings.len(), 3);
+    assert_eq!(parsed.findings[0].cwe_id.as_deref(), Some("CWE-1021"));
+    assert_eq!(parsed.findings[1].cwe_id, None);
+    assert_eq!(
+        parsed.findings[2].description,
+        report.findings[2].description
+    );
+    assert_eq!(
+        parsed.severity_counts.total(),
+        report.severity_counts.total()
+    );
+    assert_eq!(parsed.sitemap_nodes.unwrap().len(), 1);
+    assert_eq!(parsed.coverage, report.coverage);
+}
+
+#[test]
+fn test_text_report_colored_severity_tags() {
+    let text = generate_text_report_with_color(&severity_report(), true);
+
+    assert!(text.contains("\x1b[1;31m[CRITICAL]\x1b[0m 1"));
+    assert!(text.contains("\x1b[31m[HIGH]\x1b[0m     2"));
+    assert!(text.contains("\x1b[33m[MEDIUM]\x1b[0m   3"));
+    assert!(text.contains("\x1b[36m[INFO]\x1b[0m     4"));
+}
+
+#[test]
+fn test_saved_text_report_has_no_color() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("report.txt");
+
+    let text = generate_text_report(&severity_report());
+    save_report(&text, &path).unwrap();
+
+    let saved = std::fs::read_to_string(&path).unwrap();
+    assert!(saved.contains("[CRITICAL] 1"));
+    assert!(!saved.contains('\x1b'));
+}
+
+#[test]
+fn test_github_issues_one_block_per_finding() {
+    let mut report = severity_report();
+    report.findings = vec![
+        FindingData {
+            id: 1,
+            severity: "high".to_string(),
+            title: "Reflected <script> in *search*".to_string(),
+            description: "# not a heading\nuses `eval`".to_string(),
+            url: "http://example.com/search?q=1".to_string(),
+            finding_type: "xss".to_string(),
+            cwe_id: Some("CWE-79".to_string()),
+            owasp_category: Some("A03:2021".to_string()),
+            cvss_score: None,
+            confidence: None,
+            impact: Some("Session theft".to_string()),
+            remediation: Some("Encode output".to_string()),
+            request_sample: None,
+            response_sample: None,
+        },
+        FindingData {
+            id: 2,
+            severity: "low".to_string(),
+            title: "Server banner".to_string(),
+            description: "Version disclosed".to_string(),
+            url: "http://example.com/".to_string(),
+            finding_type: "information_disclosure".to_string(),
+            cwe_id: None,
+            owasp_category: None,
+            cvss_score: None,
+            confidence: None,
+            impact: None,
+            remediation: None,
+            request_sample: None,
+            response_sample: None,
+        },
+    ];
+
+    let output = generate_github_issues_report(&report);
+    let blocks: Vec<&str> = output.split("---\n\n").collect();
+    assert_eq!(blocks.len(), 2);
+
+    assert!(blocks[0].starts_with("## [HIGH] Reflected \\<script\\> in \\*search\\*\n"));
+    assert!(blocks[0].contains("**URL:** `http://example.com/search?q=1`"));
+    assert!(blocks[0].contains("### Description\n\n\\# not a heading\nuses \\`eval\\`"));
+    assert!(blocks[0].contains("### Impact\n\nSession theft"));
+    assert!(blocks[0].contains("### Remediation\n\nEncode output"));
+    assert!(blocks[0].contains("- [ ] CWE-79\n- [ ] OWASP A03:2021\n"));
+
+    assert!(blocks[1].starts_with("## [LOW] Server banner\n"));
+    assert!(!blocks[1].contains("### Impact"));
+    assert!(!blocks[1].contains("### References"));
+}
+
+fn junit_finding(id: i64, severity: &str, title: &str, url: &str) -> FindingData {
+    FindingData {
+        id,
+        severity: severity.to_string(),
+        title: title.to_string(),
+        description: "Details & <context>".to_string(),
+        url: url.to_string(),
+        finding_type: "misconfiguration".to_string(),
+        cwe_id: None,
+        owasp_category: None,
+        cvss_score: None,
+        confidence: None,
+        impact: None,
+        remediation: None,
+        request_sample: None,
+        response_sample: None,
+    }
+}
+
+fn junit_report() -> ReportData {
+    let mut report = severity_report();
+    report.findings = vec![
+        junit_finding(1, "critical", "SQL error leak", "http://example.com/api"),
+        junit_finding(2, "high", "Permissive CORS", "http://example.com/api"),
+        junit_finding(3, "high", "Exposed .env", "http://example.com/.env"),
+        junit_finding(4, "medium", "Missing \"nosniff\"", "http://example.com/"),
+        junit_finding(5, "info", "Injection point", "http://example.com/"),
+    ];
+    report.sitemap_nodes = Some(vec![
+        SitemapNode {
+            url: "http://example.com/".to_string(),
+            status_code: 200,
+            content_type: None,
+        },
+        SitemapNode {
+            url: "http://example.com/about".to_string(),
+            status_code: 200,
+            content_type: None,
+        },
+    ]);
+    report
+}
+
+#[test]
+fn test_report_format_from_str_junit() {
+    assert!(matches!(
+        ReportFormat::from_str("junit"),
+        Ok(ReportFormat::JUnit)
+    ));
+}
+
+#[test]
+fn test_junit_failures_match_high_severity_findings() {
+    let report = junit_report();
+    let high_or_above = report
+        .findings
+        .iter()
+        .filter(|f| f.severity == "critical" || f.severity == "high")
+        .count();
+
+    let xml = generate_junit_report_with_threshold(&report, &Severity::High);
+
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuite "));
+    // Five findings plus the one page without findings
+    assert!(xml.contains(&format!(
+        "tests=\"6\" failures=\"{}\"",
+        high_or_above
+    )));
+    assert_eq!(xml.matches("<failure ").count(), high_or_above);
+    assert!(xml.contains(
+        "<testcase name=\"Permissive CORS\" classname=\"http://example.com/api\">"
+    ));
+    assert!(xml.contains("<testcase name=\"Injection point\" classname=\"http://example.com/\"/>"));
+    assert!(xml.contains("<testcase name=\"no findings\" classname=\"http://example.com/about\"/>"));
+    assert!(xml.trim_end().ends_with("</testsuite>"));
+}
+
+#[test]
+fn test_junit_default_threshold_is_medium_and_escapes_xml() {
+    let xml = generate_junit_report(&junit_report());
+
+    assert!(xml.contains("failures=\"4\""));
+    assert!(xml.contains("name=\"Missing &quot;nosniff&quot;\""));
+    assert!(xml.contains(">Details &amp; &lt;context&gt;</failure>"));
+}
+
+#[test]
+fn test_group_findings_collapses_identical_findings() {
+    let findings = vec![
+        junit_finding(1, "high", "Permissive CORS", "http://example.com/a"),
+        junit_finding(2, "high", "Permissive CORS", "http://example.com/b"),
+        junit_finding(3, "high", "Permissive CORS", "http://example.com/c"),
+        junit_finding(4, "medium", "Missing \"nosniff\"", "http://example.com/a"),
+    ];
+
+    let groups = group_findings(&findings);
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].title, "Permissive CORS");
+    assert_eq!(groups[0].count, 3);
+    assert_eq!(
+        groups[0].urls,
+        vec![
+            "http://example.com/a",
+            "http://example.com/b",
+            "http://example.com/c"
+        ]
+    );
+    assert_eq!(groups[1].count, 1);
+}
+
+#[test]
+fn test_text_report_lists_grouped_findings() {
+    let mut report = junit_report();
+    report.findings = vec![
+        junit_finding(1, "high", "Permissive CORS", "http://example.com/a"),
+        junit_finding(2, "high", "Permissive CORS", "http://example.com/b"),
+        junit_finding(3, "high", "Permissive CORS", "http://example.com/c"),
+    ];
+    report.grouped_findings = Some(group_findings(&report.findings));
+
+    let text = generate_text_report(&report);
+
+    assert!(text.contains("GROUPED FINDINGS"));
+    assert!(!text.contains("DETAILED FINDINGS"));
+    assert_eq!(text.matches("Permissive CORS").count(), 1);
+    assert!(text.contains("[1] Permissive CORS (x3)"));
+    assert!(text.contains("Affected URLs (3):\n  http://example.com/a\n  http://example.com/b\n  http://example.com/c\n"));
+}
+
+#[test]
+fn test_text_report_lists_changed_since_last_scan() {
+    let mut report = severity_report();
+    assert!(!generate_text_report(&report).contains("CHANGED SINCE LAST SCAN"));
+
+    report.changed_nodes = vec![
+        ChangedNode {
+            url: "http://example.com/".to_string(),
+            change: "changed".to_string(),
+            status_code: Some(200),
+            content_length: Some(2048),
+            previous_status_code: Some(200),
+            previous_content_length: Some(1024),
+        },
+        ChangedNode {
+            url: "http://example.com/new".to_string(),
+            change: "new".to_string(),
+            status_code: Some(200),
+            content_length: Some(10),
+            previous_status_code: None,
+            previous_content_length: None,
+        },
+    ];
+
+    let text = generate_text_report(&report);
+    assert!(text.contains("CHANGED SINCE LAST SCAN"));
+    assert!(text.contains("[CHANGED] http://example.com/  (200 1024B -> 200 2048B)"));
+    assert!(text.contains("[NEW]     http://example.com/new"));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&generate_json_report(&report).unwrap()).unwrap();
+    assert_eq!(
+        json["report"]["changed_since_last_scan"]
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+}
@@ -2,7 +2,9 @@
 
 use rinzler_core::data::{FindingType, Severity};
 use rinzler_core::security::{
-    analyze_crawl_result, check_error_messages, check_insecure_transport, check_interesting_files,
+    analyze_crawl_result, check_auth_surface, check_error_messages, check_injection_points,
+    check_insecure_transport, check_interesting_files, check_mime_sniffing,
+    check_version_disclosure,
 };
 use rinzler_scanner::result::CrawlResult;
 
@@ -212,6 +214,22 @@ fn test_check_error_messages_500() {
     assert!(findings[0].title.contains("500"));
 }
 
+#[test]
+fn test_check_error_messages_captures_exchange() {
+    let mut result = create_test_result("http://example.com:8080/api?id=1", 500, Some("text/html"));
+    result.headers = vec![("content-type".to_string(), "text/html".to_string())];
+    result.body_sample = Some(format!("Traceback (most recent call last){}", "x".repeat(600)));
+    let findings = check_error_messages(&result, 1);
+
+    assert_eq!(
+        findings[0].request_sample.as_deref(),
+        Some("GET /api?id=1 HTTP/1.1\nHost: example.com:8080")
+    );
+    let response = findings[0].response_sample.as_deref().unwrap();
+    assert!(response.starts_with("HTTP/1.1 500\ncontent-type: text/html\n\nTraceback"));
+    assert!(response.ends_with("\n[truncated]"));
+}
+
 #[test]
 fn test_check_error_messages_502() {
     let result = create_test_result("http://example.com/api", 502, Some("text/html"));
@@ -246,6 +264,235 @@ fn test_check_error_messages_404() {
     assert_eq!(findings.len(), 0);
 }
 
+// ============================================================================
+// Auth Surface Tests
+// ============================================================================
+
+#[test]
+fn test_check_auth_surface_login_form() {
+    let mut result = create_test_result("https://example.com/login", 200, Some("text/html"));
+    result.forms_found = 1;
+    result.password_forms_found = 1;
+
+    let findings = check_auth_surface(&result, 1);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].severity, Severity::Info);
+    assert!(matches!(
+        findings[0].finding_type,
+        FindingType::AuthenticationIssue
+    ));
+    assert!(findings[0].title.contains("Login Form"));
+}
+
+#[test]
+fn test_check_auth_surface_admin_accessible() {
+    let result = create_test_result("https://example.com/admin", 200, Some("text/html"));
+    let findings = check_auth_surface(&result, 1);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].severity, Severity::Low);
+    assert!(matches!(
+        findings[0].finding_type,
+        FindingType::AuthorizationIssue
+    ));
+}
+
+#[test]
+fn test_check_auth_surface_admin_redirects_to_login() {
+    // Redirect not followed
+    let result = create_test_result("https://example.com/admin", 302, Some("text/html"));
+    assert!(check_auth_surface(&result, 1).is_empty());
+
+    // Redirect followed to the login page
+    let mut result = create_test_result("https://example.com/dashboard", 200, Some("text/html"));
+    result.redirected_to = Some("https://example.com/login?next=/dashboard".to_string());
+    assert!(check_auth_surface(&result, 1).is_empty());
+}
+
+#[test]
+fn test_check_auth_surface_non_admin_path() {
+    let result = create_test_result("https://example.com/about", 200, Some("text/html"));
+    assert!(check_auth_surface(&result, 1).is_empty());
+}
+
+// ============================================================================
+// MIME Sniffing Tests
+// ============================================================================
+
+fn with_headers(mut result: CrawlResult, headers: &[(&str, &str)]) -> CrawlResult {
+    result.headers = headers
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    result
+}
+
+#[test]
+fn test_check_mime_sniffing_json_without_nosniff() {
+    let result = with_headers(
+        create_test_result(
+            "https://example.com/api/users",
+            200,
+            Some("application/json"),
+        ),
+        &[("content-type", "application/json")],
+    );
+    let findings = check_mime_sniffing(&result, 1);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].severity, Severity::Low);
+    assert!(matches!(
+        findings[0].finding_type,
+        FindingType::SecurityHeaderMissing
+    ));
+    assert_eq!(findings[0].cwe_id.as_deref(), Some("CWE-430"));
+    assert!(
+        findings[0]
+            .remediation
+            .as_ref()
+            .unwrap()
+            .contains("nosniff")
+    );
+}
+
+#[test]
+fn test_check_mime_sniffing_json_with_nosniff() {
+    let result = with_headers(
+        create_test_result(
+            "https://example.com/api/users",
+            200,
+            Some("application/json"),
+        ),
+        &[
+            ("content-type", "application/json"),
+            ("x-content-type-options", "nosniff"),
+        ],
+    );
+    assert!(check_mime_sniffing(&result, 1).is_empty());
+}
+
+#[test]
+fn test_check_mime_sniffing_upload_served_as_html() {
+    let result = with_headers(
+        create_test_result("https://example.com/uploads/avatar", 200, Some("text/html")),
+        &[("content-type", "text/html")],
+    );
+    assert_eq!(check_mime_sniffing(&result, 1).len(), 1);
+}
+
+#[test]
+fn test_check_mime_sniffing_ignores_regular_pages_and_missing_headers() {
+    let page = with_headers(
+        create_test_result("https://example.com/about", 200, Some("text/html")),
+        &[("content-type", "text/html")],
+    );
+    assert!(check_mime_sniffing(&page, 1).is_empty());
+
+    // No captured headers means nothing to judge
+    let json = create_test_result(
+        "https://example.com/api/users",
+        200,
+        Some("application/json"),
+    );
+    assert!(check_mime_sniffing(&json, 1).is_empty());
+}
+
+// ============================================================================
+// Version Disclosure Tests
+// ============================================================================
+
+#[test]
+fn test_check_version_disclosure_server_with_version() {
+    let result = with_headers(
+        create_test_result("https://example.com/", 200, Some("text/html")),
+        &[("server", "nginx/1.18.0")],
+    );
+    let findings = check_version_disclosure(&result, 1);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].severity, Severity::Low);
+    assert!(matches!(
+        findings[0].finding_type,
+        FindingType::InformationDisclosure
+    ));
+    assert!(
+        findings[0]
+            .evidence
+            .as_ref()
+            .unwrap()
+            .contains("nginx/1.18.0")
+    );
+}
+
+#[test]
+fn test_check_version_disclosure_server_without_version() {
+    let result = with_headers(
+        create_test_result("https://example.com/", 200, Some("text/html")),
+        &[("server", "nginx")],
+    );
+    let findings = check_version_disclosure(&result, 1);
+
+    assert!(findings.is_empty());
+}
+
+#[test]
+fn test_check_version_disclosure_each_banner_header() {
+    let result = with_headers(
+        create_test_result("https://example.com/", 200, Some("text/html")),
+        &[
+            ("x-powered-by", "PHP/7.4.3"),
+            ("x-aspnet-version", "4.0.30319"),
+            ("x-frame-options", "SAMEORIGIN 1.0"),
+        ],
+    );
+    let findings = check_version_disclosure(&result, 1);
+
+    assert_eq!(findings.len(), 2);
+}
+
+// ============================================================================
+// Injection Point Tests
+// ============================================================================
+
+#[test]
+fn test_check_injection_points_url_with_parameters() {
+    let result = create_test_result(
+        "https://example.com/search?q=test&page=2",
+        200,
+        Some("text/html"),
+    );
+    let findings = check_injection_points(&result, 1);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].severity, Severity::Info);
+    assert!(matches!(
+        findings[0].finding_type,
+        FindingType::InjectionPoint
+    ));
+    let evidence: serde_json::Value =
+        serde_json::from_str(findings[0].evidence.as_ref().unwrap()).unwrap();
+    assert_eq!(evidence["parameters"], serde_json::json!(["q", "page"]));
+}
+
+#[test]
+fn test_check_injection_points_form_inputs() {
+    let mut result = create_test_result("https://example.com/login", 200, Some("text/html"));
+    result.parameters = vec!["user".to_string(), "pass".to_string()];
+    let findings = check_injection_points(&result, 1);
+
+    assert_eq!(findings.len(), 1);
+    assert!(findings[0].description.contains("user, pass"));
+}
+
+#[test]
+fn test_check_injection_points_without_parameters() {
+    let result = create_test_result("https://example.com/about", 200, Some("text/html"));
+    let findings = check_injection_points(&result, 1);
+
+    assert!(findings.is_empty());
+}
+
 // ============================================================================
 // Integrated Analysis Tests
 // ============================================================================
@@ -0,0 +1,138 @@
+// Tests for the local JSON API
+
+use rinzler_core::data::{Confidence, CrawlNode, Database, Finding, FindingType, Severity};
+use rinzler_core::serve::{handle_request, serve};
+use tempfile::TempDir;
+
+fn create_test_db() -> (TempDir, Database) {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::new(&db_path).unwrap();
+    (temp_dir, db)
+}
+
+/// Insert a completed session with one node and one finding
+fn insert_session(db: &Database) -> String {
+    let session_id = db
+        .create_session("crawl", "[\"http://example.com\"]")
+        .unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+
+    let node = CrawlNode {
+        url: "http://example.com/.env".to_string(),
+        domain: "example.com".to_string(),
+        status_code: 200,
+        content_type: Some("text/plain".to_string()),
+        content_length: None,
+        response_time_ms: None,
+        title: None,
+        forms_count: 0,
+        inputs_count: 0,
+        parameters: None,
+        service_type: None,
+        headers: None,
+        body_sample: None,
+        content_hash: None,
+    };
+    let node_id = db.insert_node(&map_id, &node).unwrap();
+
+    let finding = Finding {
+        node_id,
+        finding_type: FindingType::InterestingFile,
+        severity: Severity::Critical,
+        title: "Environment File Exposed".to_string(),
+        description: "Discovered http://example.com/.env".to_string(),
+        impact: None,
+        remediation: None,
+        evidence: None,
+        cwe_id: Some("CWE-200".to_string()),
+        owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Likely,
+        request_sample: None,
+        response_sample: None,
+    };
+    db.insert_finding(&session_id, &finding).unwrap();
+    db.complete_session(&session_id).unwrap();
+
+    session_id
+}
+
+fn get_json(db: &Database, path: &str, query: Option<&str>) -> serde_json::Value {
+    let response = handle_request(db, "GET", path, query);
+    assert_eq!(response.status, 200, "{}", response.body);
+    serde_json::from_str(&response.body).unwrap()
+}
+
+#[tokio::test]
+async fn test_serve_lists_sessions_over_http() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = insert_session(&db);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(serve(db, listener));
+
+    let response = reqwest::get(format!("http://{}/sessions", addr))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "application/json"
+    );
+
+    let sessions: serde_json::Value = response.json().await.unwrap();
+    let sessions = sessions.as_array().unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0]["session_id"], session_id.as_str());
+    assert_eq!(sessions[0]["status"], "completed");
+    assert_eq!(sessions[0]["total_nodes"], 1);
+    assert_eq!(sessions[0]["severity_counts"]["critical"], 1);
+
+    server.abort();
+}
+
+#[test]
+fn test_session_nodes_and_findings() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = insert_session(&db);
+
+    let nodes = get_json(&db, &format!("/sessions/{}/nodes", session_id), None);
+    assert_eq!(nodes.as_array().unwrap().len(), 1);
+    assert_eq!(nodes[0]["url"], "http://example.com/.env");
+
+    let findings = get_json(&db, &format!("/sessions/{}/findings", session_id), None);
+    assert_eq!(findings.as_array().unwrap().len(), 1);
+    assert_eq!(findings[0]["title"], "Environment File Exposed");
+    assert_eq!(findings[0]["severity"], "critical");
+}
+
+#[test]
+fn test_session_report_formats() {
+    let (_temp_dir, db) = create_test_db();
+    let session_id = insert_session(&db);
+    let path = format!("/sessions/{}/report", session_id);
+
+    let report = get_json(&db, &path, Some("format=json"));
+    assert_eq!(report["report"]["session"]["id"], session_id.as_str());
+
+    let sarif = get_json(&db, &path, Some("format=sarif"));
+    assert_eq!(sarif["version"], "2.1.0");
+
+    let unsupported = handle_request(&db, "GET", &path, Some("format=pdf"));
+    assert_eq!(unsupported.status, 400);
+}
+
+#[test]
+fn test_unknown_routes_and_methods() {
+    let (_temp_dir, db) = create_test_db();
+
+    assert_eq!(
+        handle_request(&db, "GET", "/sessions/missing/nodes", None).status,
+        404
+    );
+    assert_eq!(handle_request(&db, "GET", "/nodes", None).status, 404);
+    assert_eq!(handle_request(&db, "DELETE", "/sessions", None).status, 405);
+    assert_eq!(get_json(&db, "/sessions", None), serde_json::json!([]));
+}
@@ -0,0 +1,211 @@
+// Tests for technology fingerprinting
+
+use rinzler_core::data::ServiceType;
+use rinzler_core::tech::{
+    CONFIDENCE_COOKIE, CONFIDENCE_HEADER, CONFIDENCE_VERSIONED_HEADER, classify_service,
+    detect_technologies, detect_technologies_from_headers, technology_summary,
+};
+use rinzler_scanner::result::CrawlResult;
+
+fn headers(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_server_header_with_version() {
+    let techs = detect_technologies_from_headers(&headers(&[("server", "nginx/1.18.0 (Ubuntu)")]));
+
+    assert_eq!(techs.len(), 1);
+    assert_eq!(techs[0].name, "nginx");
+    assert_eq!(techs[0].version.as_deref(), Some("1.18.0"));
+    assert_eq!(techs[0].category, "web_server");
+    assert_eq!(techs[0].detection_method, "header");
+    assert_eq!(techs[0].confidence, CONFIDENCE_VERSIONED_HEADER);
+}
+
+#[test]
+fn test_server_header_without_version() {
+    let techs = detect_technologies_from_headers(&headers(&[("server", "cloudflare")]));
+
+    assert_eq!(techs.len(), 1);
+    assert_eq!(techs[0].name, "Cloudflare");
+    assert_eq!(techs[0].version, None);
+    assert_eq!(techs[0].category, "cdn");
+    assert_eq!(techs[0].confidence, CONFIDENCE_HEADER);
+}
+
+#[test]
+fn test_x_powered_by_with_package_suffix() {
+    let techs =
+        detect_technologies_from_headers(&headers(&[("x-powered-by", "PHP/8.1.2-1ubuntu2.14")]));
+
+    assert_eq!(techs.len(), 1);
+    assert_eq!(techs[0].name, "PHP");
+    assert_eq!(techs[0].version.as_deref(), Some("8.1.2-1ubuntu2.14"));
+    assert_eq!(techs[0].category, "framework");
+}
+
+#[test]
+fn test_space_separated_generator_header() {
+    let techs = detect_technologies_from_headers(&headers(&[(
+        "x-generator",
+        "Drupal 10 (https://www.drupal.org)",
+    )]));
+
+    assert_eq!(techs.len(), 1);
+    assert_eq!(techs[0].name, "Drupal");
+    assert_eq!(techs[0].version.as_deref(), Some("10"));
+    assert_eq!(techs[0].category, "cms");
+    assert_eq!(techs[0].confidence, CONFIDENCE_VERSIONED_HEADER);
+}
+
+#[test]
+fn test_aspnet_version_header() {
+    let techs = detect_technologies_from_headers(&headers(&[("x-aspnet-version", "4.0.30319")]));
+
+    assert_eq!(techs.len(), 1);
+    assert_eq!(techs[0].name, "ASP.NET");
+    assert_eq!(techs[0].version.as_deref(), Some("4.0.30319"));
+    assert_eq!(techs[0].confidence, CONFIDENCE_VERSIONED_HEADER);
+}
+
+#[test]
+fn test_non_numeric_version_is_ignored() {
+    let techs = detect_technologies_from_headers(&headers(&[("x-powered-by", "Express/latest")]));
+
+    assert_eq!(techs.len(), 1);
+    assert_eq!(techs[0].name, "Express");
+    assert_eq!(techs[0].version, None);
+    assert_eq!(techs[0].confidence, CONFIDENCE_HEADER);
+}
+
+#[test]
+fn test_session_cookie_detection() {
+    let techs = detect_technologies_from_headers(&headers(&[(
+        "set-cookie",
+        "JSESSIONID=abc123; Path=/; HttpOnly",
+    )]));
+
+    assert_eq!(techs.len(), 1);
+    assert_eq!(techs[0].name, "Java Servlet");
+    assert_eq!(techs[0].detection_method, "cookie");
+    assert_eq!(techs[0].confidence, CONFIDENCE_COOKIE);
+}
+
+#[test]
+fn test_strongest_evidence_wins() {
+    let techs = detect_technologies_from_headers(&headers(&[
+        ("set-cookie", "PHPSESSID=xyz; path=/"),
+        ("x-powered-by", "PHP/7.4.33"),
+    ]));
+
+    assert_eq!(techs.len(), 1);
+    assert_eq!(techs[0].name, "PHP");
+    assert_eq!(techs[0].version.as_deref(), Some("7.4.33"));
+    assert_eq!(techs[0].confidence, CONFIDENCE_VERSIONED_HEADER);
+}
+
+#[test]
+fn test_unrelated_headers_detect_nothing() {
+    let mut result = CrawlResult::new("http://example.com/".to_string());
+    result.headers = headers(&[("content-type", "text/html"), ("set-cookie", "theme=dark")]);
+
+    assert!(detect_technologies(&result).is_empty());
+}
+
+#[test]
+fn test_classify_json_api_as_rest_api() {
+    assert_eq!(
+        classify_service(
+            Some("application/json; charset=utf-8"),
+            "http://example.com/api/users",
+            200
+        ),
+        Some(ServiceType::RestApi)
+    );
+}
+
+#[test]
+fn test_classify_api_path_without_content_type_as_rest_api() {
+    assert_eq!(
+        classify_service(None, "http://example.com/api/v1/health", 200),
+        Some(ServiceType::RestApi)
+    );
+}
+
+#[test]
+fn test_classify_graphql_endpoint() {
+    assert_eq!(
+        classify_service(Some("application/json"), "http://example.com/graphql", 200),
+        Some(ServiceType::GraphQL)
+    );
+}
+
+#[test]
+fn test_classify_soap_endpoint() {
+    assert_eq!(
+        classify_service(Some("application/soap+xml"), "http://example.com/ws", 200),
+        Some(ServiceType::Soap)
+    );
+}
+
+#[test]
+fn test_classify_html_as_web() {
+    assert_eq!(
+        classify_service(Some("text/html"), "http://example.com/", 200),
+        Some(ServiceType::Web)
+    );
+}
+
+#[test]
+fn test_classify_static_assets() {
+    for content_type in [
+        "application/javascript",
+        "text/css",
+        "image/png",
+        "font/woff2",
+    ] {
+        assert_eq!(
+            classify_service(Some(content_type), "http://example.com/asset", 200),
+            Some(ServiceType::Static),
+            "{}",
+            content_type
+        );
+    }
+}
+
+#[test]
+fn test_classify_redirect() {
+    assert_eq!(
+        classify_service(Some("text/html"), "http://example.com/old", 301),
+        Some(ServiceType::Redirect)
+    );
+}
+
+#[test]
+fn test_classify_unknown_response_is_none() {
+    assert_eq!(
+        classify_service(Some("application/pdf"), "http://example.com/doc", 200),
+        None
+    );
+    assert_eq!(
+        classify_service(Some("text/html"), "http://example.com/", 0),
+        None
+    );
+}
+
+#[test]
+fn test_technology_summary_formats_versions() {
+    let technologies = vec![
+        ("nginx".to_string(), None),
+        ("PHP".to_string(), Some("7.4".to_string())),
+    ];
+    assert_eq!(
+        technology_summary(&technologies).unwrap(),
+        "Technologies: nginx, PHP/7.4"
+    );
+    assert!(technology_summary(&[]).is_none());
+}
@@ -0,0 +1,165 @@
+// Tests for finding webhook alerts
+
+use rinzler_core::batch::BatchSettings;
+use rinzler_core::data::{Confidence, Finding, FindingType, Severity};
+use rinzler_core::webhook::WebhookNotifier;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn finding(title: &str, severity: Severity) -> Finding {
+    Finding {
+        node_id: 1,
+        finding_type: FindingType::InformationDisclosure,
+        severity,
+        title: title.to_string(),
+        description: String::new(),
+        impact: None,
+        remediation: None,
+        evidence: None,
+        cwe_id: None,
+        owasp_category: None,
+        cvss_score: None,
+        confidence: Confidence::Confirmed,
+        request_sample: None,
+        response_sample: None,
+    }
+}
+
+fn batch(size: usize, flush_interval: Duration) -> BatchSettings {
+    BatchSettings {
+        size,
+        flush_interval,
+    }
+}
+
+#[tokio::test]
+async fn test_critical_finding_triggers_one_webhook_post() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(body_json(serde_json::json!({
+            "session_id": "session-1",
+            "findings": [{
+                "title": "Exposed .env File",
+                "severity": "critical",
+                "url": "http://example.com/.env"
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let notifier = WebhookNotifier::spawn(
+        format!("{}/hook", server.uri()),
+        "session-1".to_string(),
+        Severity::High,
+        batch(100, Duration::from_millis(50)),
+        |_| {},
+    );
+    // The low finding is under the threshold and must not reach the payload
+    notifier.sender().notify(
+        "http://example.com/.env",
+        &[
+            finding("Exposed .env File", Severity::Critical),
+            finding("Missing Security Headers", Severity::Low),
+        ],
+    );
+    notifier.finish().await;
+
+    server.verify().await;
+}
+
+#[tokio::test]
+async fn test_webhook_failure_is_reported_not_fatal() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let errors_clone = errors.clone();
+    let notifier = WebhookNotifier::spawn(
+        server.uri(),
+        "session-1".to_string(),
+        Severity::High,
+        batch(100, Duration::from_millis(50)),
+        move |message| errors_clone.lock().unwrap().push(message),
+    );
+    notifier
+        .sender()
+        .notify("http://example.com/", &[finding("Permissive CORS", Severity::High)]);
+    notifier.finish().await;
+
+    let errors = errors.lock().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains("500"), "{}", errors[0]);
+}
+
+/// Number of findings carried by each POST the server received, in order
+async fn posted_batch_sizes(server: &MockServer) -> Vec<usize> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| {
+            let payload: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            payload["findings"].as_array().unwrap().len()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_findings_posted_in_batches_with_partial_final_batch() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    // A long interval so only the batch size and the final flush send anything
+    let notifier = WebhookNotifier::spawn(
+        server.uri(),
+        "session-1".to_string(),
+        Severity::High,
+        batch(3, Duration::from_secs(60)),
+        |_| {},
+    );
+    let findings: Vec<Finding> = (0..7)
+        .map(|i| finding(&format!("Finding {}", i), Severity::High))
+        .collect();
+    notifier.sender().notify("http://example.com/", &findings);
+    notifier.finish().await;
+
+    assert_eq!(posted_batch_sizes(&server).await, vec![3, 3, 1]);
+}
+
+#[tokio::test]
+async fn test_partial_batch_flushed_after_interval() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let notifier = WebhookNotifier::spawn(
+        server.uri(),
+        "session-1".to_string(),
+        Severity::High,
+        batch(100, Duration::from_millis(50)),
+        |_| {},
+    );
+    notifier
+        .sender()
+        .notify("http://example.com/", &[finding("Permissive CORS", Severity::High)]);
+
+    // Sent by the interval while the notifier is still running
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(posted_batch_sizes(&server).await, vec![1]);
+    notifier.finish().await;
+}
@@ -1,23 +1,99 @@
+use crate::client::{ClientConfig, RequestSettings, build_client, retry_after};
 use crate::error::{Result, ScanError};
-use crate::result::CrawlResult;
+use crate::result::{CrawlResult, CrawlStats};
 use reqwest::Client;
 use scraper::{Html, Selector};
-use std::collections::{HashSet, VecDeque};
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
-use tokio::sync::Mutex;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Semaphore};
 use tracing::{debug, info, warn};
-use url::Url;
+use url::{Host, Url};
 
 pub type ProgressCallback = Arc<dyn Fn(usize, String) + Send + Sync>;
 pub type CrossDomainCallback = Arc<dyn Fn(String, String) -> bool + Send + Sync>;
 pub type ResultCallback = Arc<dyn Fn(CrawlResult) + Send + Sync>;
 
-type VisitedUrls = Arc<Mutex<HashSet<String>>>;
+type VisitedUrls = Arc<VisitedSet>;
 type CrawlResults = Arc<Mutex<Vec<CrawlResult>>>;
 type WorkerQueues = Arc<Vec<Mutex<VecDeque<(String, usize)>>>>;
 type WorkCounter = Arc<AtomicUsize>;
+type HostSemaphores = Arc<Mutex<HashMap<String, Arc<Semaphore>>>>;
+
+/// Simultaneous requests allowed to one host unless configured otherwise
+pub const DEFAULT_MAX_PER_HOST: usize = 8;
+
+/// Response body bytes read per page unless configured otherwise (5 MB)
+pub const DEFAULT_MAX_BODY_BYTES: usize = 5 * 1024 * 1024;
+
+/// Leading body bytes kept on a `CrawlResult` for passive checks (64 KB)
+pub const BODY_SAMPLE_BYTES: usize = 64 * 1024;
+
+/// Independently locked shards in the visited set
+const VISITED_SHARDS: usize = 64;
+
+/// Normalized URLs already queued, split across shards so workers deduplicating
+/// links at the same time rarely wait on the same lock
+struct VisitedSet {
+    shards: Vec<StdMutex<HashSet<String>>>,
+}
+
+impl VisitedSet {
+    fn new() -> Self {
+        Self {
+            shards: (0..VISITED_SHARDS)
+                .map(|_| StdMutex::new(HashSet::new()))
+                .collect(),
+        }
+    }
+
+    fn shard(&self, key: &str) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let index = (hasher.finish() as usize) % self.shards.len();
+        self.shards[index]
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Insert `key`, returning false if it was already present
+    fn insert(&self, key: String) -> bool {
+        self.shard(&key).insert(key)
+    }
+
+    /// Insert `key` if it is new and `admit` agrees; both run under the key's shard
+    /// lock, so two workers can never both admit the same URL
+    fn insert_if(&self, key: String, admit: impl FnOnce() -> bool) -> bool {
+        let mut shard = self.shard(&key);
+        !shard.contains(&key) && admit() && shard.insert(key)
+    }
+
+    fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| {
+                shard
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .len()
+            })
+            .sum()
+    }
+}
+
+/// What `extract_elements_static` finds on an HTML page
+struct PageElements {
+    links: Vec<String>,
+    forms: usize,
+    password_forms: usize,
+    scripts: usize,
+    inputs: usize,
+    parameters: Vec<String>,
+}
 
 pub struct Crawler {
     client: Client,
@@ -29,8 +105,26 @@ pub struct Crawler {
     cross_domain_callback: Option<CrossDomainCallback>,
     result_callback: Option<ResultCallback>,
     auto_follow: bool,
-    #[allow(dead_code)]
-    timeout_secs: u64,
+    client_config: ClientConfig,
+    request_delay: Option<Duration>,
+    max_per_host: usize,
+    max_body_bytes: usize,
+    skip_binary_bodies: bool,
+    host_semaphores: HostSemaphores,
+    idle_timeout: Option<Duration>,
+    max_pages: Option<usize>,
+    pages_claimed: Arc<AtomicUsize>,
+    max_queue_size: Option<usize>,
+    max_path_depth: Option<usize>,
+    queue_len: Arc<AtomicUsize>,
+    peak_queue_len: Arc<AtomicUsize>,
+    queue_dropped: Arc<AtomicUsize>,
+    max_depth_reached: Arc<AtomicUsize>,
+    depth_limit_hit: Arc<AtomicBool>,
+    page_limit_hit: Arc<AtomicBool>,
+    stalled: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
 }
 
 impl Crawler {
@@ -39,21 +133,16 @@ impl Crawler {
     }
 
     pub fn with_timeout(timeout_secs: u64) -> Self {
-        let client = Client::builder()
-            .user_agent("Rinzler/0.1 (https://github.com/trapdoorsec/rinzler)")
-            .timeout(std::time::Duration::from_secs(timeout_secs))
-            .connect_timeout(std::time::Duration::from_secs(timeout_secs / 2))
-            .pool_max_idle_per_host(50) // Connection pooling
-            .pool_idle_timeout(std::time::Duration::from_secs(90))
-            .http2_adaptive_window(true) // Enable HTTP/2 with adaptive flow control
-            .tcp_keepalive(std::time::Duration::from_secs(60))
-            .redirect(reqwest::redirect::Policy::limited(5))
-            .build()
-            .expect("Failed to create HTTP client");
+        let client_config = ClientConfig {
+            timeout_secs,
+            cookies: true,
+            ..ClientConfig::default()
+        };
+        let client = build_client(&client_config).expect("Failed to create HTTP client");
 
         Self {
             client,
-            visited: Arc::new(Mutex::new(HashSet::new())),
+            visited: Arc::new(VisitedSet::new()),
             results: Arc::new(Mutex::new(Vec::new())),
             max_depth: 3,
             base_domain: None,
@@ -61,17 +150,125 @@ impl Crawler {
             cross_domain_callback: None,
             result_callback: None,
             auto_follow: false,
-            timeout_secs,
+            client_config,
+            request_delay: None,
+            max_per_host: DEFAULT_MAX_PER_HOST,
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            skip_binary_bodies: true,
+            host_semaphores: Arc::new(Mutex::new(HashMap::new())),
+            idle_timeout: None,
+            max_pages: None,
+            pages_claimed: Arc::new(AtomicUsize::new(0)),
+            max_queue_size: None,
+            max_path_depth: None,
+            queue_len: Arc::new(AtomicUsize::new(0)),
+            peak_queue_len: Arc::new(AtomicUsize::new(0)),
+            queue_dropped: Arc::new(AtomicUsize::new(0)),
+            max_depth_reached: Arc::new(AtomicUsize::new(0)),
+            depth_limit_hit: Arc::new(AtomicBool::new(false)),
+            page_limit_hit: Arc::new(AtomicBool::new(false)),
+            stalled: Arc::new(AtomicBool::new(false)),
+            paused: Arc::new(AtomicBool::new(false)),
+            stop: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// Rebuild the HTTP client with custom connection pool settings
+    pub fn with_pool_settings(mut self, pool_size: usize, idle_timeout_secs: u64) -> Result<Self> {
+        self.client_config.pool_size = pool_size;
+        self.client_config.pool_idle_timeout_secs = idle_timeout_secs;
+        self.client = build_client(&self.client_config)?;
+        Ok(self)
+    }
+
+    /// Rebuild the HTTP client to send this User-Agent instead of the Rinzler default
+    pub fn with_user_agent(mut self, user_agent: String) -> Result<Self> {
+        self.client_config.user_agent = user_agent;
+        self.client = build_client(&self.client_config)?;
+        Ok(self)
+    }
+
+    /// Rebuild the HTTP client with or without a cookie jar (on by default), which
+    /// keeps session cookies set by one page for the rest of the crawl
+    pub fn with_cookies(mut self, enabled: bool) -> Result<Self> {
+        self.client_config.cookies = enabled;
+        self.client = build_client(&self.client_config)?;
+        Ok(self)
+    }
+
+    /// Rebuild the HTTP client with extra headers and/or a proxy
+    pub fn with_request_settings(mut self, request: RequestSettings) -> Result<Self> {
+        self.client_config.request = request;
+        self.client = build_client(&self.client_config)?;
+        Ok(self)
+    }
+
+    /// Fetch with an already configured client, e.g. one holding a login session.
+    /// Call it after any builder that rebuilds the client, which would replace it.
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Settings the crawler's own client was built from
+    pub fn client_config(&self) -> &ClientConfig {
+        &self.client_config
+    }
+
+    /// Wait this long before each request, per worker
+    pub fn with_request_delay(mut self, delay: Duration) -> Self {
+        self.request_delay = Some(delay);
+        self
+    }
+
+    /// Cap simultaneous requests to any one host (at least 1), whatever the worker count
+    pub fn with_max_per_host(mut self, max_per_host: usize) -> Self {
+        self.max_per_host = max_per_host.max(1);
+        self
+    }
+
+    /// Stop reading a response body after this many bytes; larger pages are
+    /// recorded as truncated and only the bytes read are parsed
+    pub fn with_max_body_bytes(mut self, max_body_bytes: usize) -> Self {
+        self.max_body_bytes = max_body_bytes;
+        self
+    }
+
+    /// Record images, PDFs, archives and other binary content types from their
+    /// headers alone, without downloading the body (on by default)
+    pub fn with_skip_binary_bodies(mut self, skip: bool) -> Self {
+        self.skip_binary_bodies = skip;
+        self
+    }
+
     pub fn with_max_depth(mut self, depth: usize) -> Self {
         self.max_depth = depth;
         self
     }
 
+    /// Stop fetching new pages once this many have been started; in-flight requests finish
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Cap the URLs waiting across all worker queues; further discovered links are dropped.
+    /// Seed URLs are always queued.
+    pub fn with_max_queue_size(mut self, max_queue_size: usize) -> Self {
+        self.max_queue_size = Some(max_queue_size);
+        self
+    }
+
+    /// Only queue discovered links whose path has at most this many segments,
+    /// regardless of how many link hops away they are. Seed URLs are always crawled.
+    pub fn with_max_path_depth(mut self, max_path_depth: usize) -> Self {
+        self.max_path_depth = Some(max_path_depth);
+        self
+    }
+
+    /// Override the crawl scope; hosts equal to or under this domain are same-domain
     pub fn with_base_domain(mut self, domain: String) -> Self {
-        self.base_domain = Some(domain);
+        self.base_domain = Some(domain.trim_start_matches('.').to_ascii_lowercase());
         self
     }
 
@@ -95,21 +292,62 @@ impl Crawler {
         self
     }
 
+    /// Stop the crawl as stalled if no page completes within this window
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Share a pause flag; while it is set workers hold off taking new URLs.
+    /// Time spent paused does not count towards the idle timeout.
+    pub fn with_pause_flag(mut self, paused: Arc<AtomicBool>) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Share a stop flag; once set, workers finish their current page and exit,
+    /// and the crawl returns the results collected so far
+    pub fn with_stop_flag(mut self, stop: Arc<AtomicBool>) -> Self {
+        self.stop = stop;
+        self
+    }
+
     pub async fn crawl(&self, start_url: &str, workers: usize) -> Result<Vec<CrawlResult>> {
-        info!("Starting crawl of {} with {} workers", start_url, workers);
+        self.crawl_from(&[start_url.to_string()], workers).await
+    }
+
+    /// Crawl starting from several root URLs at once, all at depth 0.
+    /// The first root determines the base domain.
+    pub async fn crawl_from(
+        &self,
+        start_urls: &[String],
+        workers: usize,
+    ) -> Result<Vec<CrawlResult>> {
+        let start_url = start_urls
+            .first()
+            .ok_or_else(|| ScanError::InvalidUrl("No start URL provided".to_string()))?;
+        info!(
+            "Starting crawl of {} ({} roots) with {} workers",
+            start_url,
+            start_urls.len(),
+            workers
+        );
 
         let parsed_url = Url::parse(start_url)
             .map_err(|e| ScanError::InvalidUrl(format!("Invalid URL: {}", e)))?;
 
+        // Scope to the seed's registrable domain so sibling subdomains stay in scope
         let base_domain = self
             .base_domain
             .clone()
-            .unwrap_or_else(|| parsed_url.host_str().unwrap_or("unknown").to_string());
+            .unwrap_or_else(|| registrable_domain(parsed_url.host_str().unwrap_or("unknown")));
 
-        // Mark initial URL as visited
-        {
-            let mut visited = self.visited.lock().await;
-            visited.insert(start_url.to_string());
+        // Mark root URLs as visited, skipping duplicates
+        let mut roots = Vec::new();
+        for url in start_urls {
+            if self.visited.insert(normalize_url(url)) {
+                roots.push(url.clone());
+            }
         }
 
         // Create worker-owned queues with work stealing
@@ -117,15 +355,38 @@ impl Crawler {
         let worker_queues: WorkerQueues =
             Arc::new((0..workers).map(|_| Mutex::new(VecDeque::new())).collect());
 
-        // Create atomic work counter to track pending work items
-        // This prevents race conditions in worker termination logic
-        let work_counter: WorkCounter = Arc::new(AtomicUsize::new(1));
+        // Distribute the root URLs across worker queues (round-robin)
+        for (idx, url) in roots.into_iter().enumerate() {
+            let mut queue = worker_queues[idx % workers].lock().await;
+            queue.push_back((url, 0));
+        }
+
+        self.run_workers(worker_queues, base_domain).await
+    }
 
-        // Initialize worker 0's queue with the starting URL
-        {
-            let mut queue = worker_queues[0].lock().await;
-            queue.push_back((start_url.to_string(), 0));
+    /// Run one worker per queue until every queue is drained.
+    /// URLs already in the queues must be marked as visited.
+    async fn run_workers(
+        &self,
+        worker_queues: WorkerQueues,
+        base_domain: String,
+    ) -> Result<Vec<CrawlResult>> {
+        let workers = worker_queues.len();
+
+        // Create atomic work counter to track pending work items.
+        // An item stays counted until its worker has queued every URL it discovered,
+        // so empty queues alone never end the crawl while a fetch is still in flight.
+        let mut queued = 0;
+        for queue in worker_queues.iter() {
+            queued += queue.lock().await.len();
         }
+        let work_counter: WorkCounter = Arc::new(AtomicUsize::new(queued));
+        self.queue_len.store(queued, Ordering::SeqCst);
+        self.peak_queue_len.fetch_max(queued, Ordering::Relaxed);
+
+        // Milliseconds since crawl start at which the last page completed
+        let crawl_start = Instant::now();
+        let last_progress_ms = Arc::new(AtomicU64::new(0));
 
         // Spawn worker tasks
         let mut worker_handles = Vec::new();
@@ -138,6 +399,24 @@ impl Crawler {
             let result_cb = self.result_callback.clone();
             let auto_follow = self.auto_follow;
             let max_depth = self.max_depth;
+            let max_depth_reached = self.max_depth_reached.clone();
+            let depth_limit_hit = self.depth_limit_hit.clone();
+            let max_pages = self.max_pages;
+            let pages_claimed = self.pages_claimed.clone();
+            let page_limit_hit = self.page_limit_hit.clone();
+            let max_queue_size = self.max_queue_size;
+            let max_path_depth = self.max_path_depth;
+            let request_delay = self.request_delay;
+            let max_per_host = self.max_per_host;
+            let max_body_bytes = self.max_body_bytes;
+            let skip_binary_bodies = self.skip_binary_bodies;
+            let host_semaphores = self.host_semaphores.clone();
+            let queue_len = self.queue_len.clone();
+            let peak_queue_len = self.peak_queue_len.clone();
+            let queue_dropped = self.queue_dropped.clone();
+            let last_progress_ms = last_progress_ms.clone();
+            let paused = self.paused.clone();
+            let stop = self.stop.clone();
             let visited = self.visited.clone();
             let results = self.results.clone();
             let worker_queues_clone = worker_queues.clone();
@@ -147,16 +426,29 @@ impl Crawler {
                 debug!("Worker {} started", worker_id);
 
                 loop {
-                    // Get work from own queue (no stealing in crawl mode)
+                    wait_while_paused(&paused, &stop).await;
+                    if stop.load(Ordering::Relaxed) {
+                        debug!("Worker {} exiting (stop requested)", worker_id);
+                        break;
+                    }
+
+                    // Try to get work from own queue first
                     let work_item = {
                         let mut queue = worker_queues_clone[worker_id].lock().await;
                         queue.pop_front()
                     };
 
                     let (url, depth) = if let Some(item) = work_item {
+                        queue_len.fetch_sub(1, Ordering::SeqCst);
+                        item
+                    } else if let Some(item) =
+                        try_steal_crawl_work(worker_id, &worker_queues_clone).await
+                    {
+                        debug!("Worker {} stole {}", worker_id, item.0);
+                        queue_len.fetch_sub(1, Ordering::SeqCst);
                         item
                     } else {
-                        // Own queue is empty - check if all work is done
+                        // Nothing to steal - check if all work is done
                         let pending_work = work_counter_clone.load(Ordering::SeqCst);
                         if pending_work == 0 {
                             debug!("Worker {} exiting (no work remaining)", worker_id);
@@ -170,6 +462,16 @@ impl Crawler {
 
                     // Check depth limit - decrement counter even if we skip
                     if depth >= max_depth {
+                        depth_limit_hit.store(true, Ordering::Relaxed);
+                        work_counter_clone.fetch_sub(1, Ordering::SeqCst);
+                        continue;
+                    }
+
+                    // Claim a slot in the page budget; drain the queue once it's spent
+                    if let Some(max_pages) = max_pages
+                        && pages_claimed.fetch_add(1, Ordering::SeqCst) >= max_pages
+                    {
+                        page_limit_hit.store(true, Ordering::Relaxed);
                         work_counter_clone.fetch_sub(1, Ordering::SeqCst);
                         continue;
                     }
@@ -179,6 +481,10 @@ impl Crawler {
                         callback(worker_id, url.clone());
                     }
 
+                    if let Some(delay) = request_delay {
+                        tokio::time::sleep(delay).await;
+                    }
+
                     // Fetch and parse the URL
                     match Self::fetch_and_parse_static(
                         &client,
@@ -186,20 +492,44 @@ impl Crawler {
                         &base_domain,
                         &cross_domain_cb,
                         auto_follow,
+                        &host_semaphores,
+                        max_per_host,
+                        max_body_bytes,
+                        skip_binary_bodies,
                     )
                     .await
                     {
-                        Ok((crawl_result, new_urls)) => {
+                        Ok((mut crawl_result, new_urls)) => {
                             // Call result callback if provided
                             if let Some(ref callback) = result_cb {
                                 callback(crawl_result.clone());
                             }
+                            // The body sample is for the callback's analysis only; don't
+                            // hold it in memory for the rest of the crawl
+                            crawl_result.body_sample = None;
 
                             // Store the result
                             {
                                 let mut results_lock = results.lock().await;
                                 results_lock.push(crawl_result);
                             }
+                            max_depth_reached.fetch_max(depth, Ordering::Relaxed);
+                            last_progress_ms.store(
+                                crawl_start.elapsed().as_millis() as u64,
+                                Ordering::Relaxed,
+                            );
+
+                            // Page budget spent: don't queue anything new
+                            let new_urls = if max_pages.is_some_and(|max_pages| {
+                                pages_claimed.load(Ordering::SeqCst) >= max_pages
+                            }) {
+                                if !new_urls.is_empty() {
+                                    page_limit_hit.store(true, Ordering::Relaxed);
+                                }
+                                Vec::new()
+                            } else {
+                                new_urls
+                            };
 
                             // Distribute new URLs across ALL worker queues (round-robin)
                             let num_workers = worker_queues_clone.len();
@@ -207,16 +537,34 @@ impl Crawler {
                             debug!("[Worker {}] Distributing {} URLs across {} workers", worker_id, num_new_urls, num_workers);
                             let mut target_worker = 0;
                             for new_url in new_urls {
-                                // Check and mark as visited
-                                let should_queue = {
-                                    let mut visited_lock = visited.lock().await;
-                                    if !visited_lock.contains(&new_url) {
-                                        visited_lock.insert(new_url.clone());
-                                        true
-                                    } else {
+                                if max_path_depth.is_some_and(|max| path_depth(&new_url) > max) {
+                                    debug!("[Worker {}] Skipping {} (path depth limit)", worker_id, new_url);
+                                    continue;
+                                }
+
+                                // Check and mark as visited (keyed on the normalized form,
+                                // the original URL is what gets fetched and reported).
+                                // A full queue drops the link without marking it, so it can
+                                // still be queued if rediscovered once there is room.
+                                let should_queue = visited.insert_if(normalize_url(&new_url), || {
+                                    if queue_len
+                                        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |len| {
+                                            max_queue_size
+                                                .is_none_or(|max| len < max)
+                                                .then_some(len + 1)
+                                        })
+                                        .is_err()
+                                    {
+                                        queue_dropped.fetch_add(1, Ordering::Relaxed);
                                         false
+                                    } else {
+                                        peak_queue_len.fetch_max(
+                                            queue_len.load(Ordering::SeqCst),
+                                            Ordering::Relaxed,
+                                        );
+                                        true
                                     }
-                                };
+                                });
 
                                 if should_queue {
                                     // Add to target worker's queue
@@ -248,11 +596,40 @@ impl Crawler {
             worker_handles.push(handle);
         }
 
+        // Watchdog: abort all workers if no page completes within the idle timeout
+        let mut stalled = false;
+        if let Some(idle_timeout) = self.idle_timeout {
+            while !worker_handles.iter().all(|h| h.is_finished()) {
+                let now_ms = crawl_start.elapsed().as_millis() as u64;
+                if self.paused.load(Ordering::Relaxed) {
+                    last_progress_ms.store(now_ms, Ordering::Relaxed);
+                }
+                let idle_ms = now_ms.saturating_sub(last_progress_ms.load(Ordering::Relaxed));
+                if idle_ms >= idle_timeout.as_millis() as u64 {
+                    warn!("Crawl stalled: no progress for {}ms, stopping", idle_ms);
+                    for handle in &worker_handles {
+                        handle.abort();
+                    }
+                    stalled = true;
+                    self.stalled.store(true, Ordering::Relaxed);
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        }
+
         // Wait for all workers to complete
         for handle in worker_handles {
-            handle
-                .await
-                .map_err(|e| ScanError::Other(format!("Worker task failed: {}", e)))?;
+            match handle.await {
+                Ok(()) => {}
+                Err(e) if stalled && e.is_cancelled() => {}
+                Err(e) => return Err(ScanError::Other(format!("Worker task failed: {}", e))),
+            }
+        }
+
+        let dropped = self.queue_dropped.load(Ordering::Relaxed);
+        if dropped > 0 {
+            warn!("Queue cap reached: dropped {} discovered links", dropped);
         }
 
         let results = self.results.lock().await;
@@ -261,17 +638,31 @@ impl Crawler {
     }
 
     /// Static version of fetch_and_parse for use in spawned tasks
+    #[allow(clippy::too_many_arguments)]
     async fn fetch_and_parse_static(
         client: &Client,
         url: &str,
         base_domain: &str,
         cross_domain_callback: &Option<CrossDomainCallback>,
         auto_follow: bool,
+        host_semaphores: &HostSemaphores,
+        max_per_host: usize,
+        max_body_bytes: usize,
+        skip_binary_bodies: bool,
     ) -> Result<(CrawlResult, Vec<String>)> {
         debug!("Fetching {}", url);
 
+        // Hold a slot for this host until the body has been read
+        let host_permit = {
+            let semaphore = host_semaphore(host_semaphores, url, max_per_host).await;
+            semaphore
+                .acquire_owned()
+                .await
+                .map_err(|e| ScanError::Other(e.to_string()))?
+        };
+
         let start = Instant::now();
-        let response = client.get(url).send().await?;
+        let mut response = send_get(client, url).await?;
         let response_time = start.elapsed();
 
         let status_code = response.status().as_u16();
@@ -281,14 +672,77 @@ impl Crawler {
             .and_then(|v| v.to_str().ok())
             .map(|s| s.to_string());
         let content_length = response.content_length();
+        let final_url = response.url().clone();
+        let backoff = retry_after(status_code, response.headers());
+        let headers: Vec<(String, String)> = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value.to_str().ok().map(|v| (name.as_str().to_string(), v.to_string()))
+            })
+            .collect();
+
+        // Nothing to parse in a binary body: record the node from its headers alone
+        if skip_binary_bodies
+            && content_type.as_deref().is_some_and(is_binary_content_type)
+        {
+            drop(response);
+            drop(host_permit);
+            debug!("{} is {}, body not read", url, content_type.as_deref().unwrap_or(""));
+            if let Some(delay) = backoff {
+                warn!("{} returned {}, backing off for {:?}", url, status_code, delay);
+                tokio::time::sleep(delay).await;
+            }
+
+            let mut result = CrawlResult::new(url.to_string());
+            result.status_code = status_code;
+            result.content_type = content_type;
+            result.content_length = content_length;
+            result.response_time = response_time;
+            result.headers = headers;
+            if Url::parse(url).ok().as_ref() != Some(&final_url) {
+                result.redirected_to = Some(final_url.to_string());
+            }
+            return Ok((result, Vec::new()));
+        }
+
+        // Read the body chunk by chunk so an oversized response can't exhaust memory
+        let mut body_bytes = Vec::new();
+        let mut body_truncated = false;
+        while let Some(chunk) = response.chunk().await? {
+            let room = max_body_bytes - body_bytes.len();
+            if chunk.len() > room {
+                body_bytes.extend_from_slice(&chunk[..room]);
+                body_truncated = true;
+                break;
+            }
+            body_bytes.extend_from_slice(&chunk);
+        }
+        drop(response);
+        drop(host_permit);
+        if body_truncated {
+            warn!("{} body exceeds {} bytes, truncated", url, max_body_bytes);
+        }
+        let body = String::from_utf8_lossy(&body_bytes).into_owned();
 
-        let body = response.text().await?;
+        // The server asked us to slow down: hold this worker before it takes more work
+        if let Some(delay) = backoff {
+            warn!("{} returned {}, backing off for {:?}", url, status_code, delay);
+            tokio::time::sleep(delay).await;
+        }
 
         let mut result = CrawlResult::new(url.to_string());
         result.status_code = status_code;
         result.content_type = content_type.clone();
         result.content_length = content_length;
         result.response_time = response_time;
+        result.headers = headers;
+        result.content_hash = Some(content_hash(body.as_bytes()));
+        result.body_truncated = body_truncated;
+        result.body_sample = Some(body_sample(&body).to_string());
+        if Url::parse(url).ok().as_ref() != Some(&final_url) {
+            result.redirected_to = Some(final_url.to_string());
+        }
 
         // Only parse HTML content
         let is_html = content_type
@@ -299,17 +753,20 @@ impl Crawler {
         let mut new_urls = Vec::new();
 
         if is_html {
-            let (links, forms, scripts) = Self::extract_elements_static(
+            let elements = Self::extract_elements_static(
                 &body,
                 url,
                 base_domain,
                 cross_domain_callback,
                 auto_follow,
             )?;
-            result.links_found = links.clone();
-            result.forms_found = forms;
-            result.scripts_found = scripts;
-            new_urls = links;
+            result.links_found = elements.links.clone();
+            result.forms_found = elements.forms;
+            result.password_forms_found = elements.password_forms;
+            result.scripts_found = elements.scripts;
+            result.inputs_found = elements.inputs;
+            result.parameters = elements.parameters;
+            new_urls = elements.links;
         }
 
         Ok((result, new_urls))
@@ -322,16 +779,34 @@ impl Crawler {
         base_domain: &str,
         cross_domain_callback: &Option<CrossDomainCallback>,
         auto_follow: bool,
-    ) -> Result<(Vec<String>, usize, usize)> {
+    ) -> Result<PageElements> {
         let document = Html::parse_document(html);
 
-        // Extract links
-        let link_selector = Selector::parse("a[href]").unwrap();
+        // Relative URLs resolve against <base href> when the page declares one
+        let base_selector = Selector::parse("base[href]").unwrap();
+        let resolve_base = document
+            .select(&base_selector)
+            .next()
+            .and_then(|base| base.value().attr("href"))
+            .and_then(|href| Url::parse(current_url).ok()?.join(href).ok())
+            .map(|url| url.to_string())
+            .unwrap_or_else(|| current_url.to_string());
+
+        // Extract links from every element that references another URL
+        let link_selector = Selector::parse(
+            "a[href], link[href], script[src], img[src], iframe[src], form[action]",
+        )
+        .unwrap();
         let mut links = Vec::new();
 
         for element in document.select(&link_selector) {
-            if let Some(href) = element.value().attr("href")
-                && let Some(absolute_url) = Self::resolve_url_static(current_url, href)
+            let value = element.value();
+            let reference = value
+                .attr("href")
+                .or_else(|| value.attr("src"))
+                .or_else(|| value.attr("action"));
+            if let Some(href) = reference
+                && let Some(absolute_url) = Self::resolve_url_static(&resolve_base, href.trim())
             {
                 debug!("Found link: {} (base_domain: {})", absolute_url, base_domain);
                 if Self::is_same_domain_static(&absolute_url, base_domain) {
@@ -360,11 +835,50 @@ impl Crawler {
         let form_selector = Selector::parse("form").unwrap();
         let forms_count = document.select(&form_selector).count();
 
+        // Count forms that ask for a password
+        let password_selector = Selector::parse("input[type=password]").unwrap();
+        let password_forms_count = document
+            .select(&form_selector)
+            .filter(|form| form.select(&password_selector).next().is_some())
+            .count();
+
         // Count scripts
         let script_selector = Selector::parse("script[src]").unwrap();
         let scripts_count = document.select(&script_selector).count();
 
-        Ok((links, forms_count, scripts_count))
+        // Form fields and their names
+        let input_selector = Selector::parse("input, select, textarea").unwrap();
+        let mut inputs_count = 0;
+        let mut parameters: Vec<String> = Vec::new();
+        for input in document.select(&input_selector) {
+            inputs_count += 1;
+            if let Some(name) = input.value().attr("name").map(str::trim)
+                && !name.is_empty()
+                && !parameters.iter().any(|p| p == name)
+            {
+                parameters.push(name.to_string());
+            }
+        }
+
+        // Query-string parameter names from the links found above
+        for link in &links {
+            if let Ok(parsed) = Url::parse(link) {
+                for (name, _) in parsed.query_pairs() {
+                    if !name.is_empty() && !parameters.iter().any(|p| *p == name) {
+                        parameters.push(name.into_owned());
+                    }
+                }
+            }
+        }
+
+        Ok(PageElements {
+            links,
+            forms: forms_count,
+            password_forms: password_forms_count,
+            scripts: scripts_count,
+            inputs: inputs_count,
+            parameters,
+        })
     }
 
     fn resolve_url_static(base: &str, href: &str) -> Option<String> {
@@ -389,12 +903,7 @@ impl Crawler {
     }
 
     fn is_same_domain_static(url: &str, base_domain: &str) -> bool {
-        if let Ok(parsed) = Url::parse(url)
-            && let Some(host) = parsed.host_str()
-        {
-            return host == base_domain || host.ends_with(&format!(".{}", base_domain));
-        }
-        false
+        Url::parse(url).is_ok_and(|parsed| host_in_scope(&parsed, base_domain))
     }
 
     pub async fn get_results(&self) -> Vec<CrawlResult> {
@@ -402,8 +911,183 @@ impl Crawler {
     }
 
     pub async fn get_visited_count(&self) -> usize {
-        self.visited.lock().await.len()
+        self.visited.len()
+    }
+
+    /// Coverage metrics accumulated across all crawls run by this crawler
+    pub async fn get_stats(&self) -> CrawlStats {
+        let results = self.results.lock().await;
+        let hosts: HashSet<String> = results
+            .iter()
+            .filter_map(|r| Url::parse(&r.url).ok()?.host_str().map(String::from))
+            .collect();
+
+        CrawlStats {
+            max_depth_reached: self.max_depth_reached.load(Ordering::Relaxed),
+            depth_limit: self.max_depth.saturating_sub(1),
+            unique_hosts: hosts.len(),
+            links_discovered: self.visited.len(),
+            pages_crawled: results.len(),
+            depth_limit_hit: self.depth_limit_hit.load(Ordering::Relaxed),
+            max_pages: self.max_pages,
+            page_limit_hit: self.page_limit_hit.load(Ordering::Relaxed),
+            stalled: self.stalled.load(Ordering::Relaxed),
+            cancelled: self.stop.load(Ordering::Relaxed),
+            max_queue_size: self.max_queue_size,
+            peak_queue_len: self.peak_queue_len.load(Ordering::Relaxed),
+            queue_dropped: self.queue_dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Content types with no links or forms to parse: images, audio, video, fonts,
+/// PDFs and archives. Parameters such as `; charset=` are ignored.
+pub fn is_binary_content_type(content_type: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_ascii_lowercase();
+    if ["image/", "audio/", "video/", "font/"]
+        .iter()
+        .any(|prefix| media_type.starts_with(prefix))
+    {
+        // SVG is XML and can carry links and scripts
+        return media_type != "image/svg+xml";
+    }
+    matches!(
+        media_type.as_str(),
+        "application/pdf"
+            | "application/zip"
+            | "application/gzip"
+            | "application/x-gzip"
+            | "application/x-tar"
+            | "application/x-bzip2"
+            | "application/x-7z-compressed"
+            | "application/x-rar-compressed"
+            | "application/vnd.rar"
+            | "application/java-archive"
+            | "application/wasm"
+            | "application/octet-stream"
+            | "application/vnd.ms-fontobject"
+            | "application/msword"
+            | "application/vnd.ms-excel"
+            | "application/x-shockwave-flash"
+    )
+}
+
+/// The first `BODY_SAMPLE_BYTES` of a body, cut back to a character boundary
+fn body_sample(body: &str) -> &str {
+    if body.len() <= BODY_SAMPLE_BYTES {
+        return body;
+    }
+    let mut end = BODY_SAMPLE_BYTES;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    &body[..end]
+}
+
+/// Hex-encoded SHA-256 of a response body, used to spot duplicate pages
+pub fn content_hash(body: &[u8]) -> String {
+    ring::digest::digest(&ring::digest::SHA256, body)
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Public suffixes with more than one label. Anything else is treated as a
+/// single-label suffix (`.com`, `.io`, ...).
+const MULTI_LABEL_SUFFIXES: &[&str] = &[
+    "co.uk", "org.uk", "ac.uk", "gov.uk", "ltd.uk", "plc.uk", "me.uk", "net.uk",
+    "com.au", "net.au", "org.au", "edu.au", "gov.au",
+    "co.nz", "org.nz", "govt.nz",
+    "co.jp", "ne.jp", "or.jp", "ac.jp",
+    "co.za", "org.za",
+    "com.br", "net.br", "org.br", "gov.br",
+    "com.cn", "net.cn", "org.cn", "gov.cn",
+    "co.in", "net.in", "org.in", "gov.in",
+    "com.mx", "com.ar", "com.tr", "com.sg", "com.hk", "com.tw",
+    "co.kr", "or.kr", "co.il", "co.id",
+];
+
+/// Registrable domain (eTLD+1) of a host, e.g. `api.example.com` -> `example.com`
+/// and `www.example.co.uk` -> `example.co.uk`. IP addresses and single-label
+/// hosts such as `localhost` are returned unchanged.
+pub fn registrable_domain(host: &str) -> String {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    if host.parse::<std::net::IpAddr>().is_ok() || host.starts_with('[') {
+        return host;
+    }
+
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() <= 2 {
+        return host;
+    }
+
+    let last_two = labels[labels.len() - 2..].join(".");
+    let keep = if MULTI_LABEL_SUFFIXES.contains(&last_two.as_str()) {
+        3
+    } else {
+        2
+    };
+
+    labels[labels.len().saturating_sub(keep)..].join(".")
+}
+
+/// Whether the host of `url` is `scope` or one of its subdomains. An IP address is
+/// its own scope and only matches itself; IPv6 scopes may be given with or
+/// without brackets.
+pub fn host_in_scope(url: &Url, scope: &str) -> bool {
+    let scope = scope.trim_end_matches('.').to_ascii_lowercase();
+    let scope_ip = scope
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+        .ok();
+
+    match url.host() {
+        Some(Host::Ipv4(ip)) => scope_ip == Some(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => scope_ip == Some(IpAddr::V6(ip)),
+        Some(Host::Domain(host)) => {
+            let host = host.to_ascii_lowercase();
+            scope_ip.is_none() && (host == scope || host.ends_with(&format!(".{}", scope)))
+        }
+        None => false,
+    }
+}
+
+/// Canonical form of a URL used to deduplicate equivalent links: lowercase host,
+/// no default port, no fragment, sorted query parameters and no trailing slashes
+/// (except for the root path). Unparseable input is returned unchanged.
+pub fn normalize_url(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url) else {
+        return url.to_string();
+    };
+
+    // Url::parse already lowercases the host and drops the scheme's default port
+    parsed.set_fragment(None);
+
+    let trimmed_path = parsed.path().trim_end_matches('/').to_string();
+    if trimmed_path.is_empty() {
+        parsed.set_path("/");
+    } else {
+        parsed.set_path(&trimmed_path);
+    }
+
+    let query = parsed.query().map(|query| {
+        let mut params: Vec<&str> = query.split('&').filter(|p| !p.is_empty()).collect();
+        params.sort_unstable();
+        params.join("&")
+    });
+    match query {
+        Some(query) if !query.is_empty() => parsed.set_query(Some(&query)),
+        _ => parsed.set_query(None),
     }
+
+    parsed.to_string()
 }
 
 impl Default for Crawler {
@@ -412,6 +1096,83 @@ impl Default for Crawler {
     }
 }
 
+/// Number of non-empty path segments in a URL (`/a/b/` is 2, `/` is 0)
+fn path_depth(url: &str) -> usize {
+    Url::parse(url)
+        .ok()
+        .and_then(|u| {
+            u.path_segments()
+                .map(|segments| segments.filter(|s| !s.is_empty()).count())
+        })
+        .unwrap_or(0)
+}
+
+/// Sleep until the pause flag is cleared or a stop is requested
+async fn wait_while_paused(paused: &AtomicBool, stop: &AtomicBool) {
+    while paused.load(Ordering::Relaxed) && !stop.load(Ordering::Relaxed) {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+}
+
+/// GET `url`, resending once when it failed with a connect or request error other
+/// than a timeout, i.e. no response came back. Under load the server can close a
+/// pooled keep-alive connection just as a request is written to it. The failed
+/// attempt may still have reached the server, which is acceptable for an idempotent
+/// GET, and resending it on a fresh connection keeps the page from being lost.
+async fn send_get(client: &Client, url: &str) -> reqwest::Result<reqwest::Response> {
+    match client.get(url).send().await {
+        Err(e) if !e.is_timeout() && (e.is_connect() || e.is_request()) => {
+            debug!("Resending {} after connection error: {}", url, e);
+            client.get(url).send().await
+        }
+        result => result,
+    }
+}
+
+/// The semaphore shared by every request to `url`'s host and port
+async fn host_semaphore(
+    host_semaphores: &HostSemaphores,
+    url: &str,
+    max_per_host: usize,
+) -> Arc<Semaphore> {
+    let key = Url::parse(url)
+        .ok()
+        .map(|u| {
+            format!(
+                "{}:{}",
+                u.host_str().unwrap_or_default(),
+                u.port_or_known_default().unwrap_or(0)
+            )
+        })
+        .unwrap_or_else(|| url.to_string());
+    host_semaphores
+        .lock()
+        .await
+        .entry(key)
+        .or_insert_with(|| Arc::new(Semaphore::new(max_per_host)))
+        .clone()
+}
+
+/// Try to steal work from the back of another worker's queue.
+/// The owner's last item is left alone so one busy worker can't drain every queue.
+async fn try_steal_crawl_work(
+    worker_id: usize,
+    worker_queues: &WorkerQueues,
+) -> Option<(String, usize)> {
+    for target_id in 0..worker_queues.len() {
+        if target_id == worker_id {
+            continue; // Don't steal from self
+        }
+
+        let mut target_queue = worker_queues[target_id].lock().await;
+        if target_queue.len() > 1 {
+            return target_queue.pop_back();
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -488,6 +1249,115 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_form_action_url_is_discovered() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(
+                        br#"<html><body>
+                            <form action="/login" method="post"><input name="user"></form>
+                        </body></html>"#,
+                    ),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/login"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(b"<html><body>Login</body></html>"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(2);
+        let results = crawler.crawl(&mock_server.uri(), 1).await.unwrap();
+
+        let uri = mock_server.uri();
+        let login_url = format!("{}/login", uri);
+        let root = results.iter().find(|r| r.url.trim_end_matches('/') == uri).unwrap();
+        assert_eq!(root.forms_found, 1);
+        assert!(root.links_found.contains(&login_url));
+        assert!(results.iter().any(|r| r.url == login_url));
+    }
+
+    #[tokio::test]
+    async fn test_form_inputs_and_query_parameters_are_collected() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"<html><body>
+                    <a href="/search?q=test&page=2">Search</a>
+                    <form action="/login" method="post">
+                        <input name="user">
+                        <input type="password" name="pass">
+                        <select name="role"><option>admin</option></select>
+                        <textarea name="note"></textarea>
+                        <input type="submit">
+                    </form>
+                </body></html>"#,
+                "text/html",
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(1);
+        let results = crawler.crawl(&mock_server.uri(), 1).await.unwrap();
+
+        let uri = mock_server.uri();
+        let root = results.iter().find(|r| r.url.trim_end_matches('/') == uri).unwrap();
+        assert_eq!(root.inputs_found, 5);
+        assert_eq!(root.parameters, vec!["user", "pass", "role", "note", "q", "page"]);
+    }
+
+    #[tokio::test]
+    async fn test_base_href_and_resource_urls() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(
+                        br#"<html><head>
+                            <base href="/app/">
+                            <link rel="stylesheet" href="style.css">
+                            <script src="main.js"></script>
+                        </head><body>
+                            <img src="logo.png"><iframe src="/embed"></iframe>
+                        </body></html>"#,
+                    ),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(1);
+        let results = crawler.crawl(&mock_server.uri(), 1).await.unwrap();
+
+        let uri = mock_server.uri();
+        let root = results.iter().find(|r| r.url.trim_end_matches('/') == uri).unwrap();
+        let links = &root.links_found;
+        assert_eq!(root.scripts_found, 1);
+        for expected in ["/app/style.css", "/app/main.js", "/app/logo.png", "/embed"] {
+            assert!(
+                links.contains(&format!("{}{}", uri, expected)),
+                "missing {} in {:?}",
+                expected,
+                links
+            );
+        }
+    }
+
     /// Test that multiple workers are actually used during crawling
     #[tokio::test]
     async fn test_multiple_workers_are_used() {
@@ -749,4 +1619,1014 @@ mod tests {
             active_workers
         );
     }
+
+    /// Test coverage metrics for a crawl cut short by the depth limit
+    #[tokio::test]
+    async fn test_crawl_stats_depth_capped() {
+        let mock_server = MockServer::start().await;
+
+        let pages = [
+            ("/", format!(r#"<a href="{}/a">A</a>"#, mock_server.uri())),
+            ("/a", format!(r#"<a href="{}/a/b">B</a>"#, mock_server.uri())),
+            ("/a/b", "<p>Too deep</p>".to_string()),
+        ];
+
+        for (page_path, body) in pages {
+            Mock::given(method("GET"))
+                .and(path(page_path))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("content-type", "text/html")
+                        .set_body_bytes(format!("<html><body>{}</body></html>", body).as_bytes()),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+
+        let crawler = Crawler::new().with_max_depth(2);
+        let results = crawler.crawl(&mock_server.uri(), 2).await.unwrap();
+        assert_eq!(results.len(), 2);
+
+        let stats = crawler.get_stats().await;
+        assert_eq!(
+            stats,
+            CrawlStats {
+                max_depth_reached: 1,
+                depth_limit: 1,
+                unique_hosts: 1,
+                links_discovered: 3,
+                pages_crawled: 2,
+                depth_limit_hit: true,
+                max_pages: None,
+                page_limit_hit: false,
+                stalled: false,
+                cancelled: false,
+                max_queue_size: None,
+                peak_queue_len: 1,
+                queue_dropped: 0,
+            }
+        );
+        assert_eq!(stats.limits_hit(), vec!["depth"]);
+    }
+
+    /// Test that every root passed to crawl_from is fetched and crawled from
+    #[tokio::test]
+    async fn test_crawl_from_multiple_roots() {
+        let mock_server = MockServer::start().await;
+
+        // Three disconnected sections: nothing links between them
+        let pages = [
+            ("/", "<p>Home</p>".to_string()),
+            ("/api", format!(r#"<a href="{}/api/users">Users</a>"#, mock_server.uri())),
+            ("/api/users", "<p>Users</p>".to_string()),
+            ("/docs", "<p>Docs</p>".to_string()),
+        ];
+
+        for (page_path, body) in pages {
+            Mock::given(method("GET"))
+                .and(path(page_path))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("content-type", "text/html")
+                        .set_body_bytes(format!("<html><body>{}</body></html>", body).as_bytes()),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+
+        let roots = vec![
+            format!("{}/", mock_server.uri()),
+            format!("{}/api", mock_server.uri()),
+            format!("{}/docs", mock_server.uri()),
+        ];
+
+        let crawler = Crawler::new().with_max_depth(2);
+        let results = crawler.crawl_from(&roots, 2).await.unwrap();
+
+        let crawled: HashSet<String> = results.iter().map(|r| r.url.clone()).collect();
+        for root in &roots {
+            assert!(crawled.contains(root), "Root {} was not crawled", root);
+        }
+        assert!(crawled.contains(&format!("{}/api/users", mock_server.uri())));
+        assert_eq!(results.len(), 4);
+    }
+
+    /// Test that a 429 with Retry-After holds the worker before its next request
+    #[tokio::test]
+    async fn test_retry_after_pauses_worker() {
+        let mock_server = MockServer::start().await;
+        let request_times = Arc::new(StdMutex::new(Vec::new()));
+
+        let times = request_times.clone();
+        Mock::given(method("GET"))
+            .and(path("/limited"))
+            .respond_with(move |_: &wiremock::Request| {
+                times.lock().unwrap().push(Instant::now());
+                ResponseTemplate::new(429).insert_header("retry-after", "1")
+            })
+            .mount(&mock_server)
+            .await;
+
+        let times = request_times.clone();
+        Mock::given(method("GET"))
+            .and(path("/next"))
+            .respond_with(move |_: &wiremock::Request| {
+                times.lock().unwrap().push(Instant::now());
+                ResponseTemplate::new(200)
+            })
+            .mount(&mock_server)
+            .await;
+
+        // One worker, so both roots are fetched in order by the same task
+        let roots = vec![
+            format!("{}/limited", mock_server.uri()),
+            format!("{}/next", mock_server.uri()),
+        ];
+        let results = Crawler::new().crawl_from(&roots, 1).await.unwrap();
+        assert_eq!(results.len(), 2);
+
+        let times = request_times.lock().unwrap();
+        assert_eq!(times.len(), 2);
+        let waited = times[1].duration_since(times[0]);
+        assert!(
+            waited >= Duration::from_millis(900) && waited < Duration::from_secs(5),
+            "worker waited {:?} after Retry-After: 1",
+            waited
+        );
+    }
+
+    #[tokio::test]
+    async fn test_idle_timeout_stops_stalled_crawl() {
+        let mock_server = MockServer::start().await;
+
+        let home = format!(
+            r#"<html><body><a href="{0}/slow1">1</a><a href="{0}/slow2">2</a></body></html>"#,
+            mock_server.uri()
+        );
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(home.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        for page_path in ["/slow1", "/slow2"] {
+            Mock::given(method("GET"))
+                .and(path(page_path))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("content-type", "text/html")
+                        .set_body_bytes(b"<html><body>slow</body></html>".as_slice())
+                        .set_delay(Duration::from_secs(30)),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+
+        let crawler = Crawler::new()
+            .with_max_depth(3)
+            .with_idle_timeout(Duration::from_millis(300));
+        let start_url = format!("{}/", mock_server.uri());
+        let results = tokio::time::timeout(Duration::from_secs(5), crawler.crawl(&start_url, 2))
+            .await
+            .expect("idle timeout did not stop the crawl")
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].url, start_url);
+
+        let stats = crawler.get_stats().await;
+        assert!(stats.stalled);
+        assert!(stats.limits_hit().contains(&"idle-timeout (stalled)"));
+    }
+
+    #[test]
+    fn test_path_depth() {
+        assert_eq!(path_depth("http://example.com"), 0);
+        assert_eq!(path_depth("http://example.com/"), 0);
+        assert_eq!(path_depth("http://example.com/a/b"), 2);
+        assert_eq!(path_depth("http://example.com/a/b/?q=1"), 2);
+        assert_eq!(path_depth("http://example.com/a//b/c/d"), 4);
+    }
+
+    #[tokio::test]
+    async fn test_max_path_depth_skips_deep_paths() {
+        let mock_server = MockServer::start().await;
+        let home = format!(
+            r#"<html><body><a href="{0}/a/b">shallow</a><a href="{0}/a/b/c/d">deep</a></body></html>"#,
+            mock_server.uri()
+        );
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(home.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(b"<html><body>leaf</body></html>".as_slice()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(3).with_max_path_depth(2);
+        let results = crawler
+            .crawl(&format!("{}/", mock_server.uri()), 2)
+            .await
+            .unwrap();
+
+        let urls: Vec<&str> = results.iter().map(|r| r.url.as_str()).collect();
+        assert!(urls.contains(&format!("{}/a/b", mock_server.uri()).as_str()));
+        assert!(!urls.contains(&format!("{}/a/b/c/d", mock_server.uri()).as_str()));
+        assert_eq!(results.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stop_flag_makes_workers_exit_promptly() {
+        let mock_server = MockServer::start().await;
+        let links: String = (0..50)
+            .map(|i| format!(r#"<a href="{}/page{}">{}</a>"#, mock_server.uri(), i, i))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(format!("<html><body>{}</body></html>", links).as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(b"<html><body>page</body></html>".as_slice())
+                    .set_delay(Duration::from_millis(200)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let crawler = Crawler::new().with_stop_flag(stop.clone());
+        let start_url = format!("{}/", mock_server.uri());
+
+        let request_stop = async {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            stop.store(true, Ordering::Relaxed);
+        };
+        let (results, ()) = tokio::time::timeout(Duration::from_secs(2), async {
+            tokio::join!(crawler.crawl(&start_url, 2), request_stop)
+        })
+        .await
+        .expect("workers did not exit after the stop flag was set");
+
+        // The seed and a few in-flight pages are kept, the rest is never fetched
+        let results = results.unwrap();
+        assert!(!results.is_empty());
+        assert!(results.len() < 51);
+
+        let stats = crawler.get_stats().await;
+        assert!(stats.cancelled);
+        assert!(stats.limits_hit().contains(&"cancelled"));
+    }
+
+    #[tokio::test]
+    async fn test_stop_flag_releases_paused_workers() {
+        let paused = AtomicBool::new(true);
+        let stop = AtomicBool::new(true);
+        tokio::time::timeout(
+            Duration::from_millis(500),
+            wait_while_paused(&paused, &stop),
+        )
+        .await
+        .expect("stop did not release a paused worker");
+    }
+
+    #[tokio::test]
+    async fn test_wait_while_paused_resumes_when_cleared() {
+        let paused = Arc::new(AtomicBool::new(true));
+        let waiter = tokio::spawn({
+            let paused = paused.clone();
+            async move { wait_while_paused(&paused, &AtomicBool::new(false)).await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert!(!waiter.is_finished(), "worker ran while paused");
+
+        paused.store(false, Ordering::Relaxed);
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("worker did not resume after the flag was cleared")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_paused_crawl_fetches_nothing_until_resumed() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(b"<html><body>ok</body></html>".as_slice()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let paused = Arc::new(AtomicBool::new(true));
+        let crawler = Crawler::new()
+            .with_idle_timeout(Duration::from_millis(200))
+            .with_pause_flag(paused.clone());
+        let start_url = format!("{}/", mock_server.uri());
+
+        let resume = async {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            assert!(crawler.results.lock().await.is_empty());
+            paused.store(false, Ordering::Relaxed);
+        };
+        let (results, ()) = tokio::join!(crawler.crawl(&start_url, 2), resume);
+
+        // The pause outlasted the idle timeout without tripping it
+        assert_eq!(results.unwrap().len(), 1);
+        assert!(!crawler.get_stats().await.stalled);
+    }
+
+    #[tokio::test]
+    async fn test_idle_workers_steal_from_busy_queue() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(b"<html><body>page</body></html>".as_slice())
+                    .set_delay(Duration::from_millis(100)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let urls: Vec<String> =
+            (0..8).map(|i| format!("{}/page{}", mock_server.uri(), i)).collect();
+
+        let seen_workers = Arc::new(std::sync::Mutex::new(HashSet::new()));
+        let seen = seen_workers.clone();
+        let crawler = Crawler::new().with_progress_callback(Arc::new(move |worker_id, _| {
+            seen.lock().unwrap().insert(worker_id);
+        }));
+
+        // Seed every URL into worker 0's queue only
+        let worker_queues: WorkerQueues =
+            Arc::new((0..4).map(|_| Mutex::new(VecDeque::new())).collect());
+        {
+            let mut queue = worker_queues[0].lock().await;
+            for url in &urls {
+                crawler.visited.insert(url.clone());
+                queue.push_back((url.clone(), 0));
+            }
+        }
+
+        let host = Url::parse(&mock_server.uri()).unwrap().host_str().unwrap().to_string();
+        let results = crawler.run_workers(worker_queues, host).await.unwrap();
+
+        assert_eq!(results.len(), urls.len());
+        let crawled: HashSet<String> = results.iter().map(|r| r.url.clone()).collect();
+        assert_eq!(crawled.len(), urls.len());
+        assert!(
+            seen_workers.lock().unwrap().len() > 1,
+            "Only worker 0 processed pages"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deep_pages_reached_with_slow_responses() {
+        let mock_server = MockServer::start().await;
+
+        // A single chain / -> /d1 -> /d2 -> /d3, each hop slow, so most workers
+        // sit on empty queues while one page is in flight
+        let chain = ["/", "/d1", "/d2", "/d3"];
+        for (idx, page_path) in chain.iter().enumerate() {
+            let body = match chain.get(idx + 1) {
+                Some(next) => format!(
+                    r#"<html><body><a href="{}{}">next</a></body></html>"#,
+                    mock_server.uri(),
+                    next
+                ),
+                None => "<html><body>bottom</body></html>".to_string(),
+            };
+            Mock::given(method("GET"))
+                .and(path(*page_path))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("content-type", "text/html")
+                        .set_body_bytes(body.as_bytes())
+                        .set_delay(Duration::from_millis(150)),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+
+        let crawler = Crawler::new().with_max_depth(5);
+        let start_url = format!("{}/", mock_server.uri());
+        let results = crawler.crawl(&start_url, 16).await.unwrap();
+
+        let crawled: HashSet<String> = results.iter().map(|r| r.url.clone()).collect();
+        for page_path in chain {
+            let url = format!("{}{}", mock_server.uri(), page_path);
+            assert!(crawled.contains(&url), "{} was not reached", url);
+        }
+        assert_eq!(crawler.get_stats().await.max_depth_reached, 3);
+    }
+
+    #[tokio::test]
+    async fn test_max_queue_size_drops_excess_links() {
+        let mock_server = MockServer::start().await;
+
+        // Root fans out to 40 pages, far more than the queue may hold
+        let mut root_html = String::from("<html><body>");
+        for i in 0..40 {
+            root_html.push_str(&format!(r#"<a href="/page{}">{}</a>"#, i, i));
+        }
+        root_html.push_str("</body></html>");
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(root_html.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(b"<html><body>Leaf</body></html>"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(3).with_max_queue_size(5);
+        let results = crawler.crawl(&format!("{}/", mock_server.uri()), 2).await.unwrap();
+
+        let stats = crawler.get_stats().await;
+        assert!(stats.peak_queue_len <= 5, "peak queue length {}", stats.peak_queue_len);
+        assert!(stats.queue_dropped > 0);
+        assert_eq!(results.len() + stats.queue_dropped, 41);
+        assert_eq!(stats.max_queue_size, Some(5));
+        assert!(stats.limits_hit().contains(&"max-queue-size"));
+    }
+
+    #[tokio::test]
+    async fn test_max_pages_stops_crawl() {
+        let mock_server = MockServer::start().await;
+
+        // Root links to 20 pages; the budget only allows 5 pages in total
+        let mut root_html = String::from("<html><body>");
+        for i in 0..20 {
+            root_html.push_str(&format!(r#"<a href="{}/page{}">{}</a>"#, mock_server.uri(), i, i));
+        }
+        root_html.push_str("</body></html>");
+
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(root_html.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(b"<html><body>page</body></html>".as_slice()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(3).with_max_pages(5);
+        let results = crawler.crawl(&format!("{}/", mock_server.uri()), 4).await.unwrap();
+
+        assert_eq!(results.len(), 5);
+        let stats = crawler.get_stats().await;
+        assert!(stats.page_limit_hit);
+        assert_eq!(stats.max_pages, Some(5));
+        assert!(stats.limits_hit().contains(&"max-pages"));
+    }
+
+    #[test]
+    fn test_content_hash_matches_identical_bodies() {
+        let not_found = b"<html><body>Page not found</body></html>";
+        assert_eq!(content_hash(not_found), content_hash(not_found.to_vec().as_slice()));
+        assert_ne!(content_hash(not_found), content_hash(b"<html><body>Home</body></html>"));
+        assert_eq!(
+            content_hash(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn test_registrable_domain() {
+        assert_eq!(registrable_domain("www.example.com"), "example.com");
+        assert_eq!(registrable_domain("a.b.API.Example.com"), "example.com");
+        assert_eq!(registrable_domain("example.com"), "example.com");
+        assert_eq!(registrable_domain("www.example.co.uk"), "example.co.uk");
+        assert_eq!(registrable_domain("example.co.uk"), "example.co.uk");
+        assert_eq!(registrable_domain("localhost"), "localhost");
+        assert_eq!(registrable_domain("127.0.0.1"), "127.0.0.1");
+        assert_eq!(registrable_domain("[::1]"), "[::1]");
+    }
+
+    #[test]
+    fn test_same_domain_ipv6_literal_is_its_own_scope() {
+        let base = registrable_domain(
+            Url::parse("http://[::1]:8080/").unwrap().host_str().unwrap(),
+        );
+        assert!(Crawler::is_same_domain_static("http://[::1]:8080/api", &base));
+        assert!(Crawler::is_same_domain_static("https://[0:0::1]/", &base));
+        assert!(Crawler::is_same_domain_static("http://[::1]/", "::1"));
+        assert!(!Crawler::is_same_domain_static("http://[::2]:8080/", &base));
+        assert!(!Crawler::is_same_domain_static("http://localhost:8080/", &base));
+    }
+
+    #[test]
+    fn test_same_domain_ipv4_is_its_own_scope() {
+        let base = registrable_domain("10.0.0.1");
+        assert!(Crawler::is_same_domain_static("http://10.0.0.1:8080/a", &base));
+        assert!(!Crawler::is_same_domain_static("http://10.0.0.10/", &base));
+        assert!(!Crawler::is_same_domain_static("http://110.0.0.1/", &base));
+        assert!(!Crawler::is_same_domain_static("http://example.com/", &base));
+    }
+
+    #[test]
+    fn test_same_domain_covers_sibling_subdomains() {
+        let base = registrable_domain("www.example.com");
+        assert!(Crawler::is_same_domain_static("http://api.example.com/v1", &base));
+        assert!(Crawler::is_same_domain_static("http://example.com/", &base));
+        assert!(!Crawler::is_same_domain_static("http://evil-example.com/", &base));
+        assert!(!Crawler::is_same_domain_static("http://example.com.evil.io/", &base));
+    }
+
+    #[test]
+    fn test_same_domain_does_not_span_public_suffix() {
+        let base = registrable_domain("www.example.co.uk");
+        assert!(Crawler::is_same_domain_static("https://shop.example.co.uk/", &base));
+        assert!(!Crawler::is_same_domain_static("https://other.co.uk/", &base));
+    }
+
+    #[test]
+    fn test_normalize_url_lowercases_host() {
+        assert_eq!(normalize_url("http://EXAMPLE.com/Path"), "http://example.com/Path");
+    }
+
+    #[test]
+    fn test_normalize_url_drops_default_port() {
+        assert_eq!(normalize_url("http://example.com:80/a"), "http://example.com/a");
+        assert_eq!(normalize_url("https://example.com:443/a"), "https://example.com/a");
+        assert_eq!(normalize_url("http://example.com:8080/a"), "http://example.com:8080/a");
+    }
+
+    #[test]
+    fn test_normalize_url_sorts_query_parameters() {
+        assert_eq!(
+            normalize_url("http://example.com/a?c=2&b=1"),
+            normalize_url("http://example.com/a?b=1&c=2")
+        );
+        assert_eq!(normalize_url("http://example.com/a?c=2&b=1"), "http://example.com/a?b=1&c=2");
+        assert_eq!(normalize_url("http://example.com/a?"), "http://example.com/a");
+    }
+
+    #[test]
+    fn test_normalize_url_trailing_slashes() {
+        assert_eq!(normalize_url("http://example.com/a/"), "http://example.com/a");
+        assert_eq!(normalize_url("http://example.com/a///"), "http://example.com/a");
+        assert_eq!(normalize_url("http://example.com"), "http://example.com/");
+        assert_eq!(normalize_url("http://example.com/"), "http://example.com/");
+    }
+
+    #[test]
+    fn test_normalize_url_strips_fragment() {
+        assert_eq!(normalize_url("http://example.com/a#top"), "http://example.com/a");
+    }
+
+    #[test]
+    fn test_normalize_url_leaves_invalid_input() {
+        assert_eq!(normalize_url("not a url"), "not a url");
+    }
+
+    #[tokio::test]
+    async fn test_equivalent_links_crawled_once() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        let home = format!(
+            r#"<html><body>
+                <a href="{0}/a">a</a>
+                <a href="{0}/a/">a slash</a>
+                <a href="{0}/search?c=2&b=1">search</a>
+                <a href="{0}/search?b=1&c=2">search sorted</a>
+            </body></html>"#,
+            uri
+        );
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(home.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(b"<html><body>page</body></html>".as_slice()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(2);
+        let results = crawler.crawl(&format!("{}/", uri), 2).await.unwrap();
+
+        // Home, one /a variant and one /search variant
+        assert_eq!(results.len(), 3);
+        let crawled: Vec<&str> = results.iter().map(|r| r.url.as_str()).collect();
+        assert!(crawled.contains(&format!("{}/a", uri).as_str()));
+        assert!(crawled.contains(&format!("{}/search?c=2&b=1", uri).as_str()));
+    }
+
+    /// Test every crawl request carries the User-Agent set with with_user_agent
+    #[tokio::test]
+    async fn test_custom_user_agent_is_sent() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        let home = format!(r#"<html><body><a href="{}/next">next</a></body></html>"#, uri);
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(home.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).insert_header("content-type", "text/html"))
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new()
+            .with_user_agent("engagement-42".to_string())
+            .unwrap()
+            .with_max_depth(2);
+        crawler.crawl(&format!("{}/", uri), 1).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests.len() >= 2);
+        for request in requests {
+            assert_eq!(request.headers.get("user-agent").unwrap(), "engagement-42");
+        }
+    }
+
+    /// Test the default Rinzler User-Agent is sent when none is configured
+    #[tokio::test]
+    async fn test_default_user_agent_is_sent() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).insert_header("content-type", "text/html"))
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(2);
+        crawler
+            .crawl(&format!("{}/", mock_server.uri()), 1)
+            .await
+            .unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(!requests.is_empty());
+        for request in requests {
+            assert_eq!(
+                request.headers.get("user-agent").unwrap(),
+                crate::client::USER_AGENT
+            );
+        }
+    }
+
+    /// Test --max-per-host 1 serializes requests to a single host
+    #[tokio::test]
+    async fn test_max_per_host_serializes_requests() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        let home = format!(
+            r#"<html><body>
+                <a href="{0}/a">a</a>
+                <a href="{0}/b">b</a>
+                <a href="{0}/c">c</a>
+                <a href="{0}/d">d</a>
+            </body></html>"#,
+            uri
+        );
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(home.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_delay(Duration::from_millis(150)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new().with_max_depth(2).with_max_per_host(1);
+        let start = Instant::now();
+        let results = crawler.crawl(&format!("{}/", uri), 4).await.unwrap();
+        let elapsed = start.elapsed();
+
+        assert_eq!(results.len(), 5);
+        // Four workers, but the delayed pages must still be fetched one at a time
+        assert!(
+            elapsed >= Duration::from_millis(600),
+            "requests overlapped: crawl took {:?}",
+            elapsed
+        );
+    }
+
+    /// Test many workers over thousands of cross-linked pages crawl each page exactly once
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_visited_dedup_under_many_workers() {
+        const PAGES: usize = 2000;
+
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+        let page_uri = uri.clone();
+        Mock::given(method("GET"))
+            .respond_with(move |request: &wiremock::Request| {
+                // The home page links every page; each page links a few others and home,
+                // so almost every discovered link is a duplicate
+                let links: Vec<String> = match request.url.path() {
+                    "/" => (0..PAGES).map(|i| format!("{}/p{}", page_uri, i)).collect(),
+                    path => {
+                        let i: usize = path.trim_start_matches("/p").parse().unwrap_or(0);
+                        vec![
+                            format!("{}/", page_uri),
+                            format!("{}/p{}", page_uri, (i + 1) % PAGES),
+                            format!("{}/p{}", page_uri, (i * 7) % PAGES),
+                            format!("{}/p{}/", page_uri, (i + 2) % PAGES),
+                        ]
+                    }
+                };
+                let body: String = links
+                    .iter()
+                    .map(|link| format!(r#"<a href="{}">x</a>"#, link))
+                    .collect();
+                ResponseTemplate::new(200).set_body_raw(
+                    format!("<html><body>{}</body></html>", body),
+                    "text/html",
+                )
+            })
+            .mount(&mock_server)
+            .await;
+
+        let crawler = Crawler::new()
+            .with_max_depth(3)
+            .with_max_per_host(64);
+        let results = crawler.crawl(&format!("{}/", uri), 32).await.unwrap();
+
+        assert_eq!(results.len(), PAGES + 1);
+        let unique: HashSet<String> = results.iter().map(|r| normalize_url(&r.url)).collect();
+        assert_eq!(unique.len(), PAGES + 1);
+        assert_eq!(crawler.get_visited_count().await, PAGES + 1);
+
+        let mut requested: HashMap<String, usize> = HashMap::new();
+        for request in mock_server.received_requests().await.unwrap() {
+            *requested
+                .entry(request.url.path().trim_end_matches('/').to_string())
+                .or_default() += 1;
+        }
+        assert_eq!(requested.len(), PAGES + 1);
+        assert!(
+            requested.values().all(|&count| count == 1),
+            "pages requested more than once"
+        );
+    }
+
+    /// Test an oversized body is cut at the limit and the page still recorded
+    #[tokio::test]
+    async fn test_max_body_bytes_truncates_page() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        let early = format!(r#"<html><body><a href="{}/early">early</a>"#, uri);
+        let late = format!(r#"<a href="{}/late">late</a></body></html>"#, uri);
+        let page = format!("{}{}{}", early, " ".repeat(4096), late);
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(page.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let root = format!("{}/", uri);
+        let crawler = Crawler::new().with_max_body_bytes(early.len() + 16);
+        let results = crawler.crawl(&root, 1).await.unwrap();
+
+        let home = results.iter().find(|r| r.url == root).unwrap();
+        assert_eq!(home.status_code, 200);
+        assert!(home.body_truncated);
+        assert!(home.links_found.iter().any(|l| l.ends_with("/early")));
+        assert!(!home.links_found.iter().any(|l| l.ends_with("/late")));
+
+        // Pages under the limit are read whole
+        let small = results.iter().find(|r| r.url.ends_with("/early")).unwrap();
+        assert!(!small.body_truncated);
+    }
+
+    #[test]
+    fn test_is_binary_content_type() {
+        assert!(is_binary_content_type("image/png"));
+        assert!(is_binary_content_type("application/PDF"));
+        assert!(is_binary_content_type("application/zip; name=site.zip"));
+        assert!(is_binary_content_type("font/woff2"));
+        assert!(!is_binary_content_type("image/svg+xml"));
+        assert!(!is_binary_content_type("text/html; charset=utf-8"));
+        assert!(!is_binary_content_type("application/json"));
+    }
+
+    /// Test a binary page is recorded from its headers without reading the body
+    #[tokio::test]
+    async fn test_binary_body_not_read() {
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        let home = format!(r#"<html><body><a href="{}/logo.png">logo</a></body></html>"#, uri);
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .set_body_bytes(home.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/logo.png"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "image/png")
+                    .set_body_bytes(vec![0u8; 64 * 1024]),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let results = Crawler::new()
+            .with_max_body_bytes(1024)
+            .crawl(&format!("{}/", uri), 1)
+            .await
+            .unwrap();
+
+        let logo = results.iter().find(|r| r.url.ends_with("/logo.png")).unwrap();
+        assert_eq!(logo.status_code, 200);
+        assert_eq!(logo.content_type.as_deref(), Some("image/png"));
+        assert_eq!(logo.content_length, Some(64 * 1024));
+        // Reading any of the body would have hashed it and hit the 1 KiB limit
+        assert!(logo.content_hash.is_none());
+        assert!(!logo.body_truncated);
+
+        // With skipping off the body is read like any other page
+        let results = Crawler::new()
+            .with_skip_binary_bodies(false)
+            .crawl(&format!("{}/logo.png", uri), 1)
+            .await
+            .unwrap();
+        assert!(results[0].content_hash.is_some());
+    }
+
+    /// Test a form login's session cookie is carried into the crawl
+    #[tokio::test]
+    async fn test_crawl_with_login_session() {
+        use crate::client::{LoginSettings, establish_session};
+        use wiremock::matchers::{body_string, header};
+
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        Mock::given(method("POST"))
+            .and(path("/login"))
+            .and(body_string("username=admin&password=hunter2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("set-cookie", "session=abc123; Path=/")
+                    .set_body_string("Welcome back, admin"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/private"))
+            .and(header("cookie", "session=abc123"))
+            .respond_with(ResponseTemplate::new(200).insert_header("content-type", "text/html"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/private"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        let private = format!("{}/private", uri);
+        let results = Crawler::new().crawl(&private, 1).await.unwrap();
+        assert_eq!(results[0].status_code, 401);
+
+        let crawler = Crawler::new();
+        let login = LoginSettings {
+            url: format!("{}/login", uri),
+            form_data: "username=admin&password=hunter2".to_string(),
+            success_contains: Some("Welcome back".to_string()),
+        };
+        let client = establish_session(crawler.client_config(), &login).await.unwrap();
+        let results = crawler.with_client(client).crawl(&private, 1).await.unwrap();
+        assert_eq!(results[0].status_code, 200);
+
+        // Wrong credentials: the form matcher misses and the marker is absent
+        let failed = LoginSettings {
+            form_data: "username=admin&password=wrong".to_string(),
+            ..login
+        };
+        assert!(establish_session(&ClientConfig::default(), &failed).await.is_err());
+    }
+
+    /// Test a cookie set by one page is sent when crawling the next
+    #[tokio::test]
+    async fn test_cookies_persist_across_pages() {
+        use wiremock::matchers::header;
+
+        let mock_server = MockServer::start().await;
+        let uri = mock_server.uri();
+
+        let page_a = format!(r#"<html><body><a href="{}/b">b</a></body></html>"#, uri);
+        Mock::given(method("GET"))
+            .and(path("/a"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/html")
+                    .insert_header("set-cookie", "visited=yes; Path=/")
+                    .set_body_bytes(page_a.as_bytes()),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/b"))
+            .and(header("cookie", "visited=yes"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/b"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&mock_server)
+            .await;
+
+        let status_of_b = |results: &[CrawlResult]| {
+            results
+                .iter()
+                .find(|r| r.url.ends_with("/b"))
+                .map(|r| r.status_code)
+        };
+
+        let results = Crawler::new().crawl(&format!("{}/a", uri), 1).await.unwrap();
+        assert_eq!(status_of_b(&results), Some(200));
+
+        let results = Crawler::new()
+            .with_cookies(false)
+            .unwrap()
+            .crawl(&format!("{}/a", uri), 1)
+            .await
+            .unwrap();
+        assert_eq!(status_of_b(&results), Some(403));
+    }
 }
@@ -8,6 +8,9 @@ pub enum ScanError {
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
 
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+
     #[error("Parse error: {0}")]
     ParseError(String),
 
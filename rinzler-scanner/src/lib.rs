@@ -1,7 +1,15 @@
+pub mod client;
 pub mod crawler;
 pub mod error;
 pub mod result;
 
-pub use crawler::{Crawler, CrossDomainCallback, ProgressCallback, ResultCallback};
+pub use client::{
+    Auth, ClientConfig, LoginSettings, MAX_RETRY_AFTER, RequestSettings, build_client,
+    establish_session, parse_retry_after, retry_after,
+};
+pub use crawler::{
+    Crawler, CrossDomainCallback, ProgressCallback, ResultCallback, content_hash, host_in_scope,
+    is_binary_content_type, normalize_url, registrable_domain,
+};
 pub use error::ScanError;
-pub use result::CrawlResult;
+pub use result::{CrawlResult, CrawlStats};
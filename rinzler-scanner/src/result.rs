@@ -10,7 +10,31 @@ pub struct CrawlResult {
     pub response_time: Duration,
     pub links_found: Vec<String>,
     pub forms_found: usize,
+    /// Forms containing a password input (login or registration pages)
+    #[serde(default)]
+    pub password_forms_found: usize,
+    /// Form fields (`input`, `select`, `textarea`) on the page
+    #[serde(default)]
+    pub inputs_found: usize,
+    /// Form field names and query-string parameter names from the page's links
+    #[serde(default)]
+    pub parameters: Vec<String>,
     pub scripts_found: usize,
+    /// Final URL when the request was redirected
+    #[serde(default)]
+    pub redirected_to: Option<String>,
+    /// Response headers as (lowercase name, value) pairs
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// Hex SHA-256 of the response body; `None` when a binary body was skipped
+    #[serde(default)]
+    pub content_hash: Option<String>,
+    /// The body exceeded the crawler's size limit; only its first bytes were read
+    #[serde(default)]
+    pub body_truncated: bool,
+    /// Start of the decoded body for passive checks; not serialized
+    #[serde(skip)]
+    pub body_sample: Option<String>,
     pub error: Option<String>,
 }
 
@@ -24,7 +48,15 @@ impl CrawlResult {
             response_time: Duration::from_secs(0),
             links_found: Vec::new(),
             forms_found: 0,
+            password_forms_found: 0,
+            inputs_found: 0,
+            parameters: Vec::new(),
             scripts_found: 0,
+            redirected_to: None,
+            headers: Vec::new(),
+            content_hash: None,
+            body_truncated: false,
+            body_sample: None,
             error: None,
         }
     }
@@ -38,8 +70,77 @@ impl CrawlResult {
             response_time: Duration::from_secs(0),
             links_found: Vec::new(),
             forms_found: 0,
+            password_forms_found: 0,
+            inputs_found: 0,
+            parameters: Vec::new(),
             scripts_found: 0,
+            redirected_to: None,
+            headers: Vec::new(),
+            content_hash: None,
+            body_truncated: false,
+            body_sample: None,
             error: Some(error),
         }
     }
 }
+
+/// Coverage metrics describing how thorough a crawl was
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CrawlStats {
+    /// Deepest link level actually fetched (the seed URL is depth 0)
+    pub max_depth_reached: usize,
+    /// Deepest level the crawler was allowed to fetch
+    pub depth_limit: usize,
+    /// Distinct hosts with at least one crawled page
+    pub unique_hosts: usize,
+    /// Unique URLs discovered, including ones never fetched
+    pub links_discovered: usize,
+    /// Pages fetched successfully
+    pub pages_crawled: usize,
+    /// Whether links were dropped because they exceeded the depth limit
+    pub depth_limit_hit: bool,
+    /// Page budget the crawl ran with, if any
+    #[serde(default)]
+    pub max_pages: Option<usize>,
+    /// Whether the crawl was truncated because the page budget ran out
+    #[serde(default)]
+    pub page_limit_hit: bool,
+    /// Whether the idle watchdog stopped the crawl because no page completed in time
+    #[serde(default)]
+    pub stalled: bool,
+    /// Whether the user stopped the crawl before it finished
+    #[serde(default)]
+    pub cancelled: bool,
+    /// Cap on URLs waiting in the worker queues, if any
+    #[serde(default)]
+    pub max_queue_size: Option<usize>,
+    /// Most URLs waiting in the worker queues at once
+    #[serde(default)]
+    pub peak_queue_len: usize,
+    /// Discovered links dropped because the queues were full
+    #[serde(default)]
+    pub queue_dropped: usize,
+}
+
+impl CrawlStats {
+    /// Names of the crawl limits that cut the crawl short
+    pub fn limits_hit(&self) -> Vec<&'static str> {
+        let mut limits = Vec::new();
+        if self.depth_limit_hit {
+            limits.push("depth");
+        }
+        if self.page_limit_hit {
+            limits.push("max-pages");
+        }
+        if self.stalled {
+            limits.push("idle-timeout (stalled)");
+        }
+        if self.cancelled {
+            limits.push("cancelled");
+        }
+        if self.queue_dropped > 0 {
+            limits.push("max-queue-size");
+        }
+        limits
+    }
+}
@@ -0,0 +1,113 @@
+/// A REPL command as known to argument checking and tab completion
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CommandSpec {
+    pub name: &'static str,
+    /// Subcommands completed after the name; empty when the command takes plain arguments
+    pub subcommands: &'static [&'static str],
+    /// Minimum and maximum number of arguments after the name (a subcommand counts as one)
+    pub arity: (usize, usize),
+    pub usage: &'static str,
+}
+
+impl CommandSpec {
+    pub fn accepts(&self, arg_count: usize) -> bool {
+        arg_count >= self.arity.0 && arg_count <= self.arity.1
+    }
+}
+
+const fn spec(
+    name: &'static str,
+    subcommands: &'static [&'static str],
+    arity: (usize, usize),
+    usage: &'static str,
+) -> CommandSpec {
+    CommandSpec {
+        name,
+        subcommands,
+        arity,
+        usage,
+    }
+}
+
+/// Every command the REPL understands
+pub const COMMANDS: &[CommandSpec] = &[
+    spec("init", &[], (0, 1), "init [path]"),
+    spec(
+        "workspace",
+        &["create", "remove", "list", "rename"],
+        (1, 3),
+        "workspace create|remove|list|rename ...",
+    ),
+    spec("crawl", &[], (1, 2), "crawl <url> [threads]"),
+    spec("fuzz", &[], (1, 3), "fuzz <url> [wordlist] [threads]"),
+    spec("node", &[], (1, 1), "node <url>"),
+    spec(
+        "plugin",
+        &["list", "register", "unregister"],
+        (1, 3),
+        "plugin list|register|unregister ...",
+    ),
+    spec("clear", &[], (0, 0), "clear"),
+    spec("help", &[], (0, 0), "help"),
+    spec("exit", &[], (0, 0), "exit"),
+    spec("quit", &[], (0, 0), "quit"),
+    spec(":q!", &[], (0, 0), ":q!"),
+    spec(":wq!", &[], (0, 0), ":wq!"),
+    spec("ZZ", &[], (0, 0), "ZZ"),
+];
+
+/// Look up a command by exact name
+pub fn find_command(name: &str) -> Option<&'static CommandSpec> {
+    COMMANDS.iter().find(|spec| spec.name == name)
+}
+
+/// Complete the token being typed against command names, or against the
+/// subcommands of the command already entered. Returns the candidate input
+/// lines in alphabetical order.
+pub fn complete(input: &str) -> Vec<String> {
+    let mut tokens: Vec<&str> = input.split_whitespace().collect();
+    // A trailing space starts a new, empty token
+    if input.is_empty() || input.ends_with(char::is_whitespace) {
+        tokens.push("");
+    }
+
+    let mut candidates: Vec<String> = match tokens.as_slice() {
+        [prefix] => COMMANDS
+            .iter()
+            .filter(|spec| spec.name.starts_with(prefix))
+            .map(|spec| spec.name.to_string())
+            .collect(),
+        [command, prefix] => find_command(command)
+            .map(|spec| {
+                spec.subcommands
+                    .iter()
+                    .filter(|sub| sub.starts_with(prefix))
+                    .map(|sub| format!("{} {}", command, sub))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    candidates.sort();
+    candidates
+}
+
+/// Longest prefix shared by every candidate
+pub fn common_prefix(candidates: &[String]) -> String {
+    let Some(first) = candidates.first() else {
+        return String::new();
+    };
+    let mut prefix_len = first.len();
+    for candidate in &candidates[1..] {
+        prefix_len = first
+            .char_indices()
+            .zip(candidate.chars())
+            .take_while(|((_, a), b)| a == b)
+            .last()
+            .map(|((idx, a), _)| idx + a.len_utf8())
+            .unwrap_or(0)
+            .min(prefix_len);
+    }
+    first[..prefix_len].to_string()
+}
@@ -1,6 +1,8 @@
 use anyhow::Result;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -10,33 +12,42 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
+};
+use rinzler_core::report::{
+    FindingData, ReportData, ScanInfo, SeverityCounts, SitemapNode, generate_text_report, save_report,
 };
+use std::collections::VecDeque;
 use std::io;
+use std::path::Path;
 use std::sync::{
     Arc,
     atomic::{AtomicBool, Ordering},
 };
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 
 /// Security finding information for TUI display
 #[derive(Debug, Clone)]
 pub struct SecurityFinding {
     pub title: String,
     pub severity: String,
+    pub finding_type: String,
     pub description: String,
     pub impact: String,
     pub remediation: String,
     pub cwe: Option<String>,
     pub owasp: Option<String>,
+    pub cvss: Option<f32>,
+    pub confidence: String,
 }
 
 /// Message types for communication between crawler and TUI
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub enum CrawlMessage {
     /// Session started with ID
     SessionStarted {
         session_id: String,
+        targets: Vec<String>,
     },
     /// A URL was discovered/processed
     Finding {
@@ -60,6 +71,11 @@ pub enum CrawlMessage {
         total: usize,
         findings_count: usize,
     },
+    /// A link to a new domain was found in prompt mode; the user's answer goes back on `reply`
+    CrossDomainPrompt {
+        domain: String,
+        reply: oneshot::Sender<bool>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -69,17 +85,308 @@ pub enum LogLevel {
     Error,
 }
 
+/// One findings panel row: (url, status_code, content_type, security_findings)
+pub type FindingRow = (String, u16, Option<String>, Vec<SecurityFinding>);
+
+/// Narrows the findings panel without touching the underlying findings
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FindingFilter {
+    /// Case-insensitive URL substring; empty matches every URL
+    pub query: String,
+    /// Minimum severity rank (see `severity_rank`) a row must reach
+    pub min_severity: Option<u8>,
+}
+
+impl FindingFilter {
+    pub fn is_active(&self) -> bool {
+        !self.query.is_empty() || self.min_severity.is_some()
+    }
+}
+
+/// Rank a severity label from info (0) up to critical (4)
+pub fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        "critical" => 4,
+        "high" => 3,
+        "medium" => 2,
+        "low" => 1,
+        _ => 0,
+    }
+}
+
+/// Whether a findings row passes the filter; rows without security findings
+/// never pass a severity filter
+pub fn matches_filter(url: &str, security_findings: &[SecurityFinding], filter: &FindingFilter) -> bool {
+    if !filter.query.is_empty() && !url.to_lowercase().contains(&filter.query.to_lowercase()) {
+        return false;
+    }
+
+    match filter.min_severity {
+        Some(min) => security_findings
+            .iter()
+            .any(|finding| severity_rank(&finding.severity) >= min),
+        None => true,
+    }
+}
+
+/// Indices of the rows that pass the filter, in their original order
+pub fn filtered_indices(findings: &[FindingRow], filter: &FindingFilter) -> Vec<usize> {
+    findings
+        .iter()
+        .enumerate()
+        .filter(|(_, (url, _, _, security_findings))| matches_filter(url, security_findings, filter))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Keep a selection inside a list of `visible_len` rows
+pub fn clamp_selection(selected: Option<usize>, visible_len: usize) -> Option<usize> {
+    match selected {
+        Some(_) if visible_len == 0 => None,
+        Some(selected) => Some(selected.min(visible_len - 1)),
+        None => None,
+    }
+}
+
+/// Scrollbar thumb `(position, size)` within a track of `track` rows, for a list
+/// of `total` items showing `visible` of them from `offset`
+pub fn scrollbar_geometry(total: usize, visible: usize, offset: usize, track: usize) -> (usize, usize) {
+    if track == 0 {
+        return (0, 0);
+    }
+    // Everything fits: full-height thumb, nothing to scroll
+    if total <= visible {
+        return (0, track);
+    }
+
+    let thumb_size = (visible * track / total).clamp(1, track);
+    let max_offset = total - visible;
+    let free_track = track - thumb_size;
+    let thumb_position = (offset.min(max_offset) * free_track / max_offset).min(free_track);
+    (thumb_position, thumb_size)
+}
+
+/// Default share of the screen width given to the findings panel
+pub const DEFAULT_FINDINGS_PERCENT: u16 = 60;
+/// Narrowest and widest the findings panel may be, as a percentage of the width
+pub const FINDINGS_PERCENT_RANGE: (u16, u16) = (20, 80);
+
+/// Parse a `findings/side` width split such as `70/30` into the findings panel's
+/// percentage, clamped to [`FINDINGS_PERCENT_RANGE`]. Both parts must add up to 100.
+pub fn parse_layout_ratio(ratio: &str) -> Result<u16, String> {
+    let (findings, side) = ratio
+        .split_once('/')
+        .ok_or_else(|| format!("expected <findings>/<side> (e.g. 70/30), got '{}'", ratio))?;
+    let parse = |part: &str| {
+        part.trim()
+            .parse::<u16>()
+            .map_err(|_| format!("'{}' is not a percentage", part.trim()))
+    };
+    let (findings, side) = (parse(findings)?, parse(side)?);
+    if findings.checked_add(side) != Some(100) {
+        return Err(format!("{}/{} does not add up to 100", findings, side));
+    }
+    Ok(findings.clamp(FINDINGS_PERCENT_RANGE.0, FINDINGS_PERCENT_RANGE.1))
+}
+
+/// Rows moved per mouse-wheel notch
+const MOUSE_SCROLL_STEP: usize = 3;
+
+/// A scrollable panel of the crawl monitor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Panel {
+    Findings,
+    Logs,
+}
+
+/// The panel under the cursor at (`column`, `row`), given where each was last rendered
+pub fn panel_at(column: u16, row: u16, findings_area: Rect, logs_area: Rect) -> Option<Panel> {
+    let contains = |area: Rect| {
+        column >= area.x && column < area.x + area.width && row >= area.y && row < area.y + area.height
+    };
+    if contains(findings_area) {
+        Some(Panel::Findings)
+    } else if contains(logs_area) {
+        Some(Panel::Logs)
+    } else {
+        None
+    }
+}
+
+/// Where a request to leave the monitor stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuitStep {
+    /// Leave the monitor now
+    Quit,
+    /// The crawl is still running: ask before leaving
+    Confirm,
+    /// Quitting was declined; back to normal input
+    Cancel,
+    /// Not an answer to the prompt; keep asking
+    Wait,
+}
+
+/// What q/Esc does: quit at once when the crawl has finished, otherwise ask first
+pub fn on_quit_key(is_complete: bool) -> QuitStep {
+    if is_complete { QuitStep::Quit } else { QuitStep::Confirm }
+}
+
+/// Answer to the quit prompt: y quits, n or Esc cancels, anything else keeps asking
+pub fn on_confirm_quit_key(key: KeyCode) -> QuitStep {
+    match key {
+        KeyCode::Char('y') | KeyCode::Char('Y') => QuitStep::Quit,
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => QuitStep::Cancel,
+        _ => QuitStep::Wait,
+    }
+}
+
+/// Answer to the cross-domain prompt: y follows, n or Esc skips, anything else keeps asking
+pub fn on_follow_prompt_key(key: KeyCode) -> Option<bool> {
+    match key {
+        KeyCode::Char('y') | KeyCode::Char('Y') => Some(true),
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => Some(false),
+        _ => None,
+    }
+}
+
+/// Ask the monitor whether to follow links to `domain`, blocking until it answers.
+/// Counts as a no if the monitor has gone away or closes without answering.
+pub fn prompt_cross_domain(tx: &mpsc::UnboundedSender<CrawlMessage>, domain: &str) -> bool {
+    let (reply, answer) = oneshot::channel();
+    if tx
+        .send(CrawlMessage::CrossDomainPrompt {
+            domain: domain.to_string(),
+            reply,
+        })
+        .is_err()
+    {
+        return false;
+    }
+
+    // Called from a crawl worker: let the runtime move its other tasks off this thread
+    tokio::task::block_in_place(|| answer.blocking_recv().unwrap_or(false))
+}
+
+/// Suggested file name for an `e` export of the given session
+pub fn default_export_path(session_id: Option<&str>) -> String {
+    match session_id {
+        Some(id) => format!("rinzler-{}.txt", id.chars().take(8).collect::<String>()),
+        None => "rinzler-report.txt".to_string(),
+    }
+}
+
+/// Build report data from the rows the monitor has collected so far
+pub fn build_report_data(
+    session_id: &str,
+    targets: &[String],
+    start_time: i64,
+    status: &str,
+    findings: &[FindingRow],
+) -> ReportData {
+    let mut severity_counts = SeverityCounts {
+        critical: 0,
+        high: 0,
+        medium: 0,
+        low: 0,
+        info: 0,
+    };
+    let mut finding_data = Vec::new();
+
+    for (url, _, _, security_findings) in findings {
+        for finding in security_findings {
+            match finding.severity.as_str() {
+                "critical" => severity_counts.critical += 1,
+                "high" => severity_counts.high += 1,
+                "medium" => severity_counts.medium += 1,
+                "low" => severity_counts.low += 1,
+                _ => severity_counts.info += 1,
+            }
+            finding_data.push(FindingData {
+                id: finding_data.len() as i64 + 1,
+                severity: finding.severity.clone(),
+                title: finding.title.clone(),
+                description: finding.description.clone(),
+                url: url.clone(),
+                finding_type: finding.finding_type.clone(),
+                cwe_id: finding.cwe.clone(),
+                owasp_category: finding.owasp.clone(),
+                cvss_score: finding.cvss,
+                confidence: Some(finding.confidence.clone()),
+                impact: Some(finding.impact.clone()),
+                remediation: Some(finding.remediation.clone()),
+                request_sample: None,
+                response_sample: None,
+            });
+        }
+    }
+
+    // Most severe first, as in reports generated from the database
+    finding_data.sort_by_key(|finding| std::cmp::Reverse(severity_rank(&finding.severity)));
+
+    let sitemap_nodes = findings
+        .iter()
+        .map(|(url, status_code, content_type, _)| SitemapNode {
+            url: url.clone(),
+            status_code: *status_code,
+            content_type: content_type.clone(),
+        })
+        .collect();
+
+    ReportData {
+        session_id: session_id.to_string(),
+        total_nodes: findings.len(),
+        findings: finding_data,
+        severity_counts,
+        occurrence_counts: None,
+        scan_info: ScanInfo {
+            start_time,
+            end_time: None,
+            status: status.to_string(),
+            seed_urls: serde_json::to_string(targets).unwrap_or_else(|_| "[]".to_string()),
+            findings_dropped: 0,
+        },
+        sitemap_nodes: Some(sitemap_nodes),
+        sitemap_baseline: None,
+        coverage: None,
+        technologies: Vec::new(),
+        timings: None,
+        grouped_findings: None,
+        changed_nodes: Vec::new(),
+    }
+}
+
+/// What keyboard input is currently being typed into
+#[derive(Debug, Clone, PartialEq)]
+enum InputMode {
+    Normal,
+    /// Editing the URL filter
+    Filter,
+    /// Editing the path for a report export
+    Export(String),
+    /// Asking whether to abandon a running crawl
+    ConfirmQuit,
+}
+
 /// TUI state for monitoring crawl progress
 pub struct CrawlMonitor {
-    findings: Vec<(String, u16, Option<String>, Vec<SecurityFinding>)>,  // (url, status_code, content_type, security_findings)
-    selected_finding: Option<usize>,
+    findings: Vec<FindingRow>,
+    selected_finding: Option<usize>,  // index into the filtered rows
+    filter: FindingFilter,
+    input_mode: InputMode,
     logs: Vec<(LogLevel, String)>,
     progress_count: usize,
     progress_message: String,
     session_id: Option<String>,
+    targets: Vec<String>,
+    started_at: i64,
     is_complete: bool,
     scroll_findings: usize,
     scroll_logs: usize,
+    /// Keep the logs pinned to the newest line; cleared by scrolling up with the mouse
+    follow_logs: bool,
+    paused: Arc<AtomicBool>,
+    /// Cross-domain questions waiting for the user, oldest first
+    follow_prompts: VecDeque<(String, oneshot::Sender<bool>)>,
     rx: mpsc::UnboundedReceiver<CrawlMessage>,
 }
 
@@ -88,24 +395,126 @@ impl CrawlMonitor {
         Self {
             findings: Vec::new(),
             selected_finding: None,
+            filter: FindingFilter::default(),
+            input_mode: InputMode::Normal,
             logs: Vec::new(),
             progress_count: 0,
             progress_message: "Starting crawl...".to_string(),
             session_id: None,
+            targets: Vec::new(),
+            started_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(0),
             is_complete: false,
             scroll_findings: 0,
             scroll_logs: 0,
+            follow_logs: true,
+            paused: Arc::new(AtomicBool::new(false)),
+            follow_prompts: VecDeque::new(),
             rx,
         }
     }
 
+    /// Flip the shared pause flag and log the new state
+    fn toggle_pause(&mut self) {
+        let now_paused = !self.paused.fetch_xor(true, Ordering::Relaxed);
+        let message = if now_paused { "Crawl paused" } else { "Crawl resumed" };
+        self.logs.push((LogLevel::Warn, message.to_string()));
+    }
+
+    /// Send the user's answer to the oldest cross-domain prompt and log it
+    fn answer_follow_prompt(&mut self, follow: bool) {
+        if let Some((domain, reply)) = self.follow_prompts.pop_front() {
+            let _ = reply.send(follow);
+            let message = if follow {
+                format!("Following links to {}", domain)
+            } else {
+                format!("Skipping links to {}", domain)
+            };
+            self.logs.push((LogLevel::Info, message));
+        }
+    }
+
+    /// Move the findings selection by `delta` rows, starting a selection if there is none
+    fn move_selection(&mut self, delta: isize) {
+        let visible_len = self.visible_findings().len();
+        if visible_len == 0 {
+            return;
+        }
+        let last = visible_len - 1;
+        self.selected_finding = Some(match self.selected_finding {
+            Some(selected) => selected.saturating_add_signed(delta).min(last),
+            // Up starts at the last item, down at the top of the viewport
+            None if delta < 0 => last,
+            None => self.scroll_findings.min(last),
+        });
+        if let Some(selected) = self.selected_finding
+            && selected < self.scroll_findings
+        {
+            self.scroll_findings = selected;
+        }
+    }
+
+    /// Scroll the logs by `delta` lines in a panel showing `height` of them
+    fn scroll_logs_by(&mut self, delta: isize, height: usize) {
+        let max_scroll = self.logs.len().saturating_sub(height);
+        let current = if self.follow_logs { max_scroll } else { self.scroll_logs.min(max_scroll) };
+        self.scroll_logs = current.saturating_add_signed(delta).min(max_scroll);
+        // Reaching the bottom resumes following new lines
+        self.follow_logs = self.scroll_logs == max_scroll;
+    }
+
+    /// Scroll whichever panel is under the cursor with the mouse wheel
+    fn handle_mouse(&mut self, kind: MouseEventKind, column: u16, row: u16, findings_area: Rect, logs_area: Rect) {
+        let delta = match kind {
+            MouseEventKind::ScrollUp => -(MOUSE_SCROLL_STEP as isize),
+            MouseEventKind::ScrollDown => MOUSE_SCROLL_STEP as isize,
+            _ => return,
+        };
+        match panel_at(column, row, findings_area, logs_area) {
+            Some(Panel::Findings) => self.move_selection(delta),
+            Some(Panel::Logs) => self.scroll_logs_by(delta, logs_area.height.saturating_sub(2) as usize),
+            None => {}
+        }
+    }
+
+    /// Indices into `findings` of the rows currently shown
+    fn visible_findings(&self) -> Vec<usize> {
+        filtered_indices(&self.findings, &self.filter)
+    }
+
+    /// Keep the selection valid and restart scrolling after the filter changes
+    fn on_filter_changed(&mut self) {
+        self.selected_finding = clamp_selection(self.selected_finding, self.visible_findings().len());
+        self.scroll_findings = 0;
+    }
+
+    /// Write a text report of everything collected so far and log the outcome
+    fn export_report(&mut self, path: &str) {
+        let status = if self.is_complete { "completed" } else { "running" };
+        let data = build_report_data(
+            self.session_id.as_deref().unwrap_or("unknown"),
+            &self.targets,
+            self.started_at,
+            status,
+            &self.findings,
+        );
+
+        match save_report(&generate_text_report(&data), Path::new(path)) {
+            Ok(()) => self.logs.push((LogLevel::Info, format!("Report saved to {}", path))),
+            Err(e) => self.logs.push((LogLevel::Error, format!("Failed to save report to {}: {}", path, e))),
+        }
+    }
+
     /// Process incoming messages from the crawler
     fn process_messages(&mut self) {
         // Process all available messages without blocking
         while let Ok(msg) = self.rx.try_recv() {
             match msg {
-                CrawlMessage::SessionStarted { session_id } => {
+                CrawlMessage::SessionStarted { session_id, targets } => {
                     self.session_id = Some(session_id);
+                    self.targets = targets;
                 }
                 CrawlMessage::Finding {
                     url,
@@ -144,12 +553,28 @@ impl CrawlMonitor {
                         total, findings_count
                     );
                 }
+                CrawlMessage::CrossDomainPrompt { domain, reply } => {
+                    self.follow_prompts.push_back((domain, reply));
+                }
             }
         }
     }
 
     fn render_findings(&self, f: &mut Frame, area: Rect) {
-        let title = format!(" Findings ({}) ", self.findings.len());
+        let visible = self.visible_findings();
+        let mut title = if self.filter.is_active() {
+            format!(" Findings ({}/{}) ", visible.len(), self.findings.len())
+        } else {
+            format!(" Findings ({}) ", self.findings.len())
+        };
+        if self.input_mode == InputMode::Filter || !self.filter.query.is_empty() {
+            let cursor = if self.input_mode == InputMode::Filter { "_" } else { "" };
+            title.push_str(&format!("/{}{} ", self.filter.query, cursor));
+        }
+        if let Some(min) = self.filter.min_severity {
+            let label = ["INFO", "LOW", "MEDIUM", "HIGH", "CRITICAL"][min.min(4) as usize];
+            title.push_str(&format!(">= {} ", label));
+        }
         let block = Block::default()
             .borders(Borders::ALL)
             .title(title)
@@ -159,10 +584,15 @@ impl CrawlMonitor {
         f.render_widget(block, area);
 
         let height = inner.height as usize;
-        let total_items = self.findings.len();
+        let total_items = visible.len();
 
         if total_items == 0 {
-            let empty_msg = Paragraph::new("No findings yet... waiting for results")
+            let message = if self.findings.is_empty() {
+                "No findings yet... waiting for results"
+            } else {
+                "No findings match the filter"
+            };
+            let empty_msg = Paragraph::new(message)
                 .style(Style::default().fg(Color::DarkGray))
                 .wrap(Wrap { trim: true });
             f.render_widget(empty_msg, inner);
@@ -191,9 +621,9 @@ impl CrawlMonitor {
             }
         };
 
-        let items: Vec<ListItem> = self
-            .findings
+        let items: Vec<ListItem> = visible
             .iter()
+            .map(|&row| &self.findings[row])
             .enumerate()
             .skip(scroll_offset)
             .take(height)
@@ -208,13 +638,7 @@ impl CrawlMonitor {
                 // Add security indicator if there are findings
                 let security_badge = if !security_findings.is_empty() {
                     let max_severity = security_findings.iter()
-                        .map(|f| match f.severity.as_str() {
-                            "critical" => 4,
-                            "high" => 3,
-                            "medium" => 2,
-                            "low" => 1,
-                            _ => 0,
-                        })
+                        .map(|f| severity_rank(&f.severity))
                         .max()
                         .unwrap_or(0);
 
@@ -293,15 +717,8 @@ impl CrawlMonitor {
             return;
         }
 
-        // Calculate thumb size (proportional to visible/total ratio)
-        let thumb_size = ((visible_items as f32 / total_items as f32) * scrollbar_height as f32)
-            .max(1.0)
-            .floor() as usize;
-
-        // Calculate thumb position
-        let scroll_ratio = scroll_offset as f32 / (total_items - visible_items) as f32;
-        let thumb_position = (scroll_ratio * (scrollbar_height - thumb_size) as f32)
-            .floor() as usize;
+        let (thumb_position, thumb_size) =
+            scrollbar_geometry(total_items, visible_items, scroll_offset, scrollbar_height);
 
         // Draw scrollbar on the right edge of the findings panel
         let scrollbar_x = area.x + area.width - 1;
@@ -336,6 +753,8 @@ impl CrawlMonitor {
     fn render_progress(&self, f: &mut Frame, area: Rect) {
         let (title, border_color) = if self.is_complete {
             (" Complete ", Color::Green)
+        } else if self.paused.load(Ordering::Relaxed) {
+            (" Paused ", Color::Magenta)
         } else {
             (" Progress ", Color::Yellow)
         };
@@ -390,7 +809,7 @@ impl CrawlMonitor {
         let total_items = self.logs.len();
 
         // Auto-scroll to bottom if not manually scrolled
-        let scroll_offset = if self.scroll_logs == 0 && total_items > height {
+        let scroll_offset = if self.follow_logs {
             total_items.saturating_sub(height)
         } else {
             self.scroll_logs.min(total_items.saturating_sub(height))
@@ -415,8 +834,64 @@ impl CrawlMonitor {
         f.render_widget(list, inner);
     }
 
+    /// Modal asking whether to follow links to the oldest pending cross-domain
+    fn render_follow_prompt(&self, f: &mut Frame, area: Rect) {
+        let Some((domain, _)) = self.follow_prompts.front() else {
+            return;
+        };
+
+        let width = area.width.min(64);
+        let height = area.height.min(5);
+        let modal = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Cross-domain link ")
+            .border_style(Style::default().fg(Color::Yellow));
+        let text = vec![
+            Line::from(Span::styled(
+                domain.clone(),
+                Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+            )),
+            Line::from(vec![
+                Span::raw("Follow links to this domain? "),
+                Span::styled("[y/n]", Style::default().fg(Color::Yellow)),
+            ]),
+        ];
+
+        f.render_widget(Clear, modal);
+        f.render_widget(Paragraph::new(text).block(block).wrap(Wrap { trim: true }), modal);
+    }
+
     fn render_hints(&self, f: &mut Frame, area: Rect) {
-        let hints = if self.is_complete {
+        let hints = if let Some((domain, _)) = self.follow_prompts.front() {
+            Line::from(vec![
+                Span::styled(
+                    format!(" Follow links to {}? [y/n] ", domain),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+            ])
+        } else if self.input_mode == InputMode::ConfirmQuit {
+            Line::from(vec![
+                Span::styled(
+                    " Crawl in progress — quit anyway? [y/n] ",
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ),
+            ])
+        } else if let InputMode::Export(ref path) = self.input_mode {
+            Line::from(vec![
+                Span::raw(format!(" Export report to: {}_  ", path)),
+                Span::styled(" Enter ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(" Save  "),
+                Span::styled(" ESC ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(" Cancel"),
+            ])
+        } else if self.is_complete {
             Line::from(vec![
                 Span::styled(" q/ESC ", Style::default().fg(Color::Black).bg(Color::Gray)),
                 Span::raw(" Exit  "),
@@ -427,12 +902,20 @@ impl CrawlMonitor {
                 Span::styled(" Home/End ", Style::default().fg(Color::Black).bg(Color::Gray)),
                 Span::raw(" Top/Bottom  "),
                 Span::styled(" Enter ", Style::default().fg(Color::Black).bg(Color::Gray)),
-                Span::raw(" Details"),
+                Span::raw(" Details  "),
+                Span::styled(" / ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(" Filter  "),
+                Span::styled(" 1-5/0 ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(" Severity  "),
+                Span::styled(" e ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(" Export"),
             ])
         } else {
             Line::from(vec![
                 Span::styled(" Ctrl+C ", Style::default().fg(Color::Black).bg(Color::Gray)),
                 Span::raw(" Stop  "),
+                Span::styled(" p ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(if self.paused.load(Ordering::Relaxed) { " Resume  " } else { " Pause  " }),
                 Span::styled(" ↑/↓ ", Style::default().fg(Color::Black).bg(Color::Gray)),
                 Span::raw(" Select  "),
                 Span::styled(" PgUp/PgDn ", Style::default().fg(Color::Black).bg(Color::Gray)),
@@ -440,7 +923,13 @@ impl CrawlMonitor {
                 Span::styled(" Home/End ", Style::default().fg(Color::Black).bg(Color::Gray)),
                 Span::raw(" Top/Bottom  "),
                 Span::styled(" Enter ", Style::default().fg(Color::Black).bg(Color::Gray)),
-                Span::raw(" Details"),
+                Span::raw(" Details  "),
+                Span::styled(" / ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(" Filter  "),
+                Span::styled(" 1-5/0 ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(" Severity  "),
+                Span::styled(" e ", Style::default().fg(Color::Black).bg(Color::Gray)),
+                Span::raw(" Export"),
             ])
         };
 
@@ -454,15 +943,23 @@ impl CrawlMonitor {
 pub fn run_monitor(
     rx: mpsc::UnboundedReceiver<CrawlMessage>,
     should_exit: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+    stop: Arc<AtomicBool>,
+    findings_percent: u16,
 ) -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     let mut monitor = CrawlMonitor::new(rx);
+    monitor.paused = paused;
+
+    // Where the scrollable panels were last drawn, for routing mouse-wheel events
+    let mut findings_area = Rect::default();
+    let mut logs_area = Rect::default();
 
     // Main loop
     loop {
@@ -486,8 +983,8 @@ pub fn run_monitor(
             let main_chunks = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([
-                    Constraint::Percentage(60), // Findings (left)
-                    Constraint::Percentage(40), // Progress + Logs (right)
+                    Constraint::Percentage(findings_percent),       // Findings (left)
+                    Constraint::Percentage(100 - findings_percent), // Progress + Logs (right)
                 ])
                 .split(vertical_chunks[0]);
 
@@ -505,6 +1002,10 @@ pub fn run_monitor(
             monitor.render_progress(f, right_chunks[0]);
             monitor.render_logs(f, right_chunks[1]);
             monitor.render_hints(f, vertical_chunks[1]);
+            monitor.render_follow_prompt(f, size);
+
+            findings_area = main_chunks[0];
+            logs_area = right_chunks[1];
         })?;
 
         // Check for exit signal (but don't auto-exit on completion)
@@ -512,109 +1013,192 @@ pub fn run_monitor(
             break;
         }
 
-        // Poll for keyboard events (non-blocking with timeout)
-        if event::poll(std::time::Duration::from_millis(100))?
-            && let Event::Key(key) = event::read()?
+        // Poll for keyboard and mouse events (non-blocking with timeout)
+        let event = if event::poll(std::time::Duration::from_millis(100))? {
+            Some(event::read()?)
+        } else {
+            None
+        };
+        if let Some(Event::Mouse(mouse)) = event {
+            monitor.handle_mouse(mouse.kind, mouse.column, mouse.row, findings_area, logs_area);
+        }
+        if let Some(Event::Key(key)) = event
             && key.kind == KeyEventKind::Press
         {
-            match key.code {
-                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    // Ctrl+C pressed - exit immediately
-                    break;
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                // Ctrl+C pressed - stop a running crawl (partial results are kept) and exit
+                if !monitor.is_complete {
+                    stop.store(true, Ordering::Relaxed);
                 }
-                KeyCode::Char('q') | KeyCode::Esc => {
-                    break;
+                break;
+            }
+
+            // An open cross-domain prompt takes every key until it is answered
+            if !monitor.follow_prompts.is_empty() {
+                if let Some(follow) = on_follow_prompt_key(key.code) {
+                    monitor.answer_follow_prompt(follow);
                 }
-                KeyCode::Up => {
-                    if !monitor.findings.is_empty() {
-                        if let Some(selected) = monitor.selected_finding {
-                            let new_selected = selected.saturating_sub(1);
-                            monitor.selected_finding = Some(new_selected);
-                            // Update scroll to keep selection in view
-                            if new_selected < monitor.scroll_findings {
-                                monitor.scroll_findings = new_selected;
-                            }
-                        } else {
-                            // Start selection at the last item
-                            monitor.selected_finding = Some(monitor.findings.len().saturating_sub(1));
+                continue;
+            }
+
+            match monitor.input_mode {
+                InputMode::Filter => {
+                    match key.code {
+                        KeyCode::Esc => {
+                            // Cancel editing and drop the URL filter
+                            monitor.filter.query.clear();
+                            monitor.input_mode = InputMode::Normal;
+                            monitor.on_filter_changed();
+                        }
+                        KeyCode::Enter => {
+                            monitor.input_mode = InputMode::Normal;
+                        }
+                        KeyCode::Backspace => {
+                            monitor.filter.query.pop();
+                            monitor.on_filter_changed();
                         }
+                        KeyCode::Char(c) => {
+                            monitor.filter.query.push(c);
+                            monitor.on_filter_changed();
+                        }
+                        _ => {}
                     }
+                    continue;
                 }
-                KeyCode::Down => {
-                    if !monitor.findings.is_empty() {
-                        if let Some(selected) = monitor.selected_finding {
-                            let new_selected = (selected + 1).min(monitor.findings.len() - 1);
-                            monitor.selected_finding = Some(new_selected);
-                            // Scroll calculation is done in render_findings, no need to update here
-                        } else {
-                            // Start selection at the first item (top of viewport)
-                            monitor.selected_finding = Some(monitor.scroll_findings);
+                InputMode::Export(ref mut path) => {
+                    match key.code {
+                        KeyCode::Esc => {
+                            monitor.input_mode = InputMode::Normal;
+                        }
+                        KeyCode::Enter if !path.is_empty() => {
+                            let path = path.clone();
+                            monitor.input_mode = InputMode::Normal;
+                            monitor.export_report(&path);
+                        }
+                        KeyCode::Backspace => {
+                            path.pop();
+                        }
+                        KeyCode::Char(c) => {
+                            path.push(c);
                         }
+                        _ => {}
                     }
+                    continue;
+                }
+                InputMode::ConfirmQuit => {
+                    match on_confirm_quit_key(key.code) {
+                        QuitStep::Quit => {
+                            // Abandon the crawl: it stops, keeps what it has and marks the session cancelled
+                            stop.store(true, Ordering::Relaxed);
+                            should_exit.store(true, Ordering::Relaxed);
+                            break;
+                        }
+                        QuitStep::Cancel => {
+                            monitor.input_mode = InputMode::Normal;
+                        }
+                        QuitStep::Confirm | QuitStep::Wait => {}
+                    }
+                    continue;
+                }
+                InputMode::Normal => {}
+            }
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    match on_quit_key(monitor.is_complete) {
+                        QuitStep::Quit => break,
+                        _ => monitor.input_mode = InputMode::ConfirmQuit,
+                    }
+                }
+                KeyCode::Char('p') if !monitor.is_complete => {
+                    monitor.toggle_pause();
+                }
+                KeyCode::Char('/') => {
+                    monitor.input_mode = InputMode::Filter;
+                }
+                KeyCode::Char('e') => {
+                    monitor.input_mode = InputMode::Export(default_export_path(monitor.session_id.as_deref()));
+                }
+                KeyCode::Char(c @ '0'..='5') => {
+                    // 1 = info and above ... 5 = critical only; 0 clears
+                    monitor.filter.min_severity = c
+                        .to_digit(10)
+                        .filter(|&digit| digit > 0)
+                        .map(|digit| digit as u8 - 1);
+                    monitor.on_filter_changed();
+                }
+                KeyCode::Up => {
+                    monitor.move_selection(-1);
+                }
+                KeyCode::Down => {
+                    // Scroll calculation is done in render_findings
+                    monitor.move_selection(1);
                 }
                 KeyCode::Enter => {
                     // Show detailed view of selected finding
                     if let Some(selected) = monitor.selected_finding {
-                        if let Some((url, status_code, content_type, security_findings)) = monitor.findings.get(selected) {
-                            // Clear previous details and add separator
-                            monitor.logs.push((LogLevel::Info, "".to_string()));
-                            monitor.logs.push((LogLevel::Info,
-                                "╔══════════════════════════════════════════════════════════╗".to_string()));
-                            monitor.logs.push((LogLevel::Info,
-                                "║                    FINDING DETAILS                       ║".to_string()));
-                            monitor.logs.push((LogLevel::Info,
-                                "╚══════════════════════════════════════════════════════════╝".to_string()));
-
-                            // Basic info
-                            monitor.logs.push((LogLevel::Info, format!("URL: {}", url)));
-                            monitor.logs.push((LogLevel::Info, format!("Status Code: {}", status_code)));
-                            monitor.logs.push((LogLevel::Info, format!(
-                                "Content-Type: {}",
-                                content_type.as_deref().unwrap_or("N/A")
-                            )));
-
-                            // Security findings if present
-                            if !security_findings.is_empty() {
+                        if let Some(&row) = monitor.visible_findings().get(selected) {
+                            if let Some((url, status_code, content_type, security_findings)) = monitor.findings.get(row) {
+                                // Clear previous details and add separator
                                 monitor.logs.push((LogLevel::Info, "".to_string()));
-                                monitor.logs.push((LogLevel::Warn,
+                                monitor.logs.push((LogLevel::Info,
                                     "╔══════════════════════════════════════════════════════════╗".to_string()));
-                                monitor.logs.push((LogLevel::Warn,
-                                    "║                  SECURITY FINDINGS                       ║".to_string()));
-                                monitor.logs.push((LogLevel::Warn,
+                                monitor.logs.push((LogLevel::Info,
+                                    "║                    FINDING DETAILS                       ║".to_string()));
+                                monitor.logs.push((LogLevel::Info,
                                     "╚══════════════════════════════════════════════════════════╝".to_string()));
 
-                                for (i, finding) in security_findings.iter().enumerate() {
-                                    let level = match finding.severity.as_str() {
-                                        "critical" | "high" => LogLevel::Error,
-                                        "medium" => LogLevel::Warn,
-                                        _ => LogLevel::Info,
-                                    };
+                                // Basic info
+                                monitor.logs.push((LogLevel::Info, format!("URL: {}", url)));
+                                monitor.logs.push((LogLevel::Info, format!("Status Code: {}", status_code)));
+                                monitor.logs.push((LogLevel::Info, format!(
+                                    "Content-Type: {}",
+                                    content_type.as_deref().unwrap_or("N/A")
+                                )));
 
+                                // Security findings if present
+                                if !security_findings.is_empty() {
                                     monitor.logs.push((LogLevel::Info, "".to_string()));
-                                    monitor.logs.push((level, format!("[{}] {}", i + 1, finding.title)));
-                                    monitor.logs.push((level, format!("  Severity: {}", finding.severity.to_uppercase())));
-
-                                    if let Some(ref cwe) = finding.cwe {
-                                        monitor.logs.push((LogLevel::Info, format!("  CWE: {}", cwe)));
+                                    monitor.logs.push((LogLevel::Warn,
+                                        "╔══════════════════════════════════════════════════════════╗".to_string()));
+                                    monitor.logs.push((LogLevel::Warn,
+                                        "║                  SECURITY FINDINGS                       ║".to_string()));
+                                    monitor.logs.push((LogLevel::Warn,
+                                        "╚══════════════════════════════════════════════════════════╝".to_string()));
+
+                                    for (i, finding) in security_findings.iter().enumerate() {
+                                        let level = match finding.severity.as_str() {
+                                            "critical" | "high" => LogLevel::Error,
+                                            "medium" => LogLevel::Warn,
+                                            _ => LogLevel::Info,
+                                        };
+
+                                        monitor.logs.push((LogLevel::Info, "".to_string()));
+                                        monitor.logs.push((level, format!("[{}] {}", i + 1, finding.title)));
+                                        monitor.logs.push((level, format!("  Severity: {}", finding.severity.to_uppercase())));
+
+                                        if let Some(ref cwe) = finding.cwe {
+                                            monitor.logs.push((LogLevel::Info, format!("  CWE: {}", cwe)));
+                                        }
+                                        if let Some(ref owasp) = finding.owasp {
+                                            monitor.logs.push((LogLevel::Info, format!("  OWASP: {}", owasp)));
+                                        }
+
+                                        monitor.logs.push((LogLevel::Info, format!("  Description: {}", finding.description)));
+                                        monitor.logs.push((LogLevel::Info, format!("  Impact: {}", finding.impact)));
+                                        monitor.logs.push((LogLevel::Info, format!("  Remediation: {}", finding.remediation)));
                                     }
-                                    if let Some(ref owasp) = finding.owasp {
-                                        monitor.logs.push((LogLevel::Info, format!("  OWASP: {}", owasp)));
-                                    }
-
-                                    monitor.logs.push((LogLevel::Info, format!("  Description: {}", finding.description)));
-                                    monitor.logs.push((LogLevel::Info, format!("  Impact: {}", finding.impact)));
-                                    monitor.logs.push((LogLevel::Info, format!("  Remediation: {}", finding.remediation)));
                                 }
-                            }
 
-                            monitor.logs.push((LogLevel::Info, "".to_string()));
-                            monitor.logs.push((LogLevel::Info,
-                                "══════════════════════════════════════════════════════════".to_string()));
+                                monitor.logs.push((LogLevel::Info, "".to_string()));
+                                monitor.logs.push((LogLevel::Info,
+                                    "══════════════════════════════════════════════════════════".to_string()));
+                            }
                         }
                     }
                 }
                 KeyCode::PageUp => {
-                    if !monitor.findings.is_empty() {
+                    if !monitor.visible_findings().is_empty() {
                         let height = 10; // Approximate page size
                         monitor.scroll_findings = monitor.scroll_findings.saturating_sub(height);
                         // Update selection to stay in view
@@ -626,9 +1210,9 @@ pub fn run_monitor(
                     }
                 }
                 KeyCode::PageDown => {
-                    if !monitor.findings.is_empty() {
+                    if !monitor.visible_findings().is_empty() {
                         let height = 10; // Approximate page size
-                        let max_scroll = monitor.findings.len().saturating_sub(height);
+                        let max_scroll = monitor.visible_findings().len().saturating_sub(height);
                         monitor.scroll_findings = (monitor.scroll_findings + height).min(max_scroll);
                         // Update selection to stay in view
                         if let Some(selected) = monitor.selected_finding {
@@ -640,14 +1224,17 @@ pub fn run_monitor(
                 }
                 KeyCode::Home => {
                     // Jump to top
-                    monitor.scroll_findings = 0;
-                    monitor.selected_finding = Some(0);
+                    if !monitor.visible_findings().is_empty() {
+                        monitor.scroll_findings = 0;
+                        monitor.selected_finding = Some(0);
+                    }
                 }
                 KeyCode::End => {
                     // Jump to bottom
-                    if !monitor.findings.is_empty() {
-                        monitor.selected_finding = Some(monitor.findings.len() - 1);
-                        monitor.scroll_findings = monitor.findings.len().saturating_sub(10);
+                    if !monitor.visible_findings().is_empty() {
+                        let visible_len = monitor.visible_findings().len();
+                        monitor.selected_finding = Some(visible_len - 1);
+                        monitor.scroll_findings = visible_len.saturating_sub(10);
                     }
                 }
                 _ => {}
@@ -655,9 +1242,12 @@ pub fn run_monitor(
         }
     }
 
+    // Never leave the crawl stuck paused once the monitor is gone
+    monitor.paused.store(false, Ordering::Relaxed);
+
     // Restore terminal
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
     terminal.show_cursor()?;
 
     Ok(())
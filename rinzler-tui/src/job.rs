@@ -0,0 +1,245 @@
+use rinzler_core::crawl::{
+    CrawlOptions, DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_PER_HOST, DEFAULT_POOL_IDLE_TIMEOUT_SECS, DEFAULT_POOL_SIZE,
+    FollowMode, RequestSettings, execute_crawl, generate_crawl_report,
+};
+use rinzler_core::fuzz::{
+    DEFAULT_MAX_RECURSION_DEPTH, FuzzOptions, FuzzResult, Method, execute_fuzz, load_wordlist,
+};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use url::Url;
+
+const DEFAULT_THREADS: usize = 10;
+const DEFAULT_WORDLIST: &str = "~/.config/rinzler/wordlists/default.txt";
+
+/// A long-running REPL command executed on a background thread
+#[derive(Debug, Clone, PartialEq)]
+pub enum Job {
+    Crawl {
+        url: String,
+        threads: usize,
+    },
+    Fuzz {
+        url: String,
+        wordlist: PathBuf,
+        threads: usize,
+    },
+}
+
+/// Map `crawl <url> [threads]` or `fuzz <url> [wordlist] [threads]` to a job
+pub fn parse_job(input: &str) -> Result<Job, String> {
+    let parts: Vec<&str> = input.split_whitespace().collect();
+    match parts.as_slice() {
+        ["crawl", url] => Ok(Job::Crawl {
+            url: parse_target(url)?,
+            threads: DEFAULT_THREADS,
+        }),
+        ["crawl", url, threads] => Ok(Job::Crawl {
+            url: parse_target(url)?,
+            threads: parse_threads(threads)?,
+        }),
+        ["crawl", ..] => Err("Usage: crawl <url> [threads]".to_string()),
+        ["fuzz", url] => Ok(Job::Fuzz {
+            url: parse_target(url)?,
+            wordlist: expand_home(DEFAULT_WORDLIST),
+            threads: DEFAULT_THREADS,
+        }),
+        ["fuzz", url, wordlist] => Ok(Job::Fuzz {
+            url: parse_target(url)?,
+            wordlist: expand_home(wordlist),
+            threads: DEFAULT_THREADS,
+        }),
+        ["fuzz", url, wordlist, threads] => Ok(Job::Fuzz {
+            url: parse_target(url)?,
+            wordlist: expand_home(wordlist),
+            threads: parse_threads(threads)?,
+        }),
+        ["fuzz", ..] => Err("Usage: fuzz <url> [wordlist] [threads]".to_string()),
+        _ => Err(format!("Not a job command: {}", input)),
+    }
+}
+
+/// Parse a target URL, assuming http:// when no scheme is given
+fn parse_target(input: &str) -> Result<String, String> {
+    Url::parse(input)
+        .ok()
+        .filter(|url| url.has_host())
+        .or_else(|| Url::parse(&format!("http://{}", input)).ok())
+        .map(|url| url.to_string())
+        .ok_or_else(|| format!("Invalid URL '{}'", input))
+}
+
+fn parse_threads(input: &str) -> Result<usize, String> {
+    match input.parse::<usize>() {
+        Ok(threads) if threads > 0 => Ok(threads),
+        _ => Err(format!(
+            "Invalid thread count '{}': must be at least 1",
+            input
+        )),
+    }
+}
+
+/// Expand a leading `~/` to the home directory
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Run `job` on its own thread and Tokio runtime. Output lines arrive on the
+/// returned channel, which disconnects once the job has finished.
+pub fn spawn_job(job: Job) -> Receiver<String> {
+    let (tx, rx) = channel();
+    std::thread::spawn(move || {
+        let runtime = match tokio::runtime::Runtime::new() {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                let _ = tx.send(format!("✗ Failed to start runtime: {}", e));
+                return;
+            }
+        };
+        match job {
+            Job::Crawl { url, threads } => runtime.block_on(run_crawl(url, threads, tx)),
+            Job::Fuzz {
+                url,
+                wordlist,
+                threads,
+            } => runtime.block_on(run_fuzz(url, wordlist, threads, tx)),
+        }
+    });
+    rx
+}
+
+async fn run_crawl(url: String, threads: usize, tx: Sender<String>) {
+    let _ = tx.send(format!("🕷️  Crawling: {}", url));
+    let _ = tx.send(format!("Workers: {}", threads));
+    let _ = tx.send("Max depth: 3".to_string());
+    let _ = tx.send("Cross-domain: disabled (same domain only)".to_string());
+    let _ = tx.send(String::new());
+
+    let options = CrawlOptions {
+        urls: vec![url],
+        threads,
+        max_depth: 3,
+        follow_mode: FollowMode::Disabled,
+        show_progress_bars: false, // Disable progress bars in TUI mode
+        entry_paths: Vec::new(),
+        idle_timeout_secs: None,
+        max_pages: None,
+        max_queue_size: None,
+        max_path_depth: None,
+        scope_domain: None,
+        resume_from_url: None,
+        user_agent: None,
+        request: RequestSettings::default(),
+        request_delay_ms: None,
+        login: None,
+        max_per_host: DEFAULT_MAX_PER_HOST,
+        max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        skip_binary_bodies: true,
+        pool_size: DEFAULT_POOL_SIZE,
+        pool_idle_timeout_secs: DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+        paused: None,
+        stop: None,
+        client: None,
+    };
+
+    let progress_tx = tx.clone();
+    let progress_callback = Arc::new(move |message: String| {
+        let _ = progress_tx.send(message);
+    });
+    let result_tx = tx.clone();
+    let result_callback = Arc::new(move |result: rinzler_scanner::CrawlResult| {
+        let line = match result.error {
+            Some(ref error) => format!("  ✗ {} ({})", result.url, error),
+            None => format!("  [{}] {}", result.status_code, result.url),
+        };
+        let _ = result_tx.send(line);
+    });
+
+    match execute_crawl(options, Some(progress_callback), Some(result_callback)).await {
+        Ok(results) => {
+            let _ = tx.send(String::new());
+            let _ = tx.send("✓ Crawl complete!".to_string());
+            let _ = tx.send(String::new());
+            for line in generate_crawl_report(&results).lines() {
+                let _ = tx.send(line.to_string());
+            }
+        }
+        Err(e) => {
+            let _ = tx.send(format!("✗ Crawl failed: {}", e));
+        }
+    }
+}
+
+async fn run_fuzz(url: String, wordlist_path: PathBuf, threads: usize, tx: Sender<String>) {
+    let wordlist = match load_wordlist(&wordlist_path) {
+        Ok(words) => words,
+        Err(e) => {
+            let _ = tx.send(format!("✗ Failed to load wordlist: {}", e));
+            return;
+        }
+    };
+
+    let _ = tx.send(format!("Fuzzing URL: {}", url));
+    let _ = tx.send(format!(
+        "  Wordlist: {} ({} words)",
+        wordlist_path.display(),
+        wordlist.len()
+    ));
+    let _ = tx.send(format!("  Threads: {}", threads));
+    let _ = tx.send(String::new());
+
+    let options = FuzzOptions {
+        base_urls: vec![url],
+        wordlist,
+        threads,
+        show_progress_bars: false, // The REPL owns the terminal
+        method: Method::HEAD,
+        body: None,
+        timeout_secs: 5,
+        db_path: None,
+        include_status: None,
+        exclude_status: None,
+        pool_size: None,
+        pool_idle_timeout_secs: DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+        wildcard_filter: true,
+        max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
+        user_agent: None,
+        request: RequestSettings::default(),
+        request_delay_ms: None,
+        skip_urls: HashSet::new(),
+        on_tested: None,
+        resumed_hits: Vec::new(),
+        on_hit: None,
+    };
+
+    match execute_fuzz(options).await {
+        Ok(results) => {
+            for result in &results {
+                let _ = tx.send(format_fuzz_result(result));
+            }
+            let _ = tx.send(String::new());
+            let _ = tx.send(format!(
+                "✓ Fuzzing complete! {} endpoints found",
+                results.len()
+            ));
+        }
+        Err(e) => {
+            let _ = tx.send(format!("✗ Fuzzing failed: {}", e));
+        }
+    }
+}
+
+fn format_fuzz_result(result: &FuzzResult) -> String {
+    match result.content_length {
+        Some(length) => format!(
+            "  [{}] {} ({} bytes)",
+            result.status_code, result.url, length
+        ),
+        None => format!("  [{}] {}", result.status_code, result.url),
+    }
+}
@@ -1,4 +1,8 @@
+pub mod command;
 pub mod crawl_monitor;
+pub mod job;
+pub mod node;
+pub mod plugin;
 
 use anyhow::Result;
 use crossterm::{
@@ -16,10 +20,11 @@ use ratatui::{
 };
 use std::fs;
 use std::io;
-use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
-use rinzler_core::crawl::{CrawlOptions, FollowMode, execute_crawl, generate_crawl_report};
-use url::Url;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Duration;
+
+const WORKSPACES_UNSUPPORTED: &str = "Workspaces are not supported yet";
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ExitMode {
@@ -40,6 +45,8 @@ pub struct App {
     temp_input: String,
     exit_mode: ExitMode,
     awaiting_save_confirmation: bool,
+    /// Output of the running crawl or fuzz, if any
+    job: Option<Receiver<String>>,
 }
 
 impl Default for App {
@@ -82,6 +89,7 @@ impl App {
             temp_input: String::new(),
             exit_mode: ExitMode::None,
             awaiting_save_confirmation: false,
+            job: None,
         }
     }
 
@@ -95,6 +103,52 @@ impl App {
         self.scroll_offset = 0;
     }
 
+    /// Move any lines the running job has produced into the output
+    pub fn poll_job(&mut self) {
+        let Some(rx) = self.job.take() else {
+            return;
+        };
+        loop {
+            match rx.try_recv() {
+                Ok(line) => self.add_output(line),
+                Err(TryRecvError::Empty) => {
+                    self.job = Some(rx);
+                    return;
+                }
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+    }
+
+    /// Complete the input against known commands; list the candidates when ambiguous
+    pub fn complete_input(&mut self) {
+        let candidates = command::complete(&self.input);
+        match candidates.as_slice() {
+            [] => {}
+            [only] => {
+                self.input = format!("{} ", only);
+                self.cursor_position = self.input.len();
+            }
+            _ => {
+                let prefix = command::common_prefix(&candidates);
+                if prefix.len() > self.input.len() {
+                    self.input = prefix;
+                    self.cursor_position = self.input.len();
+                }
+                let names: Vec<&str> = candidates
+                    .iter()
+                    .map(|c| c.rsplit(' ').next().unwrap_or(c))
+                    .collect();
+                self.add_output(names.join("  "));
+            }
+        }
+    }
+
+    /// Whether a crawl or fuzz is still running
+    pub fn job_running(&self) -> bool {
+        self.job.is_some()
+    }
+
     pub fn navigate_history_backward(&mut self) {
         if self.history.is_empty() {
             return;
@@ -143,6 +197,11 @@ impl App {
         }
     }
 
+    fn get_database_path() -> PathBuf {
+        let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+        PathBuf::from(home).join(".config/rinzler/rinzler.db")
+    }
+
     fn get_history_file_path() -> PathBuf {
         let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
         PathBuf::from(home).join(".rinzler_history")
@@ -247,6 +306,14 @@ impl App {
             return;
         }
 
+        if let Some(spec) = command::find_command(parts[0])
+            && !spec.accepts(parts.len() - 1)
+        {
+            self.add_output(format!("Error: wrong number of arguments for '{}'", spec.name));
+            self.add_output(format!("Usage: {}", spec.usage));
+            return;
+        }
+
         match parts[0] {
             "exit" | "quit" => {
                 self.request_exit(ExitMode::Normal);
@@ -268,6 +335,7 @@ impl App {
                 self.add_output("  workspace rename <old> <new>   - Rename a workspace");
                 self.add_output("  crawl <url> [threads]          - Passively crawl a URL");
                 self.add_output("  fuzz <url> [wordlist] [threads] - Actively fuzz a URL");
+                self.add_output("  node <url>                     - Show a stored node's details");
                 self.add_output("  plugin list                    - List all plugins");
                 self.add_output("  plugin register <file> <name>  - Register a plugin");
                 self.add_output("  plugin unregister <name>       - Unregister a plugin");
@@ -292,32 +360,25 @@ impl App {
                 }
                 match parts[1] {
                     "create" => {
-                        if let Some(name) = parts.get(2) {
-                            self.add_output(format!("Creating workspace: {}", name));
-                            self.add_output("TODO: Implement workspace creation");
+                        if parts.get(2).is_some() {
+                            self.add_output(WORKSPACES_UNSUPPORTED);
                         } else {
                             self.add_output("Error: workspace create requires a name");
                         }
                     }
                     "remove" => {
-                        if let Some(name) = parts.get(2) {
-                            self.add_output(format!("Removing workspace: {}", name));
-                            self.add_output("TODO: Implement workspace removal");
+                        if parts.get(2).is_some() {
+                            self.add_output(WORKSPACES_UNSUPPORTED);
                         } else {
                             self.add_output("Error: workspace remove requires a name");
                         }
                     }
                     "list" => {
-                        self.add_output("Listing workspaces:");
-                        self.add_output("TODO: Implement workspace listing");
+                        self.add_output(WORKSPACES_UNSUPPORTED);
                     }
                     "rename" => {
                         if parts.len() >= 4 {
-                            self.add_output(format!(
-                                "Renaming workspace from '{}' to '{}'",
-                                parts[2], parts[3]
-                            ));
-                            self.add_output("TODO: Implement workspace renaming");
+                            self.add_output(WORKSPACES_UNSUPPORTED);
                         } else {
                             self.add_output("Error: workspace rename requires old and new names");
                         }
@@ -330,131 +391,59 @@ impl App {
                     }
                 }
             }
-            "crawl" => {
-                if let Some(url_str) = parts.get(1) {
-                    let threads: usize = parts
-                        .get(2)
-                        .and_then(|t| t.parse().ok())
-                        .unwrap_or(10);
-
-                    // Parse URL
-                    let url = match Url::parse(url_str) {
-                        Ok(u) => u,
-                        Err(_) => {
-                            // Try adding http:// prefix
-                            match Url::parse(&format!("http://{}", url_str)) {
-                                Ok(u) => u,
-                                Err(e) => {
-                                    self.add_output(format!("Error: Invalid URL '{}': {}", url_str, e));
-                                    return;
-                                }
-                            }
-                        }
-                    };
-
-                    self.add_output(format!("🕷️  Crawling: {}", url));
-                    self.add_output(format!("Workers: {}", threads));
-                    self.add_output("Max depth: 3".to_string());
-                    self.add_output("Cross-domain: disabled (same domain only)".to_string());
-                    self.add_output("");
-
-                    // Create crawl options
-                    let options = CrawlOptions {
-                        urls: vec![url.to_string()],
-                        threads,
-                        max_depth: 3,
-                        follow_mode: FollowMode::Disabled,
-                        show_progress_bars: false,  // Disable progress bars in TUI mode
-                    };
-
-                    // Shared output buffer for progress updates
-                    let output_buffer = Arc::new(Mutex::new(Vec::new()));
-                    let output_clone = output_buffer.clone();
-
-                    // Progress callback that accumulates messages
-                    let progress_callback = Arc::new(move |msg: String| {
-                        let mut buffer = output_clone.lock().unwrap();
-                        buffer.push(msg);
-                    });
-
-                    // Execute crawl in a blocking manner using tokio runtime
-                    let result = std::thread::spawn(move || {
-                        let rt = tokio::runtime::Runtime::new().unwrap();
-                        rt.block_on(execute_crawl(options, Some(progress_callback), None))
-                    })
-                    .join();
-
-                    // Get accumulated progress messages
-                    let messages = output_buffer.lock().unwrap();
-                    for msg in messages.iter() {
-                        self.add_output(msg.clone());
-                    }
-
-                    // Process results
-                    match result {
-                        Ok(Ok(results)) => {
-                            self.add_output("");
-                            self.add_output("✓ Crawl complete!");
-                            self.add_output("");
-
-                            // Generate and display report
-                            let report = generate_crawl_report(&results);
-                            for line in report.lines() {
-                                self.add_output(line.to_string());
-                            }
-                        }
-                        Ok(Err(e)) => {
-                            self.add_output(format!("✗ Crawl failed: {}", e));
-                        }
-                        Err(e) => {
-                            self.add_output(format!("✗ Thread panic: {:?}", e));
-                        }
-                    }
-                } else {
-                    self.add_output("Error: crawl requires a URL");
-                    self.add_output("Usage: crawl <url> [threads]");
+            "crawl" | "fuzz" => {
+                if self.job.is_some() {
+                    self.add_output("Error: a crawl or fuzz is already running");
+                    return;
                 }
-            }
-            "fuzz" => {
-                if let Some(url) = parts.get(1) {
-                    let wordlist = parts
-                        .get(2)
-                        .unwrap_or(&"~/.config/rinzler/wordlists/default.txt");
-                    let threads = parts.get(3).unwrap_or(&"10");
-                    self.add_output(format!("Fuzzing URL: {}", url));
-                    self.add_output(format!("  Wordlist: {}", wordlist));
-                    self.add_output(format!("  Threads: {}", threads));
-                    self.add_output("TODO: Implement fuzzing logic");
-                } else {
-                    self.add_output("Error: fuzz requires a URL");
+                match job::parse_job(&input) {
+                    Ok(job) => self.job = Some(job::spawn_job(job)),
+                    Err(e) => self.add_output(format!("Error: {}", e)),
                 }
             }
+            "node" => match node::parse_node_command(&input) {
+                Ok(url) => match node::lookup_node(&Self::get_database_path(), &url) {
+                    Ok(lines) => {
+                        for line in lines {
+                            self.add_output(line);
+                        }
+                    }
+                    Err(e) => self.add_output(format!("Error: {}", e)),
+                },
+                Err(e) => self.add_output(format!("Error: {}", e)),
+            },
             "plugin" => {
                 if parts.len() < 2 {
                     self.add_output("Error: plugin command requires a subcommand");
                     self.add_output("Try: plugin list|register|unregister");
                     return;
                 }
+                let db_path = Self::get_database_path();
                 match parts[1] {
-                    "list" => {
-                        self.add_output("Listing plugins:");
-                        self.add_output("TODO: Implement plugin listing");
-                    }
+                    "list" => match plugin::list_plugins(&db_path) {
+                        Ok(lines) => {
+                            for line in lines {
+                                self.add_output(line);
+                            }
+                        }
+                        Err(e) => self.add_output(format!("Error: {}", e)),
+                    },
                     "register" => {
                         if parts.len() >= 4 {
-                            self.add_output(format!(
-                                "Registering plugin '{}' from file: {}",
-                                parts[3], parts[2]
-                            ));
-                            self.add_output("TODO: Implement plugin registration");
+                            match plugin::register_plugin(&db_path, Path::new(parts[2]), parts[3]) {
+                                Ok(message) => self.add_output(message),
+                                Err(e) => self.add_output(format!("Error: {}", e)),
+                            }
                         } else {
                             self.add_output("Error: plugin register requires file path and name");
                         }
                     }
                     "unregister" => {
                         if let Some(name) = parts.get(2) {
-                            self.add_output(format!("Unregistering plugin: {}", name));
-                            self.add_output("TODO: Implement plugin unregistration");
+                            match plugin::unregister_plugin(&db_path, name) {
+                                Ok(message) => self.add_output(message),
+                                Err(e) => self.add_output(format!("Error: {}", e)),
+                            }
                         } else {
                             self.add_output("Error: plugin unregister requires a name");
                         }
@@ -503,8 +492,14 @@ pub fn run() -> Result<()> {
 
 fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()> {
     loop {
+        app.poll_job();
         terminal.draw(|f| ui(f, app))?;
 
+        // Poll with a timeout so job output keeps streaming while no key is pressed
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
         if let Event::Key(key) = event::read()? {
             // Only process KeyPress events, ignore KeyRelease
             if key.kind != KeyEventKind::Press {
@@ -533,6 +528,9 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, app: &mut A
                     app.cursor_position = 0;
                     app.handle_input(input);
                 }
+                KeyCode::Tab => {
+                    app.complete_input();
+                }
                 KeyCode::Up => {
                     app.navigate_history_backward();
                 }
@@ -647,8 +645,14 @@ fn ui(f: &mut Frame, app: &App) {
         Span::raw(" for commands | "),
         Span::styled("↑↓", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw(" history | "),
+        Span::styled("Tab", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(" complete | "),
         Span::styled("PgUp/PgDn", Style::default().add_modifier(Modifier::BOLD)),
         Span::raw(" scroll"),
+        Span::styled(
+            if app.job_running() { " | running..." } else { "" },
+            Style::default().fg(Color::Yellow),
+        ),
     ]))
     .style(Style::default().fg(Color::DarkGray));
 
@@ -0,0 +1,81 @@
+use rinzler_core::data::{Database, NodeDetails};
+use std::path::Path;
+
+/// Extract the URL argument from a `node <url>` command line
+pub fn parse_node_command(input: &str) -> Result<String, String> {
+    let mut parts = input.split_whitespace();
+    if parts.next() != Some("node") {
+        return Err("Not a node command".to_string());
+    }
+
+    match (parts.next(), parts.next()) {
+        (Some(url), None) => Ok(url.to_string()),
+        _ => Err("Usage: node <url>".to_string()),
+    }
+}
+
+/// Render a stored node, its technologies and findings as output pane lines
+pub fn format_node_details(
+    node: &NodeDetails,
+    technologies: &[(String, String, Option<String>)],
+    findings: &[(String, String)],
+) -> Vec<String> {
+    let or_unknown = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+
+    let mut lines = vec![
+        format!("Node: {}", node.url),
+        format!(
+            "  Status:       {}",
+            node.status_code
+                .map(|code| code.to_string())
+                .unwrap_or_else(|| "-".to_string())
+        ),
+        format!("  Content-Type: {}", or_unknown(&node.content_type)),
+        format!("  Service:      {}", or_unknown(&node.service_type)),
+        format!("  Title:        {}", or_unknown(&node.title)),
+    ];
+
+    if technologies.is_empty() {
+        lines.push("  Technologies: none".to_string());
+    } else {
+        let names: Vec<String> = technologies
+            .iter()
+            .map(|(category, name, version)| match version {
+                Some(version) => format!("{} {} ({})", name, version, category),
+                None => format!("{} ({})", name, category),
+            })
+            .collect();
+        lines.push(format!("  Technologies: {}", names.join(", ")));
+    }
+
+    lines.push(format!("  Findings:     {}", findings.len()));
+    for (severity, title) in findings {
+        lines.push(format!("    [{}] {}", severity.to_uppercase(), title));
+    }
+
+    lines
+}
+
+/// Look up the latest stored node for `url` and format it for the output pane
+pub fn lookup_node(db_path: &Path, url: &str) -> Result<Vec<String>, String> {
+    if !Database::exists(db_path) {
+        return Err(format!(
+            "No database at {}; run 'rinzler init' first",
+            db_path.display()
+        ));
+    }
+
+    let db = Database::new(db_path).map_err(|e| format!("Failed to open database: {}", e))?;
+    let node = db
+        .get_latest_node_by_url(url)
+        .map_err(|e| format!("Failed to query node: {}", e))?
+        .ok_or_else(|| format!("No stored node for {}", url))?;
+    let technologies = db
+        .get_technologies_by_node(node.id)
+        .map_err(|e| format!("Failed to query technologies: {}", e))?;
+    let findings = db
+        .get_findings_by_node(node.id)
+        .map_err(|e| format!("Failed to query findings: {}", e))?;
+
+    Ok(format_node_details(&node, &technologies, &findings))
+}
@@ -0,0 +1,71 @@
+use rinzler_core::data::Database;
+use std::path::Path;
+
+fn open_database(db_path: &Path) -> Result<Database, String> {
+    if !Database::exists(db_path) {
+        return Err(format!(
+            "No database at {}; run 'rinzler init' first",
+            db_path.display()
+        ));
+    }
+
+    Database::new(db_path).map_err(|e| format!("Failed to open database: {}", e))
+}
+
+/// List registered plugins as output pane lines, flagging files that no longer load
+pub fn list_plugins(db_path: &Path) -> Result<Vec<String>, String> {
+    let db = open_database(db_path)?;
+    let plugins = db
+        .list_plugins()
+        .map_err(|e| format!("Failed to list plugins: {}", e))?;
+
+    if plugins.is_empty() {
+        return Ok(vec!["No plugins registered".to_string()]);
+    }
+
+    let name_width = plugins.iter().map(|p| p.name.len()).max().unwrap_or(0);
+    Ok(plugins
+        .iter()
+        .map(|plugin| {
+            let status =
+                match rinzler_core::plugin::load_check(&plugin.name, Path::new(&plugin.path)) {
+                    Ok(_) => String::new(),
+                    Err(e) => format!("  (broken: {})", e),
+                };
+            format!(
+                "{:<width$}  {}{}",
+                plugin.name,
+                plugin.path,
+                status,
+                width = name_width
+            )
+        })
+        .collect())
+}
+
+/// Register `file` under `name`, refusing a file the crawl could not load
+pub fn register_plugin(db_path: &Path, file: &Path, name: &str) -> Result<String, String> {
+    rinzler_core::plugin::load_check(name, file)
+        .map_err(|e| format!("Invalid plugin {}: {}", file.display(), e))?;
+    let path = std::fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf());
+
+    let db = open_database(db_path)?;
+    db.register_plugin(name, &path.to_string_lossy())
+        .map_err(|e| format!("Failed to register plugin: {}", e))?;
+
+    Ok(format!(
+        "Registered plugin '{}' from {}",
+        name,
+        path.display()
+    ))
+}
+
+/// Remove the plugin registered under `name`
+pub fn unregister_plugin(db_path: &Path, name: &str) -> Result<String, String> {
+    let db = open_database(db_path)?;
+    match db.unregister_plugin(name) {
+        Ok(true) => Ok(format!("Unregistered plugin '{}'", name)),
+        Ok(false) => Err(format!("No plugin named '{}'", name)),
+        Err(e) => Err(format!("Failed to unregister plugin: {}", e)),
+    }
+}
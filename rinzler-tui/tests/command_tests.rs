@@ -0,0 +1,64 @@
+// Tests for the REPL command registry and tab completion
+
+use rinzler_tui::command::{common_prefix, complete, find_command};
+
+#[test]
+fn test_complete_top_level_command() {
+    assert_eq!(complete("wor"), vec!["workspace"]);
+    assert_eq!(complete("no"), vec!["node"]);
+}
+
+#[test]
+fn test_complete_ambiguous_top_level_command() {
+    assert_eq!(complete("c"), vec!["clear", "crawl"]);
+    assert!(complete("").len() > 2);
+    assert!(complete("zzz").is_empty());
+}
+
+#[test]
+fn test_complete_subcommand_prefix() {
+    assert_eq!(complete("workspace cre"), vec!["workspace create"]);
+    assert_eq!(complete("plugin un"), vec!["plugin unregister"]);
+    assert_eq!(
+        complete("workspace "),
+        vec![
+            "workspace create",
+            "workspace list",
+            "workspace remove",
+            "workspace rename"
+        ]
+    );
+    assert_eq!(
+        complete("workspace re"),
+        vec!["workspace remove", "workspace rename"]
+    );
+}
+
+#[test]
+fn test_complete_stops_at_plain_arguments() {
+    assert!(complete("crawl ht").is_empty());
+    assert!(complete("workspace create my").is_empty());
+}
+
+#[test]
+fn test_common_prefix() {
+    let candidates = vec![
+        "workspace remove".to_string(),
+        "workspace rename".to_string(),
+    ];
+    assert_eq!(common_prefix(&candidates), "workspace re");
+    assert_eq!(common_prefix(&[]), "");
+}
+
+#[test]
+fn test_command_arity() {
+    let crawl = find_command("crawl").unwrap();
+    assert!(!crawl.accepts(0));
+    assert!(crawl.accepts(1));
+    assert!(crawl.accepts(2));
+    assert!(!crawl.accepts(3));
+
+    assert!(find_command("clear").unwrap().accepts(0));
+    assert!(!find_command("node").unwrap().accepts(2));
+    assert!(find_command("bogus").is_none());
+}
@@ -0,0 +1,107 @@
+// Tests for exporting a report from the crawl monitor
+
+use rinzler_core::report::generate_text_report;
+use rinzler_tui::crawl_monitor::{
+    FindingRow, SecurityFinding, build_report_data, default_export_path,
+};
+
+fn finding(title: &str, severity: &str) -> SecurityFinding {
+    SecurityFinding {
+        title: title.to_string(),
+        severity: severity.to_string(),
+        finding_type: "information_disclosure".to_string(),
+        description: format!("{} description", title),
+        impact: "Attackers learn things".to_string(),
+        remediation: "Stop leaking things".to_string(),
+        cwe: Some("CWE-200".to_string()),
+        owasp: None,
+        cvss: None,
+        confidence: "likely".to_string(),
+    }
+}
+
+fn rows() -> Vec<FindingRow> {
+    vec![
+        (
+            "http://example.com/".to_string(),
+            200,
+            Some("text/html".to_string()),
+            vec![finding("Server banner", "low")],
+        ),
+        ("http://example.com/about".to_string(), 200, None, vec![]),
+        (
+            "http://example.com/.env".to_string(),
+            200,
+            Some("text/plain".to_string()),
+            vec![
+                finding("Exposed .env", "critical"),
+                finding("Verbose error", "medium"),
+            ],
+        ),
+    ]
+}
+
+#[test]
+fn test_build_report_data_accumulates_findings() {
+    let targets = vec!["http://example.com".to_string()];
+    let data = build_report_data("session-1", &targets, 1_700_000_000, "running", &rows());
+
+    assert_eq!(data.session_id, "session-1");
+    assert_eq!(data.total_nodes, 3);
+    assert_eq!(data.scan_info.status, "running");
+    assert_eq!(data.scan_info.seed_urls, r#"["http://example.com"]"#);
+
+    assert_eq!(data.severity_counts.critical, 1);
+    assert_eq!(data.severity_counts.medium, 1);
+    assert_eq!(data.severity_counts.low, 1);
+    assert_eq!(data.severity_counts.total(), 3);
+
+    // Most severe first, each tied to the URL it was found on
+    let titles: Vec<(&str, &str)> = data
+        .findings
+        .iter()
+        .map(|f| (f.title.as_str(), f.url.as_str()))
+        .collect();
+    assert_eq!(
+        titles,
+        vec![
+            ("Exposed .env", "http://example.com/.env"),
+            ("Verbose error", "http://example.com/.env"),
+            ("Server banner", "http://example.com/"),
+        ]
+    );
+
+    let sitemap = data.sitemap_nodes.as_ref().unwrap();
+    assert_eq!(sitemap.len(), 3);
+    assert_eq!(sitemap[1].url, "http://example.com/about");
+}
+
+#[test]
+fn test_exported_text_report_contents() {
+    let targets = vec!["http://example.com".to_string()];
+    let data = build_report_data("session-1", &targets, 1_700_000_000, "completed", &rows());
+    let report = generate_text_report(&data);
+
+    assert!(report.contains("Session ID:   session-1"));
+    assert!(report.contains("Status:       Completed"));
+    assert!(report.contains("Targets:      http://example.com"));
+    assert!(report.contains("Exposed .env"));
+    assert!(report.contains("CWE:          CWE-200"));
+}
+
+#[test]
+fn test_build_report_data_with_no_rows() {
+    let data = build_report_data("session-1", &[], 0, "running", &[]);
+    assert_eq!(data.total_nodes, 0);
+    assert!(data.findings.is_empty());
+    assert_eq!(data.severity_counts.total(), 0);
+}
+
+#[test]
+fn test_default_export_path() {
+    assert_eq!(
+        default_export_path(Some("0123456789abcdef")),
+        "rinzler-01234567.txt"
+    );
+    assert_eq!(default_export_path(None), "rinzler-report.txt");
+}
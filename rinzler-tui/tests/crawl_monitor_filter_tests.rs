@@ -0,0 +1,114 @@
+// Tests for the crawl monitor findings filter
+
+use rinzler_tui::crawl_monitor::{
+    FindingFilter, SecurityFinding, clamp_selection, filtered_indices, matches_filter,
+    severity_rank,
+};
+
+fn finding(severity: &str) -> SecurityFinding {
+    SecurityFinding {
+        title: format!("{} issue", severity),
+        severity: severity.to_string(),
+        finding_type: "misconfiguration".to_string(),
+        description: String::new(),
+        impact: String::new(),
+        remediation: String::new(),
+        cwe: None,
+        owasp: None,
+        cvss: None,
+        confidence: "likely".to_string(),
+    }
+}
+
+fn rows() -> Vec<(String, u16, Option<String>, Vec<SecurityFinding>)> {
+    vec![
+        ("http://example.com/".to_string(), 200, None, vec![]),
+        (
+            "http://example.com/admin".to_string(),
+            200,
+            None,
+            vec![finding("high")],
+        ),
+        (
+            "http://example.com/api/users".to_string(),
+            200,
+            None,
+            vec![finding("low")],
+        ),
+        (
+            "http://example.com/Admin/login".to_string(),
+            403,
+            None,
+            vec![finding("critical")],
+        ),
+    ]
+}
+
+#[test]
+fn test_severity_rank() {
+    assert_eq!(severity_rank("critical"), 4);
+    assert_eq!(severity_rank("high"), 3);
+    assert_eq!(severity_rank("medium"), 2);
+    assert_eq!(severity_rank("low"), 1);
+    assert_eq!(severity_rank("info"), 0);
+}
+
+#[test]
+fn test_empty_filter_matches_everything() {
+    let filter = FindingFilter::default();
+    assert!(!filter.is_active());
+    assert_eq!(filtered_indices(&rows(), &filter), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_url_query_is_case_insensitive_substring() {
+    let filter = FindingFilter {
+        query: "admin".to_string(),
+        min_severity: None,
+    };
+    assert_eq!(filtered_indices(&rows(), &filter), vec![1, 3]);
+}
+
+#[test]
+fn test_min_severity_excludes_rows_without_findings() {
+    let filter = FindingFilter {
+        query: String::new(),
+        min_severity: Some(0),
+    };
+    assert_eq!(filtered_indices(&rows(), &filter), vec![1, 2, 3]);
+
+    let filter = FindingFilter {
+        query: String::new(),
+        min_severity: Some(3),
+    };
+    assert_eq!(filtered_indices(&rows(), &filter), vec![1, 3]);
+    assert!(!matches_filter("http://example.com/", &[], &filter));
+}
+
+#[test]
+fn test_query_and_severity_combine() {
+    let filter = FindingFilter {
+        query: "admin".to_string(),
+        min_severity: Some(4),
+    };
+    assert_eq!(filtered_indices(&rows(), &filter), vec![3]);
+}
+
+#[test]
+fn test_filtering_does_not_mutate_findings() {
+    let findings = rows();
+    let filter = FindingFilter {
+        query: "nothing-matches".to_string(),
+        min_severity: None,
+    };
+    assert!(filtered_indices(&findings, &filter).is_empty());
+    assert_eq!(findings.len(), 4);
+}
+
+#[test]
+fn test_clamp_selection() {
+    assert_eq!(clamp_selection(Some(3), 2), Some(1));
+    assert_eq!(clamp_selection(Some(1), 4), Some(1));
+    assert_eq!(clamp_selection(Some(0), 0), None);
+    assert_eq!(clamp_selection(None, 5), None);
+}
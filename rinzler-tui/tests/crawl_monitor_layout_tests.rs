@@ -0,0 +1,37 @@
+// Tests for the crawl monitor --layout ratio
+
+use rinzler_tui::crawl_monitor::{DEFAULT_FINDINGS_PERCENT, parse_layout_ratio};
+
+#[test]
+fn test_parse_layout_ratio_default_split() {
+    assert_eq!(parse_layout_ratio("60/40"), Ok(DEFAULT_FINDINGS_PERCENT));
+}
+
+#[test]
+fn test_parse_layout_ratio_wider_findings() {
+    assert_eq!(parse_layout_ratio("70/30"), Ok(70));
+    assert_eq!(parse_layout_ratio(" 35 / 65 "), Ok(35));
+}
+
+#[test]
+fn test_parse_layout_ratio_clamps_to_bounds() {
+    assert_eq!(parse_layout_ratio("90/10"), Ok(80));
+    assert_eq!(parse_layout_ratio("100/0"), Ok(80));
+    assert_eq!(parse_layout_ratio("5/95"), Ok(20));
+}
+
+#[test]
+fn test_parse_layout_ratio_rejects_bad_sums() {
+    assert!(parse_layout_ratio("110/0").is_err());
+    assert!(parse_layout_ratio("70/40").is_err());
+    assert!(parse_layout_ratio("65535/1").is_err());
+}
+
+#[test]
+fn test_parse_layout_ratio_rejects_malformed() {
+    assert!(parse_layout_ratio("70").is_err());
+    assert!(parse_layout_ratio("70:30").is_err());
+    assert!(parse_layout_ratio("-10/110").is_err());
+    assert!(parse_layout_ratio("a/b").is_err());
+    assert!(parse_layout_ratio("").is_err());
+}
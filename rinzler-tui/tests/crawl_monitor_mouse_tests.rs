@@ -0,0 +1,46 @@
+// Tests for routing mouse-wheel events to crawl monitor panels
+
+use ratatui::layout::Rect;
+use rinzler_tui::crawl_monitor::{Panel, panel_at};
+
+// 100x30 terminal: findings on the left 60%, progress above logs on the right
+const FINDINGS: Rect = Rect {
+    x: 0,
+    y: 0,
+    width: 60,
+    height: 29,
+};
+const LOGS: Rect = Rect {
+    x: 60,
+    y: 8,
+    width: 40,
+    height: 21,
+};
+
+#[test]
+fn test_panel_at_findings() {
+    assert_eq!(panel_at(0, 0, FINDINGS, LOGS), Some(Panel::Findings));
+    assert_eq!(panel_at(59, 28, FINDINGS, LOGS), Some(Panel::Findings));
+}
+
+#[test]
+fn test_panel_at_logs() {
+    assert_eq!(panel_at(60, 8, FINDINGS, LOGS), Some(Panel::Logs));
+    assert_eq!(panel_at(99, 28, FINDINGS, LOGS), Some(Panel::Logs));
+}
+
+#[test]
+fn test_panel_at_progress_panel_is_not_scrollable() {
+    assert_eq!(panel_at(70, 3, FINDINGS, LOGS), None);
+}
+
+#[test]
+fn test_panel_at_hints_bar_and_outside() {
+    assert_eq!(panel_at(10, 29, FINDINGS, LOGS), None);
+    assert_eq!(panel_at(100, 10, FINDINGS, LOGS), None);
+}
+
+#[test]
+fn test_panel_at_before_first_draw() {
+    assert_eq!(panel_at(0, 0, Rect::default(), Rect::default()), None);
+}
@@ -0,0 +1,51 @@
+// Tests for asking about cross-domain links through the crawl monitor
+
+use crossterm::event::KeyCode;
+use rinzler_tui::crawl_monitor::{
+    CrawlMessage, create_monitor_channel, on_follow_prompt_key, prompt_cross_domain,
+};
+
+#[test]
+fn test_prompt_round_trip_returns_the_reply() {
+    let (tx, mut rx) = create_monitor_channel();
+
+    // The crawler side blocks until the monitor answers
+    let asker = std::thread::spawn(move || prompt_cross_domain(&tx, "cdn.example.org"));
+
+    match rx.blocking_recv() {
+        Some(CrawlMessage::CrossDomainPrompt { domain, reply }) => {
+            assert_eq!(domain, "cdn.example.org");
+            reply.send(true).unwrap();
+        }
+        other => panic!("expected a cross-domain prompt, got {:?}", other),
+    }
+
+    assert!(asker.join().unwrap());
+}
+
+#[test]
+fn test_prompt_dropped_without_answer_denies() {
+    let (tx, mut rx) = create_monitor_channel();
+
+    let asker = std::thread::spawn(move || prompt_cross_domain(&tx, "cdn.example.org"));
+    drop(rx.blocking_recv());
+
+    assert!(!asker.join().unwrap());
+}
+
+#[test]
+fn test_prompt_without_monitor_denies() {
+    let (tx, rx) = create_monitor_channel();
+    drop(rx);
+
+    assert!(!prompt_cross_domain(&tx, "cdn.example.org"));
+}
+
+#[test]
+fn test_follow_prompt_keys() {
+    assert_eq!(on_follow_prompt_key(KeyCode::Char('y')), Some(true));
+    assert_eq!(on_follow_prompt_key(KeyCode::Char('Y')), Some(true));
+    assert_eq!(on_follow_prompt_key(KeyCode::Char('n')), Some(false));
+    assert_eq!(on_follow_prompt_key(KeyCode::Esc), Some(false));
+    assert_eq!(on_follow_prompt_key(KeyCode::Char('x')), None);
+}
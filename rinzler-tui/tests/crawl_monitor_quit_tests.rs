@@ -0,0 +1,35 @@
+// Tests for confirming before quitting the crawl monitor mid-crawl
+
+use crossterm::event::KeyCode;
+use rinzler_tui::crawl_monitor::{QuitStep, on_confirm_quit_key, on_quit_key};
+
+#[test]
+fn test_quit_key_exits_immediately_once_complete() {
+    assert_eq!(on_quit_key(true), QuitStep::Quit);
+}
+
+#[test]
+fn test_quit_key_asks_while_crawl_running() {
+    assert_eq!(on_quit_key(false), QuitStep::Confirm);
+}
+
+#[test]
+fn test_confirm_prompt_yes_quits() {
+    assert_eq!(on_confirm_quit_key(KeyCode::Char('y')), QuitStep::Quit);
+    assert_eq!(on_confirm_quit_key(KeyCode::Char('Y')), QuitStep::Quit);
+}
+
+#[test]
+fn test_confirm_prompt_no_or_esc_cancels() {
+    assert_eq!(on_confirm_quit_key(KeyCode::Char('n')), QuitStep::Cancel);
+    assert_eq!(on_confirm_quit_key(KeyCode::Char('N')), QuitStep::Cancel);
+    assert_eq!(on_confirm_quit_key(KeyCode::Esc), QuitStep::Cancel);
+}
+
+#[test]
+fn test_confirm_prompt_ignores_other_keys() {
+    // A second q must not count as confirmation
+    assert_eq!(on_confirm_quit_key(KeyCode::Char('q')), QuitStep::Wait);
+    assert_eq!(on_confirm_quit_key(KeyCode::Enter), QuitStep::Wait);
+    assert_eq!(on_confirm_quit_key(KeyCode::Up), QuitStep::Wait);
+}
@@ -0,0 +1,55 @@
+// Tests for the crawl monitor findings scrollbar
+
+use rinzler_tui::crawl_monitor::scrollbar_geometry;
+
+#[test]
+fn test_scrollbar_everything_visible() {
+    assert_eq!(scrollbar_geometry(10, 10, 0, 8), (0, 8));
+    assert_eq!(scrollbar_geometry(3, 10, 0, 8), (0, 8));
+    assert_eq!(scrollbar_geometry(0, 10, 0, 8), (0, 8));
+}
+
+#[test]
+fn test_scrollbar_empty_track() {
+    assert_eq!(scrollbar_geometry(100, 10, 50, 0), (0, 0));
+}
+
+#[test]
+fn test_scrollbar_top_and_bottom() {
+    assert_eq!(scrollbar_geometry(40, 10, 0, 20), (0, 5));
+    assert_eq!(scrollbar_geometry(40, 10, 30, 20), (15, 5));
+}
+
+#[test]
+fn test_scrollbar_thumb_at_least_one_row() {
+    let (position, size) = scrollbar_geometry(10_000, 5, 9_995, 10);
+    assert_eq!(size, 1);
+    assert_eq!(position, 9);
+}
+
+#[test]
+fn test_scrollbar_offset_past_end_stays_in_track() {
+    let (position, size) = scrollbar_geometry(40, 10, 500, 20);
+    assert!(position + size <= 20);
+    assert_eq!(position, 15);
+}
+
+#[test]
+fn test_scrollbar_never_overflows_track() {
+    for total in 0..60 {
+        for visible in 0..20 {
+            for offset in 0..70 {
+                for track in 0..12 {
+                    let (position, size) = scrollbar_geometry(total, visible, offset, track);
+                    assert!(
+                        position + size <= track,
+                        "total={total} visible={visible} offset={offset} track={track}"
+                    );
+                    if track > 0 {
+                        assert!(size >= 1);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+// Tests for mapping REPL commands to background jobs
+
+use rinzler_tui::job::{Job, parse_job};
+use std::path::PathBuf;
+
+#[test]
+fn test_parse_crawl_with_defaults() {
+    assert_eq!(
+        parse_job("crawl http://example.com"),
+        Ok(Job::Crawl {
+            url: "http://example.com/".to_string(),
+            threads: 10,
+        })
+    );
+}
+
+#[test]
+fn test_parse_crawl_adds_scheme_and_threads() {
+    assert_eq!(
+        parse_job("crawl localhost:8080/app 4"),
+        Ok(Job::Crawl {
+            url: "http://localhost:8080/app".to_string(),
+            threads: 4,
+        })
+    );
+}
+
+#[test]
+fn test_parse_crawl_rejects_bad_arguments() {
+    assert!(parse_job("crawl").is_err());
+    assert!(parse_job("crawl http://example.com zero").is_err());
+    assert!(parse_job("crawl http://example.com 0").is_err());
+    assert!(parse_job("crawl http://example.com 4 extra").is_err());
+}
+
+#[test]
+fn test_parse_fuzz_with_wordlist_and_threads() {
+    assert_eq!(
+        parse_job("fuzz https://example.com /tmp/words.txt 20"),
+        Ok(Job::Fuzz {
+            url: "https://example.com/".to_string(),
+            wordlist: PathBuf::from("/tmp/words.txt"),
+            threads: 20,
+        })
+    );
+}
+
+#[test]
+fn test_parse_fuzz_uses_default_wordlist() {
+    let Ok(Job::Fuzz {
+        wordlist, threads, ..
+    }) = parse_job("fuzz example.com")
+    else {
+        panic!("expected a fuzz job");
+    };
+    assert!(wordlist.ends_with(".config/rinzler/wordlists/default.txt"));
+    assert!(!wordlist.starts_with("~"));
+    assert_eq!(threads, 10);
+}
+
+#[test]
+fn test_parse_job_rejects_other_commands() {
+    assert!(parse_job("fuzz").is_err());
+    assert!(parse_job("node http://example.com").is_err());
+    assert!(parse_job("").is_err());
+}
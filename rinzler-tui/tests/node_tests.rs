@@ -0,0 +1,134 @@
+// Tests for the REPL node inspection command
+
+use rinzler_core::data::{Confidence, CrawlNode, Database, Finding, FindingType, NodeDetails, Severity};
+use rinzler_tui::node::{format_node_details, lookup_node, parse_node_command};
+use tempfile::TempDir;
+
+fn sample_node() -> NodeDetails {
+    NodeDetails {
+        id: 1,
+        url: "http://example.com/api".to_string(),
+        status_code: Some(200),
+        content_type: Some("application/json".to_string()),
+        service_type: Some("api".to_string()),
+        title: None,
+    }
+}
+
+#[test]
+fn test_parse_node_command_extracts_url() {
+    assert_eq!(
+        parse_node_command("node http://example.com/api").unwrap(),
+        "http://example.com/api"
+    );
+    assert_eq!(
+        parse_node_command("  node   http://example.com/  ").unwrap(),
+        "http://example.com/"
+    );
+}
+
+#[test]
+fn test_parse_node_command_requires_exactly_one_url() {
+    assert!(parse_node_command("node").is_err());
+    assert!(parse_node_command("node http://a.test http://b.test").is_err());
+    assert!(parse_node_command("crawl http://a.test").is_err());
+}
+
+#[test]
+fn test_format_node_details() {
+    let technologies = vec![
+        (
+            "web_server".to_string(),
+            "nginx".to_string(),
+            Some("1.25.3".to_string()),
+        ),
+        ("framework".to_string(), "Express".to_string(), None),
+    ];
+    let findings = vec![("high".to_string(), "CORS misconfiguration".to_string())];
+
+    let lines = format_node_details(&sample_node(), &technologies, &findings);
+    assert_eq!(
+        lines,
+        vec![
+            "Node: http://example.com/api",
+            "  Status:       200",
+            "  Content-Type: application/json",
+            "  Service:      api",
+            "  Title:        -",
+            "  Technologies: nginx 1.25.3 (web_server), Express (framework)",
+            "  Findings:     1",
+            "    [HIGH] CORS misconfiguration",
+        ]
+    );
+}
+
+#[test]
+fn test_format_node_details_without_technologies_or_findings() {
+    let lines = format_node_details(&sample_node(), &[], &[]);
+    assert!(lines.contains(&"  Technologies: none".to_string()));
+    assert_eq!(lines.last().unwrap(), "  Findings:     0");
+}
+
+#[test]
+fn test_lookup_node_reads_stored_details() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::new(&db_path).unwrap();
+    let session_id = db.create_session("crawl", "http://example.com").unwrap();
+    let map_id = db.create_map(&session_id).unwrap();
+    let node_id = db
+        .insert_node(
+            &map_id,
+            &CrawlNode {
+                url: "http://example.com/login".to_string(),
+                domain: "example.com".to_string(),
+                status_code: 200,
+                content_type: Some("text/html".to_string()),
+                content_length: None,
+                response_time_ms: None,
+                title: Some("Sign in".to_string()),
+                forms_count: 1,
+                inputs_count: 0,
+                parameters: None,
+                service_type: None,
+                headers: None,
+                body_sample: None,
+                content_hash: None,
+            },
+        )
+        .unwrap();
+    db.insert_finding(
+        &session_id,
+        &Finding {
+            node_id,
+            finding_type: FindingType::InsecureTransport,
+            severity: Severity::Medium,
+            title: "Login form over HTTP".to_string(),
+            description: String::new(),
+            impact: None,
+            remediation: None,
+            evidence: None,
+            cwe_id: None,
+            owasp_category: None,
+            cvss_score: None,
+            confidence: Confidence::Likely,
+            request_sample: None,
+            response_sample: None,
+        },
+    )
+    .unwrap();
+
+    let lines = lookup_node(&db_path, "http://example.com/login").unwrap();
+    assert!(lines.contains(&"  Title:        Sign in".to_string()));
+    assert!(lines.contains(&"    [MEDIUM] Login form over HTTP".to_string()));
+
+    let missing = lookup_node(&db_path, "http://example.com/nope").unwrap_err();
+    assert!(missing.contains("No stored node"));
+}
+
+#[test]
+fn test_lookup_node_without_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let err = lookup_node(&temp_dir.path().join("missing.db"), "http://a.test/").unwrap_err();
+    assert!(err.contains("rinzler init"));
+}
@@ -0,0 +1,71 @@
+// Tests for the REPL plugin commands
+
+use rinzler_core::data::Database;
+use rinzler_tui::plugin::{list_plugins, register_plugin, unregister_plugin};
+use tempfile::TempDir;
+
+const RULES: &str = r#"
+[[rule]]
+title = "Admin Panel Reachable"
+severity = "high"
+url = "/admin"
+status = [200]
+"#;
+
+#[test]
+fn test_plugin_register_list_and_unregister() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    Database::new(&db_path).unwrap();
+    let file = temp_dir.path().join("admin.toml");
+    std::fs::write(&file, RULES).unwrap();
+
+    assert_eq!(
+        list_plugins(&db_path).unwrap(),
+        vec!["No plugins registered"]
+    );
+
+    let message = register_plugin(&db_path, &file, "admin").unwrap();
+    assert!(
+        message.starts_with("Registered plugin 'admin'"),
+        "{}",
+        message
+    );
+    let lines = list_plugins(&db_path).unwrap();
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].starts_with("admin  "), "{}", lines[0]);
+    assert!(lines[0].ends_with("admin.toml"), "{}", lines[0]);
+
+    assert_eq!(
+        unregister_plugin(&db_path, "admin").unwrap(),
+        "Unregistered plugin 'admin'"
+    );
+    assert_eq!(
+        unregister_plugin(&db_path, "admin").unwrap_err(),
+        "No plugin named 'admin'"
+    );
+}
+
+#[test]
+fn test_plugin_register_rejects_invalid_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    Database::new(&db_path).unwrap();
+    let file = temp_dir.path().join("broken.toml");
+    std::fs::write(&file, "not [valid toml").unwrap();
+
+    assert!(register_plugin(&db_path, &file, "broken").is_err());
+    assert_eq!(
+        list_plugins(&db_path).unwrap(),
+        vec!["No plugins registered"]
+    );
+}
+
+#[test]
+fn test_plugin_commands_need_a_database() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("missing.db");
+
+    let error = list_plugins(&db_path).unwrap_err();
+    assert!(error.contains("rinzler init"), "{}", error);
+}
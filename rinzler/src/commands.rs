@@ -1,13 +1,39 @@
-use crate::CLAP_STYLING;
 use clap::{arg, command};
 use url::Url;
 
-pub(crate) fn command_argument_builder() -> clap::Command {
+pub const CLAP_STYLING: clap::builder::styling::Styles = clap::builder::styling::Styles::styled()
+    .header(clap_cargo::style::HEADER)
+    .usage(clap_cargo::style::USAGE)
+    .literal(clap_cargo::style::LITERAL)
+    .placeholder(clap_cargo::style::PLACEHOLDER)
+    .error(clap_cargo::style::ERROR)
+    .valid(clap_cargo::style::VALID)
+    .invalid(clap_cargo::style::INVALID);
+
+/// The full `rinzler` command tree, shared by argument parsing and shell completions
+pub fn command_argument_builder() -> clap::Command {
     clap::Command::new("rinzler")
         .version(env!("CARGO_PKG_VERSION"))
         .bin_name("rinzler")
         .styles(CLAP_STYLING)
-        .arg(arg!(-q --"quiet" "Suppress banner and non-essential output").required(false))
+        .arg(
+            arg!(-q --"quiet" "Suppress banner and non-essential output")
+                .required(false)
+                .global(true),
+        )
+        .arg(
+            arg!(-v --"verbose" "Increase log verbosity (-v warnings, -vv info, -vvv debug, -vvvv trace)")
+                .required(false)
+                .global(true)
+                .action(clap::ArgAction::Count),
+        )
+        .arg(
+            arg!(--"log-file" <PATH>)
+                .required(false)
+                .global(true)
+                .help("Write logs to this file (crawl defaults to ~/.config/rinzler/rinzler.log)")
+                .value_parser(clap::value_parser!(std::path::PathBuf)),
+        )
         .subcommand_required(false)
         .subcommand(
             command!("init")
@@ -25,6 +51,18 @@ pub(crate) fn command_argument_builder() -> clap::Command {
                         location.",
                         )
                         .required(false),
+                )
+                .arg(
+                    arg!(-y --"yes")
+                        .required(false)
+                        .help("Accept the defaults without prompting: install the wordlist if missing, keep an existing database")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"no-wordlist")
+                        .required(false)
+                        .help("Don't install the default wordlist")
+                        .action(clap::ArgAction::SetTrue),
                 ),
         )
         .subcommand(
@@ -80,12 +118,135 @@ pub(crate) fn command_argument_builder() -> clap::Command {
                         .value_parser(clap::value_parser!(std::path::PathBuf))
                         .conflicts_with("url"),
                 )
+                .arg(
+                    arg!(--"entry-path" <PATH>)
+                        .required(false)
+                        .help("Additional path to seed the crawl from (repeatable, e.g. --entry-path /api --entry-path /docs)")
+                        .action(clap::ArgAction::Append)
+                        .conflicts_with("hosts-file"),
+                )
+                .arg(
+                    arg!(--"resume-from-url" <URL>)
+                        .required(false)
+                        .help("Start the crawl at this URL (as depth 0) instead of the target's base URL")
+                        .value_parser(clap::value_parser!(Url))
+                        .requires("url")
+                        .conflicts_with("hosts-file"),
+                )
+                .arg(
+                    arg!(--"openapi" <PATH_OR_URL>)
+                        .required(false)
+                        .help("Seed the crawl with every path in an OpenAPI/Swagger spec (JSON or YAML); relative servers resolve against --url")
+                        .conflicts_with("hosts-file"),
+                )
                 .arg(
                     arg!(-t --"threads" <NUM_WORKERS>)
                         .required(false)
-                        .help("The number of async worker 'threads' in the worker pool.")
-                        .value_parser(clap::value_parser!(usize))
-                        .default_value("10"),
+                        .help("The number of async worker 'threads' in the worker pool (default: 10)")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    arg!(--"delay" <MS>)
+                        .required(false)
+                        .help("Milliseconds each worker waits before every request")
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    arg!(--"user-agent" <UA>)
+                        .required(false)
+                        .help("User-Agent sent with every request (default: Rinzler/0.1)"),
+                )
+                .arg(
+                    arg!(--"proxy" <URL>)
+                        .required(false)
+                        .help("Route every request through this proxy, e.g. http://127.0.0.1:8080"),
+                )
+                .arg(
+                    arg!(--"auth-basic" <CREDENTIALS>)
+                        .required(false)
+                        .help("HTTP Basic credentials as user:pass, sent with every request")
+                        .conflicts_with("auth-bearer"),
+                )
+                .arg(
+                    arg!(--"auth-bearer" <TOKEN>)
+                        .required(false)
+                        .help("Bearer token sent as 'Authorization: Bearer <TOKEN>' with every request"),
+                )
+                .arg(
+                    arg!(--"login-url" <URL>)
+                        .required(false)
+                        .help("POST --login-data here before crawling and keep the session cookies it sets")
+                        .requires("login-data"),
+                )
+                .arg(
+                    arg!(--"login-data" <FORM>)
+                        .required(false)
+                        .help("Urlencoded login form body, e.g. 'username=admin&password=secret'")
+                        .requires("login-url"),
+                )
+                .arg(
+                    arg!(--"login-success-contains" <TEXT>)
+                        .required(false)
+                        .help("Abort unless the login response contains this text")
+                        .requires("login-url"),
+                )
+                .arg(
+                    arg!(--"header" <HEADER>)
+                        .required(false)
+                        .help("Extra request header as 'Name: value' (repeatable)")
+                        .action(clap::ArgAction::Append),
+                )
+                .arg(
+                    arg!(--"max-pages" <NUM>)
+                        .required(false)
+                        .help("Stop crawling after this many pages; the report is marked truncated")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    arg!(--"max-queue-size" <NUM>)
+                        .required(false)
+                        .help("Cap URLs waiting to be crawled; further discovered links are dropped")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    arg!(--"depth" <N>)
+                        .required(false)
+                        .help("Maximum link depth to crawl from each seed URL (default: 3)")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    arg!(--"path-depth" <N>)
+                        .required(false)
+                        .help("Only crawl discovered URLs whose path has at most N segments, however many links away they are")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    arg!(--"layout" <RATIO>)
+                        .required(false)
+                        .help("Monitor width split between findings and progress/logs, e.g. 70/30 (default 60/40, findings 20-80%)"),
+                )
+                .arg(
+                    arg!(--"max-findings" <NUM>)
+                        .required(false)
+                        .help("Stop recording findings once the session holds this many")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    arg!(--"idle-timeout" <SECONDS>)
+                        .required(false)
+                        .help("Stop the crawl as stalled if no page completes for this many seconds")
+                        .value_parser(clap::value_parser!(u64).range(1..)),
+                )
+                .arg(
+                    arg!(--"no-analyze")
+                        .required(false)
+                        .help("Map the site only: skip security checks, probes and technology detection")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"scope-domain" <DOMAIN>)
+                        .required(false)
+                        .help("Treat this domain and its subdomains as in scope (default: the seed's registrable domain)"),
                 )
                 .arg(
                     arg!(--"follow")
@@ -110,15 +271,107 @@ pub(crate) fn command_argument_builder() -> clap::Command {
                 .arg(
                     arg!(-f --"format" <FORMAT>)
                         .required(false)
-                        .help("Report format: text, json, csv, html, markdown")
-                        .value_parser(["text", "json", "csv", "html", "markdown"])
+                        .help("Report format: text, json, jsonl, sarif, junit, csv, html, markdown")
+                        .value_parser(["text", "json", "jsonl", "sarif", "junit", "csv", "html", "markdown"])
                         .default_value("text"),
                 )
+                .arg(
+                    arg!(--"output-dir" <DIR>)
+                        .required(false)
+                        .help("Write report.txt, report.json, report.html and report.sarif into this directory (created if missing)")
+                        .value_parser(clap::value_parser!(std::path::PathBuf))
+                        .conflicts_with("output"),
+                )
                 .arg(
                     arg!(--"include-sitemap")
                         .required(false)
                         .help("Include a visual sitemap tree in the report")
                         .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"unique-findings")
+                        .required(false)
+                        .help("Count duplicate findings (same type and title) once in the severity summary")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"group-findings")
+                        .required(false)
+                        .help("List findings with the same title and type once, with every affected URL")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"fail-on" <SEVERITY>)
+                        .required(false)
+                        .help("Exit with code 2 if any finding is at or above this severity (CI gating)")
+                        .value_parser(["critical", "high", "medium", "low", "info"]),
+                )
+                .arg(
+                    arg!(--"max-per-host" <NUM>)
+                        .required(false)
+                        .help("Simultaneous requests allowed to any one host, whatever --threads is")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("8"),
+                )
+                .arg(
+                    arg!(--"max-body-size" <BYTES>)
+                        .required(false)
+                        .help("Stop reading a response body after this many bytes; the page is kept and marked truncated")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("5242880"),
+                )
+                .arg(
+                    arg!(--"binary-bodies")
+                        .required(false)
+                        .help("Download image, PDF and archive bodies instead of recording them from their headers")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"pool-size" <NUM>)
+                        .required(false)
+                        .help("Idle connections kept open per host; raise for few hosts at high concurrency")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("50"),
+                )
+                .arg(
+                    arg!(--"pool-idle-timeout" <SECONDS>)
+                        .required(false)
+                        .help("Seconds an idle pooled connection is kept alive; lower when scanning many hosts")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("90"),
+                )
+                .arg(
+                    arg!(--"webhook" <URL>)
+                        .required(false)
+                        .help("POST findings at or above --webhook-severity to this URL as JSON while the crawl runs"),
+                )
+                .arg(
+                    arg!(--"webhook-severity" <SEVERITY>)
+                        .required(false)
+                        .help("Lowest severity sent to --webhook")
+                        .value_parser(["critical", "high", "medium", "low", "info"])
+                        .default_value("high")
+                        .requires("webhook"),
+                )
+                .arg(
+                    arg!(--"batch-size" <NUM>)
+                        .required(false)
+                        .help("Findings per --webhook POST")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("100"),
+                )
+                .arg(
+                    arg!(--"flush-interval" <SECONDS>)
+                        .required(false)
+                        .help("Seconds a partial --webhook batch is held before it is sent")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("2"),
+                )
+                .arg(
+                    arg!(--"metrics-port" <PORT>)
+                        .required(false)
+                        .help("Serve live crawl counters in Prometheus format at http://127.0.0.1:<PORT>/metrics")
+                        .value_parser(clap::value_parser!(u16)),
                 ),
         )
         .subcommand(
@@ -140,18 +393,57 @@ pub(crate) fn command_argument_builder() -> clap::Command {
                         .help("a line delimited list of hosts to scan")
                         .value_parser(clap::value_parser!(std::path::PathBuf)),
                 )
+                .arg(
+                    arg!(--"openapi" <PATH_OR_URL>)
+                        .required(false)
+                        .help("Fuzz every path in an OpenAPI/Swagger spec (JSON or YAML) instead of --url; relative servers resolve against --url")
+                        .conflicts_with("hosts-file"),
+                )
                 .arg(
                     arg!(-w --"wordlist-file" <PATH>)
                         .required(false)
-                        .help("Path to wordlist file (default: ~/.config/rinzler/wordlists/default.txt)")
-                        .value_parser(clap::value_parser!(std::path::PathBuf)),
+                        .help("Path to wordlist file, or - for stdin; repeat to merge lists (default: ~/.config/rinzler/wordlists/default.txt)")
+                        .value_parser(clap::value_parser!(std::path::PathBuf))
+                        .action(clap::ArgAction::Append),
                 )
                 .arg(
                     arg!(-t --"threads" <NUM_WORKERS>)
                         .required(false)
-                        .help("The number of async worker 'threads' in the worker pool.")
-                        .value_parser(clap::value_parser!(usize))
-                        .default_value("10"),
+                        .help("The number of async worker 'threads' in the worker pool (default: 10)")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    arg!(--"delay" <MS>)
+                        .required(false)
+                        .help("Milliseconds each worker waits before every request")
+                        .value_parser(clap::value_parser!(u64)),
+                )
+                .arg(
+                    arg!(--"user-agent" <UA>)
+                        .required(false)
+                        .help("User-Agent sent with every request (default: Rinzler/0.1)"),
+                )
+                .arg(
+                    arg!(--"proxy" <URL>)
+                        .required(false)
+                        .help("Route every request through this proxy, e.g. http://127.0.0.1:8080"),
+                )
+                .arg(
+                    arg!(--"auth-basic" <CREDENTIALS>)
+                        .required(false)
+                        .help("HTTP Basic credentials as user:pass, sent with every request")
+                        .conflicts_with("auth-bearer"),
+                )
+                .arg(
+                    arg!(--"auth-bearer" <TOKEN>)
+                        .required(false)
+                        .help("Bearer token sent as 'Authorization: Bearer <TOKEN>' with every request"),
+                )
+                .arg(
+                    arg!(--"header" <HEADER>)
+                        .required(false)
+                        .help("Extra request header as 'Name: value' (repeatable)")
+                        .action(clap::ArgAction::Append),
                 )
                 .arg(
                     arg!(--"full-body")
@@ -159,12 +451,353 @@ pub(crate) fn command_argument_builder() -> clap::Command {
                         .help("Use GET requests to download full response bodies (default: HEAD requests)")
                         .action(clap::ArgAction::SetTrue),
                 )
+                .arg(
+                    arg!(--"recursion-depth" <DEPTH>)
+                        .required(false)
+                        .help("Levels of discovered endpoints to re-fuzz beneath themselves (0 disables recursion)")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("2"),
+                )
+                .arg(
+                    arg!(--"resume" <SESSION_ID>)
+                        .required(false)
+                        .help("Continue an interrupted fuzz session against its own targets, skipping URLs it already tested and expanding its earlier hits"),
+                )
+                .arg(
+                    arg!(-X --"method" <METHOD>)
+                        .required(false)
+                        .help("HTTP method for every request, e.g. POST (default: HEAD, or GET with --full-body)")
+                        .conflicts_with("full-body"),
+                )
+                .arg(
+                    arg!(-d --"data" <BODY>)
+                        .required(false)
+                        .help("Request body to send with every request (use with --method)"),
+                )
                 .arg(
                     arg!(--"timeout" <SECONDS>)
                         .required(false)
                         .help("Request timeout in seconds")
                         .value_parser(clap::value_parser!(u64))
                         .default_value("5"),
+                )
+                .arg(
+                    arg!(--"match-code" <CODES>)
+                        .required(false)
+                        .help("Only report these status codes, comma-separated (e.g. 200,301,400-499)"),
+                )
+                .arg(
+                    arg!(--"filter-code" <CODES>)
+                        .required(false)
+                        .help("Hide these status codes, comma-separated (e.g. 401,403)"),
+                )
+                .arg(
+                    arg!(--"no-wildcard-filter")
+                        .required(false)
+                        .help("Disable soft-404 / wildcard response detection")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"dry-run")
+                        .required(false)
+                        .help("List the URLs that would be fuzzed (only their count with --quiet) without sending requests")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(-o --"output" <PATH>)
+                        .required(false)
+                        .help("Save report to file (default: display to screen)")
+                        .value_parser(clap::value_parser!(std::path::PathBuf)),
+                )
+                .arg(
+                    arg!(-f --"format" <FORMAT>)
+                        .required(false)
+                        .help("Report format: text, json")
+                        .value_parser(["text", "json"])
+                        .default_value("text"),
+                )
+                .arg(
+                    arg!(--"group-by-type")
+                        .required(false)
+                        .help("In the text report, list each status code's results under their content type")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"fail-on" <SEVERITY>)
+                        .required(false)
+                        .help("Exit with code 2 if any hit is an interesting file (e.g. .env, .git/) at or above this severity (CI gating)")
+                        .value_parser(["critical", "high", "medium", "low", "info"]),
+                )
+                .arg(
+                    arg!(--"no-color")
+                        .required(false)
+                        .help("Never color the text report (color is already off when stdout isn't a terminal)")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"pool-size" <NUM>)
+                        .required(false)
+                        .help("Idle connections kept open per host (default: one per worker)")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    arg!(--"pool-idle-timeout" <SECONDS>)
+                        .required(false)
+                        .help("Seconds an idle pooled connection is kept alive; lower when scanning many hosts")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("90"),
+                ),
+        )
+        .subcommand(
+            command!("report")
+                .about("Regenerate a report from a previously captured scan or a stored session")
+                .arg(
+                    arg!(--"from-jsonl" <FILE>)
+                        .required(false)
+                        .help("Path to an NDJSON scan capture (e.g. from crawl --format jsonl)")
+                        .value_parser(clap::value_parser!(std::path::PathBuf)),
+                )
+                .arg(
+                    arg!(--"session" <SESSION_ID>)
+                        .required(false)
+                        .help("Report on a session stored in the database"),
+                )
+                .group(
+                    clap::ArgGroup::new("source")
+                        .args(["from-jsonl", "session"])
+                        .required(true),
+                )
+                .arg(
+                    arg!(-o --"output" <PATH>)
+                        .required(false)
+                        .help("Save report to file (default: display to screen)")
+                        .value_parser(clap::value_parser!(std::path::PathBuf)),
+                )
+                .arg(
+                    arg!(-f --"format" <FORMAT>)
+                        .required(false)
+                        .help("Report format: text, json, jsonl, sarif, junit, toml, github-issues, har or html-graph (--session only)")
+                        .value_parser(["text", "json", "jsonl", "sarif", "junit", "toml", "github-issues", "har", "html-graph"])
+                        .default_value("text"),
+                )
+                .arg(
+                    arg!(--"include-sitemap")
+                        .required(false)
+                        .help("Include a visual sitemap tree in the report")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"unique-findings")
+                        .required(false)
+                        .help("Count duplicate findings (same type and title) once in the severity summary")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"group-findings")
+                        .required(false)
+                        .help("List findings with the same title and type once, with every affected URL")
+                        .action(clap::ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--"baseline" <SESSION_ID>)
+                        .required(false)
+                        .help("Mark sitemap pages added (+) or removed (-) since a stored session (text format only)"),
+                ),
+        )
+        .subcommand(
+            command!("wordlist")
+                .about("Manage the installed default wordlist")
+                .subcommand_required(true)
+                .subcommand(
+                    command!("update")
+                        .about("Refresh the installed default wordlist from this binary's bundled copy")
+                        .arg(
+                            arg!([PATH])
+                                .required(false)
+                                .help("Rinzler configuration directory")
+                                .default_value("~/.config/rinzler/"),
+                        )
+                        .arg(
+                            arg!(-f --"force")
+                                .required(false)
+                                .help("Overwrite without asking for confirmation")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            command!("config")
+                .about("Manage the rinzler.toml configuration file")
+                .subcommand_required(true)
+                .subcommand(
+                    command!("init")
+                        .about("Write a commented default rinzler.toml")
+                        .arg(
+                            arg!([PATH])
+                                .required(false)
+                                .help("Rinzler configuration directory")
+                                .default_value("~/.config/rinzler/"),
+                        )
+                        .arg(
+                            arg!(-f --"force")
+                                .required(false)
+                                .help("Overwrite an existing rinzler.toml")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            command!("serve")
+                .about("Serve stored sessions, nodes and findings as a local JSON API")
+                .arg(
+                    arg!(--"db-path" <PATH>)
+                        .required(false)
+                        .help("Database to serve")
+                        .default_value("~/.config/rinzler/rinzler.db"),
+                )
+                .arg(
+                    arg!(-p --"port" <PORT>)
+                        .required(false)
+                        .help("Port to listen on")
+                        .value_parser(clap::value_parser!(u16))
+                        .default_value("8787"),
+                )
+                .arg(
+                    arg!(--"bind" <ADDR>)
+                        .required(false)
+                        .help("Address to bind (default: localhost only)")
+                        .value_parser(clap::value_parser!(std::net::IpAddr))
+                        .default_value("127.0.0.1"),
+                ),
+        )
+        .subcommand(
+            command!("replay-session")
+                .about("Re-issue a session's recorded requests and report responses that changed")
+                .arg(
+                    arg!(--"session" <SESSION_ID>)
+                        .required(true)
+                        .help("Session whose HTTP transactions are replayed"),
+                )
+                .arg(
+                    arg!(--"timeout" <SECONDS>)
+                        .required(false)
+                        .help("Per-request timeout")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("10"),
+                )
+                .arg(
+                    arg!(--"auth-basic" <CREDENTIALS>)
+                        .required(false)
+                        .help("HTTP Basic credentials as user:pass, sent in place of the redacted recorded ones")
+                        .conflicts_with("auth-bearer"),
+                )
+                .arg(
+                    arg!(--"auth-bearer" <TOKEN>)
+                        .required(false)
+                        .help("Bearer token sent in place of the redacted recorded Authorization header"),
+                ),
+        )
+        .subcommand(
+            command!("diff")
+                .about("Compare two stored sessions: new and removed URLs, newly introduced findings")
+                .arg(
+                    arg!(--"base" <SESSION_ID>)
+                        .required(true)
+                        .help("Earlier session to compare against"),
+                )
+                .arg(
+                    arg!(--"new" <SESSION_ID>)
+                        .required(true)
+                        .help("Later session whose changes are reported"),
+                ),
+        )
+        .subcommand(
+            command!("sessions")
+                .about("Manage scan sessions stored in the database")
+                .subcommand_required(true)
+                .subcommand(
+                    command!("purge")
+                        .about("Delete old or failed sessions with their nodes and findings")
+                        .arg(
+                            arg!(--"before" <DATE>)
+                                .required(false)
+                                .help("Delete sessions started before this date (YYYY-MM-DD or RFC 3339)"),
+                        )
+                        .arg(
+                            arg!(--"status" <STATUS>)
+                                .required(false)
+                                .help("Delete sessions with this status")
+                                .value_parser(["running", "completed", "failed", "cancelled"]),
+                        )
+                        .group(
+                            clap::ArgGroup::new("filter")
+                                .args(["before", "status"])
+                                .multiple(true)
+                                .required(true),
+                        ),
+                ),
+        )
+        .subcommand(
+            command!("findings")
+                .about("Triage findings stored in the database")
+                .subcommand_required(true)
+                .subcommand(
+                    command!("list")
+                        .about("List a session's findings, most severe first")
+                        .arg(
+                            arg!(--"session" <SESSION_ID>)
+                                .required(true)
+                                .help("Session whose findings are listed"),
+                        )
+                        .arg(
+                            arg!(--"severity" <SEVERITY>)
+                                .required(false)
+                                .help("Only show findings of this severity")
+                                .value_parser(["critical", "high", "medium", "low", "info"]),
+                        )
+                        .arg(
+                            arg!(--"type" <TYPE>)
+                                .required(false)
+                                .help("Only show findings of this type")
+                                .value_parser([
+                                    "vulnerability",
+                                    "misconfiguration",
+                                    "information_disclosure",
+                                    "interesting_file",
+                                    "security_header_missing",
+                                    "insecure_transport",
+                                    "authentication_issue",
+                                    "authorization_issue",
+                                    "injection_point",
+                                    "other",
+                                ]),
+                        ),
+                )
+                .subcommand(
+                    command!("mark-fp")
+                        .about("Mark a finding as a false positive so reports hide it")
+                        .arg(
+                            arg!(--"id" <ID>)
+                                .required(true)
+                                .help("The ID of the finding")
+                                .value_parser(clap::value_parser!(i64)),
+                        )
+                        .arg(
+                            arg!(--"unset")
+                                .required(false)
+                                .help("Clear the false positive flag instead")
+                                .action(clap::ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            command!("completions")
+                .about("Print a shell completion script to stdout")
+                .arg(
+                    arg!(<SHELL>)
+                        .required(true)
+                        .help("Shell to generate completions for")
+                        .value_parser(clap::value_parser!(clap_complete::Shell)),
                 ),
         )
         .subcommand(
@@ -173,11 +806,11 @@ pub(crate) fn command_argument_builder() -> clap::Command {
                 .subcommand(command!("list").about("List all registered plugins"))
                 .subcommand(
                     command!("register")
-                        .about("Register a plugin")
+                        .about("Register a passive-check plugin run on every crawled page")
                         .arg(
                             arg!(-f --"file" <PATH>)
                                 .required(true)
-                                .help("The path of the plugin file to register")
+                                .help("TOML file of [[rule]] checks, or a .rhai script defining analyze(url, status, content_type, body)")
                                 .value_parser(clap::value_parser!(std::path::PathBuf)),
                         )
                         .arg(
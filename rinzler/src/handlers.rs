@@ -1,12 +1,18 @@
 use clap::ArgMatches;
 use colored::Colorize;
-use rinzler_core::data::Database;
+use rinzler_core::config::{self, RinzlerConfig};
+use rinzler_core::crawl::RequestSettings;
+use rinzler_core::data::{self, Database, FindingRecorder, FindingSummary, SessionPurgeFilter};
+use rinzler_core::report::ScanTimings;
 use rinzler_tui::crawl_monitor::{self, CrawlMessage, LogLevel};
+use std::collections::HashSet;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tracing::level_filters::LevelFilter;
 use url::Url;
 
 const DEFAULT_WORDLIST: &str = include_str!("../wordlists/default.txt");
@@ -27,6 +33,22 @@ pub fn load_urls_from_source(
     }
 }
 
+/// Load an OpenAPI/Swagger spec and expand it to one URL per path.
+/// Relative servers resolve against the spec URL, then `url`, then http://127.0.0.1/
+pub async fn load_openapi_urls(source: &str, url: Option<&Url>) -> Result<Vec<String>, String> {
+    let spec = rinzler_core::openapi::load_spec(source).await?;
+    let base = Url::parse(source)
+        .ok()
+        .filter(|u| matches!(u.scheme(), "http" | "https"))
+        .or_else(|| url.cloned())
+        .unwrap_or_else(|| Url::parse("http://127.0.0.1/").expect("valid default base"));
+    let urls = rinzler_core::openapi::extract_urls_from_spec(&spec, &base)?;
+    if urls.is_empty() {
+        return Err(format!("No paths found in OpenAPI spec {}", source));
+    }
+    Ok(urls)
+}
+
 /// Load and parse URLs from a file
 pub fn load_urls_from_file(path: &PathBuf) -> Result<Vec<String>, String> {
     let content = fs::read_to_string(path)
@@ -47,6 +69,11 @@ pub fn load_urls_from_file(path: &PathBuf) -> Result<Vec<String>, String> {
 
 /// Parse a single line as a URL, trying to add http:// if needed
 pub fn parse_url_line(line: &str) -> Option<String> {
+    // A bare IPv6 address needs brackets; as-is, `fe80::1` would parse as scheme `fe80`
+    if let Ok(ip) = line.parse::<std::net::Ipv6Addr>() {
+        return Some(format!("http://[{}]", ip));
+    }
+
     // Try to parse as-is
     if Url::parse(line).is_ok() {
         return Some(line.to_string());
@@ -64,8 +91,8 @@ pub fn parse_url_line(line: &str) -> Option<String> {
 
 // Re-export crawl types and functions from rinzler-core
 pub use rinzler_core::crawl::{
-    CrawlOptions, CrawlProgressCallback, FollowMode, execute_crawl, extract_url_path,
-    generate_crawl_report,
+    CrawlOptions, CrawlProgressCallback, FollowMode, execute_crawl, execute_crawl_with_stats,
+    extract_url_path, generate_crawl_report, generate_crawl_report_with_color,
 };
 
 fn print_divider() {
@@ -80,6 +107,23 @@ fn print_prompt(msg: &str) -> String {
     response.trim().to_lowercase()
 }
 
+/// How `init` treats an existing install and the default wordlist
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InitOptions {
+    /// Overwrite an existing database and wordlist without asking
+    pub force: bool,
+    /// Never prompt: install the wordlist if it is missing and keep an existing database
+    pub assume_yes: bool,
+    /// Don't install the default wordlist
+    pub no_wordlist: bool,
+}
+
+impl InitOptions {
+    fn interactive(&self) -> bool {
+        !self.force && !self.assume_yes
+    }
+}
+
 pub fn handle_init(args: &ArgMatches) {
     print_divider();
     println!("{}", "  RINZLER INITIALIZATION".bright_white().bold());
@@ -87,14 +131,29 @@ pub fn handle_init(args: &ArgMatches) {
     println!();
 
     let db_path = args.get_one::<String>("PATH").unwrap();
-    let force = args.get_flag("force");
+    let options = InitOptions {
+        force: args.get_flag("force"),
+        assume_yes: args.get_flag("yes"),
+        no_wordlist: args.get_flag("no-wordlist"),
+    };
     let expanded_config_dir = shellexpand::tilde(db_path);
-    let rinzler_config_dir = Path::new(expanded_config_dir.as_ref());
+
+    println!("{} Parsed arguments", "✓".green().bold());
+    run_init(Path::new(expanded_config_dir.as_ref()), options, &mut print_prompt);
+}
+
+/// Set up the database and default wordlist under `rinzler_config_dir`. Questions
+/// are asked through `prompt`, which is never called with `--force` or `--yes`.
+pub fn run_init(
+    rinzler_config_dir: &Path,
+    options: InitOptions,
+    prompt: &mut dyn FnMut(&str) -> String,
+) {
     let db_loc = rinzler_config_dir.join("rinzler.db");
     let db_path = db_loc.as_path();
     let user_config_root = rinzler_config_dir.parent().expect("Invalid database path");
+    let interactive = options.interactive();
 
-    println!("{} Parsed arguments", "✓".green().bold());
     println!(
         "{} Target: {}",
         "→".blue(),
@@ -108,7 +167,7 @@ pub fn handle_init(args: &ArgMatches) {
     let wordlist_exists = wordlist_path.exists();
 
     // Check for existing installation
-    if (dir_exists || wordlist_exists) && !force {
+    if (dir_exists || wordlist_exists) && interactive {
         println!("{}", "⚠ WARNING".yellow().bold());
         println!("Configuration directory already exists:");
         if dir_exists {
@@ -131,7 +190,7 @@ pub fn handle_init(args: &ArgMatches) {
             "This operation will overwrite existing files.".yellow()
         );
 
-        let response = print_prompt("Do you want to continue? [y/N]:");
+        let response = prompt("Do you want to continue? [y/N]:");
         println!();
 
         if response != "y" && response != "yes" {
@@ -143,7 +202,9 @@ pub fn handle_init(args: &ArgMatches) {
     }
 
     // Wordlist installation prompt
-    let install_wordlist = if !force {
+    let install_wordlist = if options.no_wordlist {
+        false
+    } else if interactive {
         println!("{}", "WORDLIST SETUP".bright_blue().bold());
         println!("Rinzler includes a default API endpoint wordlist.");
         println!(
@@ -153,7 +214,7 @@ pub fn handle_init(args: &ArgMatches) {
         );
         println!();
 
-        let response = print_prompt("Would you like to install it? [Y/n]:");
+        let response = prompt("Would you like to install it? [Y/n]:");
         println!();
 
         response != "n" && response != "no"
@@ -162,9 +223,7 @@ pub fn handle_init(args: &ArgMatches) {
     };
 
     // Create configuration assets
-    if install_wordlist {
-        create_configuration_assets(&rinzler_config_dir, &wordlist_dir, &wordlist_path);
-    } else {
+    if !install_wordlist {
         println!("{} Skipping wordlist installation", "→".blue());
         println!(
             "{} Manual wordlist location: {}",
@@ -172,21 +231,30 @@ pub fn handle_init(args: &ArgMatches) {
             wordlist_dir.display().to_string().bright_white()
         );
         println!();
+    } else if wordlist_exists && options.assume_yes && !options.force {
+        // --yes never clobbers; only --force rewrites an existing wordlist
+        println!("{} Keeping existing wordlist", "→".blue());
+        println!();
+    } else {
+        create_configuration_assets(&rinzler_config_dir, &wordlist_dir, &wordlist_path);
     }
 
     // Handle existing database in force mode
-    if force && Database::exists(db_path) {
+    if options.force && Database::exists(db_path) {
         println!(
             "{} Deleting existing database (force mode)",
             "→".yellow().bold()
         );
-        Database::drop(db_path);
+        if let Err(e) = Database::drop(db_path) {
+            eprintln!("✗ Failed to remove existing database: {}", e);
+            std::process::exit(1);
+        }
         println!("{} Existing database removed", "✓".green().bold());
         println!();
     }
 
     // Database creation
-    if Database::exists(db_path) && !force {
+    if Database::exists(db_path) && !options.force {
         println!("{}", "⚠ WARNING".yellow().bold());
         println!("Database already exists at:");
         println!(
@@ -196,14 +264,22 @@ pub fn handle_init(args: &ArgMatches) {
         );
         println!();
 
-        let response = print_prompt("Would you like to overwrite it? [Y/n]:");
-        println!();
+        let keep = if interactive {
+            let response = prompt("Would you like to overwrite it? [Y/n]:");
+            println!();
+            response == "n" || response == "no"
+        } else {
+            true
+        };
 
-        if response == "n" || response == "no" {
+        if keep {
             println!("{} Keeping existing database", "→".blue());
             println!();
         } else {
-            Database::drop(db_path);
+            if let Err(e) = Database::drop(db_path) {
+                eprintln!("✗ Failed to remove existing database: {}", e);
+                std::process::exit(1);
+            }
             println!("{} Existing database removed", "✓".green().bold());
             println!();
         }
@@ -211,6 +287,8 @@ pub fn handle_init(args: &ArgMatches) {
 
     if !Database::exists(db_path) {
         println!("{} Creating database...", "→".blue());
+        // The wordlist step creates the directory, unless it was skipped
+        fs::create_dir_all(rinzler_config_dir).expect("Failed to create config directory");
         Database::new(db_path).expect("Failed to create database");
         println!(
             "{} Database initialized: {}",
@@ -280,6 +358,68 @@ fn create_configuration_assets(
     println!();
 }
 
+/// Log level for `count` repetitions of `-v`: errors only by default
+pub fn verbosity_level(count: u8) -> LevelFilter {
+    match count {
+        0 => LevelFilter::ERROR,
+        1 => LevelFilter::WARN,
+        2 => LevelFilter::INFO,
+        3 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Install the global tracing subscriber. Logs go to `log_file` when given; otherwise
+/// to stderr, or to `~/.config/rinzler/rinzler.log` when a TUI owns the terminal.
+pub fn init_logging(verbose: u8, log_file: Option<&Path>, tui: bool) {
+    let level = verbosity_level(verbose);
+    let default_log = shellexpand::tilde("~/.config/rinzler/rinzler.log");
+    let path = match log_file {
+        Some(path) => Some(path),
+        None if tui => Some(Path::new(default_log.as_ref())),
+        None => None,
+    };
+
+    let Some(path) = path else {
+        let _ = tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_writer(io::stderr)
+            .try_init();
+        return;
+    };
+
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    match fs::OpenOptions::new().create(true).append(true).open(path) {
+        Ok(file) => {
+            let _ = tracing_subscriber::fmt()
+                .with_max_level(level)
+                .with_ansi(false)
+                .with_writer(std::sync::Mutex::new(file))
+                .try_init();
+        }
+        Err(e) if log_file.is_some() => {
+            eprintln!("✗ Failed to open log file {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+        // Without a requested log file, run with logging off rather than fail the scan
+        Err(e) => {
+            eprintln!("⚠️  Logging disabled: could not open {}: {}", path.display(), e);
+        }
+    }
+}
+
+pub fn handle_completions(sub_matches: &ArgMatches) {
+    let shell = *sub_matches.get_one::<clap_complete::Shell>("SHELL").unwrap();
+    clap_complete::generate(
+        shell,
+        &mut crate::commands::command_argument_builder(),
+        "rinzler",
+        &mut io::stdout(),
+    );
+}
+
 pub fn handle_workspace_create(args: &ArgMatches) {
     let name = args.get_one::<String>("name").unwrap();
     println!("Creating workspace: {}", name);
@@ -304,21 +444,181 @@ pub fn handle_workspace_rename(args: &ArgMatches) {
     // TODO: Implement workspace renaming
 }
 
+/// Whether `resume_url` falls within the crawl scope of `base`: the `--scope-domain`
+/// (or a subdomain of it) when given, otherwise the base URL's registrable domain
+pub fn resume_url_in_scope(base: &Url, resume_url: &Url, scope_domain: Option<&str>) -> bool {
+    let Some(host) = resume_url.host_str() else {
+        return false;
+    };
+    match scope_domain {
+        Some(domain) => rinzler_scanner::host_in_scope(resume_url, domain),
+        None => base.host_str().is_some_and(|base_host| {
+            rinzler_scanner::registrable_domain(base_host)
+                == rinzler_scanner::registrable_domain(host)
+        }),
+    }
+}
+
 pub async fn handle_crawl(sub_matches: &ArgMatches) {
     let url = sub_matches.get_one::<Url>("url");
     let hosts_file = sub_matches.get_one::<PathBuf>("hosts-file");
-    let threads = *sub_matches.get_one::<usize>("threads").unwrap_or(&10);
+    let (config_path, config) = load_rinzler_config();
+    let threads = config::resolve(
+        sub_matches.get_one::<usize>("threads").copied(),
+        config.threads,
+        10,
+    );
+    let max_depth = config::resolve(
+        sub_matches.get_one::<usize>("depth").copied(),
+        config.depth,
+        3,
+    );
+    let user_agent = sub_matches
+        .get_one::<String>("user-agent")
+        .cloned()
+        .or_else(|| config.user_agent.clone());
+    let (request, request_delay_ms) = parse_request_settings(sub_matches, &config);
+    let login = sub_matches
+        .get_one::<String>("login-url")
+        .map(|url| rinzler_core::crawl::LoginSettings {
+            url: url.clone(),
+            form_data: sub_matches
+                .get_one::<String>("login-data")
+                .cloned()
+                .unwrap_or_default(),
+            success_contains: sub_matches.get_one::<String>("login-success-contains").cloned(),
+        });
     let follow = sub_matches.get_flag("follow");
     let auto_follow = sub_matches.get_flag("auto-follow");
-
-    // Load URLs from source
-    let urls = match load_urls_from_source(url, hosts_file) {
-        Ok(urls) => urls,
-        Err(e) => {
-            eprintln!("✗ {}", e);
+    let unique_findings = sub_matches.get_flag("unique-findings");
+    let group_findings = sub_matches.get_flag("group-findings");
+    // Checked before crawling, since the report is only written once the crawl is done
+    if group_findings {
+        if sub_matches.get_one::<PathBuf>("output-dir").is_some() {
+            eprintln!("✗ --group-findings is only supported with --output and --format text or json");
+            std::process::exit(1);
+        }
+        let format = sub_matches
+            .get_one::<String>("format")
+            .map(|s| s.as_str())
+            .unwrap_or("text");
+        if format != "text" && format != "json" {
+            eprintln!("✗ --group-findings is only supported with --format text or json");
             std::process::exit(1);
         }
+    }
+    let entry_paths: Vec<String> = sub_matches
+        .get_many::<String>("entry-path")
+        .map(|paths| paths.cloned().collect())
+        .unwrap_or_default();
+    let idle_timeout_secs = sub_matches.get_one::<u64>("idle-timeout").copied();
+    let max_pages = sub_matches.get_one::<usize>("max-pages").copied();
+    if max_pages == Some(0) {
+        eprintln!("✗ Invalid --max-pages: must be at least 1");
+        std::process::exit(1);
+    }
+    let max_queue_size = sub_matches.get_one::<usize>("max-queue-size").copied();
+    let max_path_depth = sub_matches.get_one::<usize>("path-depth").copied();
+    if max_queue_size == Some(0) {
+        eprintln!("✗ Invalid --max-queue-size: must be at least 1");
+        std::process::exit(1);
+    }
+    let scope_domain = sub_matches.get_one::<String>("scope-domain").cloned();
+    let resume_from_url = sub_matches.get_one::<Url>("resume-from-url");
+    if let (Some(url), Some(resume_url)) = (url, resume_from_url)
+        && !resume_url_in_scope(url, resume_url, scope_domain.as_deref())
+    {
+        eprintln!(
+            "⚠️  --resume-from-url {} is outside the scope of {}; crawling from it anyway",
+            resume_url, url
+        );
+    }
+    let resume_from_url = resume_from_url.map(|resume_url| resume_url.to_string());
+    let analyze = !sub_matches.get_flag("no-analyze");
+    let max_findings = sub_matches.get_one::<usize>("max-findings").copied();
+    let fail_on = sub_matches
+        .get_one::<String>("fail-on")
+        .and_then(|severity| severity.parse::<rinzler_core::data::Severity>().ok());
+    if max_findings == Some(0) {
+        eprintln!("✗ Invalid --max-findings: must be at least 1");
+        std::process::exit(1);
+    }
+    let findings_percent = match sub_matches.get_one::<String>("layout") {
+        Some(ratio) => match crawl_monitor::parse_layout_ratio(ratio) {
+            Ok(percent) => percent,
+            Err(e) => {
+                eprintln!("✗ Invalid --layout: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => crawl_monitor::DEFAULT_FINDINGS_PERCENT,
+    };
+    let (pool_size, pool_idle_timeout_secs) = parse_pool_settings(sub_matches);
+    let pool_size = pool_size.unwrap_or(rinzler_core::crawl::DEFAULT_POOL_SIZE);
+    let max_per_host = *sub_matches
+        .get_one::<usize>("max-per-host")
+        .unwrap_or(&rinzler_core::crawl::DEFAULT_MAX_PER_HOST);
+    if max_per_host == 0 {
+        eprintln!("✗ Invalid --max-per-host: must be at least 1");
+        std::process::exit(1);
+    }
+    let max_body_bytes = *sub_matches
+        .get_one::<usize>("max-body-size")
+        .unwrap_or(&rinzler_core::crawl::DEFAULT_MAX_BODY_BYTES);
+    let skip_binary_bodies = !sub_matches.get_flag("binary-bodies");
+    let batch = rinzler_core::batch::BatchSettings {
+        size: *sub_matches
+            .get_one::<usize>("batch-size")
+            .unwrap_or(&rinzler_core::batch::DEFAULT_BATCH_SIZE),
+        flush_interval: sub_matches
+            .get_one::<u64>("flush-interval")
+            .map(|secs| Duration::from_secs(*secs))
+            .unwrap_or(rinzler_core::batch::DEFAULT_FLUSH_INTERVAL),
+    };
+    if batch.size == 0 {
+        eprintln!("✗ Invalid --batch-size: must be at least 1");
+        std::process::exit(1);
+    }
+    let metrics_port = sub_matches.get_one::<u16>("metrics-port").copied();
+    let webhook_url = sub_matches.get_one::<String>("webhook").cloned();
+    let webhook_threshold = sub_matches
+        .get_one::<String>("webhook-severity")
+        .and_then(|severity| severity.parse::<rinzler_core::data::Severity>().ok())
+        .unwrap_or(rinzler_core::data::Severity::High);
+    if let Some(ref webhook_url) = webhook_url
+        && let Err(e) = Url::parse(webhook_url)
+    {
+        eprintln!("✗ Invalid --webhook URL '{}': {}", webhook_url, e);
+        std::process::exit(1);
+    }
+    let openapi = sub_matches.get_one::<String>("openapi");
+
+    // Load URLs from source, then append any endpoints from the spec
+    let mut urls = match (openapi, url, hosts_file) {
+        (Some(_), None, None) => Vec::new(),
+        _ => match load_urls_from_source(url, hosts_file) {
+            Ok(urls) => urls,
+            Err(e) => {
+                eprintln!("✗ {}", e);
+                std::process::exit(1);
+            }
+        },
     };
+    if let Some(source) = openapi {
+        match load_openapi_urls(source, url).await {
+            Ok(spec_urls) => {
+                for spec_url in spec_urls {
+                    if !urls.contains(&spec_url) {
+                        urls.push(spec_url);
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("✗ {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
 
     // Determine follow mode
     let follow_mode = if auto_follow {
@@ -331,14 +631,54 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
 
     // Print crawl configuration
     println!("\n🕷️  Crawling {} host(s)", urls.len());
-    println!("Workers: {}", threads);
-    println!("Max depth: 3");
+    if let Some(ref config_path) = config_path {
+        println!("Config: {}", config_path.display());
+    }
+    println!("Workers: {} (max {} per host)", threads, max_per_host);
+    println!("Max depth: {}", max_depth);
+    if let Some(max_path_depth) = max_path_depth {
+        println!("Max path depth: {} segments", max_path_depth);
+    }
+    if !entry_paths.is_empty() {
+        println!("Entry paths: {}", entry_paths.join(", "));
+    }
+    if let Some(ref resume_url) = resume_from_url {
+        println!("Resume from: {}", resume_url);
+    }
     let follow_mode_str = match follow_mode {
         FollowMode::Auto => "auto (follow all)",
-        FollowMode::Prompt => "prompt (ask user)",
+        FollowMode::Prompt | FollowMode::PromptWith(_) => "prompt (ask user)",
         FollowMode::Disabled => "disabled (same domain only)",
     };
-    println!("Cross-domain: {}\n", follow_mode_str);
+    if let Some(ref user_agent) = user_agent {
+        println!("User-Agent: {}", user_agent);
+    }
+    if let Some(ref login) = login {
+        println!("Login: {}", login.url);
+    }
+    if let Some(ref webhook_url) = webhook_url {
+        println!("Webhook: {} ({} and above)", webhook_url, webhook_threshold.as_str());
+    }
+    println!("Cross-domain: {}", follow_mode_str);
+
+    // Bind before the session exists so a taken port fails without leaving one behind
+    let metrics = Arc::new(rinzler_core::metrics::CrawlMetrics::new());
+    let metrics_server = match metrics_port {
+        Some(port) => {
+            let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+            let listener = match tokio::net::TcpListener::bind(addr).await {
+                Ok(listener) => listener,
+                Err(e) => {
+                    eprintln!("✗ Failed to bind metrics port {}: {}", addr, e);
+                    std::process::exit(1);
+                }
+            };
+            println!("Metrics: http://{}/metrics", addr);
+            Some(tokio::spawn(rinzler_core::metrics::serve_metrics(metrics.clone(), listener)))
+        }
+        None => None,
+    };
+    println!();
 
     // Open database
     let db_path = shellexpand::tilde("~/.config/rinzler/rinzler.db");
@@ -351,6 +691,27 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
         }
     };
 
+    // A plugin that fails to load is skipped; the crawl still runs the others
+    let checks = if analyze {
+        match rinzler_core::plugin::load_registry(&db) {
+            Ok((checks, errors)) => {
+                for error in errors {
+                    eprintln!("{} Skipping {}", "⚠".yellow(), error);
+                }
+                if !checks.is_empty() {
+                    println!("Plugins: {}\n", checks.names().join(", "));
+                }
+                checks
+            }
+            Err(e) => {
+                eprintln!("{} Failed to load plugins: {}", "⚠".yellow(), e);
+                rinzler_core::plugin::CheckRegistry::new()
+            }
+        }
+    } else {
+        rinzler_core::plugin::CheckRegistry::new()
+    };
+
     // Create session
     let seed_urls_json = serde_json::to_string(&urls).unwrap();
     let session_id = match db.create_session("crawl", &seed_urls_json) {
@@ -377,26 +738,87 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
     let (tx, rx) = crawl_monitor::create_monitor_channel();
     let should_exit = Arc::new(AtomicBool::new(false));
     let should_exit_clone = should_exit.clone();
+    let paused = Arc::new(AtomicBool::new(false));
+    let paused_clone = paused.clone();
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_clone = stop.clone();
 
     let tui_handle = std::thread::spawn(move || {
-        if let Err(e) = crawl_monitor::run_monitor(rx, should_exit_clone) {
+        if let Err(e) = crawl_monitor::run_monitor(rx, should_exit_clone, paused_clone, stop_clone, findings_percent) {
             eprintln!("TUI error: {}", e);
         }
     });
 
+    // The TUI turns Ctrl+C into a key press while it owns the terminal; catch the
+    // signal too so an interrupt outside raw mode still stops the crawl cleanly
+    let stop_signal = stop.clone();
+    let ctrl_c_handler = tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            stop_signal.store(true, Ordering::Relaxed);
+        }
+    });
+
     // Send session ID to TUI
     let _ = tx.send(CrawlMessage::SessionStarted {
         session_id: session_id.clone(),
+        targets: urls.clone(),
     });
 
+    // The TUI owns the terminal, so cross-domain questions are asked in its modal
+    let follow_mode = match follow_mode {
+        FollowMode::Prompt => {
+            let tx_prompt = tx.clone();
+            FollowMode::PromptWith(Arc::new(move |domain: &str| {
+                crawl_monitor::prompt_cross_domain(&tx_prompt, domain)
+            }))
+        }
+        mode => mode,
+    };
+
     // Create crawl options (disable built-in progress bars, using TUI instead)
-    let options = CrawlOptions {
+    let mut options = CrawlOptions {
         urls,
         threads,
-        max_depth: 3,
+        max_depth,
         follow_mode,
         show_progress_bars: false,  // Using TUI instead
+        entry_paths,
+        idle_timeout_secs,
+        max_pages,
+        max_queue_size,
+        max_path_depth,
+        scope_domain,
+        resume_from_url,
+        user_agent,
+        request,
+        request_delay_ms,
+        login,
+        max_per_host,
+        max_body_bytes,
+        skip_binary_bodies,
+        pool_size,
+        pool_idle_timeout_secs,
+        paused: Some(paused),
+        stop: Some(stop),
+        client: None,
+    };
+
+    // Built (and logged in) once, so the CORS probes share the crawl's settings and session
+    let client = match rinzler_core::crawl::build_crawl_client(&options).await {
+        Ok(client) => client,
+        Err(e) => {
+            let _ = tx.send(CrawlMessage::Log {
+                level: LogLevel::Error,
+                message: format!("Crawl failed: {}", e),
+            });
+            let _ = db.fail_session(&session_id);
+            should_exit.store(true, Ordering::Relaxed);
+            let _ = tui_handle.join();
+            std::process::exit(1);
+        }
     };
+    options.client = Some(client.clone());
+    let request_headers = rinzler_core::crawl::crawl_client_config(&options).default_headers();
 
     // Execute crawl with progress callback that sends to TUI
     let tx_progress = tx.clone();
@@ -407,120 +829,179 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
         });
     });
 
-    // Result callback that sends findings to TUI in real-time
+    // Alerts are batched off the crawl path; a failed delivery is only logged
+    let webhook = webhook_url.map(|webhook_url| {
+        let tx_webhook = tx.clone();
+        rinzler_core::webhook::WebhookNotifier::spawn(
+            webhook_url,
+            session_id.clone(),
+            webhook_threshold,
+            batch,
+            move |message| {
+                let _ = tx_webhook.send(CrawlMessage::Log {
+                    level: LogLevel::Warn,
+                    message,
+                });
+            },
+        )
+    });
+    let webhook_sender = webhook.as_ref().map(|webhook| webhook.sender());
+
+    // Each page is stored and analysed as soon as it is crawled, with its findings
+    // sent to the TUI, so a crash mid-scan keeps everything persisted so far
     let tx_result = tx.clone();
+    let findings_metrics = metrics.clone();
+    let (result_tx, persister) = spawn_crawl_persister(
+        db,
+        session_id.clone(),
+        map_id.clone(),
+        PersistSettings {
+            analyze,
+            max_findings,
+            checks,
+            probe_client: Some(client),
+            request_headers,
+        },
+        move |result, findings| {
+            findings_metrics.record_findings(findings);
+            if let Some(ref webhook_sender) = webhook_sender {
+                webhook_sender.notify(&result.url, findings);
+            }
+            let _ = tx_result.send(CrawlMessage::Finding {
+                url: result.url.clone(),
+                status_code: result.status_code,
+                content_type: result.content_type.clone(),
+                security_findings: findings.iter().map(tui_security_finding).collect(),
+            });
+        },
+    );
     let result_callback = Arc::new(move |result: rinzler_scanner::result::CrawlResult| {
-        // Perform security analysis on this result
-        // Note: We use a dummy node_id of 0 since we haven't inserted to DB yet
-        let findings = rinzler_core::security::analyze_crawl_result(&result, 0);
-
-        // Convert findings to TUI SecurityFinding format
-        let security_findings: Vec<crawl_monitor::SecurityFinding> = findings
-            .iter()
-            .map(|f| {
-                let severity_str = match f.severity {
-                    rinzler_core::data::Severity::Critical => "critical",
-                    rinzler_core::data::Severity::High => "high",
-                    rinzler_core::data::Severity::Medium => "medium",
-                    rinzler_core::data::Severity::Low => "low",
-                    rinzler_core::data::Severity::Info => "info",
-                };
-
-                crawl_monitor::SecurityFinding {
-                    title: f.title.clone(),
-                    severity: severity_str.to_string(),
-                    description: f.description.clone(),
-                    impact: f.impact.clone().unwrap_or_else(|| "No impact information available".to_string()),
-                    remediation: f.remediation.clone().unwrap_or_else(|| "No remediation available".to_string()),
-                    cwe: f.cwe_id.clone(),
-                    owasp: f.owasp_category.clone(),
-                }
-            })
-            .collect();
-
-        let _ = tx_result.send(CrawlMessage::Finding {
-            url: result.url.clone(),
-            status_code: result.status_code,
-            content_type: result.content_type.clone(),
-            security_findings,
-        });
+        metrics.record_result(&result);
+        let _ = result_tx.send(result);
     });
 
-    let start_time = std::time::Instant::now();
-    let all_results = match execute_crawl(options, Some(progress_callback), Some(result_callback)).await {
-        Ok(results) => results,
+    let start_time = Instant::now();
+    let (all_results, crawl_stats) = match execute_crawl_with_stats(options, Some(progress_callback), Some(result_callback)).await {
+        Ok(outcome) => outcome,
         Err(e) => {
             let _ = tx.send(CrawlMessage::Log {
                 level: LogLevel::Error,
                 message: format!("Crawl failed: {}", e),
             });
-            let _ = db.fail_session(&session_id);
+            if let Ok(outcome) = persister.join() {
+                let _ = outcome.db.fail_session(&session_id);
+            }
+            if let Some(webhook) = webhook {
+                webhook.finish().await;
+            }
             should_exit.store(true, Ordering::Relaxed);
             let _ = tui_handle.join();
             std::process::exit(1);
         }
     };
     let duration = start_time.elapsed();
+    ctrl_c_handler.abort();
+    if let Some(server) = metrics_server {
+        server.abort();
+    }
 
-    // Note: Findings are already sent in real-time via result_callback
-    // No need to send them again here
+    if crawl_stats.cancelled {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Warn,
+            message: format!("Crawl cancelled: saving {} pages collected so far", all_results.len()),
+        });
+    }
 
-    let _ = tx.send(CrawlMessage::Log {
-        level: LogLevel::Info,
-        message: format!("Crawl complete! Duration: {:.2}s", duration.as_secs_f64()),
-    });
+    if crawl_stats.page_limit_hit {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Warn,
+            message: format!(
+                "Page budget of {} reached, crawl truncated",
+                max_pages.unwrap_or_default()
+            ),
+        });
+    }
+
+    if crawl_stats.queue_dropped > 0 {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Warn,
+            message: format!(
+                "Queue cap of {} reached: {} discovered links dropped",
+                max_queue_size.unwrap_or_default(),
+                crawl_stats.queue_dropped
+            ),
+        });
+    }
+
+    if crawl_stats.stalled {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Warn,
+            message: format!(
+                "Crawl stalled: no page completed for {}s, stopped early",
+                idle_timeout_secs.unwrap_or_default()
+            ),
+        });
+    }
 
     let _ = tx.send(CrawlMessage::Log {
         level: LogLevel::Info,
-        message: "Persisting results to database...".to_string(),
+        message: format!("Crawl complete! Duration: {:.2}s", duration.as_secs_f64()),
     });
 
-    // Persist results to database
-    let mut findings_count = 0;
-    for result in &all_results {
-        // Extract domain from URL
-        let domain = Url::parse(&result.url)
-            .ok()
-            .and_then(|u| u.host_str().map(String::from))
-            .unwrap_or_else(|| "unknown".to_string());
-
-        // Create node structure
-        let node = rinzler_core::data::CrawlNode {
-            url: result.url.clone(),
-            domain,
-            status_code: result.status_code,
-            content_type: result.content_type.clone(),
-            content_length: None,
-            response_time_ms: None,
-            title: None,
-            forms_count: result.forms_found,
-            service_type: None,
-            headers: None,
-            body_sample: None,
-        };
-
-        // Insert node
-        match db.insert_node(&map_id, &node) {
-            Ok(node_id) => {
-                // Run security checks
-                let findings = rinzler_core::security::analyze_crawl_result(result, node_id);
+    // The crawler has dropped the result callback, so the persister drains what is
+    // left in its channel, links edges and hands the database back
+    let outcome = match persister.join() {
+        Ok(outcome) => outcome,
+        Err(_) => {
+            eprintln!("✗ Failed to persist crawl results: persistence thread panicked");
+            should_exit.store(true, Ordering::Relaxed);
+            let _ = tui_handle.join();
+            std::process::exit(1);
+        }
+    };
+    // Sends the partial last batch, on Ctrl+C as well as on completion
+    if let Some(webhook) = webhook {
+        webhook.finish().await;
+    }
+    let db = outcome.db;
+    let mut timings = outcome.timings;
+    timings.crawl_secs = duration.as_secs_f64();
+    timings.total_secs = start_time.elapsed().as_secs_f64();
+    // Pages were analysed and stored while the crawl was still running
+    timings.overlapping = true;
+
+    let findings_count = outcome.findings_recorded;
+    if outcome.findings_dropped > 0 {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Warn,
+            message: format!(
+                "Findings cap of {} reached: {} more findings were not recorded",
+                max_findings.unwrap_or_default(),
+                outcome.findings_dropped
+            ),
+        });
+    }
+    if let Some(e) = outcome.finish_error {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Error,
+            message: format!("Failed to record dropped findings: {}", e),
+        });
+    }
 
-                // Insert findings
-                for finding in findings {
-                    if db.insert_finding(&session_id, &finding).is_ok() {
-                        findings_count += 1;
-                    }
-                }
-            }
-            Err(e) => {
-                eprintln!(
-                    "  {} Failed to insert node {}: {}",
-                    "⚠".yellow(),
-                    result.url,
-                    e
-                );
-            }
+    // An interrupted crawl keeps what it found but skips the summary and report
+    if crawl_stats.cancelled {
+        should_exit.store(true, Ordering::Relaxed);
+        let _ = tui_handle.join();
+        if let Err(e) = db.cancel_session(&session_id) {
+            eprintln!("✗ Failed to mark session cancelled: {}", e);
         }
+        println!(
+            "⚠️  Crawl cancelled: saved {} nodes and {} findings to session {}",
+            outcome.nodes,
+            findings_count,
+            session_id
+        );
+        return;
     }
 
     // Complete session
@@ -533,8 +1014,41 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
 
     let _ = tx.send(CrawlMessage::Log {
         level: LogLevel::Info,
-        message: format!("Saved {} nodes and {} findings to database", all_results.len(), findings_count),
+        message: format!("Saved {} nodes and {} findings to database", outcome.nodes, findings_count),
     });
+    if outcome.changes.is_recrawl() {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Info,
+            message: format!(
+                "Since last scan: {} new, {} changed, {} unchanged pages",
+                outcome.changes.new, outcome.changes.changed, outcome.changes.unchanged
+            ),
+        });
+    }
+
+    // Checked against what was persisted, acted on once the TUI has released the terminal
+    let gate_failures = fail_on.as_ref().map(|threshold| {
+        db.get_findings_count_by_severity(&session_id)
+            .map(|counts| count_findings_at_or_above(&counts, threshold))
+    });
+    let _ = tx.send(CrawlMessage::Log {
+        level: LogLevel::Info,
+        message: format!(
+            "Timing: crawl {:.2}s, analysis {:.2}s and persistence {:.2}s during the crawl (total {:.2}s)",
+            timings.crawl_secs, timings.analysis_secs, timings.persistence_secs, timings.total_secs
+        ),
+    });
+
+    if let Some(summary) = db
+        .get_technologies_by_session(&session_id)
+        .ok()
+        .and_then(|technologies| rinzler_core::tech::technology_summary(&technologies))
+    {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Info,
+            message: summary,
+        });
+    }
 
     // Send findings summary to TUI
     if findings_count > 0 {
@@ -552,7 +1066,21 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
         });
 
         // Display findings summary
-        if let Ok(severity_counts) = db.get_findings_count_by_severity(&session_id) {
+        if unique_findings {
+            if let Ok(severity_counts) = db.get_unique_findings_count_by_severity(&session_id) {
+                for (severity, distinct, occurrences) in severity_counts {
+                    let _ = tx.send(CrawlMessage::Log {
+                        level: LogLevel::Info,
+                        message: format!(
+                            "  {}: {} ({} occurrences)",
+                            severity.to_uppercase(),
+                            distinct,
+                            occurrences
+                        ),
+                    });
+                }
+            }
+        } else if let Ok(severity_counts) = db.get_findings_count_by_severity(&session_id) {
             for (severity, count) in severity_counts {
                 let _ = tx.send(CrawlMessage::Log {
                     level: LogLevel::Info,
@@ -576,8 +1104,18 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
             message: format!("Generating {} report...", format),
         });
 
-        match rinzler_core::report::gather_report_data(&db, &session_id, include_sitemap) {
-            Ok(report_data) => {
+        // JSONL captures always carry nodes so the sitemap can be regenerated later;
+        // JUnit needs them for the passing test case of each clean page
+        let gather_sitemap = include_sitemap || format == "jsonl" || format == "junit";
+        match rinzler_core::report::gather_report_data(&db, &session_id, gather_sitemap, unique_findings) {
+            Ok(mut report_data) => {
+                report_data.coverage = Some(crawl_stats.clone());
+                report_data.timings = Some(timings.clone());
+                if group_findings {
+                    report_data.grouped_findings =
+                        Some(rinzler_core::report::group_findings(&report_data.findings));
+                }
+
                 let report_content = match format {
                     "text" => rinzler_core::report::generate_text_report(&report_data),
                     "json" => rinzler_core::report::generate_json_report(&report_data)
@@ -588,6 +1126,23 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
                             });
                             String::new()
                         }),
+                    "jsonl" => rinzler_core::report::generate_jsonl_report(&report_data)
+                        .unwrap_or_else(|e| {
+                            let _ = tx.send(CrawlMessage::Log {
+                                level: LogLevel::Error,
+                                message: format!("Failed to generate JSONL: {}", e),
+                            });
+                            String::new()
+                        }),
+                    "sarif" => rinzler_core::report::generate_sarif_report(&report_data)
+                        .unwrap_or_else(|e| {
+                            let _ = tx.send(CrawlMessage::Log {
+                                level: LogLevel::Error,
+                                message: format!("Failed to generate SARIF: {}", e),
+                            });
+                            String::new()
+                        }),
+                    "junit" => rinzler_core::report::generate_junit_report(&report_data),
                     "csv" => {
                         let _ = tx.send(CrawlMessage::Log {
                             level: LogLevel::Warn,
@@ -644,6 +1199,50 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
         }
     }
 
+    if let Some(dir) = sub_matches.get_one::<PathBuf>("output-dir") {
+        let _ = tx.send(CrawlMessage::Log {
+            level: LogLevel::Info,
+            message: format!("Writing reports to {}...", dir.display()),
+        });
+
+        let written = rinzler_core::report::gather_report_data(&db, &session_id, include_sitemap, unique_findings)
+            .map_err(|e| format!("Failed to generate reports: {}", e))
+            .and_then(|mut report_data| {
+                report_data.coverage = Some(crawl_stats);
+                report_data.timings = Some(timings);
+                if group_findings {
+                    report_data.grouped_findings =
+                        Some(rinzler_core::report::group_findings(&report_data.findings));
+                }
+                rinzler_core::report::write_report_dir(&report_data, dir)
+                    .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))
+            });
+        match written {
+            Ok(files) => {
+                for (file_name, outcome) in files {
+                    let (level, message) = match outcome {
+                        rinzler_core::report::ReportFileOutcome::Written(path) => {
+                            (LogLevel::Info, format!("Report saved to: {}", path.display()))
+                        }
+                        rinzler_core::report::ReportFileOutcome::Skipped(reason) => {
+                            (LogLevel::Warn, format!("Skipped {}: {}", file_name, reason))
+                        }
+                        rinzler_core::report::ReportFileOutcome::Failed(e) => {
+                            (LogLevel::Error, format!("Failed to save {}: {}", file_name, e))
+                        }
+                    };
+                    let _ = tx.send(CrawlMessage::Log { level, message });
+                }
+            }
+            Err(message) => {
+                let _ = tx.send(CrawlMessage::Log {
+                    level: LogLevel::Error,
+                    message,
+                });
+            }
+        }
+    }
+
     // Send completion message to TUI with all required fields
     let _ = tx.send(CrawlMessage::Complete {
         total: all_results.len(),
@@ -652,25 +1251,544 @@ pub async fn handle_crawl(sub_matches: &ArgMatches) {
 
     // Wait for TUI to close (user presses 'q' or ESC)
     let _ = tui_handle.join();
+
+    if let (Some(threshold), Some(gate_failures)) = (fail_on, gate_failures) {
+        match gate_failures {
+            Ok(0) => println!(
+                "{} No findings at or above {} severity",
+                "✓".green().bold(),
+                threshold.as_str()
+            ),
+            Ok(failures) => {
+                eprintln!(
+                    "✗ --fail-on {}: {} finding{} at or above {} severity in session {}",
+                    threshold.as_str(),
+                    failures,
+                    if failures == 1 { "" } else { "s" },
+                    threshold.as_str(),
+                    session_id
+                );
+                std::process::exit(2);
+            }
+            Err(e) => {
+                eprintln!("✗ Failed to check findings for --fail-on: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
 }
 
-pub async fn handle_fuzz(sub_matches: &ArgMatches) {
-    let url = sub_matches.get_one::<Url>("url");
-    let hosts_file = sub_matches.get_one::<PathBuf>("hosts-file");
-    let wordlist_file = sub_matches.get_one::<PathBuf>("wordlist-file");
-    let threads = *sub_matches.get_one::<usize>("threads").unwrap_or(&10);
-    let full_body = sub_matches.get_flag("full-body");
-    let use_head = !full_body; // Default to HEAD unless --full-body is specified
-    let timeout = *sub_matches.get_one::<u64>("timeout").unwrap_or(&5);
+/// Findings at or above `threshold`, summed from (severity, count) rows
+/// such as `Database::get_findings_count_by_severity` returns
+pub fn count_findings_at_or_above(
+    counts: &[(String, i64)],
+    threshold: &rinzler_core::data::Severity,
+) -> i64 {
+    counts
+        .iter()
+        .filter(|(severity, _)| {
+            severity
+                .parse::<rinzler_core::data::Severity>()
+                .is_ok_and(|severity| severity.rank() >= threshold.rank())
+        })
+        .map(|(_, count)| count)
+        .sum()
+}
 
-    // Load URLs from source
-    let urls = match load_urls_from_source(url, hosts_file) {
-        Ok(urls) => urls,
-        Err(e) => {
+/// Stores crawl results in `map_id` one page at a time as the crawl produces them,
+/// logging each fetch as an HTTP transaction. With `analyze` set, each node is also
+/// run through the security checks and technology fingerprinting, and through the
+/// CORS probe once `with_probe_client` has supplied the crawl's client.
+/// Edges are linked in `finish`, once every page's node id is known.
+pub struct CrawlPersister<'a> {
+    db: &'a Database,
+    session_id: &'a str,
+    map_id: &'a str,
+    analyze: bool,
+    probe_client: Option<reqwest::Client>,
+    /// Headers the crawl's client sent with every fetch, as stored JSON
+    request_headers: Option<String>,
+    /// Registered plugin checks run after the built-in ones
+    checks: rinzler_core::plugin::CheckRegistry,
+    /// Node id per stored URL
+    node_ids: std::collections::HashMap<String, i64>,
+    /// Source node, source URL, links and redirect target of every stored page
+    pending_edges: Vec<(i64, String, Vec<String>, Option<String>)>,
+    /// Stored pages tallied against their crawl in the previous session
+    changes: rinzler_core::diff::NodeChangeCounts,
+    started: Instant,
+    analysis: Duration,
+    persistence: Duration,
+}
+
+impl<'a> CrawlPersister<'a> {
+    pub fn new(db: &'a Database, session_id: &'a str, map_id: &'a str, analyze: bool) -> Self {
+        Self {
+            db,
+            session_id,
+            map_id,
+            analyze,
+            probe_client: None,
+            request_headers: None,
+            checks: rinzler_core::plugin::CheckRegistry::new(),
+            node_ids: std::collections::HashMap::new(),
+            pending_edges: Vec::new(),
+            changes: rinzler_core::diff::NodeChangeCounts::default(),
+            started: Instant::now(),
+            analysis: Duration::ZERO,
+            persistence: Duration::ZERO,
+        }
+    }
+
+    /// Run these plugin checks on every analysed page
+    pub fn with_checks(mut self, checks: rinzler_core::plugin::CheckRegistry) -> Self {
+        self.checks = checks;
+        self
+    }
+
+    /// Send CORS probes with this client, the crawl's own, so they carry its proxy,
+    /// User-Agent, headers, auth and login session; ignored unless analysing
+    pub fn with_probe_client(mut self, client: reqwest::Client) -> Self {
+        if self.analyze {
+            self.probe_client = Some(client);
+        }
+        self
+    }
+
+    /// Log these as the request headers of every fetch, so a replay sends them again;
+    /// credential values are redacted before they are stored
+    pub fn with_request_headers(mut self, headers: &[(String, String)]) -> Self {
+        self.request_headers =
+            serde_json::to_string(&rinzler_core::replay::redact_credentials(headers)).ok();
+        self
+    }
+
+    /// Pages stored so far
+    pub fn stored(&self) -> usize {
+        self.node_ids.len()
+    }
+
+    /// How the pages stored so far compare with the previous scan
+    pub fn changes(&self) -> rinzler_core::diff::NodeChangeCounts {
+        self.changes
+    }
+
+    /// Store a batch of pages and their findings, inserting the nodes and the findings
+    /// in one transaction each; returns each page's findings for display
+    pub async fn persist_batch(
+        &mut self,
+        results: &[rinzler_scanner::result::CrawlResult],
+        recorder: &mut FindingRecorder<'_>,
+    ) -> Vec<Vec<rinzler_core::data::Finding>> {
+        let nodes: Vec<rinzler_core::data::CrawlNode> = results
+            .iter()
+            .map(|result| rinzler_core::data::CrawlNode {
+                url: result.url.clone(),
+                domain: Url::parse(&result.url)
+                    .ok()
+                    .and_then(|u| u.host_str().map(String::from))
+                    .unwrap_or_else(|| "unknown".to_string()),
+                status_code: result.status_code,
+                content_type: result.content_type.clone(),
+                content_length: result.content_length.map(|length| length as usize),
+                response_time_ms: None,
+                title: None,
+                forms_count: result.forms_found,
+                inputs_count: result.inputs_found,
+                parameters: (!result.parameters.is_empty())
+                    .then(|| serde_json::to_string(&result.parameters).unwrap_or_default()),
+                service_type: rinzler_core::tech::classify_service(
+                    result.content_type.as_deref(),
+                    &result.url,
+                    result.status_code,
+                ),
+                headers: None,
+                body_sample: None,
+                content_hash: result.content_hash.clone(),
+            })
+            .collect();
+
+        // Compare with each page's last crawl before this session's copy is stored
+        let phase = Instant::now();
+        for result in results {
+            if let Ok(previous) = self.db.get_previous_node(&result.url, self.session_id) {
+                self.changes.record(rinzler_core::diff::classify_node_change(
+                    previous.as_ref(),
+                    result.status_code,
+                    result.content_length.map(|length| length as i64),
+                    result.content_hash.as_deref(),
+                ));
+            }
+        }
+
+        // Insert nodes
+        let node_ids = match self.db.insert_nodes_batch(self.map_id, &nodes) {
+            Ok(node_ids) => node_ids,
+            Err(e) => {
+                self.persistence += phase.elapsed();
+                eprintln!(
+                    "  {} Failed to insert {} nodes: {}",
+                    "⚠".yellow(),
+                    nodes.len(),
+                    e
+                );
+                return vec![Vec::new(); results.len()];
+            }
+        };
+
+        for (result, &node_id) in results.iter().zip(&node_ids) {
+            self.node_ids.insert(result.url.clone(), node_id);
+            self.pending_edges.push((
+                node_id,
+                result.url.clone(),
+                result.links_found.clone(),
+                result.redirected_to.clone(),
+            ));
+
+            // Record the exchange for HAR export
+            let response_headers = serde_json::to_string(&result.headers).ok();
+            let _ = self.db.log_http_transaction(
+                self.session_id,
+                Some(node_id),
+                "GET",
+                &result.url,
+                self.request_headers.as_deref(),
+                result.status_code,
+                response_headers.as_deref(),
+                Some(result.response_time.as_millis() as u64),
+            );
+        }
+        self.persistence += phase.elapsed();
+
+        // --no-analyze stores the map only
+        if !self.analyze {
+            return vec![Vec::new(); results.len()];
+        }
+
+        // Run security checks
+        let phase = Instant::now();
+        let mut page_findings = Vec::with_capacity(results.len());
+        let mut page_technologies = Vec::with_capacity(results.len());
+        for (result, &node_id) in results.iter().zip(&node_ids) {
+            let mut findings = rinzler_core::security::analyze_crawl_result(result, node_id);
+            findings.extend(self.checks.analyze(result, node_id));
+
+            // CORS preflight probe (only sent to REST API nodes)
+            if let Some(ref client) = self.probe_client {
+                findings.extend(rinzler_core::probe::check_cors_preflight(client, result, node_id).await);
+            }
+
+            page_findings.push(findings);
+            page_technologies.push((node_id, rinzler_core::tech::detect_technologies(result)));
+        }
+        self.analysis += phase.elapsed();
+
+        // Insert findings (the recorder drops them once --max-findings is reached)
+        let phase = Instant::now();
+        let findings: Vec<rinzler_core::data::Finding> = page_findings.iter().flatten().cloned().collect();
+        let _ = recorder.record_batch(&findings);
+
+        // Record fingerprinted technologies
+        for (node_id, technologies) in page_technologies {
+            for tech in technologies {
+                let _ = self.db.insert_technology(
+                    node_id,
+                    tech.category,
+                    &tech.name,
+                    tech.version.as_deref(),
+                    tech.detection_method,
+                    Some(&tech.evidence),
+                    tech.confidence,
+                );
+            }
+        }
+        self.persistence += phase.elapsed();
+
+        page_findings
+    }
+
+    /// Link pages to the stored pages they point at. Returns the time spent analysing
+    /// and writing to the database; `crawl_secs` is left at zero for the caller to fill in.
+    pub fn finish(self) -> ScanTimings {
+        let phase = Instant::now();
+        for (source_id, source_url, links, redirected_to) in &self.pending_edges {
+            let source_host = Url::parse(source_url).ok().and_then(|u| u.host_str().map(String::from));
+
+            for link in links {
+                let Some(&target_id) = self.node_ids.get(link) else {
+                    continue;
+                };
+                if target_id == *source_id {
+                    continue;
+                }
+                let target_host = Url::parse(link).ok().and_then(|u| u.host_str().map(String::from));
+                let edge_type = if target_host == source_host {
+                    rinzler_core::data::EdgeType::Navigation
+                } else {
+                    rinzler_core::data::EdgeType::Reference
+                };
+                let _ = self.db.insert_edge(self.map_id, *source_id, target_id, edge_type);
+            }
+
+            if let Some(redirect) = redirected_to
+                && let Some(&target_id) = self.node_ids.get(redirect)
+                && target_id != *source_id
+            {
+                let _ = self.db.insert_edge(self.map_id, *source_id, target_id, rinzler_core::data::EdgeType::Redirect);
+            }
+        }
+        let persistence = self.persistence + phase.elapsed();
+
+        ScanTimings {
+            crawl_secs: 0.0,
+            analysis_secs: self.analysis.as_secs_f64(),
+            persistence_secs: persistence.as_secs_f64(),
+            total_secs: self.started.elapsed().as_secs_f64(),
+            overlapping: false,
+        }
+    }
+}
+
+/// Most crawl results written to the database in one flush
+pub const PERSIST_BATCH_SIZE: usize = 50;
+
+/// Store already-collected crawl results through a `CrawlPersister`
+pub async fn persist_crawl_results(
+    db: &Database,
+    session_id: &str,
+    map_id: &str,
+    results: &[rinzler_scanner::result::CrawlResult],
+    recorder: &mut FindingRecorder<'_>,
+    analyze: bool,
+) -> ScanTimings {
+    let mut persister = CrawlPersister::new(db, session_id, map_id, analyze);
+    for batch in results.chunks(PERSIST_BATCH_SIZE) {
+        persister.persist_batch(batch, recorder).await;
+    }
+    persister.finish()
+}
+
+/// A finding in the crawl monitor's display form
+fn tui_security_finding(f: &rinzler_core::data::Finding) -> crawl_monitor::SecurityFinding {
+    let severity_str = match f.severity {
+        rinzler_core::data::Severity::Critical => "critical",
+        rinzler_core::data::Severity::High => "high",
+        rinzler_core::data::Severity::Medium => "medium",
+        rinzler_core::data::Severity::Low => "low",
+        rinzler_core::data::Severity::Info => "info",
+    };
+
+    crawl_monitor::SecurityFinding {
+        title: f.title.clone(),
+        severity: severity_str.to_string(),
+        finding_type: f.finding_type.as_str().to_string(),
+        description: f.description.clone(),
+        impact: f.impact.clone().unwrap_or_else(|| "No impact information available".to_string()),
+        remediation: f.remediation.clone().unwrap_or_else(|| "No remediation available".to_string()),
+        cwe: f.cwe_id.clone(),
+        owasp: f.owasp_category.clone(),
+        cvss: f.cvss_score,
+        confidence: f.confidence.as_str().to_string(),
+    }
+}
+
+/// What a streaming crawl persister stored, handed back with its database
+pub struct PersistOutcome {
+    pub db: Database,
+    pub timings: ScanTimings,
+    pub nodes: usize,
+    pub findings_recorded: usize,
+    pub findings_dropped: usize,
+    /// Stored pages that are new, changed or unchanged since the previous scan
+    pub changes: rinzler_core::diff::NodeChangeCounts,
+    /// Set if the dropped-findings count could not be saved
+    pub finish_error: Option<String>,
+}
+
+/// How a spawned crawl persister treats each page beyond storing it
+#[derive(Default)]
+pub struct PersistSettings {
+    /// Run the security checks and technology fingerprinting on each page
+    pub analyze: bool,
+    /// Stop recording findings once the session holds this many
+    pub max_findings: Option<usize>,
+    /// Registered plugin checks run after the built-in ones
+    pub checks: rinzler_core::plugin::CheckRegistry,
+    /// The crawl's client; CORS probes are only sent when it is given
+    pub probe_client: Option<reqwest::Client>,
+    /// Headers the crawl's client sends, logged with each fetch
+    pub request_headers: Vec<(String, String)>,
+}
+
+/// Spawn a thread that owns `db` and persists every crawl result sent on the returned
+/// channel as it arrives; `on_findings` sees each page's findings once stored.
+/// The thread finishes, linking edges, when every sender has been dropped.
+/// It runs its own runtime because a SQLite connection can't be shared across tasks.
+pub fn spawn_crawl_persister(
+    db: Database,
+    session_id: String,
+    map_id: String,
+    settings: PersistSettings,
+    on_findings: impl Fn(&rinzler_scanner::result::CrawlResult, &[rinzler_core::data::Finding]) + Send + 'static,
+) -> (
+    tokio::sync::mpsc::UnboundedSender<rinzler_scanner::result::CrawlResult>,
+    std::thread::JoinHandle<PersistOutcome>,
+) {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<rinzler_scanner::result::CrawlResult>();
+    let handle = std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to start persistence runtime");
+        let (timings, nodes, changes, findings_recorded, findings_dropped, finish_error) = {
+            let mut recorder = FindingRecorder::new(&db, &session_id, settings.max_findings);
+            let mut persister = CrawlPersister::new(&db, &session_id, &map_id, settings.analyze)
+                .with_checks(settings.checks)
+                .with_request_headers(&settings.request_headers);
+            if let Some(client) = settings.probe_client {
+                persister = persister.with_probe_client(client);
+            }
+            runtime.block_on(async {
+                // Flush whatever has queued up since the last write, up to a batch
+                while let Some(result) = rx.recv().await {
+                    let mut batch = vec![result];
+                    while batch.len() < PERSIST_BATCH_SIZE {
+                        match rx.try_recv() {
+                            Ok(result) => batch.push(result),
+                            Err(_) => break,
+                        }
+                    }
+
+                    let page_findings = persister.persist_batch(&batch, &mut recorder).await;
+                    for (result, findings) in batch.iter().zip(&page_findings) {
+                        on_findings(result, findings);
+                    }
+                }
+            });
+            let nodes = persister.stored();
+            let changes = persister.changes();
+            let finish_error = recorder.finish().err().map(|e| e.to_string());
+            (persister.finish(), nodes, changes, recorder.recorded(), recorder.dropped(), finish_error)
+        };
+
+        PersistOutcome {
+            db,
+            timings,
+            nodes,
+            changes,
+            findings_recorded,
+            findings_dropped,
+            finish_error,
+        }
+    });
+
+    (tx, handle)
+}
+
+pub async fn handle_fuzz(sub_matches: &ArgMatches) {
+    let url = sub_matches.get_one::<Url>("url");
+    let hosts_file = sub_matches.get_one::<PathBuf>("hosts-file");
+    let wordlist_files = sub_matches.get_many::<PathBuf>("wordlist-file");
+    let (config_path, config) = load_rinzler_config();
+    let threads = config::resolve(
+        sub_matches.get_one::<usize>("threads").copied(),
+        config.threads,
+        10,
+    );
+    let user_agent = sub_matches
+        .get_one::<String>("user-agent")
+        .cloned()
+        .or_else(|| config.user_agent.clone());
+    let (request, request_delay_ms) = parse_request_settings(sub_matches, &config);
+    let full_body = sub_matches.get_flag("full-body");
+    let body = sub_matches.get_one::<String>("data").cloned();
+    // Default to HEAD unless --full-body is specified; a body without --method implies POST
+    let method = match sub_matches.get_one::<String>("method") {
+        Some(method) => match rinzler_core::fuzz::Method::from_bytes(
+            method.to_ascii_uppercase().as_bytes(),
+        ) {
+            Ok(method) => method,
+            Err(_) => {
+                eprintln!("✗ Invalid --method: {}", method);
+                std::process::exit(1);
+            }
+        },
+        None if body.is_some() => rinzler_core::fuzz::Method::POST,
+        None if full_body => rinzler_core::fuzz::Method::GET,
+        None => rinzler_core::fuzz::Method::HEAD,
+    };
+    let timeout = *sub_matches.get_one::<u64>("timeout").unwrap_or(&5);
+    let wildcard_filter = !sub_matches.get_flag("no-wildcard-filter");
+    let dry_run = sub_matches.get_flag("dry-run");
+    let quiet = sub_matches.get_flag("quiet");
+    let fail_on = sub_matches
+        .get_one::<String>("fail-on")
+        .and_then(|severity| severity.parse::<rinzler_core::data::Severity>().ok());
+    let max_recursion_depth = *sub_matches
+        .get_one::<usize>("recursion-depth")
+        .unwrap_or(&rinzler_core::fuzz::DEFAULT_MAX_RECURSION_DEPTH);
+    let (pool_size, pool_idle_timeout_secs) = parse_pool_settings(sub_matches);
+
+    // Parse status code filters
+    let include_status = parse_status_filter(sub_matches, "match-code");
+    let exclude_status = parse_status_filter(sub_matches, "filter-code");
+
+    // Get database path
+    let db_path = {
+        let expanded = shellexpand::tilde("~/.config/rinzler/rinzler.db");
+        let path = PathBuf::from(expanded.as_ref());
+        if path.exists() { Some(path) } else { None }
+    };
+
+    // A resumed run skips every URL its session already got a response for
+    let resume = sub_matches.get_one::<String>("resume");
+    let resumed = resume.map(|session_id| {
+        let loaded = db_path
+            .as_deref()
+            .ok_or_else(|| "No database found; run 'rinzler init' first".to_string())
+            .and_then(|path| {
+                Database::new(path).map_err(|e| format!("Failed to open database: {}", e))
+            })
+            .and_then(|db| load_fuzz_resume(&db, session_id));
+        match loaded {
+            Ok(resumed) => {
+                println!(
+                    "Resuming session {}: {} URL(s) already tested, {} hit(s) carried over\n",
+                    session_id,
+                    resumed.tested.len(),
+                    resumed.hits.len()
+                );
+                resumed
+            }
+            Err(e) => {
+                eprintln!("✗ {}", e);
+                std::process::exit(1);
+            }
+        }
+    });
+
+    // Load URLs from source; a spec replaces --url as the target list, and a
+    // resumed run without targets continues the session's own
+    let urls = match (sub_matches.get_one::<String>("openapi"), &resumed) {
+        (Some(source), _) => load_openapi_urls(source, url).await,
+        (None, Some(resumed)) if url.is_none() && hosts_file.is_none() => {
+            Ok(resumed.seed_urls.clone())
+        }
+        (None, _) => load_urls_from_source(url, hosts_file),
+    };
+    let urls = match urls {
+        Ok(urls) => urls,
+        Err(e) => {
             eprintln!("✗ {}", e);
             std::process::exit(1);
         }
     };
+    if let Some(ref resumed) = resumed
+        && let Err(e) = check_resume_targets(&resumed.seed_urls, &urls)
+    {
+        eprintln!("✗ {}", e);
+        std::process::exit(1);
+    }
 
     // Load wordlist - use default if not specified
     let default_wordlist_path = {
@@ -678,9 +1796,12 @@ pub async fn handle_fuzz(sub_matches: &ArgMatches) {
         PathBuf::from(expanded.as_ref())
     };
 
-    let wordlist_path = wordlist_file.cloned().unwrap_or(default_wordlist_path);
+    let wordlist_paths: Vec<PathBuf> = match wordlist_files {
+        Some(paths) => paths.cloned().collect(),
+        None => vec![default_wordlist_path],
+    };
 
-    let wordlist = match rinzler_core::fuzz::load_wordlist(&wordlist_path) {
+    let wordlist = match rinzler_core::fuzz::load_wordlists(&wordlist_paths) {
         Ok(words) => words,
         Err(e) => {
             eprintln!("✗ Failed to load wordlist: {}", e);
@@ -689,45 +1810,157 @@ pub async fn handle_fuzz(sub_matches: &ArgMatches) {
         }
     };
 
-    // Print fuzz configuration
-    println!("\n🎯 Fuzzing {} target(s)", urls.len());
-    println!("Workers: {}", threads);
-    println!(
-        "Wordlist: {} entries from {}",
-        wordlist.len(),
-        wordlist_path.display()
-    );
-    println!("Method: {}", if use_head { "HEAD" } else { "GET" });
-    println!("Timeout: {}s", timeout);
-    println!("Total requests: {}\n", urls.len() * wordlist.len());
+    // Print fuzz configuration; a quiet dry run prints only the count
+    if !(dry_run && quiet) {
+        println!("\n🎯 Fuzzing {} target(s)", urls.len());
+        if let Some(ref config_path) = config_path {
+            println!("Config: {}", config_path.display());
+        }
+        println!("Workers: {}", threads);
+        println!(
+            "Wordlist: {} entries from {}",
+            wordlist.len(),
+            wordlist_paths
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        println!("Method: {}", method);
+        if let Some(ref user_agent) = user_agent {
+            println!("User-Agent: {}", user_agent);
+        }
+        println!("Timeout: {}s", timeout);
+        if let Some(codes) = sub_matches.get_one::<String>("match-code") {
+            println!("Match codes: {}", codes);
+        }
+        if let Some(codes) = sub_matches.get_one::<String>("filter-code") {
+            println!("Filter codes: {}", codes);
+        }
+        if !wildcard_filter {
+            println!("Wildcard filter: disabled");
+        }
+        println!("Recursion depth: {}", max_recursion_depth);
+        println!("Total requests: {}\n", urls.len() * wordlist.len());
+    }
 
-    // Get database path
-    let db_path = {
-        let expanded = shellexpand::tilde("~/.config/rinzler/rinzler.db");
-        let path = PathBuf::from(expanded.as_ref());
-        if path.exists() { Some(path) } else { None }
+    let (skip_urls, resumed_hits) = match resumed {
+        Some(resumed) => (resumed.tested, resumed.hits),
+        None => (HashSet::new(), Vec::new()),
     };
+    let seed_urls_json = serde_json::to_string(&urls).unwrap();
 
     // Execute fuzzing
-    let options = rinzler_core::fuzz::FuzzOptions {
+    let mut options = rinzler_core::fuzz::FuzzOptions {
         base_urls: urls,
         wordlist,
         threads,
         show_progress_bars: true,
-        use_head_requests: use_head,
+        method,
+        body,
         timeout_secs: timeout,
         db_path,
+        include_status,
+        exclude_status,
+        wildcard_filter,
+        pool_size,
+        pool_idle_timeout_secs,
+        max_recursion_depth,
+        user_agent,
+        request,
+        request_delay_ms,
+        skip_urls,
+        on_tested: None,
+        resumed_hits,
+        on_hit: None,
     };
 
+    if dry_run {
+        let options = rinzler_core::fuzz::FuzzOptions {
+            show_progress_bars: !quiet,
+            ..options
+        };
+        match rinzler_core::fuzz::build_fuzz_targets(&options) {
+            Ok(targets) if quiet => println!("{}", targets.len()),
+            Ok(targets) => {
+                for (url, source) in &targets {
+                    match source {
+                        rinzler_core::fuzz::FuzzSource::Database => println!("{} [DB]", url),
+                        _ => println!("{}", url),
+                    }
+                }
+                println!(
+                    "\n{} Dry run: {} request(s) would be sent, before recursion",
+                    "✓".green().bold(),
+                    targets.len()
+                );
+            }
+            Err(e) => {
+                eprintln!("✗ {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    // Track the run as a fuzz session so it can be resumed if interrupted
+    let mut tracking = None;
+    if let Some(ref path) = options.db_path {
+        let session = Database::new(path).and_then(|db| {
+            let session_id = match resume {
+                Some(session_id) => {
+                    db.resume_session(session_id)?;
+                    session_id.clone()
+                }
+                None => db.create_session("fuzz", &seed_urls_json)?,
+            };
+            Ok((db, session_id))
+        });
+        match session {
+            Ok((db, session_id)) => {
+                println!("Session ID: {}\n", session_id.bright_white());
+                let (tx, writer) = spawn_fuzz_progress_writer(db, session_id.clone());
+                let hit_tx = tx.clone();
+                options.on_hit = Some(Arc::new(move |hit: &rinzler_core::fuzz::FuzzHit| {
+                    let _ = hit_tx.send(FuzzProgress::Hit(hit.clone()));
+                }));
+                options.on_tested = Some(Arc::new(move |url: &str| {
+                    let _ = tx.send(FuzzProgress::Tested(url.to_string()));
+                }));
+                tracking = Some((session_id, writer));
+            }
+            Err(e) => eprintln!("⚠ Fuzz session not tracked, this run can't be resumed: {}", e),
+        }
+    }
+
     let start_time = std::time::Instant::now();
-    let results = match rinzler_core::fuzz::execute_fuzz(options).await {
+    let outcome = rinzler_core::fuzz::execute_fuzz(options).await;
+    let duration = start_time.elapsed();
+
+    // The callback went away with the options, so the writer drains and finishes
+    if let Some((session_id, writer)) = tracking {
+        match writer.join() {
+            Ok(db) => {
+                let status = if outcome.is_ok() {
+                    db.complete_session(&session_id)
+                } else {
+                    db.fail_session(&session_id)
+                };
+                if let Err(e) = status {
+                    eprintln!("⚠ Failed to update session status: {}", e);
+                }
+            }
+            Err(_) => eprintln!("⚠ Fuzz progress writer panicked; session {} may be incomplete", session_id),
+        }
+    }
+
+    let results = match outcome {
         Ok(results) => results,
         Err(e) => {
             eprintln!("✗ Fuzzing failed: {}", e);
             std::process::exit(1);
         }
     };
-    let duration = start_time.elapsed();
 
     println!("\n✓ Fuzzing complete!");
     println!(
@@ -739,29 +1972,939 @@ pub async fn handle_fuzz(sub_matches: &ArgMatches) {
         results.len() as f64 / duration.as_secs_f64()
     );
 
-    // Generate and display report
-    let report = rinzler_core::fuzz::generate_fuzz_report(&results);
-    println!("{}", report);
+    // Save report to file if requested, otherwise display it
+    let format = sub_matches
+        .get_one::<String>("format")
+        .map(|s| s.as_str())
+        .unwrap_or("text");
+    let group_by_content_type = sub_matches.get_flag("group-by-type");
+
+    match sub_matches.get_one::<PathBuf>("output") {
+        Some(path) => match save_fuzz_report(&results, format, group_by_content_type, path) {
+            Ok(_) => println!(
+                "{} Report saved to: {}",
+                "✓".green().bold(),
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!("✗ {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => {
+            // Only color the report when it goes straight to a terminal
+            let style = rinzler_core::fuzz::FuzzReportStyle {
+                color: !sub_matches.get_flag("no-color") && io::stdout().is_terminal(),
+                group_by_content_type,
+            };
+            let report = rinzler_core::fuzz::generate_fuzz_report_with_style(&results, style);
+            println!("{}", report);
+        }
+    }
+
+    // Hits are checked with the crawl's interesting-file rules; fuzzing records no other findings
+    if let Some(threshold) = fail_on {
+        let failures = rinzler_core::fuzz::fuzz_findings(&results)
+            .iter()
+            .filter(|finding| finding.severity.rank() >= threshold.rank())
+            .count();
+        if failures == 0 {
+            println!(
+                "{} No findings at or above {} severity",
+                "✓".green().bold(),
+                threshold.as_str()
+            );
+        } else {
+            eprintln!(
+                "✗ --fail-on {}: {} finding{} at or above {} severity",
+                threshold.as_str(),
+                failures,
+                if failures == 1 { "" } else { "s" },
+                threshold.as_str()
+            );
+            std::process::exit(2);
+        }
+    }
 }
 
-pub fn handle_plugin_list() {
-    println!("Listing plugins");
-    // TODO: Implement plugin listing
+/// What an interrupted fuzz session left for `--resume` to continue from
+#[derive(Debug)]
+pub struct FuzzResume {
+    /// Targets the session was started with
+    pub seed_urls: Vec<String>,
+    /// URLs that already got a response; they are not requested again
+    pub tested: HashSet<String>,
+    /// Responses it reported or expanded, carried into the new report and recursion
+    pub hits: Vec<rinzler_core::fuzz::FuzzHit>,
 }
 
-pub fn handle_plugin_register(args: &ArgMatches) {
-    let file = args.get_one::<PathBuf>("file").unwrap();
-    let name = args.get_one::<String>("name").unwrap();
-    println!(
-        "Registering plugin '{}' from file: {}",
-        name,
-        file.display()
-    );
-    // TODO: Implement plugin registration
+/// Load an interrupted fuzz session's targets, tested URLs and hits; errors if
+/// the session doesn't exist or isn't a fuzz session
+pub fn load_fuzz_resume(db: &Database, session_id: &str) -> Result<FuzzResume, String> {
+    match db.get_session_scan_type(session_id) {
+        Ok(Some(scan_type)) if scan_type == "fuzz" => {}
+        Ok(Some(scan_type)) => {
+            return Err(format!(
+                "Session {} is a {} session, not a fuzz session",
+                session_id, scan_type
+            ));
+        }
+        Ok(None) => return Err(format!("Session not found: {}", session_id)),
+        Err(e) => return Err(format!("Failed to look up session: {}", e)),
+    }
+
+    let seed_urls = db
+        .get_session_seed_urls(session_id)
+        .map_err(|e| format!("Failed to load session targets: {}", e))?
+        .and_then(|json| serde_json::from_str::<Vec<String>>(&json).ok())
+        .ok_or_else(|| format!("Session {} has no readable targets", session_id))?;
+    let mut tested = db
+        .get_tested_urls(session_id)
+        .map_err(|e| format!("Failed to load tested URLs: {}", e))?;
+    let hits = db
+        .get_fuzz_hits(session_id)
+        .map_err(|e| format!("Failed to load fuzz hits: {}", e))?;
+    // A hit is written before its URL is marked tested; don't request it twice
+    tested.extend(hits.iter().map(|hit| hit.result.url.clone()));
+
+    Ok(FuzzResume {
+        seed_urls,
+        tested,
+        hits,
+    })
 }
 
-pub fn handle_plugin_unregister(args: &ArgMatches) {
-    let name = args.get_one::<String>("name").unwrap();
-    println!("Unregistering plugin: {}", name);
-    // TODO: Implement plugin unregistration
+/// Refuse to resume a session against targets other than the ones it was started with
+pub fn check_resume_targets(seed_urls: &[String], urls: &[String]) -> Result<(), String> {
+    let seeds: HashSet<&String> = seed_urls.iter().collect();
+    let targets: HashSet<&String> = urls.iter().collect();
+    if seeds == targets {
+        Ok(())
+    } else {
+        Err(format!(
+            "Session targets {} don't match this run's; drop --url/--hosts-file/--openapi to reuse them",
+            seed_urls.join(", ")
+        ))
+    }
+}
+
+/// Progress a fuzz run sends to `spawn_fuzz_progress_writer()`
+pub enum FuzzProgress {
+    /// A URL got a response
+    Tested(String),
+    /// A response was reported or expanded
+    Hit(rinzler_core::fuzz::FuzzHit),
+}
+
+/// Spawn a thread that owns `db` and records the progress sent on the returned channel
+/// against `session_id`, a batch per transaction; a batch's hits are written before its
+/// tested URLs. The thread hands the database back once every sender has been dropped.
+pub fn spawn_fuzz_progress_writer(
+    db: Database,
+    session_id: String,
+) -> (
+    std::sync::mpsc::Sender<FuzzProgress>,
+    std::thread::JoinHandle<Database>,
+) {
+    let (tx, rx) = std::sync::mpsc::channel::<FuzzProgress>();
+    let handle = std::thread::spawn(move || {
+        while let Ok(progress) = rx.recv() {
+            let mut batch = vec![progress];
+            while batch.len() < PERSIST_BATCH_SIZE {
+                match rx.try_recv() {
+                    Ok(progress) => batch.push(progress),
+                    Err(_) => break,
+                }
+            }
+
+            let mut tested = Vec::new();
+            let mut hits = Vec::new();
+            for progress in batch {
+                match progress {
+                    FuzzProgress::Tested(url) => tested.push(url),
+                    FuzzProgress::Hit(hit) => hits.push(hit),
+                }
+            }
+            if !hits.is_empty()
+                && let Err(e) = db.record_fuzz_hits(&session_id, &hits)
+            {
+                tracing::warn!("Failed to record fuzz hits: {}", e);
+            }
+            if !tested.is_empty()
+                && let Err(e) = db.record_tested_urls(&session_id, &tested)
+            {
+                tracing::warn!("Failed to record tested URLs: {}", e);
+            }
+        }
+        db
+    });
+
+    (tx, handle)
+}
+
+/// Render fuzz results in the requested format and write them to a file.
+/// Saved text reports are never colored.
+pub fn save_fuzz_report(
+    results: &[rinzler_core::fuzz::FuzzResult],
+    format: &str,
+    group_by_content_type: bool,
+    path: &Path,
+) -> Result<(), String> {
+    let content = match format {
+        "text" => rinzler_core::fuzz::generate_fuzz_report_with_style(
+            results,
+            rinzler_core::fuzz::FuzzReportStyle {
+                color: false,
+                group_by_content_type,
+            },
+        ),
+        "json" => rinzler_core::fuzz::generate_fuzz_json_report(results)
+            .map_err(|e| format!("Failed to generate JSON: {}", e))?,
+        _ => return Err(format!("Unknown format: {}", format)),
+    };
+
+    rinzler_core::report::save_report(&content, path)
+        .map_err(|e| format!("Failed to save report: {}", e))
+}
+
+/// Parse an optional comma-separated status code argument, exiting on invalid input
+fn parse_status_filter(sub_matches: &ArgMatches, arg: &str) -> Option<Vec<u16>> {
+    let spec = sub_matches.get_one::<String>(arg)?;
+    match rinzler_core::fuzz::parse_status_codes(spec) {
+        Ok(codes) => Some(codes),
+        Err(e) => {
+            eprintln!("✗ Invalid --{}: {}", arg, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Read connection pool flags, exiting if either is zero
+fn parse_pool_settings(sub_matches: &ArgMatches) -> (Option<usize>, u64) {
+    let pool_size = sub_matches.get_one::<usize>("pool-size").copied();
+    let idle_timeout = *sub_matches
+        .get_one::<u64>("pool-idle-timeout")
+        .unwrap_or(&rinzler_core::crawl::DEFAULT_POOL_IDLE_TIMEOUT_SECS);
+
+    if pool_size == Some(0) {
+        eprintln!("✗ Invalid --pool-size: must be at least 1");
+        std::process::exit(1);
+    }
+    if idle_timeout == 0 {
+        eprintln!("✗ Invalid --pool-idle-timeout: must be at least 1 second");
+        std::process::exit(1);
+    }
+
+    (pool_size, idle_timeout)
+}
+
+/// Load rinzler.toml from the working directory or ~/.config/rinzler/, exiting if it is malformed
+fn load_rinzler_config() -> (Option<PathBuf>, RinzlerConfig) {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let config_dir = shellexpand::tilde("~/.config/rinzler/");
+    let paths = config::config_search_paths(&cwd, Path::new(config_dir.as_ref()));
+    match config::load_config(&paths) {
+        Ok(Some((path, config))) => (Some(path), config),
+        Ok(None) => (None, RinzlerConfig::default()),
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Merge --header/--proxy/--delay over the config file's request defaults
+fn parse_request_settings(
+    sub_matches: &ArgMatches,
+    config: &RinzlerConfig,
+) -> (RequestSettings, Option<u64>) {
+    let mut headers = Vec::new();
+    for header in sub_matches.get_many::<String>("header").into_iter().flatten() {
+        match config::parse_header(header) {
+            Ok(header) => headers.push(header),
+            Err(e) => {
+                eprintln!("✗ Invalid --header: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+    let proxy = sub_matches.get_one::<String>("proxy").cloned();
+    let delay_ms = sub_matches
+        .get_one::<u64>("delay")
+        .copied()
+        .or(config.delay_ms)
+        .filter(|delay_ms| *delay_ms > 0);
+
+    let mut request = config.request_settings(&headers, proxy);
+    if let Some(auth) = parse_auth(sub_matches) {
+        request.auth = Some(auth);
+    }
+
+    (request, delay_ms)
+}
+
+/// Credentials from --auth-basic or --auth-bearer, if either was given
+fn parse_auth(sub_matches: &ArgMatches) -> Option<rinzler_core::crawl::Auth> {
+    if let Some(credentials) = sub_matches.get_one::<String>("auth-basic") {
+        match rinzler_core::crawl::Auth::parse_basic(credentials) {
+            Ok(auth) => return Some(auth),
+            Err(e) => {
+                eprintln!("✗ Invalid --auth-basic: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+    sub_matches
+        .get_one::<String>("auth-bearer")
+        .map(|token| rinzler_core::crawl::Auth::Bearer(token.clone()))
+}
+
+/// Open the default database for report commands, exiting with a hint if it is missing
+fn open_report_database() -> Database {
+    let db_path = shellexpand::tilde("~/.config/rinzler/rinzler.db");
+    match Database::new(Path::new(db_path.as_ref())) {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("✗ Failed to open database: {}", e);
+            eprintln!("  Run 'rinzler init' first to create the database.");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print a rendered report, or save it when `--output` was given
+fn emit_report(content: &str, output_path: Option<&PathBuf>) {
+    match output_path {
+        Some(path) => match rinzler_core::report::save_report(content, path) {
+            Ok(_) => println!(
+                "{} Report saved to: {}",
+                "✓".green().bold(),
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!("✗ Failed to save report: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => print!("{}", content),
+    }
+}
+
+pub fn handle_report(sub_matches: &ArgMatches) {
+    let input_path = sub_matches.get_one::<PathBuf>("from-jsonl");
+    let session = sub_matches.get_one::<String>("session");
+    let output_path = sub_matches.get_one::<PathBuf>("output");
+    let format = sub_matches
+        .get_one::<String>("format")
+        .map(|s| s.as_str())
+        .unwrap_or("text");
+    let unique_findings = sub_matches.get_flag("unique-findings");
+    let group_findings = sub_matches.get_flag("group-findings");
+    let baseline_session = sub_matches.get_one::<String>("baseline");
+    // A baseline diff is drawn on the sitemap, so it implies --include-sitemap;
+    // JUnit reports each clean page as a passing test case
+    let include_sitemap = sub_matches.get_flag("include-sitemap")
+        || baseline_session.is_some()
+        || format == "junit";
+
+    if baseline_session.is_some() && format != "text" {
+        eprintln!("✗ --baseline is only supported with --format text");
+        std::process::exit(1);
+    }
+    if group_findings && format != "text" && format != "json" {
+        eprintln!("✗ --group-findings is only supported with --format text or json");
+        std::process::exit(1);
+    }
+
+    // HAR is exported straight from the recorded HTTP transactions
+    if format == "har" {
+        let Some(session_id) = session else {
+            eprintln!("✗ --format har requires --session");
+            std::process::exit(1);
+        };
+
+        let db = open_report_database();
+        if !db.list_sessions().is_ok_and(|ids| ids.contains(session_id)) {
+            eprintln!("✗ Session {} not found", session_id);
+            std::process::exit(1);
+        }
+        match db.export_har(session_id) {
+            Ok(har) => emit_report(&har, output_path),
+            Err(e) => {
+                eprintln!("✗ Failed to export HAR: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    // The interactive graph is drawn from the stored nodes and edges
+    if format == "html-graph" {
+        let Some(session_id) = session else {
+            eprintln!("✗ --format html-graph requires --session");
+            std::process::exit(1);
+        };
+
+        let db = open_report_database();
+        if !db.list_sessions().is_ok_and(|ids| ids.contains(session_id)) {
+            eprintln!("✗ Session {} not found", session_id);
+            std::process::exit(1);
+        }
+        let html = rinzler_core::graph::gather_graph_data(&db, session_id)
+            .map_err(|e| e.to_string())
+            .and_then(|data| {
+                if data.nodes.len() > rinzler_core::graph::LARGE_GRAPH_NODES {
+                    eprintln!(
+                        "{} {} nodes: the graph layout will be approximate and slow to open",
+                        "⚠".yellow(),
+                        data.nodes.len()
+                    );
+                }
+                rinzler_core::graph::generate_html_graph(&data).map_err(|e| e.to_string())
+            });
+        match html {
+            Ok(html) => emit_report(&html, output_path),
+            Err(e) => {
+                eprintln!("✗ Failed to export graph: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let mut report_data = match (session, input_path) {
+        (Some(session_id), _) => {
+            let db = open_report_database();
+            match rinzler_core::report::gather_report_data(&db, session_id, include_sitemap, unique_findings) {
+                Ok(data) => data,
+                Err(rusqlite::Error::QueryReturnedNoRows) => {
+                    eprintln!("✗ Session {} not found", session_id);
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("✗ Failed to load session {}: {}", session_id, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        (None, Some(input_path)) => {
+            let file = match fs::File::open(input_path) {
+                Ok(file) => file,
+                Err(e) => {
+                    eprintln!("✗ Failed to open {}: {}", input_path.display(), e);
+                    std::process::exit(1);
+                }
+            };
+
+            match rinzler_core::report::read_jsonl_report(
+                io::BufReader::new(file),
+                include_sitemap,
+                unique_findings,
+            ) {
+                Ok(data) => data,
+                Err(e) => {
+                    eprintln!("✗ Failed to read {}: {}", input_path.display(), e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        (None, None) => unreachable!("clap requires --from-jsonl or --session"),
+    };
+
+    if let Some(baseline_session) = baseline_session {
+        let db = open_report_database();
+
+        match rinzler_core::report::gather_sitemap_nodes(&db, baseline_session) {
+            Ok(nodes) if nodes.is_empty() => {
+                eprintln!("✗ No pages recorded for baseline session {}", baseline_session);
+                std::process::exit(1);
+            }
+            Ok(nodes) => report_data.sitemap_baseline = Some(nodes),
+            Err(e) => {
+                eprintln!("✗ Failed to load baseline session: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if group_findings {
+        report_data.grouped_findings = Some(rinzler_core::report::group_findings(&report_data.findings));
+    }
+
+    let report_content = match format {
+        "json" => rinzler_core::report::generate_json_report(&report_data).map_err(|e| e.to_string()),
+        "jsonl" => {
+            rinzler_core::report::generate_jsonl_report(&report_data).map_err(|e| e.to_string())
+        }
+        "sarif" => {
+            rinzler_core::report::generate_sarif_report(&report_data).map_err(|e| e.to_string())
+        }
+        "toml" => {
+            rinzler_core::report::generate_toml_report(&report_data).map_err(|e| e.to_string())
+        }
+        "github-issues" => Ok(rinzler_core::report::generate_github_issues_report(&report_data)),
+        "junit" => Ok(rinzler_core::report::generate_junit_report(&report_data)),
+        // Only color the text report when it goes straight to a terminal
+        _ => Ok(rinzler_core::report::generate_text_report_with_color(
+            &report_data,
+            output_path.is_none() && io::stdout().is_terminal(),
+        )),
+    };
+
+    let report_content = match report_content {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("✗ Failed to generate {} report: {}", format, e);
+            std::process::exit(1);
+        }
+    };
+
+    emit_report(&report_content, output_path);
+}
+
+/// Count non-empty, non-comment entries the way the fuzzer loads them
+fn count_wordlist_entries(content: &str) -> usize {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .count()
+}
+
+/// Rewrite `<config_dir>/wordlists/default.txt` with the bundled wordlist.
+/// Returns the entry counts before and after the update.
+pub fn refresh_default_wordlist(config_dir: &Path) -> Result<(usize, usize), String> {
+    let wordlist_dir = config_dir.join("wordlists");
+    let wordlist_path = wordlist_dir.join("default.txt");
+
+    let before = match fs::read_to_string(&wordlist_path) {
+        Ok(content) => count_wordlist_entries(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+        Err(e) => {
+            return Err(format!(
+                "Failed to read {}: {}",
+                wordlist_path.display(),
+                e
+            ));
+        }
+    };
+
+    fs::create_dir_all(&wordlist_dir)
+        .map_err(|e| format!("Failed to create {}: {}", wordlist_dir.display(), e))?;
+    fs::write(&wordlist_path, DEFAULT_WORDLIST)
+        .map_err(|e| format!("Failed to write {}: {}", wordlist_path.display(), e))?;
+
+    Ok((before, count_wordlist_entries(DEFAULT_WORDLIST)))
+}
+
+pub fn handle_wordlist_update(args: &ArgMatches) {
+    let config_dir = args.get_one::<String>("PATH").unwrap();
+    let force = args.get_flag("force");
+    let expanded_config_dir = shellexpand::tilde(config_dir);
+    let config_dir = Path::new(expanded_config_dir.as_ref());
+    let wordlist_path = config_dir.join("wordlists").join("default.txt");
+
+    if !force {
+        println!(
+            "{} This will overwrite {}",
+            "⚠".yellow().bold(),
+            wordlist_path.display().to_string().bright_white()
+        );
+        let response = print_prompt("Continue? [y/N]:");
+        if response != "y" && response != "yes" {
+            println!("{} Wordlist left unchanged", "→".blue());
+            return;
+        }
+    }
+
+    match refresh_default_wordlist(config_dir) {
+        Ok((before, after)) => println!(
+            "{} Updated {} ({} → {} entries)",
+            "✓".green().bold(),
+            wordlist_path.display().to_string().bright_white(),
+            before.to_string().cyan(),
+            after.to_string().cyan()
+        ),
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Write the commented default rinzler.toml into `config_dir`, refusing to
+/// replace an existing file unless `force` is set
+pub fn write_default_config(config_dir: &Path, force: bool) -> Result<PathBuf, String> {
+    let config_path = config_dir.join(config::CONFIG_FILE_NAME);
+    if config_path.exists() && !force {
+        return Err(format!(
+            "{} already exists (use --force to overwrite)",
+            config_path.display()
+        ));
+    }
+
+    fs::create_dir_all(config_dir)
+        .map_err(|e| format!("Failed to create {}: {}", config_dir.display(), e))?;
+    fs::write(&config_path, config::DEFAULT_CONFIG)
+        .map_err(|e| format!("Failed to write {}: {}", config_path.display(), e))?;
+    Ok(config_path)
+}
+
+pub fn handle_config_init(args: &ArgMatches) {
+    let config_dir = args.get_one::<String>("PATH").unwrap();
+    let expanded_config_dir = shellexpand::tilde(config_dir);
+    let force = args.get_flag("force");
+
+    match write_default_config(Path::new(expanded_config_dir.as_ref()), force) {
+        Ok(config_path) => println!(
+            "{} Wrote {}",
+            "✓".green().bold(),
+            config_path.display().to_string().bright_white()
+        ),
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+pub async fn handle_serve(args: &ArgMatches) {
+    let db_path = shellexpand::tilde(args.get_one::<String>("db-path").unwrap()).to_string();
+    let port = *args.get_one::<u16>("port").unwrap();
+    let addr = std::net::SocketAddr::new(*args.get_one::<std::net::IpAddr>("bind").unwrap(), port);
+
+    if !Database::exists(Path::new(&db_path)) {
+        eprintln!("✗ No database at {}", db_path);
+        eprintln!("  Run 'rinzler init' first to create the database.");
+        std::process::exit(1);
+    }
+
+    let db = match Database::new(Path::new(&db_path)) {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("✗ Failed to open database: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let listener = match tokio::net::TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("✗ Failed to bind {}: {}", addr, e);
+            std::process::exit(1);
+        }
+    };
+
+    println!(
+        "{} Serving {} on {}",
+        "✓".green().bold(),
+        db_path.bright_white(),
+        format!("http://{}/sessions", addr).cyan()
+    );
+
+    if let Err(e) = rinzler_core::serve::serve(db, listener).await {
+        eprintln!("✗ Server error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+pub async fn handle_replay_session(args: &ArgMatches) {
+    let session_id = args.get_one::<String>("session").unwrap();
+    let timeout = *args.get_one::<u64>("timeout").unwrap();
+    // Logged credentials are redacted, so auth comes from this run's flags
+    let request = rinzler_core::crawl::RequestSettings {
+        auth: parse_auth(args),
+        ..Default::default()
+    };
+
+    let db = open_report_database();
+    if !db.list_sessions().is_ok_and(|ids| ids.contains(session_id)) {
+        eprintln!("✗ Session {} not found", session_id);
+        std::process::exit(1);
+    }
+    let transactions = match db.get_http_transactions(session_id) {
+        Ok(transactions) => transactions,
+        Err(e) => {
+            eprintln!("✗ Failed to load HTTP transactions: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if transactions.is_empty() {
+        eprintln!("✗ Session {} has no recorded HTTP transactions", session_id);
+        std::process::exit(1);
+    }
+
+    println!("Replaying {} requests from session {}", transactions.len(), session_id);
+    let outcomes = match rinzler_core::replay::replay_transactions(&transactions, timeout, request).await {
+        Ok(outcomes) => outcomes,
+        Err(e) => {
+            eprintln!("✗ {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let drifted: Vec<_> = outcomes.iter().filter(|o| o.has_drift()).collect();
+    for outcome in &drifted {
+        println!("  {} {} {}", "✗".red().bold(), outcome.method, outcome.url);
+        for change in &outcome.drift {
+            println!("      {}", change);
+        }
+    }
+
+    if drifted.is_empty() {
+        println!(
+            "{} No drift across {} requests",
+            "✓".green().bold(),
+            outcomes.len()
+        );
+    } else {
+        eprintln!("✗ Drift in {} of {} requests", drifted.len(), outcomes.len());
+        std::process::exit(1);
+    }
+}
+
+pub fn handle_diff(args: &ArgMatches) {
+    let base_session = args.get_one::<String>("base").unwrap();
+    let new_session = args.get_one::<String>("new").unwrap();
+
+    let db = open_report_database();
+    for session_id in [base_session, new_session] {
+        if !db.list_sessions().is_ok_and(|ids| ids.contains(session_id)) {
+            eprintln!("✗ Session {} not found", session_id);
+            std::process::exit(1);
+        }
+    }
+
+    let diff = match rinzler_core::diff::diff_sessions(&db, base_session, new_session) {
+        Ok(diff) => diff,
+        Err(e) => {
+            eprintln!("✗ Failed to compare sessions: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    println!("Comparing session {} against {}", new_session, base_session);
+    if diff.is_empty() {
+        println!("{} No changes", "✓".green().bold());
+        return;
+    }
+
+    if !diff.new_urls.is_empty() {
+        println!("\nNew URLs ({}):", diff.new_urls.len());
+        for url in &diff.new_urls {
+            println!("  {} {}", "+".green().bold(), url);
+        }
+    }
+    if !diff.removed_urls.is_empty() {
+        println!("\nRemoved URLs ({}):", diff.removed_urls.len());
+        for url in &diff.removed_urls {
+            println!("  {} {}", "-".red().bold(), url);
+        }
+    }
+    if !diff.new_findings.is_empty() {
+        println!("\nNew findings ({}):", diff.new_findings.len());
+        for (url, title) in &diff.new_findings {
+            println!("  {} {} ({})", "+".yellow().bold(), title, url);
+        }
+    }
+}
+
+pub fn handle_sessions_purge(args: &ArgMatches) {
+    let before = args.get_one::<String>("before").map(|date| {
+        data::parse_date_timestamp(date).unwrap_or_else(|e| {
+            eprintln!("✗ Invalid --before: {}", e);
+            std::process::exit(1);
+        })
+    });
+    let filter = SessionPurgeFilter {
+        before,
+        status: args.get_one::<String>("status").cloned(),
+    };
+
+    let db_path = shellexpand::tilde("~/.config/rinzler/rinzler.db");
+    let db = match Database::new(Path::new(db_path.as_ref())) {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("✗ Failed to open database: {}", e);
+            eprintln!("  Run 'rinzler init' first to create the database.");
+            std::process::exit(1);
+        }
+    };
+
+    match db.purge_sessions(&filter) {
+        Ok(0) => println!("No sessions matched"),
+        Ok(deleted) => println!(
+            "{} Purged {} session{}",
+            "✓".green().bold(),
+            deleted,
+            if deleted == 1 { "" } else { "s" }
+        ),
+        Err(e) => {
+            eprintln!("✗ Failed to purge sessions: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Render findings as a compact table: id, severity, type, title, URL
+pub fn format_findings_table(findings: &[FindingSummary]) -> String {
+    let mut table = format!(
+        "{:>6}  {:<8}  {:<23}  {:<40}  {}\n",
+        "ID", "SEVERITY", "TYPE", "TITLE", "URL"
+    );
+    for finding in findings {
+        let title: String = if finding.title.chars().count() > 40 {
+            finding.title.chars().take(39).chain(['…']).collect()
+        } else {
+            finding.title.clone()
+        };
+        table.push_str(&format!(
+            "{:>6}  {:<8}  {:<23}  {:<40}  {}\n",
+            finding.id, finding.severity, finding.finding_type, title, finding.url
+        ));
+    }
+    table
+}
+
+pub fn handle_findings_list(args: &ArgMatches) {
+    let session_id = args.get_one::<String>("session").unwrap();
+    let severity = args.get_one::<String>("severity").map(String::as_str);
+    let finding_type = args.get_one::<String>("type").map(String::as_str);
+
+    let db_path = shellexpand::tilde("~/.config/rinzler/rinzler.db");
+    let db = match Database::new(Path::new(db_path.as_ref())) {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("✗ Failed to open database: {}", e);
+            eprintln!("  Run 'rinzler init' first to create the database.");
+            std::process::exit(1);
+        }
+    };
+
+    match db.query_findings(session_id, severity, finding_type) {
+        Ok(findings) if findings.is_empty() => println!("No findings matched"),
+        Ok(findings) => {
+            print!("{}", format_findings_table(&findings));
+            println!(
+                "\n{} finding{}",
+                findings.len(),
+                if findings.len() == 1 { "" } else { "s" }
+            );
+        }
+        Err(e) => {
+            eprintln!("✗ Failed to query findings: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+pub fn handle_findings_mark_fp(args: &ArgMatches) {
+    let finding_id = *args.get_one::<i64>("id").unwrap();
+    let value = !args.get_flag("unset");
+
+    let db_path = shellexpand::tilde("~/.config/rinzler/rinzler.db");
+    let db = match Database::new(Path::new(db_path.as_ref())) {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("✗ Failed to open database: {}", e);
+            eprintln!("  Run 'rinzler init' first to create the database.");
+            std::process::exit(1);
+        }
+    };
+
+    match db.mark_false_positive(finding_id, value) {
+        Ok(_) if value => println!(
+            "{} Finding {} marked as a false positive",
+            "✓".green().bold(),
+            finding_id
+        ),
+        Ok(_) => println!(
+            "{} Finding {} is no longer a false positive",
+            "✓".green().bold(),
+            finding_id
+        ),
+        Err(rusqlite::Error::QueryReturnedNoRows) => {
+            eprintln!("✗ No finding with ID {}", finding_id);
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("✗ Failed to update finding: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+pub fn handle_plugin_list() {
+    let db = open_report_database();
+    let plugins = match db.list_plugins() {
+        Ok(plugins) => plugins,
+        Err(e) => {
+            eprintln!("✗ Failed to list plugins: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if plugins.is_empty() {
+        println!("No plugins registered");
+        return;
+    }
+
+    let name_width = plugins.iter().map(|p| p.name.len()).max().unwrap_or(0);
+    for plugin in &plugins {
+        // Flag files that have moved or broken since registration
+        let status = match rinzler_core::plugin::load_check(&plugin.name, Path::new(&plugin.path)) {
+            Ok(_) => String::new(),
+            Err(e) => format!("  {} {}", "⚠".yellow(), e),
+        };
+        println!(
+            "{:<width$}  {}{}",
+            plugin.name.bright_white(),
+            plugin.path,
+            status,
+            width = name_width
+        );
+    }
+}
+
+pub fn handle_plugin_register(args: &ArgMatches) {
+    let file = args.get_one::<PathBuf>("file").unwrap();
+    let name = args.get_one::<String>("name").unwrap();
+
+    // Refuse a file the crawl could not load
+    if let Err(e) = rinzler_core::plugin::load_check(name, file) {
+        eprintln!("✗ Invalid plugin {}: {}", file.display(), e);
+        std::process::exit(1);
+    }
+    let path = fs::canonicalize(file).unwrap_or_else(|_| file.clone());
+
+    let db = open_report_database();
+    match db.register_plugin(name, &path.to_string_lossy()) {
+        Ok(()) => println!(
+            "{} Registered plugin '{}' from {}",
+            "✓".green().bold(),
+            name,
+            path.display()
+        ),
+        Err(e) => {
+            eprintln!("✗ Failed to register plugin: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+pub fn handle_plugin_unregister(args: &ArgMatches) {
+    let name = args.get_one::<String>("name").unwrap();
+
+    let db = open_report_database();
+    match db.unregister_plugin(name) {
+        Ok(true) => println!("{} Unregistered plugin '{}'", "✓".green().bold(), name),
+        Ok(false) => {
+            eprintln!("✗ No plugin named '{}'", name);
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("✗ Failed to unregister plugin: {}", e);
+            std::process::exit(1);
+        }
+    }
 }
@@ -2,11 +2,13 @@
 #[path = "handlers.rs"]
 pub mod handlers;
 
+pub mod commands;
+
 // Re-export commonly used handler functions for convenience
 pub use handlers::{load_urls_from_file, load_urls_from_source, parse_url_line};
 
 // Re-export crawl functionality from rinzler-core
 pub use rinzler_core::crawl::{
     CrawlOptions, CrawlProgressCallback, FollowMode, execute_crawl, extract_url_path,
-    generate_crawl_report,
+    generate_crawl_report, generate_crawl_report_with_color,
 };
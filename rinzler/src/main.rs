@@ -1,17 +1,25 @@
-use commands::command_argument_builder;
+use rinzler::commands::command_argument_builder;
 use rinzler::handlers;
 use rinzler_core::print_banner;
 
-mod commands;
-
 #[tokio::main]
 async fn main() {
     let cmd = command_argument_builder();
     let chosen_command = cmd.get_matches();
     let quiet = chosen_command.get_flag("quiet");
 
-    // Show banner unless --quiet flag is set
-    if !quiet {
+    // The crawl monitor owns the terminal, so its logs always go to a file
+    let tui = chosen_command.subcommand_name() == Some("crawl");
+    handlers::init_logging(
+        chosen_command.get_count("verbose"),
+        chosen_command
+            .get_one::<std::path::PathBuf>("log-file")
+            .map(|path| path.as_path()),
+        tui,
+    );
+
+    // Show banner unless --quiet flag is set; completion scripts must stay clean
+    if !quiet && chosen_command.subcommand_name() != Some("completions") {
         print_banner();
     }
 
@@ -37,6 +45,36 @@ async fn main() {
         },
         Some(("crawl", primary_command)) => handlers::handle_crawl(primary_command).await,
         Some(("fuzz", primary_command)) => handlers::handle_fuzz(primary_command).await,
+        Some(("report", primary_command)) => handlers::handle_report(primary_command),
+        Some(("wordlist", primary_command)) => match primary_command.subcommand() {
+            Some(("update", secondary_command)) => {
+                handlers::handle_wordlist_update(secondary_command)
+            }
+            _ => unreachable!("clap should ensure we don't get here"),
+        },
+        Some(("config", primary_command)) => match primary_command.subcommand() {
+            Some(("init", secondary_command)) => handlers::handle_config_init(secondary_command),
+            _ => unreachable!("clap should ensure we don't get here"),
+        },
+        Some(("serve", primary_command)) => handlers::handle_serve(primary_command).await,
+        Some(("replay-session", primary_command)) => {
+            handlers::handle_replay_session(primary_command).await
+        }
+        Some(("diff", primary_command)) => handlers::handle_diff(primary_command),
+        Some(("sessions", primary_command)) => match primary_command.subcommand() {
+            Some(("purge", secondary_command)) => {
+                handlers::handle_sessions_purge(secondary_command)
+            }
+            _ => unreachable!("clap should ensure we don't get here"),
+        },
+        Some(("findings", primary_command)) => match primary_command.subcommand() {
+            Some(("list", secondary_command)) => handlers::handle_findings_list(secondary_command),
+            Some(("mark-fp", secondary_command)) => {
+                handlers::handle_findings_mark_fp(secondary_command)
+            }
+            _ => unreachable!("clap should ensure we don't get here"),
+        },
+        Some(("completions", primary_command)) => handlers::handle_completions(primary_command),
         Some(("plugin", primary_command)) => match primary_command.subcommand() {
             Some(("list", _)) => handlers::handle_plugin_list(),
             Some(("register", secondary_command)) => {
@@ -50,12 +88,3 @@ async fn main() {
         _ => unreachable!("clap should ensure we don't get here"),
     }
 }
-
-pub const CLAP_STYLING: clap::builder::styling::Styles = clap::builder::styling::Styles::styled()
-    .header(clap_cargo::style::HEADER)
-    .usage(clap_cargo::style::USAGE)
-    .literal(clap_cargo::style::LITERAL)
-    .placeholder(clap_cargo::style::PLACEHOLDER)
-    .error(clap_cargo::style::ERROR)
-    .valid(clap_cargo::style::VALID)
-    .invalid(clap_cargo::style::INVALID);
@@ -0,0 +1,72 @@
+// Tests for generating shell completion scripts
+
+use clap_complete::Shell;
+use rinzler::commands::command_argument_builder;
+
+fn completions(shell: Shell) -> String {
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut command_argument_builder(), "rinzler", &mut script);
+    String::from_utf8(script).unwrap()
+}
+
+#[test]
+fn test_completions_shell_argument_parses() {
+    for shell in ["bash", "zsh", "fish", "powershell"] {
+        let matches = command_argument_builder()
+            .try_get_matches_from(["rinzler", "completions", shell])
+            .unwrap();
+        let (_, sub_matches) = matches.subcommand().unwrap();
+        assert!(sub_matches.get_one::<Shell>("SHELL").is_some());
+    }
+    assert!(
+        command_argument_builder()
+            .try_get_matches_from(["rinzler", "completions", "tcsh"])
+            .is_err()
+    );
+}
+
+#[test]
+fn test_bash_completions_cover_subcommands_and_flags() {
+    let script = completions(Shell::Bash);
+
+    assert!(script.contains("complete -F _rinzler"));
+    assert!(script.contains("rinzler__workspace__create)"));
+    // Crawl flags, including globals propagated from the root
+    let lines: Vec<&str> = script.lines().collect();
+    let crawl = lines
+        .iter()
+        .position(|line| line.trim() == "rinzler__crawl)")
+        .expect("crawl should have a case");
+    let crawl_opts = lines[crawl + 1].trim();
+    assert!(crawl_opts.starts_with("opts="));
+    assert!(crawl_opts.contains("--url"));
+    assert!(crawl_opts.contains("--verbose"));
+}
+
+#[test]
+fn test_bash_completions_offer_possible_values() {
+    let script = completions(Shell::Bash);
+    assert!(script.contains(r#"compgen -W "text json jsonl sarif junit csv html markdown""#));
+}
+
+#[test]
+fn test_zsh_completions_are_native() {
+    let script = completions(Shell::Zsh);
+    assert!(script.starts_with("#compdef rinzler"));
+    assert!(!script.contains("bashcompinit"));
+    assert!(script.contains("(text json jsonl sarif junit csv html markdown)"));
+}
+
+#[test]
+fn test_fish_completions_include_crawl() {
+    let script = completions(Shell::Fish);
+    assert!(script.contains("complete -c rinzler"));
+    assert!(script.contains(r#"-a "crawl""#));
+}
+
+#[test]
+fn test_powershell_completions_include_crawl() {
+    let script = completions(Shell::PowerShell);
+    assert!(script.contains("Register-ArgumentCompleter -Native -CommandName 'rinzler'"));
+    assert!(script.contains("'rinzler;crawl'"));
+}
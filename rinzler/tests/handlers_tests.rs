@@ -1,7 +1,14 @@
 use rinzler::handlers::*;
+use rinzler_core::crawl::{
+    DEFAULT_MAX_BODY_BYTES, DEFAULT_MAX_PER_HOST, DEFAULT_POOL_IDLE_TIMEOUT_SECS, DEFAULT_POOL_SIZE,
+    RequestSettings,
+};
+use rinzler_core::data::{Database, FindingRecorder, FindingSummary, Severity};
+use rinzler_core::fuzz::{FuzzHit, FuzzResult, FuzzSource};
+use rinzler_scanner::result::CrawlResult;
 use std::io::Write;
 use std::path::PathBuf;
-use tempfile::NamedTempFile;
+use tempfile::{NamedTempFile, TempDir};
 use url::Url;
 
 #[test]
@@ -16,6 +23,20 @@ fn test_parse_url_line_without_scheme() {
     assert_eq!(result, Some("http://example.com".to_string()));
 }
 
+#[test]
+fn test_parse_url_line_ipv6_literal() {
+    assert_eq!(
+        parse_url_line("http://[::1]:8080"),
+        Some("http://[::1]:8080".to_string())
+    );
+    assert_eq!(
+        parse_url_line("[::1]:8080"),
+        Some("http://[::1]:8080".to_string())
+    );
+    assert_eq!(parse_url_line("::1"), Some("http://[::1]".to_string()));
+    assert_eq!(parse_url_line("fe80::1"), Some("http://[fe80::1]".to_string()));
+}
+
 #[test]
 fn test_parse_url_line_invalid() {
     let result = parse_url_line("not a valid url!!!");
@@ -51,6 +72,30 @@ fn test_load_urls_from_file() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn test_load_urls_from_file_bare_ips() -> Result<(), Box<dyn std::error::Error>> {
+    let mut temp_file = NamedTempFile::new()?;
+    writeln!(temp_file, "192.168.1.10")?;
+    writeln!(temp_file, "10.0.0.5:8080")?;
+    writeln!(temp_file, "[::1]:3000")?;
+
+    let urls = load_urls_from_file(&PathBuf::from(temp_file.path()))?;
+
+    assert_eq!(
+        urls,
+        vec![
+            "http://192.168.1.10",
+            "http://10.0.0.5:8080",
+            "http://[::1]:3000"
+        ]
+    );
+    for url in &urls {
+        assert!(Url::parse(url)?.host().is_some());
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_load_urls_from_file_empty() {
     let mut temp_file = NamedTempFile::new().unwrap();
@@ -86,7 +131,6 @@ fn test_load_urls_from_source_no_input() {
 
 #[test]
 fn test_generate_crawl_report() {
-    use rinzler_scanner::result::CrawlResult;
     use std::time::Duration;
 
     let results = vec![
@@ -98,7 +142,15 @@ fn test_generate_crawl_report() {
             response_time: Duration::from_millis(100),
             links_found: vec!["https://example.com/about".to_string()],
             forms_found: 1,
+            password_forms_found: 0,
+            inputs_found: 0,
+            parameters: vec![],
             scripts_found: 2,
+            redirected_to: None,
+            headers: vec![],
+            content_hash: None,
+            body_truncated: false,
+            body_sample: None,
             error: None,
         },
         CrawlResult {
@@ -109,7 +161,15 @@ fn test_generate_crawl_report() {
             response_time: Duration::from_millis(50),
             links_found: vec![],
             forms_found: 0,
+            password_forms_found: 0,
+            inputs_found: 0,
+            parameters: vec![],
             scripts_found: 0,
+            redirected_to: None,
+            headers: vec![],
+            content_hash: None,
+            body_truncated: false,
+            body_sample: None,
             error: None,
         },
     ];
@@ -125,3 +185,716 @@ fn test_generate_crawl_report() {
     assert!(report.contains("application/json"));
     assert!(!report.contains("text/html")); // Should be hidden
 }
+
+#[test]
+fn test_save_fuzz_report_text() -> Result<(), Box<dyn std::error::Error>> {
+    let results = vec![
+        FuzzResult {
+            url: "https://example.com/admin".to_string(),
+            status_code: 200,
+            content_length: Some(1024),
+            content_type: Some("text/html".to_string()),
+            source: FuzzSource::Initial,
+        },
+        FuzzResult {
+            url: "https://example.com/missing".to_string(),
+            status_code: 404,
+            content_length: None,
+            content_type: None,
+            source: FuzzSource::Initial,
+        },
+    ];
+
+    let temp_dir = TempDir::new()?;
+    let path = temp_dir.path().join("fuzz.txt");
+    save_fuzz_report(&results, "text", false, &path)?;
+
+    assert!(path.exists());
+    let content = std::fs::read_to_string(&path)?;
+    assert!(content.contains("FUZZ RESULTS"));
+    assert!(content.contains("https://example.com/admin"));
+    assert!(!content.contains("https://example.com/missing"));
+
+    Ok(())
+}
+
+#[test]
+fn test_refresh_default_wordlist() -> Result<(), Box<dyn std::error::Error>> {
+    let config_dir = TempDir::new()?;
+    let wordlist_dir = config_dir.path().join("wordlists");
+    std::fs::create_dir_all(&wordlist_dir)?;
+    std::fs::write(wordlist_dir.join("default.txt"), "# stale\nadmin\napi\n")?;
+
+    let (before, after) = refresh_default_wordlist(config_dir.path())?;
+
+    let embedded = include_str!("../wordlists/default.txt");
+    let installed = std::fs::read_to_string(wordlist_dir.join("default.txt"))?;
+    assert_eq!(installed, embedded);
+    assert_eq!(before, 2);
+    assert!(after > before);
+
+    Ok(())
+}
+
+#[test]
+fn test_refresh_default_wordlist_creates_missing_file() -> Result<(), Box<dyn std::error::Error>> {
+    let config_dir = TempDir::new()?;
+
+    let (before, _) = refresh_default_wordlist(config_dir.path())?;
+
+    assert_eq!(before, 0);
+    assert!(config_dir.path().join("wordlists/default.txt").exists());
+
+    Ok(())
+}
+
+fn no_prompt(question: &str) -> String {
+    panic!("init prompted in non-interactive mode: {}", question)
+}
+
+#[test]
+fn test_run_init_assume_yes_creates_db_and_wordlist() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let config_dir = temp_dir.path().join("rinzler");
+    let options = InitOptions {
+        assume_yes: true,
+        ..Default::default()
+    };
+
+    run_init(&config_dir, options, &mut no_prompt);
+
+    assert!(Database::exists(&config_dir.join("rinzler.db")));
+    let installed = std::fs::read_to_string(config_dir.join("wordlists/default.txt"))?;
+    assert_eq!(installed, include_str!("../wordlists/default.txt"));
+
+    // A second run keeps what is there instead of asking or clobbering
+    std::fs::write(config_dir.join("wordlists/default.txt"), "custom
+")?;
+    run_init(&config_dir, options, &mut no_prompt);
+    assert_eq!(
+        std::fs::read_to_string(config_dir.join("wordlists/default.txt"))?,
+        "custom\n"
+    );
+    assert!(Database::exists(&config_dir.join("rinzler.db")));
+
+    Ok(())
+}
+
+#[test]
+fn test_run_init_no_wordlist_skips_install() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let config_dir = temp_dir.path().join("rinzler");
+    let options = InitOptions {
+        force: true,
+        no_wordlist: true,
+        ..Default::default()
+    };
+
+    run_init(&config_dir, options, &mut no_prompt);
+
+    assert!(Database::exists(&config_dir.join("rinzler.db")));
+    assert!(!config_dir.join("wordlists/default.txt").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_write_default_config() -> Result<(), Box<dyn std::error::Error>> {
+    let config_dir = TempDir::new()?;
+
+    let config_path = write_default_config(config_dir.path(), false)?;
+
+    assert_eq!(config_path, config_dir.path().join("rinzler.toml"));
+    let written = std::fs::read_to_string(&config_path)?;
+    assert_eq!(written, rinzler_core::config::DEFAULT_CONFIG);
+
+    Ok(())
+}
+
+#[test]
+fn test_write_default_config_requires_force_to_overwrite() -> Result<(), Box<dyn std::error::Error>>
+{
+    let config_dir = TempDir::new()?;
+    let config_path = config_dir.path().join("rinzler.toml");
+    std::fs::write(&config_path, "threads = 4\n")?;
+
+    assert!(write_default_config(config_dir.path(), false).is_err());
+    assert_eq!(std::fs::read_to_string(&config_path)?, "threads = 4\n");
+
+    write_default_config(config_dir.path(), true)?;
+    assert_eq!(
+        std::fs::read_to_string(&config_path)?,
+        rinzler_core::config::DEFAULT_CONFIG
+    );
+
+    Ok(())
+}
+
+fn exposed_env_result() -> CrawlResult {
+    let mut result = CrawlResult::new("http://example.com/.env".to_string());
+    result.status_code = 200;
+    result.content_type = Some("text/plain".to_string());
+    result
+}
+
+#[tokio::test]
+async fn test_persist_crawl_results_records_findings() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let db = Database::new(&temp_dir.path().join("test.db"))?;
+    let session_id = db.create_session("crawl", "[]")?;
+    let map_id = db.create_map(&session_id)?;
+
+    let mut recorder = FindingRecorder::new(&db, &session_id, None);
+    persist_crawl_results(
+        &db,
+        &session_id,
+        &map_id,
+        &[exposed_env_result()],
+        &mut recorder,
+        true,
+    )
+    .await;
+
+    assert!(recorder.recorded() > 0);
+    let findings = db.get_findings_by_session(&session_id)?;
+    assert!(
+        findings
+            .iter()
+            .any(|(_, _, title, _)| title == "Environment File Exposed")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_persist_crawl_results_links_stored_pages() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let db = Database::new(&temp_dir.path().join("test.db"))?;
+    let session_id = db.create_session("crawl", "[]")?;
+    let map_id = db.create_map(&session_id)?;
+
+    let mut home = CrawlResult::new("http://example.com/".to_string());
+    home.status_code = 200;
+    home.links_found = vec![
+        "http://example.com/about".to_string(),
+        "http://other.example/".to_string(),
+        "http://example.com/never-crawled".to_string(),
+        "http://example.com/".to_string(),
+    ];
+    let mut about = CrawlResult::new("http://example.com/about".to_string());
+    about.status_code = 200;
+    let mut other = CrawlResult::new("http://other.example/".to_string());
+    other.status_code = 200;
+    let mut old = CrawlResult::new("http://example.com/old".to_string());
+    old.status_code = 301;
+    old.redirected_to = Some("http://example.com/about".to_string());
+
+    let mut recorder = FindingRecorder::new(&db, &session_id, None);
+    persist_crawl_results(
+        &db,
+        &session_id,
+        &map_id,
+        &[home, about, other, old],
+        &mut recorder,
+        false,
+    )
+    .await;
+
+    let ids: std::collections::HashMap<String, i64> = db
+        .get_nodes_by_session(&session_id)?
+        .into_iter()
+        .map(|(id, url, _, _)| (url, id))
+        .collect();
+    let edges = db.get_edges_by_session(&session_id)?;
+    assert_eq!(
+        edges,
+        vec![
+            (
+                ids["http://example.com/"],
+                ids["http://example.com/about"],
+                "navigation".to_string()
+            ),
+            (
+                ids["http://example.com/"],
+                ids["http://other.example/"],
+                "reference".to_string()
+            ),
+            (
+                ids["http://example.com/old"],
+                ids["http://example.com/about"],
+                "redirect".to_string()
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_persist_crawl_results_without_analysis() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let db = Database::new(&temp_dir.path().join("test.db"))?;
+    let session_id = db.create_session("crawl", "[]")?;
+    let map_id = db.create_map(&session_id)?;
+
+    let mut recorder = FindingRecorder::new(&db, &session_id, None);
+    persist_crawl_results(
+        &db,
+        &session_id,
+        &map_id,
+        &[exposed_env_result()],
+        &mut recorder,
+        false,
+    )
+    .await;
+
+    assert_eq!(db.get_nodes_by_session(&session_id)?.len(), 1);
+    let har: serde_json::Value = serde_json::from_str(&db.export_har(&session_id)?)?;
+    assert_eq!(har["log"]["entries"].as_array().map(Vec::len), Some(1));
+    assert_eq!(recorder.recorded(), 0);
+    assert!(db.get_findings_by_session(&session_id)?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_crawl_persister_stores_nodes_during_crawl() -> Result<(), Box<dyn std::error::Error>>
+{
+    use std::sync::Arc;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    let home = format!(
+        r#"<html><body><a href="{0}/fast">fast</a><a href="{0}/slow">slow</a></body></html>"#,
+        server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(home, "text/html"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/fast"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/slow"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(1500)))
+        .mount(&server)
+        .await;
+
+    let temp_dir = TempDir::new()?;
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::new(&db_path)?;
+    let session_id = db.create_session("crawl", "[]")?;
+    let map_id = db.create_map(&session_id)?;
+    let reader = Database::new(&db_path)?;
+
+    let (result_tx, persister) = spawn_crawl_persister(
+        db,
+        session_id.clone(),
+        map_id,
+        PersistSettings::default(),
+        |_, _| {},
+    );
+    let options = CrawlOptions {
+        urls: vec![format!("{}/", server.uri())],
+        threads: 2,
+        max_depth: 2,
+        follow_mode: FollowMode::Disabled,
+        show_progress_bars: false,
+        entry_paths: Vec::new(),
+        idle_timeout_secs: None,
+        max_pages: None,
+        max_queue_size: None,
+        max_path_depth: None,
+        scope_domain: None,
+        resume_from_url: None,
+        user_agent: None,
+        request: RequestSettings::default(),
+        request_delay_ms: None,
+        login: None,
+        max_per_host: DEFAULT_MAX_PER_HOST,
+        max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        skip_binary_bodies: true,
+        pool_size: DEFAULT_POOL_SIZE,
+        pool_idle_timeout_secs: DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+        paused: None,
+        stop: None,
+        client: None,
+    };
+    let result_callback = Arc::new(move |result: CrawlResult| {
+        let _ = result_tx.send(result);
+    });
+    let crawl = tokio::spawn(execute_crawl(options, None, Some(result_callback)));
+
+    // The home and fast pages are stored while /slow is still being fetched
+    let mut stored_early = false;
+    for _ in 0..100 {
+        let stored = reader
+            .get_nodes_by_session(&session_id)
+            .map(|nodes| nodes.len())
+            .unwrap_or(0);
+        if stored >= 2 {
+            stored_early = !crawl.is_finished();
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(stored_early);
+
+    assert_eq!(crawl.await??.len(), 3);
+    let outcome = persister.join().expect("persister thread");
+    assert_eq!(outcome.nodes, 3);
+    assert_eq!(reader.get_nodes_by_session(&session_id)?.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_spawned_persister_uses_crawl_client_settings() -> Result<(), Box<dyn std::error::Error>> {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    // The API only answers the preflight for requests carrying the crawl's key
+    let server = MockServer::start().await;
+    Mock::given(method("OPTIONS"))
+        .and(path("/api/users"))
+        .and(header("x-api-key", "secret"))
+        .respond_with(
+            ResponseTemplate::new(204)
+                .insert_header("access-control-allow-origin", "*")
+                .insert_header("access-control-allow-credentials", "true"),
+        )
+        .mount(&server)
+        .await;
+
+    let temp_dir = TempDir::new()?;
+    let db_path = temp_dir.path().join("test.db");
+    let db = Database::new(&db_path)?;
+    let session_id = db.create_session("crawl", "[]")?;
+    let map_id = db.create_map(&session_id)?;
+
+    let config = rinzler_scanner::ClientConfig {
+        request: RequestSettings {
+            headers: vec![("X-Api-Key".to_string(), "secret".to_string())],
+            auth: Some(rinzler_core::crawl::Auth::Bearer("token".to_string())),
+            ..RequestSettings::default()
+        },
+        ..rinzler_scanner::ClientConfig::default()
+    };
+    let (result_tx, persister) = spawn_crawl_persister(
+        db,
+        session_id.clone(),
+        map_id,
+        PersistSettings {
+            analyze: true,
+            probe_client: Some(rinzler_scanner::build_client(&config)?),
+            request_headers: config.default_headers(),
+            ..PersistSettings::default()
+        },
+        |_, _| {},
+    );
+    let mut result = CrawlResult::new(format!("{}/api/users", server.uri()));
+    result.status_code = 200;
+    result.content_type = Some("application/json".to_string());
+    result_tx.send(result)?;
+    drop(result_tx);
+
+    let outcome = persister.join().expect("persister thread");
+    let findings = outcome.db.get_findings_by_session(&session_id)?;
+    assert!(
+        findings
+            .iter()
+            .any(|(_, _, title, _)| title == "Permissive CORS Policy With Credentials")
+    );
+    // The fetch is logged with the headers the crawl sent, for replay and HAR export
+    let transactions = outcome.db.get_http_transactions(&session_id)?;
+    assert_eq!(transactions.len(), 1);
+    assert!(
+        transactions[0]
+            .request_headers
+            .contains(&("x-api-key".to_string(), "secret".to_string()))
+    );
+    // but never with the credentials themselves
+    assert!(
+        transactions[0]
+            .request_headers
+            .contains(&("authorization".to_string(), "<redacted>".to_string()))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_persist_crawl_results_reports_timings() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let db = Database::new(&temp_dir.path().join("test.db"))?;
+    let session_id = db.create_session("crawl", "[]")?;
+    let map_id = db.create_map(&session_id)?;
+
+    let results: Vec<CrawlResult> = (0..20)
+        .map(|i| {
+            let mut result = exposed_env_result();
+            result.url = format!("http://example.com/{}/.env", i);
+            result
+        })
+        .collect();
+
+    let mut recorder = FindingRecorder::new(&db, &session_id, None);
+    let timings =
+        persist_crawl_results(&db, &session_id, &map_id, &results, &mut recorder, true).await;
+
+    assert_eq!(timings.crawl_secs, 0.0);
+    assert!(timings.analysis_secs > 0.0);
+    assert!(timings.persistence_secs > 0.0);
+    let phases = timings.analysis_secs + timings.persistence_secs;
+    assert!(phases <= timings.total_secs);
+    assert!(timings.total_secs - phases < 0.25);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_output_dir_writes_every_report_after_crawl() -> Result<(), Box<dyn std::error::Error>> {
+    use rinzler_core::report::{ReportFileOutcome, gather_report_data, write_report_dir};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    let home = format!(r#"<html><body><a href="{}/.env">env</a></body></html>"#, server.uri());
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(home, "text/html"))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/.env"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw("SECRET=1", "text/plain"))
+        .mount(&server)
+        .await;
+
+    let temp_dir = TempDir::new()?;
+    let db = Database::new(&temp_dir.path().join("test.db"))?;
+    let session_id = db.create_session("crawl", "[]")?;
+    let map_id = db.create_map(&session_id)?;
+
+    let options = CrawlOptions {
+        urls: vec![format!("{}/", server.uri())],
+        threads: 2,
+        max_depth: 2,
+        follow_mode: FollowMode::Disabled,
+        show_progress_bars: false,
+        entry_paths: Vec::new(),
+        idle_timeout_secs: None,
+        max_pages: None,
+        max_queue_size: None,
+        max_path_depth: None,
+        scope_domain: None,
+        resume_from_url: None,
+        user_agent: None,
+        request: RequestSettings::default(),
+        request_delay_ms: None,
+        login: None,
+        max_per_host: DEFAULT_MAX_PER_HOST,
+        max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        skip_binary_bodies: true,
+        pool_size: DEFAULT_POOL_SIZE,
+        pool_idle_timeout_secs: DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+        paused: None,
+        stop: None,
+        client: None,
+    };
+    let results = execute_crawl(options, None, None).await?;
+    let mut recorder = FindingRecorder::new(&db, &session_id, None);
+    persist_crawl_results(&db, &session_id, &map_id, &results, &mut recorder, true).await;
+    recorder.finish()?;
+    db.complete_session(&session_id)?;
+
+    // The directory doesn't exist yet, parents included
+    let dir = temp_dir.path().join("artifacts").join("scan");
+    let data = gather_report_data(&db, &session_id, false, false)?;
+    let outcomes = write_report_dir(&data, &dir)?;
+
+    for file_name in ["report.txt", "report.json", "report.sarif"] {
+        assert!(dir.join(file_name).is_file(), "{} missing", file_name);
+    }
+    assert!(!dir.join("report.html").exists());
+    assert!(matches!(
+        outcomes.iter().find(|(name, _)| *name == "report.html"),
+        Some((_, ReportFileOutcome::Skipped(_)))
+    ));
+
+    let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(dir.join("report.json"))?)?;
+    assert!(json.is_object());
+    let text = std::fs::read_to_string(dir.join("report.txt"))?;
+    assert!(text.contains("Environment File Exposed"));
+
+    Ok(())
+}
+
+#[test]
+fn test_resume_url_in_scope_same_registrable_domain() {
+    let base = Url::parse("https://example.com/").unwrap();
+    let resume = Url::parse("https://app.example.com/settings/profile").unwrap();
+    assert!(resume_url_in_scope(&base, &resume, None));
+}
+
+#[test]
+fn test_resume_url_in_scope_other_domain() {
+    let base = Url::parse("https://example.com/").unwrap();
+    let resume = Url::parse("https://example.org/deep").unwrap();
+    assert!(!resume_url_in_scope(&base, &resume, None));
+}
+
+#[test]
+fn test_resume_url_in_scope_ip_literals() {
+    let base = Url::parse("http://[::1]:8080/").unwrap();
+    let same = Url::parse("http://[::1]:8080/app/settings").unwrap();
+    let other = Url::parse("http://[::2]:8080/").unwrap();
+    assert!(resume_url_in_scope(&base, &same, None));
+    assert!(!resume_url_in_scope(&base, &other, None));
+    assert!(resume_url_in_scope(&base, &same, Some("::1")));
+
+    let base = Url::parse("http://10.0.0.1/").unwrap();
+    let other = Url::parse("http://10.0.0.10/").unwrap();
+    assert!(!resume_url_in_scope(&base, &other, Some("10.0.0.1")));
+}
+
+#[test]
+fn test_resume_url_in_scope_uses_scope_domain() {
+    let base = Url::parse("https://www.example.com/").unwrap();
+    let inside = Url::parse("https://api.internal.example.com/v1").unwrap();
+    let outside = Url::parse("https://www.example.com/deep").unwrap();
+    assert!(resume_url_in_scope(
+        &base,
+        &inside,
+        Some("internal.example.com")
+    ));
+    assert!(!resume_url_in_scope(
+        &base,
+        &outside,
+        Some("internal.example.com")
+    ));
+}
+
+#[test]
+fn test_verbosity_level_mapping() {
+    use tracing::level_filters::LevelFilter;
+
+    assert_eq!(verbosity_level(0), LevelFilter::ERROR);
+    assert_eq!(verbosity_level(1), LevelFilter::WARN);
+    assert_eq!(verbosity_level(2), LevelFilter::INFO);
+    assert_eq!(verbosity_level(3), LevelFilter::DEBUG);
+    assert_eq!(verbosity_level(4), LevelFilter::TRACE);
+    assert_eq!(verbosity_level(u8::MAX), LevelFilter::TRACE);
+}
+
+#[test]
+fn test_format_findings_table() {
+    let findings = vec![
+        FindingSummary {
+            id: 7,
+            severity: "high".to_string(),
+            finding_type: "interesting_file".to_string(),
+            title: ".git exposed".to_string(),
+            url: "http://example.com/.git/HEAD".to_string(),
+        },
+        FindingSummary {
+            id: 12,
+            severity: "info".to_string(),
+            finding_type: "security_header_missing".to_string(),
+            title: "A".repeat(60),
+            url: "http://example.com/".to_string(),
+        },
+    ];
+
+    let table = format_findings_table(&findings);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].contains("SEVERITY"));
+    assert!(lines[1].contains(".git exposed"));
+    assert!(lines[1].ends_with("http://example.com/.git/HEAD"));
+    assert!(lines[2].contains(&format!("{}…", "A".repeat(39))));
+    assert!(!lines[2].contains(&"A".repeat(40)));
+}
+
+#[test]
+fn test_count_findings_at_or_above_threshold() {
+    let counts = vec![
+        ("critical".to_string(), 1),
+        ("high".to_string(), 2),
+        ("medium".to_string(), 3),
+        ("info".to_string(), 4),
+    ];
+
+    assert_eq!(count_findings_at_or_above(&counts, &Severity::Critical), 1);
+    assert_eq!(count_findings_at_or_above(&counts, &Severity::High), 3);
+    assert_eq!(count_findings_at_or_above(&counts, &Severity::Medium), 6);
+    assert_eq!(count_findings_at_or_above(&counts, &Severity::Low), 6);
+    assert_eq!(count_findings_at_or_above(&counts, &Severity::Info), 10);
+    assert_eq!(count_findings_at_or_above(&[], &Severity::Info), 0);
+}
+
+#[test]
+fn test_fuzz_progress_writer_feeds_resume() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let db = Database::new(&temp_dir.path().join("test.db"))?;
+    let session_id = db.create_session("fuzz", r#"["http://example.com"]"#)?;
+    let admin = FuzzHit {
+        result: FuzzResult {
+            url: "http://example.com/admin".to_string(),
+            status_code: 200,
+            content_length: None,
+            content_type: None,
+            source: FuzzSource::Initial,
+        },
+        depth: 0,
+    };
+
+    let (tx, writer) = spawn_fuzz_progress_writer(db, session_id.clone());
+    tx.send(FuzzProgress::Hit(admin.clone()))?;
+    tx.send(FuzzProgress::Tested("http://example.com/admin".to_string()))?;
+    tx.send(FuzzProgress::Tested("http://example.com/login".to_string()))?;
+    drop(tx);
+    let db = writer.join().expect("writer thread panicked");
+
+    let resume = load_fuzz_resume(&db, &session_id)?;
+    assert_eq!(resume.seed_urls, vec!["http://example.com"]);
+    assert_eq!(resume.tested.len(), 2);
+    assert!(resume.tested.contains("http://example.com/admin"));
+    assert_eq!(resume.hits, vec![admin]);
+    Ok(())
+}
+
+#[test]
+fn test_check_resume_targets_rejects_other_urls() {
+    let seeds = vec![
+        "http://example.com".to_string(),
+        "http://other.com".to_string(),
+    ];
+    let reordered = vec![
+        "http://other.com".to_string(),
+        "http://example.com".to_string(),
+    ];
+    assert!(check_resume_targets(&seeds, &reordered).is_ok());
+
+    let err = check_resume_targets(&seeds, &["http://example.com".to_string()]).unwrap_err();
+    assert!(err.contains("http://other.com"), "{}", err);
+}
+
+#[test]
+fn test_load_fuzz_resume_rejects_other_sessions() -> Result<(), Box<dyn std::error::Error>> {
+    let temp_dir = TempDir::new()?;
+    let db = Database::new(&temp_dir.path().join("test.db"))?;
+    let crawl_session = db.create_session("crawl", "[]")?;
+
+    let err = load_fuzz_resume(&db, &crawl_session).unwrap_err();
+    assert!(err.contains("not a fuzz session"));
+    let err = load_fuzz_resume(&db, "missing").unwrap_err();
+    assert!(err.contains("Session not found"));
+    Ok(())
+}